@@ -65,6 +65,7 @@ fn main() {
                     &values,
                     &periods,
                     anofox_fcst_core::InsufficientDataMode::Fail,
+                    false,
                 )
             },
         );
@@ -87,6 +88,7 @@ fn main() {
                     &values,
                     &periods,
                     anofox_fcst_core::InsufficientDataMode::Fail,
+                    false,
                 )
             },
         );
@@ -163,6 +165,7 @@ fn main() {
                         s,
                         &periods,
                         anofox_fcst_core::InsufficientDataMode::Fail,
+                        false,
                     )
                 })
                 .collect::<Vec<_>>()
@@ -237,6 +240,7 @@ fn main() {
             &values,
             &periods,
             anofox_fcst_core::InsufficientDataMode::Fail,
+            false,
         )
     });
 
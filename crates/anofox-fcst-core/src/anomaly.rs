@@ -0,0 +1,171 @@
+//! Anomaly detection on decomposition residuals.
+//!
+//! The series is split into trend, seasonal and remainder components with a
+//! robust MSTL decomposition; points whose remainder lies far from the bulk of
+//! the remainders (measured by a median/MAD robust z-score) are flagged.
+//!
+//! # Example Usage
+//!
+//! ```
+//! use anofox_fcst_core::anomaly::detect_anomalies;
+//!
+//! let mut values: Vec<f64> = (0..48)
+//!     .map(|i| 10.0 + (i % 12) as f64 + 0.1 * ((i * 7) % 5) as f64)
+//!     .collect();
+//! values[30] += 25.0;
+//!
+//! let result = detect_anomalies(&values, 12, 3.5).unwrap();
+//! assert!(result.is_anomaly[30]);
+//! ```
+
+use crate::decomposition::{mstl_decompose, InsufficientDataMode};
+use crate::error::{ForecastError, Result};
+use crate::stats::median;
+use crate::stats::robust_scale;
+
+/// Result of residual-based anomaly detection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnomalyResult {
+    /// Indices of flagged points, in increasing order
+    pub indices: Vec<usize>,
+    /// Robust z-score of the remainder at every point
+    pub scores: Vec<f64>,
+    /// Whether each point is flagged (`|score| > threshold`)
+    pub is_anomaly: Vec<bool>,
+}
+
+/// Robust z-scores `(x - median) / (1.4826 * MAD)`.
+///
+/// When the MAD is zero, points equal to the median score 0 and every other
+/// point scores ±infinity.
+fn robust_z_scores(values: &[f64]) -> Vec<f64> {
+    let center = median(values);
+    let scale = robust_scale(values);
+
+    values
+        .iter()
+        .map(|v| {
+            let deviation = v - center;
+            if deviation == 0.0 {
+                0.0
+            } else {
+                deviation / scale
+            }
+        })
+        .collect()
+}
+
+/// Detect anomalies from the remainder of a robust MSTL decomposition.
+///
+/// # Arguments
+/// * `values` - Time series values
+/// * `period` - Seasonal period (values below 2 remove the trend only)
+/// * `threshold` - Absolute robust z-score above which a point is flagged
+///   (3.5 is a common choice)
+///
+/// # Returns
+/// * `Result<AnomalyResult>` - Flagged indices, per-point scores and mask
+pub fn detect_anomalies(values: &[f64], period: usize, threshold: f64) -> Result<AnomalyResult> {
+    if !threshold.is_finite() || threshold <= 0.0 {
+        return Err(ForecastError::InvalidParameter {
+            param: "threshold".to_string(),
+            value: threshold.to_string(),
+            reason: "Threshold must be positive".to_string(),
+        });
+    }
+    if values.iter().any(|v| !v.is_finite()) {
+        return Err(ForecastError::InvalidInput(
+            "Values must be finite".to_string(),
+        ));
+    }
+    if values.len() < 3 {
+        return Err(ForecastError::InsufficientData {
+            needed: 3,
+            got: values.len(),
+        });
+    }
+
+    let periods: Vec<i32> = if period >= 2 {
+        vec![
+            i32::try_from(period).map_err(|_| ForecastError::InvalidParameter {
+                param: "period".to_string(),
+                value: period.to_string(),
+                reason: "Period is too large".to_string(),
+            })?,
+        ]
+    } else {
+        vec![]
+    };
+
+    let decomposition = mstl_decompose(values, &periods, InsufficientDataMode::Fail, true)?;
+    let remainder = decomposition.remainder.ok_or_else(|| {
+        ForecastError::ComputationError("Decomposition produced no remainder".to_string())
+    })?;
+
+    let scores = robust_z_scores(&remainder);
+    let is_anomaly: Vec<bool> = scores.iter().map(|s| s.abs() > threshold).collect();
+    let indices = is_anomaly
+        .iter()
+        .enumerate()
+        .filter(|(_, &flag)| flag)
+        .map(|(i, _)| i)
+        .collect();
+
+    Ok(AnomalyResult {
+        indices,
+        scores,
+        is_anomaly,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seasonal_with_noise(n: usize) -> Vec<f64> {
+        let mut state: u64 = 17;
+        (0..n)
+            .map(|i| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                let e = (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5;
+                let seasonal = 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 12.0).sin();
+                50.0 + 0.1 * i as f64 + seasonal + e
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_single_spike() {
+        let mut values = seasonal_with_noise(96);
+        values[40] += 15.0;
+
+        let result = detect_anomalies(&values, 12, 3.5).unwrap();
+        assert_eq!(result.indices, vec![40]);
+        assert_eq!(result.scores.len(), values.len());
+        assert_eq!(result.is_anomaly.iter().filter(|&&f| f).count(), 1);
+        assert!(result.scores[40] > 3.5);
+    }
+
+    #[test]
+    fn test_clean_series_has_no_anomalies() {
+        let values = seasonal_with_noise(96);
+        let result = detect_anomalies(&values, 12, 3.5).unwrap();
+        assert!(result.indices.is_empty());
+    }
+
+    #[test]
+    fn test_detect_anomalies_invalid_input() {
+        let values = seasonal_with_noise(96);
+        assert!(detect_anomalies(&values, 12, 0.0).is_err());
+        assert!(detect_anomalies(&[1.0, f64::NAN, 3.0], 1, 3.5).is_err());
+        // Fewer than two seasonal cycles
+        assert!(detect_anomalies(&values[..20], 12, 3.5).is_err());
+    }
+
+    #[test]
+    fn test_robust_z_scores_zero_mad() {
+        let scores = robust_z_scores(&[1.0, 1.0, 1.0, 5.0]);
+        assert_eq!(&scores[..3], &[0.0, 0.0, 0.0]);
+        assert!(scores[3].is_infinite());
+    }
+}
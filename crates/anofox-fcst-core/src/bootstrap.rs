@@ -67,12 +67,12 @@ pub fn bootstrap_intervals(
         predictor = predictor.seed(s);
     }
 
-    // Fit: compute actuals from residuals + a dummy forecast of zeros
-    // BootstrapPredictor.fit(forecasts, actuals) computes residuals = actuals - forecasts
-    // We have residuals directly, so actuals = residuals (with forecasts = zeros)
+    // BootstrapPredictor.fit(forecasts, actuals) stores forecasts - actuals and
+    // adds those errors to the point forecast, so pass the residuals as the
+    // "forecasts" against zero actuals to keep their sign (and skew).
     let zeros: Vec<f64> = vec![0.0; residuals.len()];
     let result = predictor
-        .fit(&zeros, residuals)
+        .fit(residuals, &zeros)
         .map_err(|e| ForecastError::ComputationError(format!("Bootstrap fit failed: {}", e)))?;
 
     // Predict intervals
@@ -131,9 +131,10 @@ pub fn bootstrap_quantiles(
         predictor = predictor.seed(s);
     }
 
+    // Residuals as "forecasts" against zero actuals, as in `bootstrap_intervals`
     let zeros: Vec<f64> = vec![0.0; residuals.len()];
     let result = predictor
-        .fit(&zeros, residuals)
+        .fit(residuals, &zeros)
         .map_err(|e| ForecastError::ComputationError(format!("Bootstrap fit failed: {}", e)))?;
 
     let qf = predictor.predict_quantiles(&result, forecasts, quantile_levels);
@@ -172,6 +173,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bootstrap_intervals_skewed_residuals() {
+        // Right-skewed residuals: frequent small misses below, rare large ones above
+        let residuals: Vec<f64> = (0..50)
+            .map(|i| if i % 10 == 0 { 9.0 } else { -1.0 })
+            .collect();
+        let forecasts = vec![100.0];
+        let result = bootstrap_intervals(&residuals, &forecasts, 1000, 0.95, Some(42)).unwrap();
+
+        let below = result.point[0] - result.lower[0];
+        let above = result.upper[0] - result.point[0];
+        assert!(
+            above > 2.0 * below,
+            "expected a longer upper tail: below {}, above {}",
+            below,
+            above
+        );
+    }
+
     #[test]
     fn test_bootstrap_quantiles_basic() {
         let residuals: Vec<f64> = (0..50).map(|i| (i as f64 * 0.7).sin() * 2.0).collect();
@@ -1,6 +1,7 @@
 //! Changepoint detection using PELT algorithm.
 
 use crate::error::{ForecastError, Result};
+use crate::stats::median;
 
 /// Result of changepoint detection.
 #[derive(Debug, Clone)]
@@ -12,9 +13,9 @@ pub struct ChangepointResult {
 }
 
 /// Cost function type for changepoint detection.
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum CostFunction {
-    /// L1 (mean absolute deviation)
+    /// L1 (absolute deviation from the segment median, robust to outliers)
     L1,
     /// L2 (variance)
     #[default]
@@ -23,6 +24,21 @@ pub enum CostFunction {
     Normal,
 }
 
+impl CostFunction {
+    /// Create from integer (for FFI): 0 = L2, 1 = L1, 2 = Normal (RBF).
+    pub fn from_int(i: i32) -> Result<Self> {
+        match i {
+            0 => Ok(Self::L2),
+            1 => Ok(Self::L1),
+            2 => Ok(Self::Normal),
+            _ => Err(ForecastError::InvalidInput(format!(
+                "Unknown cost function: {}. Valid: 0 (L2), 1 (L1), 2 (Normal)",
+                i
+            ))),
+        }
+    }
+}
+
 /// Calculate segment cost using L2 (variance) cost function.
 fn cost_l2(values: &[f64], start: usize, end: usize) -> f64 {
     if end <= start {
@@ -40,21 +56,18 @@ fn cost_l2(values: &[f64], start: usize, end: usize) -> f64 {
     segment.iter().map(|v| (v - mean).powi(2)).sum()
 }
 
-/// Calculate segment cost using L1 (mean absolute deviation) cost function.
+/// Calculate segment cost using L1 (absolute deviation from the median) cost function.
+///
+/// The median is the L1-optimal location, so a single spike shifts the cost
+/// by its own deviation only instead of dragging the segment centre.
 fn cost_l1(values: &[f64], start: usize, end: usize) -> f64 {
     if end <= start {
         return 0.0;
     }
 
     let segment = &values[start..end];
-    let n = segment.len() as f64;
-
-    if n < 1.0 {
-        return 0.0;
-    }
-
-    let mean: f64 = segment.iter().sum::<f64>() / n;
-    segment.iter().map(|v| (v - mean).abs()).sum()
+    let center = median(segment);
+    segment.iter().map(|v| (v - center).abs()).sum()
 }
 
 /// Calculate segment cost using normal distribution (change in mean and variance).
@@ -174,6 +187,193 @@ pub fn detect_changepoints(
     })
 }
 
+/// Incremental PELT state for streaming changepoint detection.
+///
+/// Keeps the optimal-partition cost `F(tau)` for each surviving candidate
+/// changepoint and only the observations from the oldest candidate onwards,
+/// so each `push` costs O(|candidates|) segment evaluations instead of a full
+/// re-run over the history. Candidates are pruned with the usual PELT rule;
+/// `with_max_history` additionally caps how far back a segment may start,
+/// which bounds memory on long homogeneous stretches where pruning cannot fire.
+///
+/// # Example
+/// ```
+/// use anofox_fcst_core::changepoint::{CostFunction, PeltState};
+///
+/// let mut pelt = PeltState::new(5, 10.0, CostFunction::L2).unwrap();
+/// let mut detected = Vec::new();
+/// for i in 0..100 {
+///     let value = if i < 50 { 0.0 } else { 10.0 };
+///     if let Some(cp) = pelt.push(value) {
+///         detected.push(cp);
+///     }
+/// }
+/// assert_eq!(detected, vec![50]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PeltState {
+    cost_fn: CostFunction,
+    min_size: usize,
+    penalty: f64,
+    max_history: Option<usize>,
+    /// Observations from absolute index `offset` onwards
+    buffer: Vec<f64>,
+    offset: usize,
+    n_observations: usize,
+    /// Surviving candidates as (changepoint index, optimal cost up to it)
+    candidates: Vec<(usize, f64)>,
+    /// Candidate that stands for "no changepoint here" (series start or the
+    /// start of the history window)
+    origin: usize,
+    /// Unconfirmed last changepoint and how many pushes it has stayed optimal
+    pending: Option<(usize, usize)>,
+    last_changepoint: usize,
+}
+
+impl PeltState {
+    /// Create a new streaming detector.
+    ///
+    /// # Arguments
+    /// * `min_size` - Minimum segment size (values below 1 are treated as 1)
+    /// * `penalty` - Penalty for adding a changepoint; must be positive since
+    ///   the batch BIC default depends on the (unknown) series length
+    /// * `cost_fn` - Cost function to use
+    pub fn new(min_size: usize, penalty: f64, cost_fn: CostFunction) -> Result<Self> {
+        if !penalty.is_finite() || penalty <= 0.0 {
+            return Err(ForecastError::InvalidParameter {
+                param: "penalty".to_string(),
+                value: penalty.to_string(),
+                reason: "must be a positive finite number".to_string(),
+            });
+        }
+
+        Ok(Self {
+            cost_fn,
+            min_size: min_size.max(1),
+            penalty,
+            max_history: None,
+            buffer: Vec::new(),
+            offset: 0,
+            n_observations: 0,
+            candidates: vec![(0, -penalty)],
+            origin: 0,
+            pending: None,
+            last_changepoint: 0,
+        })
+    }
+
+    /// Limit segments to start at most `max_history` observations back.
+    ///
+    /// Older candidates are discarded even if PELT would keep them and the
+    /// oldest survivor becomes a penalty-free origin, so a segment longer than
+    /// the window is continued rather than split. Detection is approximate for
+    /// such segments, since the cost before the window start is frozen.
+    pub fn with_max_history(mut self, max_history: usize) -> Self {
+        self.max_history = Some(max_history.max(2 * self.min_size));
+        self
+    }
+
+    /// Number of observations pushed so far.
+    pub fn n_observations(&self) -> usize {
+        self.n_observations
+    }
+
+    /// Most recently emitted changepoint, if any.
+    pub fn last_changepoint(&self) -> Option<usize> {
+        (self.last_changepoint > 0).then_some(self.last_changepoint)
+    }
+
+    /// Number of observations currently retained.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Add one observation and update the optimal partition.
+    ///
+    /// Returns the index of a newly detected changepoint (the first index of
+    /// the new segment, as in `detect_changepoints`). A changepoint is emitted
+    /// once it has been the last changepoint of the optimal segmentation for
+    /// `min_size` consecutive observations, which keeps the estimate from
+    /// jittering while the new segment is still short. Emitted changepoints
+    /// are never retracted. Non-finite values would poison every segment cost,
+    /// so they are ignored and do not count as an observation.
+    pub fn push(&mut self, value: f64) -> Option<usize> {
+        if !value.is_finite() {
+            return None;
+        }
+        self.buffer.push(value);
+        self.n_observations += 1;
+        let t = self.n_observations;
+
+        if t < self.min_size {
+            return None;
+        }
+
+        let cost = get_cost_fn(self.cost_fn);
+        let offset = self.offset;
+        let buffer = &self.buffer;
+        let segment_cost = |tau: usize| cost(buffer, tau - offset, t - offset);
+
+        let mut best_f = f64::INFINITY;
+        let mut best_tau = 0;
+        for &(tau, f_tau) in &self.candidates {
+            if tau + self.min_size <= t {
+                let candidate_cost = f_tau + segment_cost(tau) + self.penalty;
+                if candidate_cost < best_f {
+                    best_f = candidate_cost;
+                    best_tau = tau;
+                }
+            }
+        }
+
+        if !best_f.is_finite() {
+            return None;
+        }
+
+        // Pruning step: candidates still too recent to close a segment are kept
+        let min_size = self.min_size;
+        self.candidates
+            .retain(|&(tau, f_tau)| tau + min_size > t || f_tau + segment_cost(tau) <= best_f);
+        self.candidates.push((t, best_f));
+
+        if let Some(max_history) = self.max_history {
+            let cutoff = t.saturating_sub(max_history);
+            if self.candidates[0].0 < cutoff {
+                self.candidates.retain(|&(tau, _)| tau >= cutoff);
+                // Choosing the new oldest candidate must not cost a changepoint
+                self.candidates[0].1 -= self.penalty;
+                self.origin = self.candidates[0].0;
+            }
+        }
+
+        // Drop observations no remaining candidate can reach
+        let oldest = self.candidates[0].0;
+        if oldest > self.offset {
+            self.buffer.drain(..oldest - self.offset);
+            self.offset = oldest;
+        }
+
+        if best_tau == self.origin || best_tau <= self.last_changepoint {
+            self.pending = None;
+            return None;
+        }
+
+        let streak = match self.pending {
+            Some((tau, count)) if tau == best_tau => count + 1,
+            _ => 1,
+        };
+
+        if streak >= self.min_size {
+            self.pending = None;
+            self.last_changepoint = best_tau;
+            Some(best_tau)
+        } else {
+            self.pending = Some((best_tau, streak));
+            None
+        }
+    }
+}
+
 /// Result of BOCPD changepoint detection with per-point probabilities.
 /// C++ API compatible structure.
 #[derive(Debug, Clone)]
@@ -500,6 +700,93 @@ mod tests {
         assert_eq!(empty_cost, 0.0);
     }
 
+    #[test]
+    fn test_cost_l1_uses_median() {
+        // Median is 2.0, so the outlier contributes only its own distance
+        let values = vec![1.0, 2.0, 3.0, 100.0, 2.0];
+        assert!((cost_l1(&values, 0, 5) - 100.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_cost_function_from_int() {
+        assert_eq!(CostFunction::from_int(0).unwrap(), CostFunction::L2);
+        assert_eq!(CostFunction::from_int(1).unwrap(), CostFunction::L1);
+        assert_eq!(CostFunction::from_int(2).unwrap(), CostFunction::Normal);
+        assert!(CostFunction::from_int(3).is_err());
+        assert!(CostFunction::from_int(-1).is_err());
+    }
+
+    #[test]
+    fn test_l1_ignores_spike_that_l2_splits_on() {
+        // Level shift from 0 to 5 at index 40, plus a single spike at index 20
+        let mut values: Vec<f64> = (0..80)
+            .map(|i| {
+                let level = if i < 40 { 0.0 } else { 5.0 };
+                level + 0.1 * (i as f64 * 1.7).sin()
+            })
+            .collect();
+        values[20] = 50.0;
+
+        let l1 = detect_changepoints(&values, 2, None, CostFunction::L1).unwrap();
+        assert_eq!(l1.changepoints, vec![40], "L1 found {:?}", l1.changepoints);
+
+        let l2 = detect_changepoints(&values, 2, None, CostFunction::L2).unwrap();
+        assert!(
+            l2.changepoints.iter().any(|&cp| (18..=22).contains(&cp)),
+            "L2 should split on the spike, got {:?}",
+            l2.changepoints
+        );
+        assert!(l2.changepoints.contains(&40));
+    }
+
+    fn three_level_stream() -> Vec<f64> {
+        (0..300)
+            .map(|i| [0.0, 8.0, -4.0][i / 100] + 0.5 * (i as f64 * 1.3).sin())
+            .collect()
+    }
+
+    fn push_all(pelt: &mut PeltState, values: &[f64]) -> Vec<usize> {
+        values.iter().filter_map(|&v| pelt.push(v)).collect()
+    }
+
+    #[test]
+    fn test_pelt_state_detects_shifts() {
+        let values = three_level_stream();
+        let mut pelt = PeltState::new(5, 20.0, CostFunction::L2).unwrap();
+
+        assert_eq!(push_all(&mut pelt, &values), vec![100, 200]);
+        assert_eq!(pelt.n_observations(), 300);
+        assert_eq!(pelt.last_changepoint(), Some(200));
+
+        // Matches the batch detector on the same data
+        let batch = detect_changepoints(&values, 5, Some(20.0), CostFunction::L2).unwrap();
+        assert_eq!(batch.changepoints, vec![100, 200]);
+    }
+
+    #[test]
+    fn test_pelt_state_bounded_history() {
+        let values = three_level_stream();
+        let mut pelt = PeltState::new(5, 20.0, CostFunction::L2)
+            .unwrap()
+            .with_max_history(50);
+        assert_eq!(push_all(&mut pelt, &values), vec![100, 200]);
+        assert!(pelt.buffered() <= 51);
+
+        // A long homogeneous stream stays bounded and raises no changepoints
+        let noise: Vec<f64> = (0..1000).map(|i| 0.5 * (i as f64 * 1.3).sin()).collect();
+        let mut pelt = PeltState::new(5, 20.0, CostFunction::L2)
+            .unwrap()
+            .with_max_history(50);
+        assert!(push_all(&mut pelt, &noise).is_empty());
+        assert!(pelt.buffered() <= 51);
+    }
+
+    #[test]
+    fn test_pelt_state_invalid_penalty() {
+        assert!(PeltState::new(2, 0.0, CostFunction::L2).is_err());
+        assert!(PeltState::new(2, f64::NAN, CostFunction::L2).is_err());
+    }
+
     #[test]
     fn test_cost_normal() {
         let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
@@ -425,6 +425,138 @@ pub fn conformal_predict_asymmetric(
     })
 }
 
+/// Computes CQR conformity scores for a calibration set.
+///
+/// The score for each observation is `max(lower - actual, actual - upper)`:
+/// negative when the actual falls inside the quantile band, positive by the
+/// distance to the nearest bound otherwise.
+///
+/// # Arguments
+/// * `cal_lower` - Lower quantile predictions on the calibration set
+/// * `cal_upper` - Upper quantile predictions on the calibration set
+/// * `cal_actual` - Observed values on the calibration set
+pub fn cqr_scores(cal_lower: &[f64], cal_upper: &[f64], cal_actual: &[f64]) -> Result<Vec<f64>> {
+    if cal_lower.len() != cal_actual.len() || cal_upper.len() != cal_actual.len() {
+        return Err(ForecastError::InvalidInput(format!(
+            "Calibration lengths must match: lower={}, upper={}, actual={}",
+            cal_lower.len(),
+            cal_upper.len(),
+            cal_actual.len()
+        )));
+    }
+
+    if cal_actual.is_empty() {
+        return Err(ForecastError::InsufficientData { needed: 1, got: 0 });
+    }
+
+    Ok(cal_lower
+        .iter()
+        .zip(cal_upper.iter())
+        .zip(cal_actual.iter())
+        .map(|((&lo, &hi), &y)| (lo - y).max(y - hi))
+        .collect())
+}
+
+/// Conformalized Quantile Regression (CQR).
+///
+/// Calibrates pre-estimated quantile forecasts instead of point forecasts.
+/// The correction `q` is the finite-sample quantile of the CQR scores (see
+/// [`cqr_scores`]); the returned interval is `[lower_q - q, upper_q + q]`.
+/// Because the base band comes from a quantile model, interval widths follow
+/// the heteroscedasticity of the series rather than a single global offset.
+/// A negative `q` shrinks an over-wide quantile band.
+///
+/// # Arguments
+/// * `lower_q` - Lower quantile forecasts (e.g. the alpha/2 quantile)
+/// * `upper_q` - Upper quantile forecasts (e.g. the 1 - alpha/2 quantile)
+/// * `cal_lower` - Lower quantile predictions on the calibration set
+/// * `cal_upper` - Upper quantile predictions on the calibration set
+/// * `cal_actual` - Observed values on the calibration set
+/// * `alpha` - Miscoverage rate (e.g., 0.1 for 90% coverage)
+///
+/// # Returns
+/// A `ConformalResult` whose `point` is the midpoint of the quantile band
+/// and whose `conformity_score` is the additive correction.
+///
+/// # Example
+/// ```
+/// use anofox_fcst_core::conformal::conformalize_quantiles;
+///
+/// let cal_lower = vec![9.0, 19.0, 29.0, 39.0, 49.0];
+/// let cal_upper = vec![11.0, 21.0, 31.0, 41.0, 51.0];
+/// let cal_actual = vec![10.0, 22.0, 30.0, 38.5, 50.0];
+///
+/// let result = conformalize_quantiles(&[59.0], &[61.0], &cal_lower, &cal_upper, &cal_actual, 0.2)
+///     .unwrap();
+/// assert!(result.lower[0] <= 59.0);
+/// assert!(result.upper[0] >= 61.0);
+/// ```
+pub fn conformalize_quantiles(
+    lower_q: &[f64],
+    upper_q: &[f64],
+    cal_lower: &[f64],
+    cal_upper: &[f64],
+    cal_actual: &[f64],
+    alpha: f64,
+) -> Result<ConformalResult> {
+    if lower_q.len() != upper_q.len() {
+        return Err(ForecastError::InvalidInput(format!(
+            "Quantile forecast lengths must match: lower={}, upper={}",
+            lower_q.len(),
+            upper_q.len()
+        )));
+    }
+
+    if !(0.0..1.0).contains(&alpha) {
+        return Err(ForecastError::InvalidInput(
+            "Alpha must be between 0 and 1 (exclusive)".to_string(),
+        ));
+    }
+
+    let mut scores = cqr_scores(cal_lower, cal_upper, cal_actual)?;
+    scores.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let n = scores.len() as f64;
+    let quantile_level = ((n + 1.0) * (1.0 - alpha)).ceil() / n;
+    let correction = compute_quantile(&scores, quantile_level.clamp(0.0, 1.0));
+
+    let (lower, upper) = apply_cqr_correction(lower_q, upper_q, correction);
+    let point = lower_q
+        .iter()
+        .zip(upper_q.iter())
+        .map(|(&lo, &hi)| (lo + hi) / 2.0)
+        .collect();
+
+    Ok(ConformalResult {
+        point,
+        lower,
+        upper,
+        coverage: 1.0 - alpha,
+        conformity_score: correction,
+        method: "cqr".to_string(),
+    })
+}
+
+/// Widens (or shrinks) a quantile band by a CQR correction.
+///
+/// Bounds that would cross after a negative correction collapse to the
+/// band midpoint.
+fn apply_cqr_correction(lower_q: &[f64], upper_q: &[f64], correction: f64) -> (Vec<f64>, Vec<f64>) {
+    lower_q
+        .iter()
+        .zip(upper_q.iter())
+        .map(|(&lo, &hi)| {
+            let (l, u) = (lo - correction, hi + correction);
+            if l > u {
+                let mid = (lo + hi) / 2.0;
+                (mid, mid)
+            } else {
+                (l, u)
+            }
+        })
+        .unzip()
+}
+
 /// Computes quantile from sorted data using linear interpolation.
 fn compute_quantile(sorted_data: &[f64], quantile: f64) -> f64 {
     if sorted_data.is_empty() {
@@ -478,6 +610,9 @@ pub enum ConformalMethod {
     Asymmetric,
     /// Adaptive intervals scaled by difficulty scores
     Adaptive,
+    /// Conformalized Quantile Regression: residuals are CQR scores and the
+    /// learned correction is added to quantile forecasts
+    Cqr,
 }
 
 /// Strategy for conformal calibration (how residuals are used).
@@ -533,6 +668,7 @@ impl std::fmt::Display for ConformalMethod {
             Self::Symmetric => write!(f, "symmetric"),
             Self::Asymmetric => write!(f, "asymmetric"),
             Self::Adaptive => write!(f, "adaptive"),
+            Self::Cqr => write!(f, "cqr"),
         }
     }
 }
@@ -545,8 +681,9 @@ impl std::str::FromStr for ConformalMethod {
             "symmetric" | "sym" => Ok(Self::Symmetric),
             "asymmetric" | "asym" => Ok(Self::Asymmetric),
             "adaptive" | "adapt" => Ok(Self::Adaptive),
+            "cqr" | "quantile" => Ok(Self::Cqr),
             _ => Err(ForecastError::InvalidInput(format!(
-                "Unknown conformal method: '{}'. Valid: symmetric, asymmetric, adaptive",
+                "Unknown conformal method: '{}'. Valid: symmetric, asymmetric, adaptive, cqr",
                 s
             ))),
         }
@@ -749,6 +886,12 @@ pub fn conformal_learn(
         ));
     }
 
+    if strategy == ConformalStrategy::JackknifePlus && method == ConformalMethod::Cqr {
+        return Err(ForecastError::InvalidInput(
+            "JackknifePlus strategy does not support cqr method".to_string(),
+        ));
+    }
+
     let n_residuals = residuals.len();
     let mut scores_lower = Vec::with_capacity(alphas.len());
     let mut scores_upper = Vec::with_capacity(alphas.len());
@@ -848,6 +991,23 @@ pub fn conformal_learn(
                         scores_upper.push(score);
                     }
                 }
+
+                ConformalMethod::Cqr => {
+                    // Residuals are signed CQR scores; a negative correction
+                    // means the quantile band was already too wide
+                    let mut sorted_scores = residuals.to_vec();
+                    sorted_scores
+                        .sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+                    for &alpha in alphas {
+                        let n = sorted_scores.len() as f64;
+                        let quantile_level = ((n + 1.0) * (1.0 - alpha)).ceil() / n;
+                        let quantile_level = quantile_level.clamp(0.0, 1.0);
+                        let score = compute_quantile(&sorted_scores, quantile_level);
+                        scores_lower.push(score);
+                        scores_upper.push(score);
+                    }
+                }
             }
 
             // For Split/CrossVal, state_vector stores quantiles: [lower_q1, ..., upper_q1, ...]
@@ -1012,6 +1172,14 @@ pub fn conformal_apply(
                     lower.push(level_lower);
                     upper.push(level_upper);
                 }
+
+                ConformalMethod::Cqr => {
+                    // Point forecasts act as a zero-width quantile band
+                    let (level_lower, level_upper) =
+                        apply_cqr_correction(forecasts, forecasts, score_lower);
+                    lower.push(level_lower);
+                    upper.push(level_upper);
+                }
             }
         }
     }
@@ -1025,6 +1193,62 @@ pub fn conformal_apply(
     })
 }
 
+/// Apply a CQR calibration profile to quantile forecasts.
+///
+/// Each coverage level adds its learned correction to the supplied band:
+/// `[lower_q - q, upper_q + q]`. Use this with profiles learned with
+/// `ConformalMethod::Cqr` from [`cqr_scores`]; the `point` of the result is
+/// the band midpoint.
+///
+/// # Arguments
+/// * `lower_q` - Lower quantile forecasts
+/// * `upper_q` - Upper quantile forecasts (same length as `lower_q`)
+/// * `profile` - Calibration profile learned with the CQR method
+pub fn conformal_apply_quantiles(
+    lower_q: &[f64],
+    upper_q: &[f64],
+    profile: &CalibrationProfile,
+) -> Result<PredictionIntervals> {
+    if profile.method != ConformalMethod::Cqr {
+        return Err(ForecastError::InvalidInput(format!(
+            "Quantile forecasts require a cqr profile, got {}",
+            profile.method
+        )));
+    }
+
+    if lower_q.is_empty() {
+        return Err(ForecastError::InvalidInput(
+            "At least one forecast is required".to_string(),
+        ));
+    }
+
+    if lower_q.len() != upper_q.len() {
+        return Err(ForecastError::InvalidInput(format!(
+            "Quantile forecast lengths must match: lower={}, upper={}",
+            lower_q.len(),
+            upper_q.len()
+        )));
+    }
+
+    let (lower, upper) = profile
+        .scores_lower
+        .iter()
+        .map(|&q| apply_cqr_correction(lower_q, upper_q, q))
+        .unzip();
+
+    Ok(PredictionIntervals {
+        point: lower_q
+            .iter()
+            .zip(upper_q.iter())
+            .map(|(&lo, &hi)| (lo + hi) / 2.0)
+            .collect(),
+        lower,
+        upper,
+        coverage: profile.coverage_levels(),
+        method: profile.method,
+    })
+}
+
 /// Convenience function combining learn and apply in one step.
 ///
 /// # Arguments
@@ -1864,6 +2088,160 @@ mod tests {
         assert_eq!(result.upper.len(), 2);
         assert_eq!(result.half_widths.len(), 2);
     }
+
+    /// Calibration data with noise scale growing linearly over time and a
+    /// quantile model whose band tracks that scale.
+    fn heteroscedastic_quantiles(range: std::ops::Range<usize>) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let mut lower = Vec::new();
+        let mut upper = Vec::new();
+        let mut actual = Vec::new();
+        for t in range {
+            let sigma = 0.1 * t as f64;
+            let noise = ((t * 7919) % 100) as f64 / 100.0 * 3.0 - 1.5;
+            lower.push(100.0 - 1.2 * sigma);
+            upper.push(100.0 + 1.2 * sigma);
+            actual.push(100.0 + noise * sigma);
+        }
+        (lower, upper, actual)
+    }
+
+    #[test]
+    fn test_cqr_scores() {
+        let scores = cqr_scores(&[9.0, 9.0, 9.0], &[11.0, 11.0, 11.0], &[10.0, 12.5, 8.0]).unwrap();
+        assert_relative_eq!(scores[0], -1.0, epsilon = 1e-10);
+        assert_relative_eq!(scores[1], 1.5, epsilon = 1e-10);
+        assert_relative_eq!(scores[2], 1.0, epsilon = 1e-10);
+
+        assert!(cqr_scores(&[9.0], &[11.0, 12.0], &[10.0]).is_err());
+        assert!(cqr_scores(&[], &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_conformalize_quantiles_adapts_to_variance() {
+        let (cal_lower, cal_upper, cal_actual) = heteroscedastic_quantiles(1..101);
+        let (fc_lower, fc_upper, _) = heteroscedastic_quantiles(101..111);
+        let (early_lower, early_upper, _) = heteroscedastic_quantiles(5..6);
+
+        let late = conformalize_quantiles(
+            &fc_lower,
+            &fc_upper,
+            &cal_lower,
+            &cal_upper,
+            &cal_actual,
+            0.1,
+        )
+        .unwrap();
+        let early = conformalize_quantiles(
+            &early_lower,
+            &early_upper,
+            &cal_lower,
+            &cal_upper,
+            &cal_actual,
+            0.1,
+        )
+        .unwrap();
+
+        assert_eq!(late.method, "cqr");
+        assert_relative_eq!(late.coverage, 0.9, epsilon = 1e-10);
+        assert_relative_eq!(late.point[0], 100.0, epsilon = 1e-10);
+
+        // CQR widths follow the quantile model
+        let late_widths = interval_width(&late.lower, &late.upper);
+        let early_width = early.upper[0] - early.lower[0];
+        assert!(late_widths.windows(2).all(|w| w[1] >= w[0]));
+        assert!(late_widths[0] > early_width);
+
+        // A single global offset gives the same width everywhere, which is far
+        // too wide for the low-variance region
+        let residuals: Vec<f64> = cal_actual.iter().map(|a| a - 100.0).collect();
+        let symmetric = conformal_predict(&residuals, &[100.0], 0.1).unwrap();
+        let symmetric_width = symmetric.upper[0] - symmetric.lower[0];
+        assert!(early_width < symmetric_width);
+    }
+
+    #[test]
+    fn test_conformalize_quantiles_shrinks_wide_band() {
+        // Every actual sits well inside the band, so the correction is negative
+        let cal_lower = vec![0.0; 10];
+        let cal_upper = vec![20.0; 10];
+        let cal_actual: Vec<f64> = (0..10).map(|i| 9.0 + 0.2 * i as f64).collect();
+
+        let result =
+            conformalize_quantiles(&[0.0], &[20.0], &cal_lower, &cal_upper, &cal_actual, 0.2)
+                .unwrap();
+        assert!(result.conformity_score < 0.0);
+        assert!(result.lower[0] > 0.0);
+        assert!(result.upper[0] < 20.0);
+        assert!(result.lower[0] <= result.upper[0]);
+    }
+
+    #[test]
+    fn test_conformalize_quantiles_invalid_input() {
+        assert!(conformalize_quantiles(&[1.0], &[2.0, 3.0], &[1.0], &[2.0], &[1.5], 0.1).is_err());
+        assert!(conformalize_quantiles(&[1.0], &[2.0], &[1.0], &[2.0], &[1.5], 1.5).is_err());
+        assert!(conformalize_quantiles(&[1.0], &[2.0], &[1.0], &[2.0], &[], 0.1).is_err());
+    }
+
+    #[test]
+    fn test_conformal_learn_apply_cqr() {
+        let (cal_lower, cal_upper, cal_actual) = heteroscedastic_quantiles(1..101);
+        let (fc_lower, fc_upper, _) = heteroscedastic_quantiles(101..111);
+        let scores = cqr_scores(&cal_lower, &cal_upper, &cal_actual).unwrap();
+
+        let profile = conformal_learn(
+            &scores,
+            &[0.1],
+            ConformalMethod::Cqr,
+            ConformalStrategy::Split,
+            None,
+        )
+        .unwrap();
+        let intervals = conformal_apply_quantiles(&fc_lower, &fc_upper, &profile).unwrap();
+        let direct = conformalize_quantiles(
+            &fc_lower,
+            &fc_upper,
+            &cal_lower,
+            &cal_upper,
+            &cal_actual,
+            0.1,
+        )
+        .unwrap();
+
+        for (a, b) in intervals.lower[0].iter().zip(direct.lower.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
+        for (a, b) in intervals.upper[0].iter().zip(direct.upper.iter()) {
+            assert_relative_eq!(a, b, epsilon = 1e-10);
+        }
+
+        // Point forecasts are treated as a zero-width band
+        let point = conformal_apply(&[100.0], &profile, None).unwrap();
+        assert!(point.lower[0][0] <= 100.0 && point.upper[0][0] >= 100.0);
+
+        // Quantile apply requires a CQR profile
+        let symmetric = conformal_learn(
+            &scores,
+            &[0.1],
+            ConformalMethod::Symmetric,
+            ConformalStrategy::Split,
+            None,
+        )
+        .unwrap();
+        assert!(conformal_apply_quantiles(&fc_lower, &fc_upper, &symmetric).is_err());
+
+        assert!(conformal_learn(
+            &scores,
+            &[0.1],
+            ConformalMethod::Cqr,
+            ConformalStrategy::JackknifePlus,
+            None
+        )
+        .is_err());
+        assert_eq!(
+            "cqr".parse::<ConformalMethod>().unwrap(),
+            ConformalMethod::Cqr
+        );
+    }
 }
 
 /// Result of per-step conformal prediction.
@@ -0,0 +1,220 @@
+//! Autocorrelation diagnostics.
+//!
+//! This module provides the sample autocorrelation function (ACF) and the
+//! partial autocorrelation function (PACF), the usual tools for identifying
+//! AR and MA orders before fitting an ARIMA model.
+//!
+//! # Example Usage
+//!
+//! ```
+//! use anofox_fcst_core::correlation::{acf, pacf};
+//!
+//! let values: Vec<f64> = (0..50).map(|i| (i as f64 * 0.5).sin()).collect();
+//! let r = acf(&values, 5).unwrap();
+//! let p = pacf(&values, 5).unwrap();
+//! assert_eq!(r.len(), 6);
+//! assert_eq!(p.len(), 6);
+//! assert_eq!(r[0], 1.0);
+//! ```
+
+use crate::error::{ForecastError, Result};
+
+/// Compute the sample autocorrelation at a single lag.
+///
+/// Uses the biased estimator (normalised by the lag-0 sum of squares), which
+/// keeps the autocorrelation sequence positive semi-definite. Returns 0.0 for
+/// lags beyond the series or for a constant series.
+pub(crate) fn acf_at_lag(values: &[f64], lag: usize) -> f64 {
+    let n = values.len();
+    if lag >= n {
+        return 0.0;
+    }
+
+    let mean: f64 = values.iter().sum::<f64>() / n as f64;
+    let variance: f64 = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+
+    if variance.abs() < f64::EPSILON {
+        return 0.0;
+    }
+
+    let mut sum = 0.0;
+    for i in 0..(n - lag) {
+        sum += (values[i] - mean) * (values[i + lag] - mean);
+    }
+    sum / variance
+}
+
+fn validate_lags(values: &[f64], max_lag: usize) -> Result<()> {
+    if values.is_empty() {
+        return Err(ForecastError::InsufficientData { needed: 1, got: 0 });
+    }
+    if values.iter().any(|v| !v.is_finite()) {
+        return Err(ForecastError::InvalidInput(
+            "Values must be finite".to_string(),
+        ));
+    }
+    if max_lag >= values.len() {
+        return Err(ForecastError::InvalidParameter {
+            param: "max_lag".to_string(),
+            value: max_lag.to_string(),
+            reason: format!("Must be less than the series length ({})", values.len()),
+        });
+    }
+    Ok(())
+}
+
+/// Compute the sample autocorrelation function.
+///
+/// # Arguments
+/// * `values` - Time series values
+/// * `max_lag` - Largest lag to compute (must be less than the series length)
+///
+/// # Returns
+/// * `Result<Vec<f64>>` - Autocorrelations for lags `0..=max_lag` (lag 0 is 1.0)
+pub fn acf(values: &[f64], max_lag: usize) -> Result<Vec<f64>> {
+    validate_lags(values, max_lag)?;
+
+    let mut result: Vec<f64> = (0..=max_lag).map(|k| acf_at_lag(values, k)).collect();
+    result[0] = 1.0;
+    Ok(result)
+}
+
+/// Compute the sample partial autocorrelation function.
+///
+/// The partial autocorrelation at lag k is the last coefficient of the
+/// order-k autoregression fitted to the sample ACF, obtained with the
+/// Durbin-Levinson recursion.
+///
+/// # Arguments
+/// * `values` - Time series values
+/// * `max_lag` - Largest lag to compute (must be less than the series length)
+///
+/// # Returns
+/// * `Result<Vec<f64>>` - Partial autocorrelations for lags `0..=max_lag` (lag 0 is 1.0)
+pub fn pacf(values: &[f64], max_lag: usize) -> Result<Vec<f64>> {
+    let r = acf(values, max_lag)?;
+
+    let mut result = vec![0.0; max_lag + 1];
+    result[0] = 1.0;
+
+    // phi[j] holds the AR coefficients of the current order
+    let mut phi: Vec<f64> = Vec::with_capacity(max_lag);
+    let mut v: f64 = 1.0;
+
+    for k in 1..=max_lag {
+        if v.abs() < f64::EPSILON {
+            // Perfectly predictable: higher orders add nothing
+            break;
+        }
+        let fitted: f64 = phi.iter().enumerate().map(|(j, p)| p * r[k - 1 - j]).sum();
+        let phi_kk = (r[k] - fitted) / v;
+
+        let prev = phi.clone();
+        for (j, p) in phi.iter_mut().enumerate() {
+            *p = prev[j] - phi_kk * prev[prev.len() - 1 - j];
+        }
+        phi.push(phi_kk);
+
+        v *= 1.0 - phi_kk * phi_kk;
+        result[k] = phi_kk;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(n: usize, seed: u64) -> Vec<f64> {
+        let mut state = seed;
+        (0..n)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5
+            })
+            .collect()
+    }
+
+    fn ar1(phi: f64, n: usize) -> Vec<f64> {
+        let e = noise(n, 7);
+        let mut y = Vec::with_capacity(n);
+        let mut prev = 0.0;
+        for &et in &e {
+            prev = phi * prev + et;
+            y.push(prev);
+        }
+        y
+    }
+
+    #[test]
+    fn test_acf_white_noise() {
+        let values = noise(1000, 42);
+        let r = acf(&values, 10).unwrap();
+
+        assert_eq!(r.len(), 11);
+        assert_eq!(r[0], 1.0);
+        // All lags inside a generous band around the 2/sqrt(n) bound
+        let bound = 3.0 / (values.len() as f64).sqrt();
+        for &rk in &r[1..] {
+            assert!(rk.abs() < bound, "acf {} exceeds {}", rk, bound);
+        }
+    }
+
+    #[test]
+    fn test_acf_ar1_decays_geometrically() {
+        let phi = 0.7;
+        let values = ar1(phi, 5000);
+        let r = acf(&values, 5).unwrap();
+
+        for (k, &rk) in r.iter().enumerate().skip(1) {
+            let expected = phi.powi(k as i32);
+            assert!(
+                (rk - expected).abs() < 0.08,
+                "lag {}: {} vs {}",
+                k,
+                rk,
+                expected
+            );
+        }
+        // Monotone decay
+        assert!(r.windows(2).all(|w| w[1] < w[0]));
+    }
+
+    #[test]
+    fn test_pacf_ar1_cuts_off() {
+        let phi = 0.7;
+        let values = ar1(phi, 5000);
+        let p = pacf(&values, 5).unwrap();
+
+        assert_eq!(p[0], 1.0);
+        assert!((p[1] - phi).abs() < 0.05);
+        let bound = 3.0 / (values.len() as f64).sqrt();
+        for &pk in &p[2..] {
+            assert!(pk.abs() < bound, "pacf {} exceeds {}", pk, bound);
+        }
+    }
+
+    #[test]
+    fn test_pacf_lag1_equals_acf_lag1() {
+        let values = noise(200, 3);
+        let r = acf(&values, 3).unwrap();
+        let p = pacf(&values, 3).unwrap();
+        assert!((r[1] - p[1]).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_acf_invalid_input() {
+        assert!(acf(&[], 0).is_err());
+        assert!(acf(&[1.0, 2.0, 3.0], 3).is_err());
+        assert!(pacf(&[1.0, f64::NAN, 3.0], 1).is_err());
+    }
+
+    #[test]
+    fn test_acf_constant_series() {
+        let r = acf(&[5.0; 10], 3).unwrap();
+        assert_eq!(r, vec![1.0, 0.0, 0.0, 0.0]);
+        let p = pacf(&[5.0; 10], 3).unwrap();
+        assert_eq!(p, vec![1.0, 0.0, 0.0, 0.0]);
+    }
+}
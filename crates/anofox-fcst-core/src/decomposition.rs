@@ -1,6 +1,8 @@
 //! Time series decomposition (MSTL).
 
 use crate::error::{ForecastError, Result};
+use crate::forecast::SeasonalMode;
+use crate::stats::median;
 use std::str::FromStr;
 
 /// Mode for handling insufficient data in MSTL decomposition.
@@ -51,10 +53,125 @@ pub struct MstlDecomposition {
     pub remainder: Option<Vec<f64>>,
     /// Whether decomposition was actually applied
     pub decomposition_applied: bool,
+    /// Seasonal strength `max(0, 1 - var(R) / var(R + S))`, with `S` the sum
+    /// of the seasonal components (None without seasonal components)
+    pub seasonal_strength: Option<f64>,
+    /// Trend strength `max(0, 1 - var(R) / var(R + T))` (None if
+    /// decomposition was skipped)
+    pub trend_strength: Option<f64>,
 }
 
+/// Population variance.
+fn variance(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n
+}
+
+/// Variance-ratio strength `max(0, 1 - var(R) / var(R + C))` of a component.
+fn component_strength(remainder: &[f64], component: &[f64]) -> f64 {
+    let combined: Vec<f64> = remainder
+        .iter()
+        .zip(component.iter())
+        .map(|(r, c)| r + c)
+        .collect();
+    let combined_var = variance(&combined);
+    if combined_var <= f64::EPSILON {
+        return 0.0;
+    }
+    (1.0 - variance(remainder) / combined_var).clamp(0.0, 1.0)
+}
+
+/// Number of robustness iterations in robust MSTL.
+const ROBUST_ITERATIONS: usize = 15;
+
 /// Perform STL decomposition for a single seasonal period.
-fn stl_decompose(values: &[f64], period: usize) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>)> {
+pub(crate) fn stl_decompose(
+    values: &[f64],
+    period: usize,
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>)> {
+    weighted_stl_decompose(values, period, &vec![1.0; values.len()])
+}
+
+/// Weighted mean of `values`; the median when all weights are zero, so a
+/// fully down-weighted group still ignores its outliers.
+fn weighted_mean(values: &[f64], weights: &[f64]) -> f64 {
+    let total: f64 = weights.iter().sum();
+    if total > 0.0 {
+        values.iter().zip(weights).map(|(v, w)| v * w).sum::<f64>() / total
+    } else {
+        median(values)
+    }
+}
+
+/// Centred moving average of length `window` (2×`window` for even windows).
+///
+/// Edge positions without a full window hold the nearest computed value; a
+/// series shorter than one window gets its mean everywhere.
+pub(crate) fn centered_moving_average(values: &[f64], window: usize) -> Vec<f64> {
+    weighted_centered_moving_average(values, &vec![1.0; values.len()], window)
+}
+
+/// Centred moving average with per-observation weights; unit weights give
+/// `centered_moving_average`.
+pub(crate) fn weighted_centered_moving_average(
+    values: &[f64],
+    weights: &[f64],
+    window: usize,
+) -> Vec<f64> {
+    let n = values.len();
+    let half = window / 2;
+    let span = 2 * half + 1;
+    if span > n {
+        let mean = if n == 0 {
+            0.0
+        } else {
+            weighted_mean(values, weights)
+        };
+        return vec![mean; n];
+    }
+    // Note: is_multiple_of() is unstable and breaks WASM builds
+    #[allow(clippy::manual_is_multiple_of)]
+    let even = window % 2 == 0;
+
+    let mut result = vec![0.0; n];
+    for (i, item) in result.iter_mut().enumerate().take(n - half).skip(half) {
+        let range = i - half..=i + half;
+        let (mut num, mut den) = (0.0, 0.0);
+        for (j, (v, w)) in values[range.clone()]
+            .iter()
+            .zip(&weights[range.clone()])
+            .enumerate()
+        {
+            // Half weights on the two outermost points of a 2×m average
+            let taper = if even && (j == 0 || j == span - 1) {
+                0.5
+            } else {
+                1.0
+            };
+            num += taper * w * v;
+            den += taper * w;
+        }
+        *item = if den > 0.0 {
+            num / den
+        } else {
+            median(&values[range])
+        };
+    }
+
+    let (first, last) = (result[half], result[n - half - 1]);
+    result[..half].fill(first);
+    result[n - half..].fill(last);
+    result
+}
+
+/// STL decomposition for a single period with per-observation robustness
+/// weights. Unit weights give the plain decomposition.
+fn weighted_stl_decompose(
+    values: &[f64],
+    period: usize,
+    weights: &[f64],
+) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>)> {
     if values.len() < 2 * period {
         return Err(ForecastError::InsufficientData {
             needed: 2 * period,
@@ -69,24 +186,7 @@ fn stl_decompose(values: &[f64], period: usize) -> Result<(Vec<f64>, Vec<f64>, V
     // Note: is_multiple_of() is unstable and breaks WASM builds
     #[allow(clippy::manual_is_multiple_of)]
     let window = if period % 2 == 0 { period + 1 } else { period };
-    let half_window = window / 2;
-
-    let mut trend = vec![f64::NAN; n];
-    for i in half_window..(n - half_window) {
-        let sum: f64 = values[i - half_window..=i + half_window].iter().sum();
-        trend[i] = sum / window as f64;
-    }
-
-    // Extend trend to edges
-    let first_valid = trend.iter().position(|v| !v.is_nan()).unwrap_or(0);
-    let last_valid = trend.iter().rposition(|v| !v.is_nan()).unwrap_or(n - 1);
-
-    for i in 0..first_valid {
-        trend[i] = trend[first_valid];
-    }
-    for i in (last_valid + 1)..n {
-        trend[i] = trend[last_valid];
-    }
+    let trend = weighted_centered_moving_average(values, weights, window);
 
     // 2. Detrended series
     let detrended: Vec<f64> = values
@@ -100,16 +200,20 @@ fn stl_decompose(values: &[f64], period: usize) -> Result<(Vec<f64>, Vec<f64>, V
     let num_cycles = n / period;
 
     for s in 0..period {
-        let mut sum = 0.0;
-        let mut count = 0;
+        let mut season_values = Vec::with_capacity(num_cycles + 1);
+        let mut season_weights = Vec::with_capacity(num_cycles + 1);
         for c in 0..=num_cycles {
             let idx = c * period + s;
             if idx < n {
-                sum += detrended[idx];
-                count += 1;
+                season_values.push(detrended[idx]);
+                season_weights.push(weights[idx]);
             }
         }
-        let avg = if count > 0 { sum / count as f64 } else { 0.0 };
+        let avg = if season_values.is_empty() {
+            0.0
+        } else {
+            weighted_mean(&season_values, &season_weights)
+        };
 
         // Center the seasonal component
         for c in 0..=num_cycles {
@@ -148,10 +252,15 @@ fn stl_decompose(values: &[f64], period: usize) -> Result<(Vec<f64>, Vec<f64>, V
 ///   - `Fail`: Error on insufficient data (default)
 ///   - `Trend`: Apply trend-only decomposition, seasonal components are empty
 ///   - `None`: Skip decomposition entirely, return empty result
+/// * `robust` - Run the STL robustness iterations: observations with large
+///   remainders are downweighted (bisquare weights) so outliers do not bend
+///   the trend or seasonal components. Only applies when seasonal components
+///   are extracted.
 pub fn mstl_decompose(
     values: &[f64],
     periods: &[i32],
     insufficient_data_mode: InsufficientDataMode,
+    robust: bool,
 ) -> Result<MstlDecomposition> {
     if values.is_empty() {
         match insufficient_data_mode {
@@ -165,6 +274,8 @@ pub fn mstl_decompose(
                     periods: vec![],
                     remainder: None,
                     decomposition_applied: false,
+                    seasonal_strength: None,
+                    trend_strength: None,
                 });
             }
         }
@@ -195,6 +306,8 @@ pub fn mstl_decompose(
                     periods: vec![],
                     remainder: None,
                     decomposition_applied: false,
+                    seasonal_strength: None,
+                    trend_strength: None,
                 });
             }
             InsufficientDataMode::Trend => {
@@ -205,25 +318,9 @@ pub fn mstl_decompose(
 
     if periods.is_empty() || insufficient {
         // No seasonal periods or insufficient data with Trend mode - just compute trend and remainder
-        let window = (n / 5).max(3).min(n);
-        let half_window = window / 2;
-
-        let mut trend = vec![f64::NAN; n];
-        for i in half_window..(n - half_window) {
-            let sum: f64 = values[i - half_window..=i + half_window].iter().sum();
-            trend[i] = sum / window as f64;
-        }
-
-        // Extend trend
-        let first_valid = trend.iter().position(|v| !v.is_nan()).unwrap_or(0);
-        let last_valid = trend.iter().rposition(|v| !v.is_nan()).unwrap_or(n - 1);
-
-        for i in 0..first_valid {
-            trend[i] = trend[first_valid];
-        }
-        for i in (last_valid + 1)..n {
-            trend[i] = trend[last_valid];
-        }
+        // Odd window: an even one would average window + 1 points
+        let window = (n / 5).max(3).min(n) | 1;
+        let trend = centered_moving_average(values, window);
 
         let remainder: Vec<f64> = values
             .iter()
@@ -231,12 +328,15 @@ pub fn mstl_decompose(
             .map(|(v, t)| v - t)
             .collect();
 
+        let trend_strength = Some(component_strength(&remainder, &trend));
         return Ok(MstlDecomposition {
             trend: Some(trend),
             seasonal: vec![],
             periods: vec![],
             remainder: Some(remainder),
             decomposition_applied: true,
+            seasonal_strength: None,
+            trend_strength,
         });
     }
 
@@ -244,19 +344,53 @@ pub fn mstl_decompose(
     let mut sorted_periods: Vec<i32> = periods.to_vec();
     sorted_periods.sort_by(|a, b| b.cmp(a));
 
+    let mut decomp = mstl_pass(values, &sorted_periods, &vec![1.0; n]);
+    if robust {
+        for _ in 0..ROBUST_ITERATIONS {
+            let weights = match decomp.remainder.as_deref() {
+                Some(remainder) => bisquare_weights(remainder),
+                None => break,
+            };
+            decomp = mstl_pass(values, &sorted_periods, &weights);
+        }
+    }
+
+    Ok(decomp)
+}
+
+/// STL robustness weights: bisquare of `|r| / (6 * median(|r|))`.
+fn bisquare_weights(remainder: &[f64]) -> Vec<f64> {
+    let abs: Vec<f64> = remainder.iter().map(|r| r.abs()).collect();
+    let h = (6.0 * median(&abs)).max(f64::EPSILON);
+
+    remainder
+        .iter()
+        .map(|r| {
+            let u = r.abs() / h;
+            if u < 1.0 {
+                (1.0 - u * u).powi(2)
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// One MSTL pass over `sorted_periods` (longest first) with robustness weights.
+fn mstl_pass(values: &[f64], sorted_periods: &[i32], weights: &[f64]) -> MstlDecomposition {
     let n = values.len();
     let mut current = values.to_vec();
     let mut seasonal_components: Vec<Vec<f64>> = Vec::new();
     let mut final_periods: Vec<i32> = Vec::new();
 
     // Iteratively extract seasonal components
-    for &period in &sorted_periods {
+    for &period in sorted_periods {
         let p = period as usize;
         if p < 2 || n < 2 * p {
             continue;
         }
 
-        match stl_decompose(&current, p) {
+        match weighted_stl_decompose(&current, p, weights) {
             Ok((_, seasonal, _)) => {
                 seasonal_components.push(seasonal.clone());
                 final_periods.push(period);
@@ -271,32 +405,8 @@ pub fn mstl_decompose(
     }
 
     // Final trend extraction from remaining series
-    let window = (n / 5).max(3).min(n);
-    let half_window = window / 2;
-
-    let mut trend = vec![f64::NAN; n];
-    for (i, trend_val) in trend
-        .iter_mut()
-        .enumerate()
-        .take(n.saturating_sub(half_window))
-        .skip(half_window)
-    {
-        let end = (i + half_window + 1).min(n);
-        let start = i.saturating_sub(half_window);
-        let sum: f64 = current[start..end].iter().sum();
-        *trend_val = sum / (end - start) as f64;
-    }
-
-    // Extend trend
-    let first_valid = trend.iter().position(|v| !v.is_nan()).unwrap_or(0);
-    let last_valid = trend.iter().rposition(|v| !v.is_nan()).unwrap_or(n - 1);
-
-    for i in 0..first_valid {
-        trend[i] = trend[first_valid];
-    }
-    for i in (last_valid + 1)..n {
-        trend[i] = trend[last_valid];
-    }
+    let window = (n / 5).max(3).min(n) | 1;
+    let trend = weighted_centered_moving_average(&current, weights, window);
 
     // Calculate remainder
     let mut remainder = current;
@@ -304,13 +414,108 @@ pub fn mstl_decompose(
         remainder[i] -= t;
     }
 
-    Ok(MstlDecomposition {
+    let seasonal_strength = (!seasonal_components.is_empty()).then(|| {
+        let total: Vec<f64> = (0..n)
+            .map(|i| seasonal_components.iter().map(|s| s[i]).sum())
+            .collect();
+        component_strength(&remainder, &total)
+    });
+    let trend_strength = Some(component_strength(&remainder, &trend));
+
+    MstlDecomposition {
         trend: Some(trend),
         seasonal: seasonal_components,
         periods: final_periods,
         remainder: Some(remainder),
         decomposition_applied: true,
-    })
+        seasonal_strength,
+        trend_strength,
+    }
+}
+
+/// Perform multiplicative MSTL decomposition.
+///
+/// Decomposes `ln(values)` with [`mstl_decompose`] and exponentiates the
+/// components, so `values = trend * seasonal_1 * ... * seasonal_k * remainder`.
+/// Suited to series whose seasonal swings grow with the level. Strengths are
+/// measured on the log scale.
+///
+/// # Errors
+/// Returns an error if any value is zero or negative, or on insufficient
+/// data as for [`mstl_decompose`].
+pub fn mstl_decompose_multiplicative(
+    values: &[f64],
+    periods: &[i32],
+    insufficient_data_mode: InsufficientDataMode,
+    robust: bool,
+) -> Result<MstlDecomposition> {
+    if values.iter().any(|&v| v <= 0.0) {
+        return Err(ForecastError::InvalidInput(
+            "Multiplicative MSTL requires strictly positive values".to_string(),
+        ));
+    }
+
+    let logs: Vec<f64> = values.iter().map(|v| v.ln()).collect();
+    let mut decomp = mstl_decompose(&logs, periods, insufficient_data_mode, robust)?;
+
+    let exp_all = |component: &mut Vec<f64>| component.iter_mut().for_each(|v| *v = v.exp());
+    if let Some(trend) = decomp.trend.as_mut() {
+        exp_all(trend);
+    }
+    decomp.seasonal.iter_mut().for_each(exp_all);
+    if let Some(remainder) = decomp.remainder.as_mut() {
+        exp_all(remainder);
+    }
+
+    Ok(decomp)
+}
+
+/// Remove the seasonal component from a series.
+///
+/// Uses the MSTL decomposition for a single period and returns
+/// `values - seasonal` (additive) or `values / seasonal` (multiplicative,
+/// strictly positive values only).
+///
+/// # Arguments
+/// * `values` - Time series values
+/// * `period` - Seasonal period (at least 2; the series needs two full cycles)
+/// * `mode` - Whether the seasonal pattern adds to or scales the level
+///
+/// # Returns
+/// * `Result<Vec<f64>>` - Seasonally adjusted series, same length as `values`
+pub fn seasonally_adjust(values: &[f64], period: usize, mode: SeasonalMode) -> Result<Vec<f64>> {
+    let period = i32::try_from(period)
+        .ok()
+        .filter(|&p| p >= 2)
+        .ok_or_else(|| ForecastError::InvalidParameter {
+            param: "period".to_string(),
+            value: period.to_string(),
+            reason: "Period must be at least 2".to_string(),
+        })?;
+
+    let adjusted = match mode {
+        SeasonalMode::Additive => {
+            let decomp = mstl_decompose(values, &[period], InsufficientDataMode::Fail, false)?;
+            let seasonal = decomp.seasonal.first().ok_or_else(|| {
+                ForecastError::ComputationError("No seasonal component".to_string())
+            })?;
+            values.iter().zip(seasonal).map(|(v, s)| v - s).collect()
+        }
+        SeasonalMode::Multiplicative => {
+            let decomp = mstl_decompose_multiplicative(
+                values,
+                &[period],
+                InsufficientDataMode::Fail,
+                false,
+            )?;
+            let seasonal = decomp.seasonal.first().ok_or_else(|| {
+                ForecastError::ComputationError("No seasonal component".to_string())
+            })?;
+            values.iter().zip(seasonal).map(|(v, s)| v / s).collect()
+        }
+    };
+
+    Ok(adjusted)
 }
 
 #[cfg(test)]
@@ -329,7 +534,7 @@ mod tests {
             })
             .collect();
 
-        let result = mstl_decompose(&values, &[12], InsufficientDataMode::Fail).unwrap();
+        let result = mstl_decompose(&values, &[12], InsufficientDataMode::Fail, false).unwrap();
 
         assert!(result.decomposition_applied);
         assert_eq!(result.trend.unwrap().len(), values.len());
@@ -338,17 +543,88 @@ mod tests {
         assert_eq!(result.remainder.unwrap().len(), values.len());
     }
 
+    #[test]
+    fn test_mstl_strengths() {
+        let seasonal: Vec<f64> = (0..120)
+            .map(|i| {
+                let noise = ((i * 7 + 3) % 11) as f64 * 0.02 - 0.1;
+                10.0 * (2.0 * PI * i as f64 / 12.0).sin() + noise
+            })
+            .collect();
+        let result = mstl_decompose(&seasonal, &[12], InsufficientDataMode::Fail, false).unwrap();
+        assert!(result.seasonal_strength.unwrap() > 0.95);
+        assert!(result.trend_strength.unwrap() < 0.5);
+
+        let trending: Vec<f64> = (0..120)
+            .map(|i| 0.5 * i as f64 + ((i * 7 + 3) % 11) as f64 * 0.1)
+            .collect();
+        let result = mstl_decompose(&trending, &[12], InsufficientDataMode::Fail, false).unwrap();
+        assert!(result.trend_strength.unwrap() > 0.95);
+        assert!(result.seasonal_strength.unwrap() < 0.5);
+
+        // Trend-only decompositions have no seasonal strength
+        let result = mstl_decompose(&trending, &[], InsufficientDataMode::Fail, false).unwrap();
+        assert!(result.seasonal_strength.is_none());
+        assert!(result.trend_strength.is_some());
+    }
+
+    #[test]
+    fn test_seasonally_adjust_additive() {
+        let values: Vec<f64> = (0..120)
+            .map(|i| {
+                0.5 * i as f64
+                    + 10.0 * (2.0 * PI * i as f64 / 12.0).sin()
+                    + ((i * 7 + 3) % 11) as f64 * 0.1
+            })
+            .collect();
+        let before = mstl_decompose(&values, &[12], InsufficientDataMode::Fail, false).unwrap();
+        assert!(before.seasonal_strength.unwrap() > 0.9);
+
+        let adjusted = seasonally_adjust(&values, 12, SeasonalMode::Additive).unwrap();
+        assert_eq!(adjusted.len(), values.len());
+        let after = mstl_decompose(&adjusted, &[12], InsufficientDataMode::Fail, false).unwrap();
+        assert!(
+            after.seasonal_strength.unwrap() < 0.5,
+            "seasonal strength after adjustment: {:?}",
+            after.seasonal_strength
+        );
+    }
+
+    #[test]
+    fn test_seasonally_adjust_multiplicative() {
+        // Seasonal swings proportional to the level
+        let values: Vec<f64> = (0..120)
+            .map(|i| (50.0 + i as f64) * (1.0 + 0.2 * (2.0 * PI * i as f64 / 12.0).sin()))
+            .collect();
+
+        let adjusted = seasonally_adjust(&values, 12, SeasonalMode::Multiplicative).unwrap();
+        // Away from the edges the adjusted series is close to the level
+        for (i, v) in adjusted.iter().enumerate().skip(12).take(96) {
+            let level = 50.0 + i as f64;
+            assert!(
+                (v - level).abs() / level < 0.05,
+                "t={}: {} vs {}",
+                i,
+                v,
+                level
+            );
+        }
+
+        assert!(seasonally_adjust(&[-1.0; 48], 12, SeasonalMode::Multiplicative).is_err());
+        assert!(seasonally_adjust(&values, 1, SeasonalMode::Additive).is_err());
+    }
+
     #[test]
     fn test_mstl_insufficient_data_fail() {
         let values = vec![1.0, 2.0, 3.0];
-        let result = mstl_decompose(&values, &[12], InsufficientDataMode::Fail);
+        let result = mstl_decompose(&values, &[12], InsufficientDataMode::Fail, false);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_mstl_insufficient_data_trend() {
         let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
-        let result = mstl_decompose(&values, &[12], InsufficientDataMode::Trend).unwrap();
+        let result = mstl_decompose(&values, &[12], InsufficientDataMode::Trend, false).unwrap();
 
         assert!(result.decomposition_applied);
         assert!(result.trend.is_some());
@@ -356,14 +632,118 @@ mod tests {
         assert!(result.remainder.is_some());
     }
 
+    #[test]
+    fn test_mstl_trend_only_even_window_keeps_level() {
+        // n / 5 = 4 is even; the trend must still be a mean of the window
+        // (dividing the 5-point sum by 4 used to overstate it by 25%).
+        let values = vec![10.0; 20];
+        let result = mstl_decompose(&values, &[], InsufficientDataMode::Fail, false).unwrap();
+
+        let trend = result.trend.unwrap();
+        assert!(
+            trend.iter().all(|t| (t - 10.0).abs() < 1e-12),
+            "{:?}",
+            trend
+        );
+        assert!(result.remainder.unwrap().iter().all(|r| r.abs() < 1e-12));
+    }
+
     #[test]
     fn test_mstl_insufficient_data_none() {
         let values = vec![1.0, 2.0];
-        let result = mstl_decompose(&values, &[12], InsufficientDataMode::None).unwrap();
+        let result = mstl_decompose(&values, &[12], InsufficientDataMode::None, false).unwrap();
 
         assert!(!result.decomposition_applied);
         assert!(result.trend.is_none());
         assert!(result.seasonal.is_empty());
         assert!(result.remainder.is_none());
     }
+
+    #[test]
+    fn test_mstl_multiplicative_flat_remainder() {
+        // Seasonal amplitude proportional to an exponentially growing level
+        let values: Vec<f64> = (0..120)
+            .map(|i| {
+                let level = 100.0 * (0.01 * i as f64).exp();
+                level * (1.0 + 0.3 * (2.0 * PI * i as f64 / 12.0).sin())
+            })
+            .collect();
+        let interior = 12..108;
+
+        let mult = mstl_decompose_multiplicative(&values, &[12], InsufficientDataMode::Fail, false)
+            .unwrap();
+        let trend = mult.trend.as_ref().unwrap();
+        let remainder = mult.remainder.as_ref().unwrap();
+        assert_eq!(mult.seasonal.len(), 1);
+
+        // Components multiply back to the series
+        for i in 0..values.len() {
+            let product = trend[i] * mult.seasonal[0][i] * remainder[i];
+            assert!((product / values[i] - 1.0).abs() < 1e-9);
+        }
+        assert!(remainder[interior.clone()]
+            .iter()
+            .all(|r| (r - 1.0).abs() < 0.05));
+
+        // The additive remainder still carries the growing seasonal swing
+        let add = mstl_decompose(&values, &[12], InsufficientDataMode::Fail, false).unwrap();
+        let add_trend = add.trend.unwrap();
+        let add_remainder = add.remainder.unwrap();
+        let max_relative = interior
+            .map(|i| (add_remainder[i] / add_trend[i]).abs())
+            .fold(0.0, f64::max);
+        assert!(max_relative > 0.1);
+    }
+
+    #[test]
+    fn test_mstl_multiplicative_rejects_non_positive() {
+        let mut values: Vec<f64> = (0..48).map(|i| 10.0 + (i % 12) as f64).collect();
+        values[5] = 0.0;
+        assert!(
+            mstl_decompose_multiplicative(&values, &[12], InsufficientDataMode::Fail, false)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_bisquare_weights_use_even_length_median() {
+        // median(|r|) = 2, so h = 12 rather than 18 from the upper middle value
+        let weights = bisquare_weights(&[1.0, -1.0, 3.0, -3.0]);
+        let expected = (1.0f64 - 0.25 * 0.25).powi(2);
+        assert!((weights[2] - expected).abs() < 1e-12);
+        assert!((weights[3] - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_mstl_robust_ignores_outlier() {
+        let mut values: Vec<f64> = (0..120)
+            .map(|i| 0.1 * i as f64 + 5.0 * (2.0 * PI * i as f64 / 12.0).sin())
+            .collect();
+        values[60] += 200.0;
+
+        let plain = mstl_decompose(&values, &[12], InsufficientDataMode::Fail, false).unwrap();
+        let robust = mstl_decompose(&values, &[12], InsufficientDataMode::Fail, true).unwrap();
+        let plain_trend = plain.trend.unwrap();
+        let robust_trend = robust.trend.unwrap();
+
+        // Largest deviation from the true linear trend near the spike
+        let bend = |trend: &[f64]| {
+            (40..80)
+                .map(|i| (trend[i] - 0.1 * i as f64).abs())
+                .fold(0.0, f64::max)
+        };
+        assert!(
+            bend(&plain_trend) > 4.0,
+            "plain bend {}",
+            bend(&plain_trend)
+        );
+        assert!(
+            bend(&robust_trend) < 1.0,
+            "robust bend {}",
+            bend(&robust_trend)
+        );
+
+        // The spike ends up in the robust remainder
+        assert!(robust.remainder.unwrap()[60] > 150.0);
+    }
 }
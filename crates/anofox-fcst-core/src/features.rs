@@ -3,8 +3,132 @@
 use crate::error::{ForecastError, Result};
 use std::collections::{HashMap, HashSet};
 
+/// Tunable parameters for feature extraction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureParams {
+    /// Highest lag for the `autocorrelation_lag{k}` features (default 10)
+    pub max_lag: usize,
+    /// Number of histogram bins for `binned_entropy` (default 10)
+    pub n_bins: usize,
+    /// Window length for the `agg_linear_trend_*` features (None = length / 10, at least 2)
+    pub rolling_window: Option<usize>,
+    /// Seasonal period for the tsfeatures-style STL features and tiling
+    /// (None = non-seasonal trend smoothing and tiles of 10)
+    pub seasonal_period: Option<usize>,
+}
+
+impl Default for FeatureParams {
+    fn default() -> Self {
+        Self {
+            max_lag: 10,
+            n_bins: 10,
+            rolling_window: None,
+            seasonal_period: None,
+        }
+    }
+}
+
+impl FeatureParams {
+    fn validate(&self) -> Result<()> {
+        if self.max_lag == 0 {
+            return Err(ForecastError::InvalidParameter {
+                param: "max_lag".to_string(),
+                value: self.max_lag.to_string(),
+                reason: "Maximum lag must be at least 1".to_string(),
+            });
+        }
+        if self.n_bins == 0 {
+            return Err(ForecastError::InvalidParameter {
+                param: "n_bins".to_string(),
+                value: self.n_bins.to_string(),
+                reason: "Number of bins must be at least 1".to_string(),
+            });
+        }
+        if let Some(window) = self.rolling_window {
+            if window < 2 {
+                return Err(ForecastError::InvalidParameter {
+                    param: "rolling_window".to_string(),
+                    value: window.to_string(),
+                    reason: "Rolling window must be at least 2".to_string(),
+                });
+            }
+        }
+        if let Some(period) = self.seasonal_period {
+            if period < 2 {
+                return Err(ForecastError::InvalidParameter {
+                    param: "seasonal_period".to_string(),
+                    value: period.to_string(),
+                    reason: "Seasonal period must be at least 2".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Extract all available features from a time series.
 pub fn extract_features(values: &[f64]) -> Result<HashMap<String, f64>> {
+    compute_features(values, &FeatureParams::default(), |_| true)
+}
+
+/// Extract all features using caller-supplied parameters.
+///
+/// With a `max_lag` other than 10 the set of `autocorrelation_lag{k}` keys
+/// differs from [`list_features`].
+pub fn extract_features_with_params(
+    values: &[f64],
+    params: &FeatureParams,
+) -> Result<HashMap<String, f64>> {
+    params.validate()?;
+    compute_features(values, params, |_| true)
+}
+
+/// Extract all features from many series.
+///
+/// Results are returned in input order. With the `parallel` feature the
+/// series are spread over `n_threads` threads (0 = rayon default).
+pub fn extract_features_batch(
+    series: &[&[f64]],
+    n_threads: usize,
+) -> Vec<Result<HashMap<String, f64>>> {
+    crate::parallel::map_batch(series, n_threads, |values| extract_features(values))
+}
+
+/// Extract only the named features from a time series.
+///
+/// Names must come from [`list_features`]; an unknown name is an error rather
+/// than being silently dropped. Expensive feature groups (entropies, DFT) are
+/// skipped entirely when none of their features is requested. Features that
+/// are undefined for the series (e.g. skewness of a constant) are absent from
+/// the result, as with [`extract_features`].
+pub fn extract_features_subset(values: &[f64], names: &[String]) -> Result<HashMap<String, f64>> {
+    if names.is_empty() {
+        return Err(ForecastError::InvalidInput(
+            "At least one feature name is required".to_string(),
+        ));
+    }
+
+    let available: HashSet<String> = list_features().into_iter().collect();
+    if let Some(unknown) = names.iter().find(|name| !available.contains(*name)) {
+        return Err(ForecastError::InvalidParameter {
+            param: "features".to_string(),
+            value: unknown.clone(),
+            reason: "Unknown feature name".to_string(),
+        });
+    }
+
+    let wanted: HashSet<&str> = names.iter().map(String::as_str).collect();
+    compute_features(values, &FeatureParams::default(), |name| {
+        wanted.contains(name)
+    })
+}
+
+/// Compute the features accepted by `wants`.
+fn compute_features(
+    values: &[f64],
+    params: &FeatureParams,
+    wants: impl Fn(&str) -> bool,
+) -> Result<HashMap<String, f64>> {
     if values.is_empty() {
         return Err(ForecastError::InsufficientData { needed: 1, got: 0 });
     }
@@ -108,8 +232,8 @@ pub fn extract_features(values: &[f64]) -> Result<HashMap<String, f64>> {
         features.insert("mean_abs_change".to_string(), mean_abs_change);
     }
 
-    // Extended Autocorrelation - lags 1-10
-    for lag in 1..=10 {
+    // Extended Autocorrelation - lags 1 to max_lag
+    for lag in 1..=params.max_lag {
         if values.len() > lag {
             features.insert(format!("autocorrelation_lag{}", lag), autocorr(values, lag));
         }
@@ -195,10 +319,12 @@ pub fn extract_features(values: &[f64]) -> Result<HashMap<String, f64>> {
     );
 
     // Benford correlation (first digit distribution)
-    features.insert(
-        "benford_correlation".to_string(),
-        benford_correlation(values),
-    );
+    if wants("benford_correlation") {
+        features.insert(
+            "benford_correlation".to_string(),
+            benford_correlation(values),
+        );
+    }
 
     // Linear trend
     let (slope, intercept, r_squared) = linear_trend(values);
@@ -207,19 +333,28 @@ pub fn extract_features(values: &[f64]) -> Result<HashMap<String, f64>> {
     features.insert("linear_trend_r_squared".to_string(), r_squared);
 
     // Entropy features
-    features.insert("binned_entropy".to_string(), binned_entropy(values, 10));
-    features.insert(
-        "sample_entropy".to_string(),
-        sample_entropy(values, 2, 0.2 * std_dev),
-    );
-    features.insert(
-        "approximate_entropy".to_string(),
-        approximate_entropy(values, 2, 0.2 * std_dev),
-    );
     features.insert(
-        "permutation_entropy".to_string(),
-        permutation_entropy(values, 3),
+        "binned_entropy".to_string(),
+        binned_entropy(values, params.n_bins),
     );
+    if wants("sample_entropy") {
+        features.insert(
+            "sample_entropy".to_string(),
+            sample_entropy(values, 2, 0.2 * std_dev),
+        );
+    }
+    if wants("approximate_entropy") {
+        features.insert(
+            "approximate_entropy".to_string(),
+            approximate_entropy(values, 2, 0.2 * std_dev),
+        );
+    }
+    if wants("permutation_entropy") {
+        features.insert(
+            "permutation_entropy".to_string(),
+            permutation_entropy(values, 3),
+        );
+    }
 
     // Ratio beyond r sigma
     for r in 1..=3 {
@@ -290,29 +425,42 @@ pub fn extract_features(values: &[f64]) -> Result<HashMap<String, f64>> {
     }
 
     // Lempel-Ziv complexity
-    features.insert(
-        "lempel_ziv_complexity".to_string(),
-        lempel_ziv_complexity(values, mean),
-    );
-
-    // Simplified FFT features (using DFT for small series)
-    let fft_coeffs = simple_dft(values);
-    for (i, coeff) in fft_coeffs.iter().enumerate().take(10) {
-        features.insert(format!("fft_coefficient_{}_real", i), coeff.0);
-        features.insert(format!("fft_coefficient_{}_imag", i), coeff.1);
+    if wants("lempel_ziv_complexity") {
         features.insert(
-            format!("fft_coefficient_{}_abs", i),
-            (coeff.0.powi(2) + coeff.1.powi(2)).sqrt(),
+            "lempel_ziv_complexity".to_string(),
+            lempel_ziv_complexity(values, mean),
         );
     }
 
-    // Spectral features
-    let (spectral_centroid, spectral_variance) = spectral_features(&fft_coeffs);
-    features.insert("spectral_centroid".to_string(), spectral_centroid);
-    features.insert("spectral_variance".to_string(), spectral_variance);
+    // Simplified FFT features (using DFT for small series)
+    let wants_spectral = wants("spectral_centroid")
+        || wants("spectral_variance")
+        || (0..10).any(|i| {
+            wants(&format!("fft_coefficient_{}_real", i))
+                || wants(&format!("fft_coefficient_{}_imag", i))
+                || wants(&format!("fft_coefficient_{}_abs", i))
+        });
+    if wants_spectral {
+        let fft_coeffs = simple_dft(values);
+        for (i, coeff) in fft_coeffs.iter().enumerate().take(10) {
+            features.insert(format!("fft_coefficient_{}_real", i), coeff.0);
+            features.insert(format!("fft_coefficient_{}_imag", i), coeff.1);
+            features.insert(
+                format!("fft_coefficient_{}_abs", i),
+                (coeff.0.powi(2) + coeff.1.powi(2)).sqrt(),
+            );
+        }
+
+        // Spectral features
+        let (spectral_centroid, spectral_variance) = spectral_features(&fft_coeffs);
+        features.insert("spectral_centroid".to_string(), spectral_centroid);
+        features.insert("spectral_variance".to_string(), spectral_variance);
+    }
 
     // Aggregated linear trend (chunked)
-    let chunk_len = (values.len() / 10).max(2);
+    let chunk_len = params
+        .rolling_window
+        .unwrap_or_else(|| (values.len() / 10).max(2));
     let (agg_slope, agg_intercept, agg_rvalue, agg_stderr) =
         aggregated_linear_trend(values, chunk_len);
     features.insert("agg_linear_trend_slope".to_string(), agg_slope);
@@ -320,9 +468,148 @@ pub fn extract_features(values: &[f64]) -> Result<HashMap<String, f64>> {
     features.insert("agg_linear_trend_rvalue".to_string(), agg_rvalue);
     features.insert("agg_linear_trend_stderr".to_string(), agg_stderr);
 
+    // tsfeatures-compatible STL and tiling features
+    if TSFEATURES_NAMES.iter().any(|name| wants(name)) {
+        insert_tsfeatures(&mut features, values, params.seasonal_period);
+    }
+
+    features.retain(|name, _| wants(name));
     Ok(features)
 }
 
+/// Feature names shared with R's `tsfeatures` package.
+const TSFEATURES_NAMES: [&str; 7] = [
+    "trend",
+    "spikiness",
+    "linearity",
+    "curvature",
+    "e_acf1",
+    "stability",
+    "lumpiness",
+];
+
+/// Insert the tsfeatures `stl_features`, `stability` and `lumpiness` values.
+///
+/// As with `tsfeatures(scale = TRUE)`, the series is standardised first. The
+/// trend comes from STL when a usable seasonal period is given and from a
+/// centered moving average otherwise. Nothing is inserted for constant or
+/// very short series.
+fn insert_tsfeatures(features: &mut HashMap<String, f64>, values: &[f64], period: Option<usize>) {
+    let n = values.len();
+    let sd = sample_variance(values).sqrt();
+    if n < 4 || !sd.is_finite() || sd < f64::EPSILON {
+        return;
+    }
+    let mean = values.iter().sum::<f64>() / n as f64;
+    let scaled: Vec<f64> = values.iter().map(|v| (v - mean) / sd).collect();
+
+    let period = period.filter(|&p| p >= 2 && n >= 2 * p);
+    let (trend, remainder) = match period {
+        Some(p) => match crate::decomposition::stl_decompose(&scaled, p) {
+            Ok((trend, _, remainder)) => (trend, remainder),
+            Err(_) => return,
+        },
+        None => {
+            let trend = crate::decomposition::centered_moving_average(&scaled, (n / 10).max(3) | 1);
+            let remainder = scaled.iter().zip(&trend).map(|(v, t)| v - t).collect();
+            (trend, remainder)
+        }
+    };
+
+    // Trend strength: 1 - Var(R) / Var(T + R)
+    let deseasonal: Vec<f64> = trend.iter().zip(&remainder).map(|(t, r)| t + r).collect();
+    let var_deseasonal = sample_variance(&deseasonal);
+    let trend_strength = if var_deseasonal > f64::EPSILON {
+        (1.0 - sample_variance(&remainder) / var_deseasonal).max(0.0)
+    } else {
+        0.0
+    };
+    features.insert("trend".to_string(), trend_strength);
+    features.insert("spikiness".to_string(), spikiness(&remainder));
+
+    let (linearity, curvature) = orthogonal_quadratic_coefficients(&trend);
+    features.insert("linearity".to_string(), linearity);
+    features.insert("curvature".to_string(), curvature);
+    features.insert("e_acf1".to_string(), autocorr(&remainder, 1));
+
+    // Tiled (non-overlapping) window statistics
+    let width = period.unwrap_or(10);
+    let tile_means: Vec<f64> = scaled
+        .chunks(width)
+        .map(|tile| tile.iter().sum::<f64>() / tile.len() as f64)
+        .collect();
+    let tile_vars: Vec<f64> = scaled
+        .chunks(width)
+        .filter(|tile| tile.len() > 1)
+        .map(sample_variance)
+        .collect();
+    features.insert("stability".to_string(), sample_variance(&tile_means));
+    features.insert("lumpiness".to_string(), sample_variance(&tile_vars));
+}
+
+/// Sample variance (n - 1 denominator); NaN for fewer than two values.
+fn sample_variance(values: &[f64]) -> f64 {
+    let n = values.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+    let mean = values.iter().sum::<f64>() / n as f64;
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+}
+
+/// Variance of the leave-one-out variances of the remainder.
+fn spikiness(remainder: &[f64]) -> f64 {
+    let n = remainder.len();
+    if n < 3 {
+        return f64::NAN;
+    }
+    let sum: f64 = remainder.iter().sum();
+    let sum_sq: f64 = remainder.iter().map(|r| r * r).sum();
+    let m = (n - 1) as f64;
+
+    let loo_vars: Vec<f64> = remainder
+        .iter()
+        .map(|r| {
+            let s = sum - r;
+            (sum_sq - r * r - s * s / m) / (m - 1.0)
+        })
+        .collect();
+    sample_variance(&loo_vars)
+}
+
+/// Linear and quadratic coefficients of a regression on orthonormal
+/// polynomials of time, as R's `lm(x ~ poly(t, 2))`.
+fn orthogonal_quadratic_coefficients(values: &[f64]) -> (f64, f64) {
+    let n = values.len();
+    let t_mean = (n as f64 - 1.0) / 2.0;
+
+    let p1: Vec<f64> = (0..n).map(|i| i as f64 - t_mean).collect();
+    let p1_norm = p1.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if p1_norm < f64::EPSILON {
+        return (0.0, 0.0);
+    }
+    let p1: Vec<f64> = p1.iter().map(|v| v / p1_norm).collect();
+
+    // Orthogonalise t^2 against the constant and linear terms
+    let sq: Vec<f64> = p1.iter().map(|v| v * v).collect();
+    let sq_mean = sq.iter().sum::<f64>() / n as f64;
+    let proj: f64 = sq.iter().zip(&p1).map(|(a, b)| a * b).sum();
+    let p2: Vec<f64> = sq
+        .iter()
+        .zip(&p1)
+        .map(|(s, l)| s - sq_mean - proj * l)
+        .collect();
+    let p2_norm = p2.iter().map(|v| v * v).sum::<f64>().sqrt();
+
+    let linearity = values.iter().zip(&p1).map(|(v, p)| v * p).sum();
+    let curvature = if p2_norm < f64::EPSILON {
+        0.0
+    } else {
+        values.iter().zip(&p2).map(|(v, p)| v * p).sum::<f64>() / p2_norm
+    };
+    (linearity, curvature)
+}
+
 /// List all available feature names.
 pub fn list_features() -> Vec<String> {
     let mut features = vec![
@@ -437,6 +724,9 @@ pub fn list_features() -> Vec<String> {
         features.push(format!("fft_coefficient_{}_abs", i));
     }
 
+    // tsfeatures-compatible (7)
+    features.extend(TSFEATURES_NAMES.iter().map(|name| name.to_string()));
+
     features
 }
 
@@ -1126,6 +1416,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_features_subset() {
+        let values: Vec<f64> = (0..50).map(|i| (i as f64 * 0.3).sin()).collect();
+        let names = vec!["mean".to_string(), "sample_entropy".to_string()];
+        let features = extract_features_subset(&values, &names).unwrap();
+
+        assert_eq!(features.len(), 2);
+        let all = extract_features(&values).unwrap();
+        assert_eq!(features["mean"], all["mean"]);
+        assert_eq!(
+            features["sample_entropy"].to_bits(),
+            all["sample_entropy"].to_bits()
+        );
+
+        let bad = vec!["mean".to_string(), "not_a_feature".to_string()];
+        assert!(matches!(
+            extract_features_subset(&values, &bad),
+            Err(ForecastError::InvalidParameter { value, .. }) if value == "not_a_feature"
+        ));
+        assert!(extract_features_subset(&values, &[]).is_err());
+    }
+
+    #[test]
+    fn test_extract_features_with_params() {
+        let values: Vec<f64> = (0..60)
+            .map(|i| (i as f64 * 0.5).sin() + i as f64 * 0.1)
+            .collect();
+
+        let default = extract_features_with_params(&values, &FeatureParams::default()).unwrap();
+        let mut default_keys: Vec<_> = default.keys().collect();
+        let all = extract_features(&values).unwrap();
+        let mut all_keys: Vec<_> = all.keys().collect();
+        default_keys.sort();
+        all_keys.sort();
+        assert_eq!(default_keys, all_keys);
+
+        let params = FeatureParams {
+            max_lag: 3,
+            n_bins: 4,
+            rolling_window: Some(5),
+            seasonal_period: None,
+        };
+        let tuned = extract_features_with_params(&values, &params).unwrap();
+        assert!(tuned.contains_key("autocorrelation_lag3"));
+        assert!(!tuned.contains_key("autocorrelation_lag4"));
+        assert!(default.contains_key("autocorrelation_lag10"));
+        assert_ne!(tuned["binned_entropy"], default["binned_entropy"]);
+        assert_ne!(
+            tuned["agg_linear_trend_stderr"],
+            default["agg_linear_trend_stderr"]
+        );
+
+        let long = FeatureParams {
+            max_lag: 20,
+            ..FeatureParams::default()
+        };
+        let long = extract_features_with_params(&values, &long).unwrap();
+        assert!(long.contains_key("autocorrelation_lag20"));
+
+        let bad = FeatureParams {
+            max_lag: 0,
+            ..FeatureParams::default()
+        };
+        assert!(extract_features_with_params(&values, &bad).is_err());
+    }
+
+    #[test]
+    fn test_tsfeatures_compatible_features() {
+        let values: Vec<f64> = (0..96)
+            .map(|i| {
+                let t = i as f64;
+                10.0 + 0.2 * t
+                    + 3.0 * (2.0 * std::f64::consts::PI * t / 12.0).sin()
+                    + ((i * 7) % 5) as f64 * 0.1
+            })
+            .collect();
+        let names = list_features();
+
+        for params in [
+            FeatureParams::default(),
+            FeatureParams {
+                seasonal_period: Some(12),
+                ..FeatureParams::default()
+            },
+        ] {
+            let features = extract_features_with_params(&values, &params).unwrap();
+            for name in TSFEATURES_NAMES {
+                assert!(names.contains(&name.to_string()));
+                let value = features[name];
+                assert!(value.is_finite(), "{name} = {value}");
+            }
+            assert!((0.0..=1.0).contains(&features["trend"]));
+            assert!(features["linearity"] > 0.0);
+        }
+
+        // A pure linear trend is fully explained by the trend component
+        let linear: Vec<f64> = (0..50).map(|i| i as f64).collect();
+        let features = extract_features(&linear).unwrap();
+        assert!(features["trend"] > 0.99);
+        assert!(features["curvature"].abs() < 1e-6);
+    }
+
     #[test]
     fn test_new_entropy_features() {
         let values = vec![1.0, 2.0, 3.0, 2.0, 1.0, 3.0, 2.0, 1.0, 3.0, 2.0];
@@ -5,7 +5,7 @@
 //! - **Constant detection**: Identify and remove series with no variation
 //! - **Length filtering**: Remove series that are too short for analysis
 //! - **Edge trimming**: Remove leading/trailing zeros
-//! - **Differencing**: Compute differences for stationarity
+//! - **Differencing**: Compute differences for stationarity, and integrate them back
 //!
 //! # Example Usage
 //!
@@ -17,7 +17,7 @@
 //! assert!(!is_constant(&trimmed));
 //! ```
 
-use crate::error::Result;
+use crate::error::{ForecastError, Result};
 
 /// Checks if a series is constant (all non-NULL values are the same).
 ///
@@ -149,6 +149,41 @@ pub fn diff(values: &[f64], order: usize) -> Result<Vec<f64>> {
     Ok(result)
 }
 
+/// Invert [`diff`] by cumulatively summing back up `order` times.
+///
+/// `initial[i]` is the first value of the series differenced `i` times
+/// (so `initial[0]` is the first original value). The result has
+/// `diffed.len() + order` values, and `undiff(&diff(x, k)?, seeds, k)`
+/// reproduces `x`.
+///
+/// # Errors
+/// Returns `InvalidInput` if `initial.len() != order`.
+pub fn undiff(diffed: &[f64], initial: &[f64], order: usize) -> Result<Vec<f64>> {
+    if initial.len() != order {
+        return Err(ForecastError::InvalidInput(format!(
+            "undiff requires {} initial values for order {}, got {}",
+            order,
+            order,
+            initial.len()
+        )));
+    }
+
+    let mut result = diffed.to_vec();
+
+    for &seed in initial.iter().rev() {
+        let mut level = Vec::with_capacity(result.len() + 1);
+        let mut acc = seed;
+        level.push(acc);
+        for d in &result {
+            acc += d;
+            level.push(acc);
+        }
+        result = level;
+    }
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -250,6 +285,28 @@ mod tests {
         assert_eq!(result, values);
     }
 
+    #[test]
+    fn test_undiff_round_trip() {
+        let values = vec![3.0, 1.5, 4.0, 10.0, 9.25, 2.0, 8.5];
+
+        for order in 0..=3 {
+            let seeds: Vec<f64> = (0..order).map(|i| diff(&values, i).unwrap()[0]).collect();
+            let diffed = diff(&values, order).unwrap();
+            let restored = undiff(&diffed, &seeds, order).unwrap();
+
+            assert_eq!(restored.len(), values.len());
+            for (r, v) in restored.iter().zip(values.iter()) {
+                assert!((r - v).abs() < 1e-10, "order {}: {} vs {}", order, r, v);
+            }
+        }
+    }
+
+    #[test]
+    fn test_undiff_invalid_initial() {
+        let result = undiff(&[1.0, 2.0], &[0.0], 2);
+        assert!(matches!(result, Err(ForecastError::InvalidInput(_))));
+    }
+
     #[test]
     fn test_diff_short_series() {
         let values = vec![1.0];
@@ -3,6 +3,7 @@
 use crate::error::{ForecastError, Result};
 use crate::imputation::fill_nulls_interpolate;
 use crate::seasonality::detect_seasonality;
+use crate::stats::{golden_section_min, robust_scale};
 
 // Model types from anofox-forecast crate
 use anofox_forecast::core::TimeSeries;
@@ -15,9 +16,10 @@ use anofox_forecast::models::intermittent::{Croston, ADIDA, IMAPA, TSB};
 use anofox_forecast::models::laplace::LaplaceForecaster;
 use anofox_forecast::models::mstl_forecaster::MSTLForecaster;
 use anofox_forecast::models::tbats::{AutoTBATS, TBATS as TBATSModel};
-use anofox_forecast::models::theta::{AutoTheta, DynamicTheta, OptimizedTheta, Theta};
+use anofox_forecast::models::theta::{AutoTheta, DynamicTheta, OptimizedTheta};
 use anofox_forecast::models::MFLES;
 use anofox_forecast::prelude::Forecaster;
+use statrs::distribution::{ContinuousCDF, Normal};
 
 /// Forecast result.
 #[derive(Debug, Clone)]
@@ -40,6 +42,18 @@ pub struct ForecastOutput {
     pub bic: Option<f64>,
     /// MSE of in-sample fit
     pub mse: Option<f64>,
+    /// Holdout score (MASE, or RMSE when MASE is undefined) of the selected
+    /// model when `auto_select_by_holdout` was used
+    pub holdout_score: Option<f64>,
+    /// Seasonal period the forecast used (1 when treated as non-seasonal);
+    /// set by the public forecasting entry points
+    pub seasonal_period: Option<usize>,
+    /// Automatic fallbacks taken while producing the forecast (e.g. a model
+    /// replaced by a simpler one, or no seasonal period detected)
+    pub warnings: Vec<String>,
+    /// `(lower, upper)` bounds for each of `ForecastOptions::confidence_levels`,
+    /// in the same order
+    pub intervals: Vec<(Vec<f64>, Vec<f64>)>,
 }
 
 /// Selector variant for [`ModelType::Laplace`].
@@ -87,6 +101,59 @@ impl LaplaceVariant {
     }
 }
 
+/// Seasonal component form for HoltWinters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SeasonalMode {
+    /// `level + trend + seasonal`; works for series with zeros or negatives.
+    #[default]
+    Additive,
+    /// `(level + trend) * seasonal`; seasonal swings scale with the level.
+    /// Requires strictly positive values.
+    Multiplicative,
+}
+
+impl SeasonalMode {
+    /// Parse a mode name (case-insensitive; `additive`, `multiplicative`).
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "additive" | "add" | "a" => Ok(SeasonalMode::Additive),
+            "multiplicative" | "mul" | "m" => Ok(SeasonalMode::Multiplicative),
+            other => Err(ForecastError::InvalidParameter {
+                param: "seasonal_mode".to_string(),
+                value: other.to_string(),
+                reason: "expected one of: additive, multiplicative".to_string(),
+            }),
+        }
+    }
+}
+
+/// How prediction intervals around the point forecast are computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PredictionIntervalMethod {
+    /// Gaussian intervals: `point ± z * scale * sqrt(h)`.
+    #[default]
+    Normal,
+    /// Empirical quantiles of simulated paths that accumulate resampled
+    /// in-sample residuals along the horizon. Captures skewed and
+    /// fat-tailed errors.
+    Bootstrap,
+}
+
+impl PredictionIntervalMethod {
+    /// Parse a method name (case-insensitive; `normal`, `bootstrap`).
+    pub fn parse(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "" | "normal" | "gaussian" => Ok(PredictionIntervalMethod::Normal),
+            "bootstrap" => Ok(PredictionIntervalMethod::Bootstrap),
+            other => Err(ForecastError::InvalidParameter {
+                param: "interval_method".to_string(),
+                value: other.to_string(),
+                reason: "expected one of: normal, bootstrap".to_string(),
+            }),
+        }
+    }
+}
+
 /// Available forecast models - matches C++ extension exactly.
 /// See: <https://github.com/DataZooDE/anofox-forecast/blob/main/docs/API_REFERENCE.md#supported-models>
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -139,6 +206,9 @@ pub enum ModelType {
     IMAPA,
     TSB,
 
+    // Count Data Models (1)
+    CountSES,
+
     // Distributional Models (1) — Laplace shell over EMA/drift/AR(1)/Holt
     // leaves. Variant (Auto / AutoAid / Skaters) is carried in
     // `ForecastOptions.laplace_variant`.
@@ -192,6 +262,7 @@ impl std::str::FromStr for ModelType {
             "IMAPA" => return Ok(ModelType::IMAPA),
             "TSB" => return Ok(ModelType::TSB),
             // Distributional
+            "CountSES" => return Ok(ModelType::CountSES),
             "Laplace" => return Ok(ModelType::Laplace),
             _ => {}
         }
@@ -247,11 +318,17 @@ impl std::str::FromStr for ModelType {
             "adida" => Ok(ModelType::ADIDA),
             "imapa" => Ok(ModelType::IMAPA),
             "tsb" => Ok(ModelType::TSB),
+            // Count Data
+            "countses" | "count_ses" | "poisson_ses" => Ok(ModelType::CountSES),
             // Distributional
             "laplace" => Ok(ModelType::Laplace),
             // Auto selection (legacy, maps to AutoETS)
             "auto" => Ok(ModelType::AutoETS),
-            _ => Err(ForecastError::InvalidModel(format!("Unknown model: {}", s))),
+            _ => Err(ForecastError::InvalidModel(format!(
+                "Unknown model: '{}'. Valid models: {}",
+                s,
+                list_models().join(", ")
+            ))),
         }
     }
 }
@@ -300,10 +377,69 @@ impl ModelType {
             ModelType::ADIDA => "ADIDA",
             ModelType::IMAPA => "IMAPA",
             ModelType::TSB => "TSB",
+            // Count Data
+            ModelType::CountSES => "CountSES",
             // Distributional
             ModelType::Laplace => "Laplace",
         }
     }
+
+    /// Features the model supports.
+    pub fn capabilities(&self) -> ModelCapabilities {
+        let supports_exog = matches!(
+            self,
+            ModelType::ARIMA
+                | ModelType::AutoARIMA
+                | ModelType::OptimizedTheta
+                | ModelType::DynamicTheta
+                | ModelType::MFLES
+                | ModelType::AutoMFLES
+                | ModelType::ETS
+                | ModelType::AutoETS
+                | ModelType::HoltWinters
+        );
+        let is_intermittent = matches!(
+            self,
+            ModelType::CrostonClassic
+                | ModelType::CrostonOptimized
+                | ModelType::CrostonSBA
+                | ModelType::TSB
+                | ModelType::ADIDA
+                | ModelType::IMAPA
+        );
+        let supports_seasonal = !is_intermittent
+            && !matches!(
+                self,
+                ModelType::Naive
+                    | ModelType::SES
+                    | ModelType::SESOptimized
+                    | ModelType::Holt
+                    | ModelType::RandomWalkDrift
+                    | ModelType::ARIMA
+                    | ModelType::CountSES
+            );
+
+        ModelCapabilities {
+            supports_exog,
+            supports_seasonal,
+            is_intermittent,
+            produces_intervals: true,
+        }
+    }
+}
+
+/// Capability flags of a [`ModelType`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModelCapabilities {
+    /// Uses exogenous regressors in [`forecast_with_exog`]
+    pub supports_exog: bool,
+    /// Uses `seasonal_period`; other models reject an explicit period
+    pub supports_seasonal: bool,
+    /// Designed for intermittent (sparse) demand
+    pub is_intermittent: bool,
+    /// Returns prediction intervals. Every model does today (count models
+    /// use discrete Poisson bounds, the rest the configured interval method).
+    pub produces_intervals: bool,
 }
 
 /// Forecast options.
@@ -318,6 +454,9 @@ pub struct ForecastOptions {
     pub horizon: usize,
     /// Confidence level (0-1)
     pub confidence_level: f64,
+    /// Additional confidence levels (0-1) whose bounds are returned in
+    /// [`ForecastOutput::intervals`] from the same fit. Default empty.
+    pub confidence_levels: Vec<f64>,
     /// Seasonal period (0 = auto-detect)
     pub seasonal_period: usize,
     /// Auto-detect seasonality
@@ -344,6 +483,33 @@ pub struct ForecastOptions {
     /// abandons the seasonal-EMA leaf for a differenced-EMA leaf and
     /// the forecast collapses to flat. Default `false`.
     pub laplace_seasonal_batch_init: bool,
+    /// Estimate the interval scale robustly via the MAD of the in-sample
+    /// residuals (`1.4826 * median(|r|)`) instead of their root mean square.
+    /// Default `false`.
+    pub robust_intervals: bool,
+    /// For Auto* models, backtest a pool of candidate models on a holdout
+    /// tail (scored by MASE, falling back to RMSE) and refit the winner on
+    /// the full series instead of running the auto model directly. Series
+    /// too short to hold out a tail get the model picked by
+    /// [`select_best_model`] from their trend and seasonal strength.
+    /// Default `false`.
+    pub auto_select_by_holdout: bool,
+    /// Damp the trend of Holt and HoltWinters so that long-horizon forecasts
+    /// flatten out (`level + trend * Σ phi^i`). Default `false`.
+    pub damped: bool,
+    /// Damping factor in (0, 1] used when `damped` is set. `None` estimates
+    /// it together with the smoothing parameters.
+    pub phi: Option<f64>,
+    /// Prediction interval method. `robust_intervals` only applies to
+    /// [`PredictionIntervalMethod::Normal`].
+    pub interval_method: PredictionIntervalMethod,
+    /// HoltWinters seasonal form. Multiplicative requires strictly positive
+    /// values. Default additive.
+    pub seasonal_mode: SeasonalMode,
+    /// Fit and forecast on `ln(y)`. Point forecasts are back-transformed with
+    /// the bias correction `exp(mu + sigma^2 / 2)`, interval bounds with plain
+    /// `exp`. Requires strictly positive values. Default `false`.
+    pub log_transform: bool,
 }
 
 impl Default for ForecastOptions {
@@ -353,6 +519,7 @@ impl Default for ForecastOptions {
             ets_spec: None,
             horizon: 12,
             confidence_level: 0.95,
+            confidence_levels: vec![],
             seasonal_period: 0,
             auto_detect_seasonality: true,
             include_fitted: false,
@@ -362,6 +529,13 @@ impl Default for ForecastOptions {
             model_pool: None,
             laplace_variant: None,
             laplace_seasonal_batch_init: false,
+            robust_intervals: false,
+            auto_select_by_holdout: false,
+            damped: false,
+            phi: None,
+            interval_method: PredictionIntervalMethod::Normal,
+            seasonal_mode: SeasonalMode::Additive,
+            log_transform: false,
         }
     }
 }
@@ -464,6 +638,16 @@ pub struct ForecastOptionsExog {
     pub laplace_variant: Option<LaplaceVariant>,
     /// Enable `LaplaceForecaster::with_seasonal_batch_init()` (opt-in).
     pub laplace_seasonal_batch_init: bool,
+    /// Estimate the interval scale robustly via MAD instead of the standard deviation.
+    pub robust_intervals: bool,
+    /// Prediction interval method.
+    pub interval_method: PredictionIntervalMethod,
+    /// Damp the trend of Holt and HoltWinters (see [`ForecastOptions::damped`]).
+    pub damped: bool,
+    /// Damping factor in (0, 1]; `None` estimates it.
+    pub phi: Option<f64>,
+    /// HoltWinters seasonal form.
+    pub seasonal_mode: SeasonalMode,
 }
 
 impl Default for ForecastOptionsExog {
@@ -483,6 +667,11 @@ impl Default for ForecastOptionsExog {
             model_pool: None,
             laplace_variant: None,
             laplace_seasonal_batch_init: false,
+            robust_intervals: false,
+            interval_method: PredictionIntervalMethod::Normal,
+            damped: false,
+            phi: None,
+            seasonal_mode: SeasonalMode::Additive,
         }
     }
 }
@@ -504,14 +693,219 @@ impl From<ForecastOptions> for ForecastOptionsExog {
             model_pool: opts.model_pool,
             laplace_variant: opts.laplace_variant,
             laplace_seasonal_batch_init: opts.laplace_seasonal_batch_init,
+            robust_intervals: opts.robust_intervals,
+            interval_method: opts.interval_method,
+            damped: opts.damped,
+            phi: opts.phi,
+            seasonal_mode: opts.seasonal_mode,
         }
     }
 }
 
 /// Generate forecasts for a time series.
+///
+/// Equivalent to `fit(values, options)?.predict(options.horizon)`.
 pub fn forecast(values: &[Option<f64>], options: &ForecastOptions) -> Result<ForecastOutput> {
+    fit(values, options)?.predict(options.horizon)
+}
+
+/// Forecast many series with shared options.
+///
+/// Each series is forecast independently, so one failing series does not
+/// affect the others; results are returned in input order. With the
+/// `parallel` feature the series are spread over `n_threads` threads
+/// (0 = rayon default).
+pub fn forecast_batch(
+    series: &[&[Option<f64>]],
+    options: &ForecastOptions,
+    n_threads: usize,
+) -> Vec<Result<ForecastOutput>> {
+    crate::parallel::map_batch(series, n_threads, |values| forecast(values, options))
+}
+
+/// Simple exponential smoothing updated one observation at a time.
+///
+/// Each [`OnlineSes::update`] is O(1), so forecasts stay current without
+/// reprocessing history. The level starts at the first observation;
+/// non-finite values (NULLs) are skipped.
+#[derive(Debug, Clone)]
+pub struct OnlineSes {
+    alpha: f64,
+    level: Option<f64>,
+    n_observations: usize,
+}
+
+impl OnlineSes {
+    /// Create an updater with smoothing parameter `alpha` in (0, 1].
+    pub fn new(alpha: f64) -> Result<Self> {
+        if alpha.is_nan() || alpha <= 0.0 || alpha > 1.0 {
+            return Err(ForecastError::InvalidParameter {
+                param: "alpha".to_string(),
+                value: alpha.to_string(),
+                reason: "must be in (0, 1]".to_string(),
+            });
+        }
+
+        Ok(Self {
+            alpha,
+            level: None,
+            n_observations: 0,
+        })
+    }
+
+    /// Incorporate one observation.
+    pub fn update(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        self.level = Some(match self.level {
+            Some(level) => level + self.alpha * (value - level),
+            None => value,
+        });
+        self.n_observations += 1;
+    }
+
+    /// Current smoothed level, or `None` before the first observation.
+    pub fn level(&self) -> Option<f64> {
+        self.level
+    }
+
+    /// Number of observations incorporated so far.
+    pub fn n_observations(&self) -> usize {
+        self.n_observations
+    }
+
+    /// Flat forecast of the current level; empty before the first observation.
+    pub fn forecast(&self, horizon: usize) -> Vec<f64> {
+        self.level
+            .map(|level| vec![level; horizon])
+            .unwrap_or_default()
+    }
+}
+
+/// Forecast with several models and average them (model averaging).
+///
+/// Point forecasts are the weighted mean of the member forecasts; `weights`
+/// are normalised to sum to one and default to equal weights. Intervals are
+/// combined conservatively as the pointwise minimum of the member lower
+/// bounds and maximum of the upper bounds. Members with zero weight are not
+/// fitted and do not widen the intervals.
+pub fn forecast_ensemble(
+    values: &[Option<f64>],
+    models: &[ModelType],
+    horizon: usize,
+    weights: Option<&[f64]>,
+) -> Result<ForecastOutput> {
+    if models.is_empty() {
+        return Err(ForecastError::InvalidInput(
+            "Ensemble requires at least one model".to_string(),
+        ));
+    }
+
+    let weights: Vec<f64> = match weights {
+        Some(w) => {
+            if w.len() != models.len() {
+                return Err(ForecastError::InvalidInput(format!(
+                    "Ensemble has {} models but {} weights",
+                    models.len(),
+                    w.len()
+                )));
+            }
+            let total: f64 = w.iter().sum();
+            if w.iter().any(|v| !v.is_finite() || *v < 0.0) || total <= 0.0 {
+                return Err(ForecastError::InvalidParameter {
+                    param: "weights".to_string(),
+                    value: format!("{:?}", w),
+                    reason: "must be non-negative with a positive sum".to_string(),
+                });
+            }
+            w.iter().map(|v| v / total).collect()
+        }
+        None => vec![1.0 / models.len() as f64; models.len()],
+    };
+
+    let mut point = vec![0.0; horizon];
+    let mut lower = vec![f64::INFINITY; horizon];
+    let mut upper = vec![f64::NEG_INFINITY; horizon];
+    let mut warnings = Vec::new();
+    for (&model, &weight) in models.iter().zip(weights.iter()) {
+        if weight == 0.0 {
+            continue;
+        }
+        let options = ForecastOptions {
+            model,
+            horizon,
+            ..Default::default()
+        };
+        let member = forecast(values, &options)?;
+        for (p, m) in point.iter_mut().zip(member.point.iter()) {
+            *p += weight * m;
+        }
+        for (l, m) in lower.iter_mut().zip(member.lower.iter()) {
+            *l = l.min(*m);
+        }
+        for (u, m) in upper.iter_mut().zip(member.upper.iter()) {
+            *u = u.max(*m);
+        }
+        warnings.extend(
+            member
+                .warnings
+                .into_iter()
+                .map(|w| format!("{}: {}", model.name(), w)),
+        );
+    }
+
+    let names: Vec<&str> = models.iter().map(|m| m.name()).collect();
+    Ok(ForecastOutput {
+        point,
+        lower,
+        upper,
+        fitted: None,
+        residuals: None,
+        model_name: format!("Ensemble({})", names.join(",")),
+        aic: None,
+        bic: None,
+        mse: None,
+        holdout_score: None,
+        seasonal_period: None,
+        warnings,
+        intervals: Vec::new(),
+    })
+}
+
+/// A model prepared by [`fit`] that can produce forecasts with [`FittedModel::predict`].
+///
+/// Holds the fitted model state together with the preprocessed series, the
+/// resolved model and seasonal period, and the in-sample fitted values and
+/// residuals of that fit, so forecasting and residual reuse (e.g. for
+/// `conformal_learn`) never fit a second time.
+#[derive(Debug)]
+pub struct FittedModel {
+    values: Vec<f64>,
+    options: ForecastOptions,
+    model: ModelType,
+    period: usize,
+    holdout_score: Option<f64>,
+    fit: ModelFit,
+    fitted: Vec<f64>,
+    residuals: Vec<f64>,
+    warnings: Vec<String>,
+}
+
+/// Fit a model for forecasting: interpolate NULLs, resolve the seasonal
+/// period and model, and fit the model to the series.
+pub fn fit(values: &[Option<f64>], options: &ForecastOptions) -> Result<FittedModel> {
+    if options.log_transform && values.iter().flatten().any(|&v| v <= 0.0) {
+        return Err(ForecastError::InvalidInput(
+            "log_transform requires strictly positive values".to_string(),
+        ));
+    }
+
     // Handle NULLs by interpolation
-    let clean_values: Vec<f64> = fill_nulls_interpolate(values);
+    let mut clean_values: Vec<f64> = fill_nulls_interpolate(values);
+    if options.log_transform {
+        clean_values.iter_mut().for_each(|v| *v = v.ln());
+    }
 
     if clean_values.is_empty() {
         return Err(ForecastError::InsufficientData { needed: 1, got: 0 });
@@ -524,12 +918,31 @@ pub fn forecast(values: &[Option<f64>], options: &ForecastOptions) -> Result<For
         });
     }
 
+    if let Some(&level) = options
+        .confidence_levels
+        .iter()
+        .find(|&&l| !(l > 0.0 && l < 1.0))
+    {
+        return Err(ForecastError::InvalidParameter {
+            param: "confidence_levels".to_string(),
+            value: level.to_string(),
+            reason: "Confidence levels must be in (0, 1)".to_string(),
+        });
+    }
+
+    let mut warnings = Vec::new();
+
     // Detect seasonality if needed
     let period = if options.auto_detect_seasonality && options.seasonal_period == 0 {
-        detect_seasonality(&clean_values, None)
+        let detected = detect_seasonality(&clean_values, None)
             .ok()
-            .and_then(|p| p.first().cloned())
-            .unwrap_or(1) as usize
+            .and_then(|p| p.first().cloned());
+        if detected.is_none() && options.model.capabilities().supports_seasonal {
+            warnings.push(
+                "No seasonal period detected; forecasting as non-seasonal (period 1)".to_string(),
+            );
+        }
+        detected.unwrap_or(1) as usize
     } else if options.seasonal_period > 0 {
         options.seasonal_period
     } else {
@@ -539,22 +952,7 @@ pub fn forecast(values: &[Option<f64>], options: &ForecastOptions) -> Result<For
     // Validate seasonal_period vs model compatibility
     // Only error when user explicitly set seasonal_period (auto_detect_seasonality == false)
     if !options.auto_detect_seasonality && options.seasonal_period > 1 {
-        let non_seasonal = matches!(
-            options.model,
-            ModelType::Naive
-                | ModelType::SES
-                | ModelType::SESOptimized
-                | ModelType::Holt
-                | ModelType::RandomWalkDrift
-                | ModelType::ARIMA
-                | ModelType::CrostonClassic
-                | ModelType::CrostonOptimized
-                | ModelType::CrostonSBA
-                | ModelType::TSB
-                | ModelType::ADIDA
-                | ModelType::IMAPA
-        );
-        if non_seasonal {
+        if !options.model.capabilities().supports_seasonal {
             return Err(ForecastError::InvalidInput(format!(
                 "Model '{}' does not use seasonal_period (got {}). \
                  For seasonal forecasting, use: SeasonalNaive, HoltWinters, SeasonalES, AutoETS, AutoMFLES, AutoMSTL, or AutoTBATS.",
@@ -562,174 +960,229 @@ pub fn forecast(values: &[Option<f64>], options: &ForecastOptions) -> Result<For
                 options.seasonal_period
             )));
         }
+        let max_period = clean_values.len() / 2;
+        if options.seasonal_period > max_period {
+            return Err(ForecastError::InvalidInput(format!(
+                "seasonal_period {} needs at least two full cycles; a series of {} points allows at most {}",
+                options.seasonal_period,
+                clean_values.len(),
+                max_period
+            )));
+        }
     }
 
-    // Generate forecast based on model
+    // Optionally replace the Auto* model with the best candidate on a holdout
+    // tail, or with the strength-based pick when no tail can be held out
+    let (model, holdout_score) = if options.auto_select_by_holdout && is_auto_model(options.model) {
+        match select_model_by_holdout(&clean_values, options, period) {
+            Some((winner, score)) => (winner, Some(score)),
+            None => (select_best_model(&clean_values, period).model, None),
+        }
+    } else {
+        (options.model, None)
+    };
+
+    // Generate the fit based on model
     // Note: Auto* models (AutoARIMA, AutoETS, etc.) run their respective algorithms
     // with automatic parameter selection, not a generic model selection heuristic
-    let result = match options.model {
-        // Basic Models
-        ModelType::Naive => forecast_naive(&clean_values, options.horizon),
-        ModelType::SeasonalNaive => forecast_seasonal_naive(&clean_values, options.horizon, period),
-        ModelType::SMA => {
-            let w = if options.window > 0 {
-                options.window
-            } else {
-                period.max(3)
-            };
-            forecast_sma(&clean_values, options.horizon, w)
-        }
-        ModelType::RandomWalkDrift => forecast_drift(&clean_values, options.horizon),
-        // Exponential Smoothing
-        ModelType::SES => forecast_ses_fixed(&clean_values, options.horizon),
-        ModelType::SESOptimized => forecast_ses_optimized(&clean_values, options.horizon),
-        ModelType::Holt => forecast_holt_lib(&clean_values, options.horizon),
-        ModelType::HoltWinters => forecast_holt_winters_lib(&clean_values, options.horizon, period),
-        ModelType::SeasonalES => forecast_seasonal_es_lib(&clean_values, options.horizon, period),
-        ModelType::SeasonalESOptimized => {
-            forecast_seasonal_es_optimized(&clean_values, options.horizon, period)
-        }
-        ModelType::SeasonalWindowAverage => {
-            forecast_seasonal_window_average(&clean_values, options.horizon, period)
-        }
-        ModelType::ETS => forecast_ets(
-            &clean_values,
-            options.horizon,
-            period,
-            options.ets_spec.as_deref(),
-        ),
-        ModelType::AutoETS => forecast_auto_ets(
-            &clean_values,
-            options.horizon,
+    let model_fit = fit_with_model(&clean_values, model, period, options)?;
+
+    Ok(FittedModel::new(
+        clean_values,
+        options.clone(),
+        model,
+        period,
+        holdout_score,
+        model_fit,
+        warnings,
+    ))
+}
+
+impl FittedModel {
+    /// Wrap a model fit, deriving in-sample fitted values and residuals from it.
+    fn new(
+        values: Vec<f64>,
+        options: ForecastOptions,
+        model: ModelType,
+        period: usize,
+        holdout_score: Option<f64>,
+        fit: ModelFit,
+        warnings: Vec<String>,
+    ) -> Self {
+        let fitted = fit.fitted_values(&values);
+        let residuals = values
+            .iter()
+            .zip(fitted.iter())
+            .map(|(a, f)| a - f)
+            .collect();
+        Self {
+            values,
+            options,
+            model,
             period,
-            options.model_pool.as_deref(),
-        ),
-        // Theta Methods
-        ModelType::Theta => forecast_theta_stm(&clean_values, options.horizon, period),
-        ModelType::OptimizedTheta => {
-            forecast_optimized_theta(&clean_values, options.horizon, period)
+            holdout_score,
+            fit,
+            fitted,
+            residuals,
+            warnings,
         }
-        ModelType::DynamicTheta => forecast_dynamic_theta(&clean_values, options.horizon, period),
-        ModelType::DynamicOptimizedTheta => {
-            forecast_dynamic_optimized_theta(&clean_values, options.horizon, period)
+    }
+
+    /// The model that will produce forecasts (after any holdout selection).
+    pub fn model(&self) -> ModelType {
+        self.model
+    }
+
+    /// The resolved seasonal period (1 when non-seasonal).
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// In-sample one-step fitted values (on the log scale with `log_transform`).
+    pub fn fitted_values(&self) -> &[f64] {
+        &self.fitted
+    }
+
+    /// In-sample residuals (actual - fitted; on the log scale with `log_transform`).
+    pub fn residuals(&self) -> &[f64] {
+        &self.residuals
+    }
+
+    /// Forecast `horizon` steps ahead from the fitted state.
+    pub fn predict(&self, horizon: usize) -> Result<ForecastOutput> {
+        let mut output = self.predict_model_scale(horizon)?;
+        output.warnings.splice(0..0, self.warnings.iter().cloned());
+        if self.options.log_transform {
+            Ok(self.exp_back_transform(output))
+        } else {
+            Ok(output)
         }
-        ModelType::AutoTheta => forecast_auto_theta(&clean_values, options.horizon, period),
-        // ARIMA
-        ModelType::ARIMA => forecast_arima(&clean_values, options.horizon),
-        ModelType::AutoARIMA => forecast_auto_arima(&clean_values, options.horizon, period),
-        // Multiple Seasonality
-        ModelType::MFLES | ModelType::AutoMFLES => {
-            let periods = if !options.seasonal_periods.is_empty() {
-                &options.seasonal_periods
-            } else if period > 1 {
-                &vec![period]
-            } else {
-                &vec![]
-            };
-            forecast_mfles(&clean_values, options.horizon, periods)
+    }
+
+    /// Map a forecast made on the log scale back to the data scale.
+    ///
+    /// Point forecasts use the lognormal mean `exp(mu + sigma^2 / 2)`, where
+    /// `sigma^2` is the mean squared in-sample residual; interval bounds and
+    /// fitted values are quantiles and use plain `exp`.
+    fn exp_back_transform(&self, mut output: ForecastOutput) -> ForecastOutput {
+        let finite: Vec<f64> = self
+            .residuals
+            .iter()
+            .copied()
+            .filter(|r| r.is_finite())
+            .collect();
+        let sigma2 = if finite.is_empty() {
+            0.0
+        } else {
+            finite.iter().map(|r| r * r).sum::<f64>() / finite.len() as f64
+        };
+
+        output
+            .point
+            .iter_mut()
+            .for_each(|p| *p = (*p + sigma2 / 2.0).exp());
+        output.lower.iter_mut().for_each(|v| *v = v.exp());
+        output.upper.iter_mut().for_each(|v| *v = v.exp());
+        for (lower, upper) in output.intervals.iter_mut() {
+            lower.iter_mut().for_each(|v| *v = v.exp());
+            upper.iter_mut().for_each(|v| *v = v.exp());
         }
-        ModelType::MSTL | ModelType::AutoMSTL => {
-            let periods = if !options.seasonal_periods.is_empty() {
-                options.seasonal_periods.clone()
-            } else if period > 1 {
-                vec![period]
-            } else {
-                vec![12]
-            };
-            let mut result = forecast_mstl_lib(&clean_values, options.horizon, &periods)?;
-            if options.model == ModelType::AutoMSTL {
-                result.model_name = "AutoMSTL".to_string();
-            }
-            Ok(result)
+
+        let actual: Vec<f64> = self.values.iter().map(|v| v.exp()).collect();
+        let fitted: Vec<f64> = self.fitted.iter().map(|f| f.exp()).collect();
+        if let Some(out_fitted) = output.fitted.as_mut() {
+            out_fitted.clone_from(&fitted);
         }
-        ModelType::TBATS => {
-            let periods = if !options.seasonal_periods.is_empty() {
-                options.seasonal_periods.clone()
-            } else if period > 1 {
-                vec![period]
-            } else {
-                vec![12]
-            };
-            forecast_tbats_lib(&clean_values, options.horizon, &periods)
+        if let Some(residuals) = output.residuals.as_mut() {
+            *residuals = actual.iter().zip(&fitted).map(|(a, f)| a - f).collect();
         }
-        ModelType::AutoTBATS => {
-            let periods = if !options.seasonal_periods.is_empty() {
-                &options.seasonal_periods
-            } else if period > 1 {
-                &vec![period]
-            } else {
-                &vec![12]
-            };
-            forecast_auto_tbats(&clean_values, options.horizon, periods)
+        if output.mse.is_some() {
+            let sse: f64 = actual
+                .iter()
+                .zip(&fitted)
+                .map(|(a, f)| (a - f).powi(2))
+                .sum();
+            output.mse = Some(sse / actual.len() as f64);
         }
-        // Intermittent Demand
-        ModelType::CrostonClassic => forecast_croston_classic(&clean_values, options.horizon),
-        ModelType::CrostonOptimized => forecast_croston_optimized(&clean_values, options.horizon),
-        ModelType::CrostonSBA => forecast_croston_sba(&clean_values, options.horizon),
-        ModelType::TSB => forecast_tsb(&clean_values, options.horizon),
-        ModelType::ADIDA => forecast_adida(&clean_values, options.horizon),
-        ModelType::IMAPA => forecast_imapa(&clean_values, options.horizon),
-        // Distributional
-        ModelType::Laplace => forecast_laplace(
-            &clean_values,
-            options.horizon,
-            period,
-            options.laplace_variant.unwrap_or_default(),
-            options.laplace_seasonal_batch_init,
-            options.confidence_level,
-        ),
-    }?;
+        output
+    }
 
-    // Calculate confidence intervals
-    let (lower, upper) =
-        calculate_confidence_intervals(&result.point, &clean_values, options.confidence_level);
+    /// Forecast on the scale the model was fitted on.
+    fn predict_model_scale(&self, horizon: usize) -> Result<ForecastOutput> {
+        let options = &self.options;
+        let clean_values = &self.values;
+        let model = self.model;
+        let period = self.period;
+
+        let point = self.fit.forecast(horizon)?;
+
+        // Calculate confidence intervals (count models keep their discrete intervals)
+        let intervals_at = |level: f64| -> Result<(Vec<f64>, Vec<f64>)> {
+            if let ModelState::Count { lambda } = self.fit.state {
+                Ok(count_intervals(lambda, horizon, level))
+            } else if options.interval_method == PredictionIntervalMethod::Bootstrap {
+                residual_bootstrap_intervals(&point, &self.residuals, level)
+            } else {
+                Ok(calculate_confidence_intervals(
+                    &point,
+                    &self.residuals,
+                    level,
+                    options.robust_intervals,
+                    self.fit.growth,
+                ))
+            }
+        };
+        let (lower, upper) = intervals_at(options.confidence_level)?;
+        let intervals = options
+            .confidence_levels
+            .iter()
+            .map(|&level| intervals_at(level))
+            .collect::<Result<Vec<_>>>()?;
 
-    // Calculate fitted values and residuals if requested
-    let (fitted, residuals) = if options.include_fitted || options.include_residuals {
-        let fitted = calculate_fitted_values(&clean_values, options.model, period);
-        let residuals = if options.include_residuals {
-            Some(
-                clean_values
-                    .iter()
-                    .zip(fitted.iter())
-                    .map(|(a, f)| a - f)
-                    .collect(),
-            )
+        // Report fitted values and residuals if requested
+        let (fitted, residuals) = if options.include_fitted || options.include_residuals {
+            let residuals = if options.include_residuals {
+                Some(self.residuals.clone())
+            } else {
+                None
+            };
+            (Some(self.fitted.clone()), residuals)
         } else {
-            None
+            (None, None)
         };
-        (Some(fitted), residuals)
-    } else {
-        (None, None)
-    };
 
-    // Calculate MSE
-    let mse = fitted.as_ref().map(|f| {
-        let sse: f64 = clean_values
-            .iter()
-            .zip(f.iter())
-            .map(|(a, f)| (a - f).powi(2))
-            .sum();
-        sse / clean_values.len() as f64
-    });
+        // Calculate MSE
+        let mse = fitted.as_ref().map(|f| {
+            let sse: f64 = clean_values
+                .iter()
+                .zip(f.iter())
+                .map(|(a, f)| (a - f).powi(2))
+                .sum();
+            sse / clean_values.len() as f64
+        });
 
-    Ok(ForecastOutput {
-        point: result.point,
-        lower,
-        upper,
-        fitted: if options.include_fitted { fitted } else { None },
-        residuals,
-        // Use the model_name from the result (contains selected parameters for Auto* models)
-        // Fall back to enum name if result doesn't have a specific name
-        model_name: if result.model_name.is_empty() {
-            options.model.name().to_string()
-        } else {
-            result.model_name
-        },
-        aic: None,
-        bic: None,
-        mse,
-    })
+        Ok(ForecastOutput {
+            point,
+            lower,
+            upper,
+            fitted: if options.include_fitted { fitted } else { None },
+            residuals,
+            // Use the model_name from the fit (contains selected parameters for Auto* models)
+            // Fall back to enum name if the fit doesn't have a specific name
+            model_name: if self.fit.model_name.is_empty() {
+                model.name().to_string()
+            } else {
+                self.fit.model_name.clone()
+            },
+            aic: self.fit.aic,
+            bic: self.fit.bic,
+            mse,
+            holdout_score: self.holdout_score,
+            seasonal_period: Some(seasonal_period_used(model, period)),
+            warnings: self.fit.warnings.clone(),
+            intervals,
+        })
+    }
 }
 
 /// Generate forecasts with exogenous variables.
@@ -743,6 +1196,7 @@ pub fn forecast(values: &[Option<f64>], options: &ForecastOptions) -> Result<For
 /// - `AutoARIMA`, `ARIMA` (ARIMAX)
 /// - `OptimizedTheta`, `DynamicTheta`
 /// - `MFLES`
+/// - `ETS`, `AutoETS` (ETSX), `HoltWinters` (HoltWintersX)
 ///
 /// Other models will ignore the exogenous data and produce a standard forecast.
 ///
@@ -800,27 +1254,39 @@ pub fn forecast_with_exog(
         1
     };
 
+    let model_options = ForecastOptions {
+        model: options.model,
+        ets_spec: options.ets_spec.clone(),
+        horizon: options.horizon,
+        confidence_level: options.confidence_level,
+        include_fitted: options.include_fitted,
+        include_residuals: options.include_residuals,
+        window: options.window,
+        seasonal_periods: options.seasonal_periods.clone(),
+        model_pool: options.model_pool.clone(),
+        laplace_variant: options.laplace_variant,
+        laplace_seasonal_batch_init: options.laplace_seasonal_batch_init,
+        robust_intervals: options.robust_intervals,
+        interval_method: options.interval_method,
+        damped: options.damped,
+        phi: options.phi,
+        seasonal_mode: options.seasonal_mode,
+        ..Default::default()
+    };
+
     // Check if requested model supports exogenous variables
-    let supports_exog = matches!(
-        options.model,
-        ModelType::ARIMA
-            | ModelType::AutoARIMA
-            | ModelType::OptimizedTheta
-            | ModelType::DynamicTheta
-            | ModelType::MFLES
-            | ModelType::AutoMFLES
-    );
+    let supports_exog = options.model.capabilities().supports_exog;
 
-    // Generate forecast based on model
-    // For models that support exog with exog data provided, use exogenous-aware forecasting
+    // Fit based on model
+    // For models that support exog with exog data provided, use exogenous-aware fitting
     // Don't do auto-selection when using exog - use the requested model family
-    let result = if let (true, Some(exog)) = (supports_exog, options.exog.as_ref()) {
+    let model_fit = if let (true, Some(exog)) = (supports_exog, options.exog.as_ref()) {
         match options.model {
             ModelType::ARIMA | ModelType::AutoARIMA => {
-                forecast_arima_with_exog(&clean_values, options.horizon, exog)
+                fit_arima_with_exog(&clean_values, options.horizon, exog)
             }
             ModelType::OptimizedTheta | ModelType::DynamicTheta | ModelType::AutoTheta => {
-                forecast_theta_with_exog(&clean_values, options.horizon, exog)
+                fit_theta_with_exog(&clean_values, options.horizon, exog)
             }
             ModelType::MFLES | ModelType::AutoMFLES => {
                 let periods = if !options.seasonal_periods.is_empty() {
@@ -830,337 +1296,792 @@ pub fn forecast_with_exog(
                 } else {
                     &vec![]
                 };
-                forecast_mfles_with_exog(&clean_values, options.horizon, periods, exog)
+                fit_mfles_with_exog(&clean_values, options.horizon, periods, exog)
+            }
+            ModelType::ETS | ModelType::AutoETS | ModelType::HoltWinters => {
+                fit_ets_with_exog(&clean_values, period, &model_options, exog)
             }
             _ => {
                 // Shouldn't happen due to supports_exog check, but fallback to ARIMA with exog
-                forecast_arima_with_exog(&clean_values, options.horizon, exog)
+                fit_arima_with_exog(&clean_values, options.horizon, exog)
             }
         }
     } else {
-        // No exog data or model doesn't support exog - use standard forecasting
+        // No exog data or model doesn't support exog - use standard fitting
         // Auto* models run their respective algorithms with automatic parameter selection
-        forecast_with_model(
-            &clean_values,
-            options.horizon,
-            options.model,
-            period,
-            options.window,
-            &options.seasonal_periods,
-            options.model_pool.as_deref(),
-            options.laplace_variant.unwrap_or_default(),
-            options.laplace_seasonal_batch_init,
-            options.confidence_level,
-        )
+        fit_with_model(&clean_values, options.model, period, &model_options)
     }?;
 
-    // For fitted values calculation, use the requested model
-    let model = options.model;
-
-    // Calculate confidence intervals
-    let (lower, upper) =
-        calculate_confidence_intervals(&result.point, &clean_values, options.confidence_level);
-
-    // Calculate fitted values and residuals if requested
-    let (fitted, residuals) = if options.include_fitted || options.include_residuals {
-        let fitted = calculate_fitted_values(&clean_values, options.model, period);
-        let residuals = if options.include_residuals {
-            Some(
-                clean_values
-                    .iter()
-                    .zip(fitted.iter())
-                    .map(|(a, f)| a - f)
-                    .collect(),
-            )
-        } else {
-            None
-        };
-        (Some(fitted), residuals)
-    } else {
-        (None, None)
-    };
+    FittedModel::new(
+        clean_values,
+        model_options,
+        options.model,
+        period,
+        None,
+        model_fit,
+        Vec::new(),
+    )
+    .predict(options.horizon)
+}
 
-    // Calculate MSE
-    let mse = fitted.as_ref().map(|f| {
-        let sse: f64 = clean_values
-            .iter()
-            .zip(f.iter())
-            .map(|(a, f)| (a - f).powi(2))
-            .sum();
-        sse / clean_values.len() as f64
-    });
-
-    // Use the model name from the result (e.g., "ARIMAX" for exog) if available,
-    // otherwise use the model enum name
-    let model_name = if !result.model_name.is_empty() {
-        result.model_name
+/// Seasonal period reported in [`ForecastOutput::seasonal_period`]:
+/// 1 for models that ignore seasonality.
+fn seasonal_period_used(model: ModelType, period: usize) -> usize {
+    if model.capabilities().supports_seasonal {
+        period.max(1)
     } else {
-        model.name().to_string()
-    };
-
-    Ok(ForecastOutput {
-        point: result.point,
-        lower,
-        upper,
-        fitted: if options.include_fitted { fitted } else { None },
-        residuals,
-        model_name,
-        aic: None,
-        bic: None,
-        mse,
-    })
+        1
+    }
 }
 
-/// Internal helper to forecast with a specific model (no exog).
-#[allow(clippy::too_many_arguments)]
-fn forecast_with_model(
+/// Internal helper to fit a specific model (no exog).
+fn fit_with_model(
     values: &[f64],
-    horizon: usize,
     model: ModelType,
     period: usize,
-    window: usize,
-    seasonal_periods: &[usize],
-    model_pool: Option<&str>,
-    laplace_variant: LaplaceVariant,
-    laplace_seasonal_batch_init: bool,
-    confidence_level: f64,
-) -> Result<ForecastOutput> {
+    options: &ForecastOptions,
+) -> Result<ModelFit> {
     match model {
         // Basic Models
-        ModelType::Naive => forecast_naive(values, horizon),
-        ModelType::SeasonalNaive => forecast_seasonal_naive(values, horizon, period),
+        ModelType::Naive => Ok(fit_naive(values)),
+        ModelType::SeasonalNaive => Ok(fit_seasonal_naive(values, period)),
         ModelType::SMA => {
-            let w = if window > 0 { window } else { period.max(3) };
-            forecast_sma(values, horizon, w)
+            let w = if options.window > 0 {
+                options.window
+            } else {
+                period.max(3)
+            };
+            Ok(fit_sma(values, w))
         }
-        ModelType::RandomWalkDrift => forecast_drift(values, horizon),
+        ModelType::RandomWalkDrift => Ok(fit_drift(values)),
         // Exponential Smoothing
-        ModelType::SES => forecast_ses_fixed(values, horizon),
-        ModelType::SESOptimized => forecast_ses_optimized(values, horizon),
-        ModelType::Holt => forecast_holt_lib(values, horizon),
-        ModelType::HoltWinters => forecast_holt_winters_lib(values, horizon, period),
-        ModelType::SeasonalES => forecast_seasonal_es_lib(values, horizon, period),
-        ModelType::SeasonalESOptimized => forecast_seasonal_es_optimized(values, horizon, period),
-        ModelType::SeasonalWindowAverage => {
-            forecast_seasonal_window_average(values, horizon, period)
-        }
-        ModelType::ETS => forecast_ets(values, horizon, period, None),
-        ModelType::AutoETS => forecast_auto_ets(values, horizon, period, model_pool),
-        // Theta Methods
-        ModelType::Theta => forecast_theta_stm(values, horizon, period),
-        ModelType::OptimizedTheta => forecast_optimized_theta(values, horizon, period),
-        ModelType::DynamicTheta => forecast_dynamic_theta(values, horizon, period),
-        ModelType::DynamicOptimizedTheta => {
-            forecast_dynamic_optimized_theta(values, horizon, period)
+        ModelType::SES => fit_ses_fixed(values),
+        ModelType::SESOptimized => fit_ses_optimized(values),
+        ModelType::Holt if options.damped => {
+            fit_damped_holt(values, 1, options.seasonal_mode, options.phi, "Holt")
         }
-        ModelType::AutoTheta => forecast_auto_theta(values, horizon, period),
+        ModelType::HoltWinters if options.damped => fit_damped_holt(
+            values,
+            period,
+            options.seasonal_mode,
+            options.phi,
+            "HoltWinters",
+        ),
+        ModelType::Holt => fit_holt(values),
+        ModelType::HoltWinters => fit_holt_winters(values, period, options.seasonal_mode),
+        ModelType::SeasonalES => fit_seasonal_es(values, period),
+        ModelType::SeasonalESOptimized => fit_seasonal_es_optimized(values, period),
+        ModelType::SeasonalWindowAverage => fit_seasonal_window_average(values, period),
+        ModelType::ETS => fit_ets(values, period, options.ets_spec.as_deref()),
+        ModelType::AutoETS => fit_auto_ets(values, period, options.model_pool.as_deref()),
+        // Theta Methods
+        ModelType::Theta => fit_theta(values, period),
+        ModelType::OptimizedTheta => fit_optimized_theta(values, period),
+        ModelType::DynamicTheta => fit_dynamic_theta(values, period),
+        ModelType::DynamicOptimizedTheta => fit_dynamic_optimized_theta(values, period),
+        ModelType::AutoTheta => fit_auto_theta(values, period),
         // ARIMA
-        ModelType::ARIMA => forecast_arima(values, horizon),
-        ModelType::AutoARIMA => forecast_auto_arima(values, horizon, period),
+        ModelType::ARIMA => Ok(fit_arima(values)),
+        ModelType::AutoARIMA => fit_auto_arima(values, period),
         // Multiple Seasonality
         ModelType::MFLES | ModelType::AutoMFLES => {
-            let periods = if !seasonal_periods.is_empty() {
-                seasonal_periods
+            let periods = if !options.seasonal_periods.is_empty() {
+                &options.seasonal_periods
             } else if period > 1 {
                 &vec![period]
             } else {
                 &vec![]
             };
-            forecast_mfles(values, horizon, periods)
+            let mut fit = fit_mfles(values, periods)?;
+            fit.model_name = model.name().to_string();
+            Ok(fit)
         }
         ModelType::MSTL | ModelType::AutoMSTL => {
-            let periods = if !seasonal_periods.is_empty() {
-                seasonal_periods.to_vec()
+            let periods = if !options.seasonal_periods.is_empty() {
+                options.seasonal_periods.clone()
             } else if period > 1 {
                 vec![period]
             } else {
                 vec![12]
             };
-            let mut result = forecast_mstl_lib(values, horizon, &periods)?;
+            let mut fit = fit_mstl(values, &periods)?;
             if model == ModelType::AutoMSTL {
-                result.model_name = "AutoMSTL".to_string();
+                fit.model_name = "AutoMSTL".to_string();
             }
-            Ok(result)
+            Ok(fit)
         }
         ModelType::TBATS => {
-            let periods = if !seasonal_periods.is_empty() {
-                seasonal_periods.to_vec()
+            let periods = if !options.seasonal_periods.is_empty() {
+                options.seasonal_periods.clone()
             } else if period > 1 {
                 vec![period]
             } else {
                 vec![12]
             };
-            forecast_tbats_lib(values, horizon, &periods)
+            fit_tbats(values, &periods)
         }
         ModelType::AutoTBATS => {
-            let periods = if !seasonal_periods.is_empty() {
-                seasonal_periods
+            let periods = if !options.seasonal_periods.is_empty() {
+                &options.seasonal_periods
             } else if period > 1 {
                 &vec![period]
             } else {
                 &vec![12]
             };
-            forecast_auto_tbats(values, horizon, periods)
+            fit_auto_tbats(values, periods)
         }
         // Intermittent Demand
-        ModelType::CrostonClassic => forecast_croston_classic(values, horizon),
-        ModelType::CrostonOptimized => forecast_croston_optimized(values, horizon),
-        ModelType::CrostonSBA => forecast_croston_sba(values, horizon),
-        ModelType::TSB => forecast_tsb(values, horizon),
-        ModelType::ADIDA => forecast_adida(values, horizon),
-        ModelType::IMAPA => forecast_imapa(values, horizon),
+        ModelType::CrostonClassic => fit_croston_classic(values),
+        ModelType::CrostonOptimized => fit_croston_optimized(values),
+        ModelType::CrostonSBA => fit_croston_sba(values),
+        ModelType::TSB => fit_tsb(values),
+        ModelType::ADIDA => fit_adida(values),
+        ModelType::IMAPA => fit_imapa(values),
+        // Count Data
+        ModelType::CountSES => fit_count_ses(values),
         // Distributional
-        ModelType::Laplace => forecast_laplace(
+        ModelType::Laplace => fit_laplace(
             values,
-            horizon,
             period,
-            laplace_variant,
-            laplace_seasonal_batch_init,
-            confidence_level,
+            options.laplace_variant.unwrap_or_default(),
+            options.laplace_seasonal_batch_init,
+            options.confidence_level,
         ),
     }
 }
 
+/// State a fitted model forecasts from.
+#[derive(Debug)]
+enum ModelState {
+    /// Fitted anofox-forecast model; with `level`, forecasts come from
+    /// `predict_with_intervals` at that level.
+    Library {
+        model: LibraryModel,
+        level: Option<f64>,
+    },
+    /// Repeats the cycle (last value, last season or window mean).
+    Cycle(Vec<f64>),
+    /// Random walk with drift.
+    Drift { last: f64, slope: f64 },
+    /// Simplified ARIMA(1,1,1): AR(1) on the differenced series.
+    Arima {
+        last: f64,
+        last_diff: f64,
+        mean_diff: f64,
+        ar_coef: f64,
+    },
+    /// Smoothed Poisson rate of [`fit_count_ses`].
+    Count { lambda: f64 },
+    /// Classic Theta: the theta=0 line at the last observation, its slope and
+    /// the SES level of the theta=2 line; `index[i]` applies `i + 1` steps ahead.
+    Theta {
+        intercept: f64,
+        slope: f64,
+        level: f64,
+        index: Vec<f64>,
+    },
+    /// Final state of a damped-trend run; `seasonal[i]` applies `i + 1` steps
+    /// ahead, as a factor when `multiplicative`.
+    Damped {
+        level: f64,
+        trend: f64,
+        phi: f64,
+        seasonal: Vec<f64>,
+        multiplicative: bool,
+    },
+    /// Model on the residuals of a regression on exogenous variables;
+    /// `effect` is the regression part over the known future.
+    Regression {
+        residual_fit: Box<ModelFit>,
+        effect: Vec<f64>,
+    },
+}
+
+/// A fitted library model, boxed so [`ModelState`] can own any of them.
+struct LibraryModel(Box<dyn Forecaster>);
+
+impl std::fmt::Debug for LibraryModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("LibraryModel").field(&self.0.name()).finish()
+    }
+}
+
+/// Result of fitting one model: the state to forecast from and its in-sample fit.
+#[derive(Debug)]
+struct ModelFit {
+    state: ModelState,
+    /// Reported model name; empty means the `ModelType` name.
+    model_name: String,
+    /// One-step fitted values (NaN where the model leaves them undefined).
+    fitted: Option<Vec<f64>>,
+    aic: Option<f64>,
+    bic: Option<f64>,
+    /// Forecast-error variance growth of the fitted model.
+    growth: VarianceGrowth,
+    warnings: Vec<String>,
+}
+
+impl ModelFit {
+    /// Fit computed in this crate.
+    fn new(state: ModelState, fitted: Vec<f64>) -> Self {
+        Self {
+            state,
+            model_name: String::new(),
+            fitted: Some(fitted),
+            aic: None,
+            bic: None,
+            growth: VarianceGrowth::RandomWalk,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Fit of a library model; fitted values are the ones the model reports.
+    /// `name`: use this instead of model.name() to match our ModelType enum names.
+    fn library(model: impl Forecaster + 'static, name: &str) -> Self {
+        let fitted = model.fitted_values().map(|v| v.to_vec());
+        Self {
+            state: ModelState::Library {
+                model: LibraryModel(Box::new(model)),
+                level: None,
+            },
+            model_name: name.to_string(),
+            fitted,
+            aic: None,
+            bic: None,
+            growth: VarianceGrowth::RandomWalk,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Regression on exogenous variables plus `residual_fit` on its residuals.
+    fn regression(
+        values: &[f64],
+        residuals: &[f64],
+        mut residual_fit: ModelFit,
+        effect: Vec<f64>,
+        name: &str,
+    ) -> Self {
+        let fitted = values
+            .iter()
+            .zip(residuals.iter())
+            .zip(residual_fit.fitted_values(residuals))
+            .map(|((y, r), f)| y - r + f)
+            .collect();
+        Self {
+            warnings: std::mem::take(&mut residual_fit.warnings),
+            growth: residual_fit.growth,
+            state: ModelState::Regression {
+                residual_fit: Box::new(residual_fit),
+                effect,
+            },
+            model_name: name.to_string(),
+            fitted: Some(fitted),
+            aic: None,
+            bic: None,
+        }
+    }
+
+    /// Point forecasts `horizon` steps ahead of the fitted state.
+    fn forecast(&self, horizon: usize) -> Result<Vec<f64>> {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        match &self.state {
+            ModelState::Library { model, level } => {
+                let predicted = catch_unwind(AssertUnwindSafe(|| match level {
+                    Some(level) => model.0.predict_with_intervals(horizon, *level),
+                    None => model.0.predict(horizon),
+                }))
+                .map_err(|_| {
+                    ForecastError::ComputationError(format!("{} predict panicked", self.model_name))
+                })?;
+                let forecast = predicted.map_err(|e| {
+                    ForecastError::ComputationError(format!(
+                        "{} predict failed: {}",
+                        self.model_name, e
+                    ))
+                })?;
+                Ok(forecast.primary().to_vec())
+            }
+            ModelState::Cycle(cycle) => Ok((0..horizon).map(|i| cycle[i % cycle.len()]).collect()),
+            ModelState::Drift { last, slope } => {
+                Ok((1..=horizon).map(|h| last + slope * h as f64).collect())
+            }
+            ModelState::Arima {
+                last,
+                last_diff,
+                mean_diff,
+                ar_coef,
+            } => {
+                let mut point = Vec::with_capacity(horizon);
+                let mut prev_diff = *last_diff;
+                let mut cumsum = *last;
+                for _ in 0..horizon {
+                    let next_diff = mean_diff + ar_coef * (prev_diff - mean_diff);
+                    cumsum += next_diff;
+                    point.push(cumsum);
+                    prev_diff = next_diff;
+                }
+                Ok(point)
+            }
+            ModelState::Count { lambda } => Ok(vec![poisson_quantile(*lambda, 0.5); horizon]),
+            ModelState::Theta {
+                intercept,
+                slope,
+                level,
+                index,
+            } => Ok((1..=horizon)
+                .map(|h| {
+                    let line = intercept + slope * h as f64;
+                    0.5 * (line + level) * index[(h - 1) % index.len()]
+                })
+                .collect()),
+            ModelState::Damped {
+                level,
+                trend,
+                phi,
+                seasonal,
+                multiplicative,
+            } => {
+                let mut damping = 0.0;
+                Ok((1..=horizon)
+                    .map(|h| {
+                        damping += phi.powi(h as i32);
+                        let base = level + trend * damping;
+                        let s = seasonal[(h - 1) % seasonal.len()];
+                        if *multiplicative {
+                            base * s
+                        } else {
+                            base + s
+                        }
+                    })
+                    .collect())
+            }
+            ModelState::Regression {
+                residual_fit,
+                effect,
+            } => {
+                let residual_forecast = residual_fit.forecast(horizon)?;
+                Ok(residual_forecast
+                    .iter()
+                    .zip(effect.iter())
+                    .map(|(r, e)| r + e)
+                    .collect())
+            }
+        }
+    }
+
+    /// In-sample fitted values aligned with `values`.
+    ///
+    /// Positions the model leaves undefined (NaN during initialisation) use the
+    /// actual value. Falls back to SES fitted values when the model does not
+    /// report a full-length fit.
+    fn fitted_values(&self, values: &[f64]) -> Vec<f64> {
+        match self.fitted.as_deref() {
+            Some(fitted) if fitted.len() == values.len() => fitted
+                .iter()
+                .zip(values.iter())
+                .map(|(&f, &v)| if f.is_finite() { f } else { v })
+                .collect(),
+            _ => ses_fitted(values, 0.3),
+        }
+    }
+}
+
 // Model implementations
 
-fn forecast_naive(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_naive(values: &[f64]) -> ModelFit {
     let last = *values.last().expect("values validated non-empty by caller");
-    Ok(ForecastOutput {
-        point: vec![last; horizon],
-        lower: vec![],
-        upper: vec![],
-        fitted: None,
-        residuals: None,
-        model_name: String::new(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    let mut fitted = vec![values[0]];
+    fitted.extend(values[..values.len() - 1].iter().cloned());
+    ModelFit::new(ModelState::Cycle(vec![last]), fitted)
 }
 
-fn forecast_seasonal_naive(
-    values: &[f64],
-    horizon: usize,
-    period: usize,
-) -> Result<ForecastOutput> {
+fn fit_seasonal_naive(values: &[f64], period: usize) -> ModelFit {
     let p = period.max(1).min(values.len());
     let last_season: Vec<f64> = values.iter().rev().take(p).rev().cloned().collect();
 
-    let point: Vec<f64> = (0..horizon).map(|i| last_season[i % p]).collect();
-
-    Ok(ForecastOutput {
-        point,
-        lower: vec![],
-        upper: vec![],
-        fitted: None,
-        residuals: None,
-        model_name: String::new(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    let mut fitted = vec![values[0]; p];
+    for i in p..values.len() {
+        fitted.push(values[i - p]);
+    }
+    let mut fit = ModelFit::new(ModelState::Cycle(last_season), fitted);
+    fit.growth = VarianceGrowth::Seasonal { period: p };
+    fit
 }
 
-fn forecast_sma(values: &[f64], horizon: usize, window: usize) -> Result<ForecastOutput> {
+fn fit_sma(values: &[f64], window: usize) -> ModelFit {
     let w = window.min(values.len());
     let forecast_value: f64 = values.iter().rev().take(w).sum::<f64>() / w as f64;
 
-    Ok(ForecastOutput {
-        point: vec![forecast_value; horizon],
-        lower: vec![],
-        upper: vec![],
-        fitted: None,
-        residuals: None,
-        model_name: String::new(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    // Fitted value at t is the mean of the (up to) `w` preceding values
+    let mut fitted = vec![values[0]];
+    fitted.extend((1..values.len()).map(|t| {
+        let start = t.saturating_sub(w);
+        values[start..t].iter().sum::<f64>() / (t - start) as f64
+    }));
+    ModelFit::new(ModelState::Cycle(vec![forecast_value]), fitted)
 }
 
-fn forecast_drift(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_drift(values: &[f64]) -> ModelFit {
     let n = values.len();
     let first = values[0];
     let last = values[n - 1];
-    let drift = (last - first) / (n - 1) as f64;
-
-    let point: Vec<f64> = (1..=horizon).map(|h| last + drift * h as f64).collect();
+    let slope = (last - first) / (n - 1) as f64;
 
-    Ok(ForecastOutput {
-        point,
-        lower: vec![],
-        upper: vec![],
-        fitted: None,
-        residuals: None,
-        model_name: String::new(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    let mut fitted = vec![first];
+    fitted.extend(values[..n - 1].iter().map(|v| v + slope));
+    let mut fit = ModelFit::new(ModelState::Drift { last, slope }, fitted);
+    fit.growth = VarianceGrowth::Drift { n: (n - 1).max(1) };
+    fit
 }
 
-fn forecast_ses_fixed(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_ses_fixed(values: &[f64]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = SimpleExponentialSmoothing::new(0.3);
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("SES fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "SES")
+    let mut fit = ModelFit::library(model, "SES");
+    fit.growth = VarianceGrowth::Ses { alpha: 0.3 };
+    Ok(fit)
 }
 
-fn forecast_ses_optimized(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_ses_optimized(values: &[f64]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = SimpleExponentialSmoothing::auto();
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("SESOptimized fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "SESOptimized")
+    let growth = model
+        .alpha()
+        .map_or(VarianceGrowth::RandomWalk, |alpha| VarianceGrowth::Ses {
+            alpha,
+        });
+    let mut fit = ModelFit::library(model, "SESOptimized");
+    fit.growth = growth;
+    Ok(fit)
 }
 
-fn forecast_holt_lib(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_holt(values: &[f64]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = HoltLinearTrend::auto();
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("Holt fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "Holt")
+    let growth = match (model.alpha(), model.beta()) {
+        (Some(alpha), Some(beta)) => VarianceGrowth::Holt { alpha, beta },
+        _ => VarianceGrowth::RandomWalk,
+    };
+    let mut fit = ModelFit::library(model, "Holt");
+    fit.growth = growth;
+    Ok(fit)
 }
 
-fn forecast_holt_winters_lib(
-    values: &[f64],
-    horizon: usize,
-    period: usize,
-) -> Result<ForecastOutput> {
+fn fit_holt_winters(values: &[f64], period: usize, mode: SeasonalMode) -> Result<ModelFit> {
+    use anofox_forecast::models::exponential::SeasonalType;
+
+    let seasonal_type = match mode {
+        SeasonalMode::Additive => SeasonalType::Additive,
+        SeasonalMode::Multiplicative => {
+            check_multiplicative_values(values)?;
+            SeasonalType::Multiplicative
+        }
+    };
+
     let ts = make_timeseries(values)?;
     let p = period.max(2);
-    let mut model = HoltWintersModel::auto(
-        p,
-        anofox_forecast::models::exponential::SeasonalType::Additive,
-    );
+    let mut model = HoltWintersModel::auto(p, seasonal_type);
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("HoltWinters fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "HoltWinters")
+    Ok(ModelFit::library(model, "HoltWinters"))
 }
 
-fn forecast_theta_stm(values: &[f64], horizon: usize, period: usize) -> Result<ForecastOutput> {
-    let ts = make_timeseries(values)?;
-    let mut model = if period > 1 {
-        Theta::seasonal(period)
+/// Smoothing parameters of the damped-trend method.
+#[derive(Debug, Clone, Copy)]
+struct DampedParams {
+    alpha: f64,
+    beta: f64,
+    gamma: f64,
+    phi: f64,
+}
+
+/// Final state and in-sample fit of a damped-trend run.
+struct DampedFit {
+    level: f64,
+    trend: f64,
+    seasonal: Vec<f64>,
+    fitted: Vec<f64>,
+}
+
+/// Run the damped-trend recursions (Gardner & McKenzie, 1985).
+///
+/// `period <= 1` gives damped Holt; larger periods add additive or
+/// multiplicative seasonality and require at least two full periods.
+fn damped_holt_run(
+    values: &[f64],
+    period: usize,
+    mode: SeasonalMode,
+    p: DampedParams,
+) -> DampedFit {
+    let m = period.max(1);
+    let multiplicative = m > 1 && mode == SeasonalMode::Multiplicative;
+    let (mut level, mut trend, mut seasonal) = if m > 1 {
+        let first = values[..m].iter().sum::<f64>() / m as f64;
+        let second = values[m..2 * m].iter().sum::<f64>() / m as f64;
+        let seasonal = if multiplicative {
+            values[..m].iter().map(|v| v / first).collect()
+        } else {
+            values[..m].iter().map(|v| v - first).collect()
+        };
+        (first, (second - first) / m as f64, seasonal)
     } else {
-        Theta::new()
+        (values[0], values[1] - values[0], vec![0.0])
     };
-    model
-        .fit(&ts)
-        .map_err(|e| ForecastError::ComputationError(format!("Theta fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "Theta")
+
+    let mut fitted = Vec::with_capacity(values.len());
+    for (t, &y) in values.iter().enumerate() {
+        let s = seasonal[t % m];
+        let damped_trend = p.phi * trend;
+        let base = level + damped_trend;
+        fitted.push(if multiplicative { base * s } else { base + s });
+
+        let prev_level = level;
+        level = if multiplicative {
+            p.alpha * (y / s) + (1.0 - p.alpha) * base
+        } else {
+            p.alpha * (y - s) + (1.0 - p.alpha) * base
+        };
+        trend = p.beta * (level - prev_level) + (1.0 - p.beta) * damped_trend;
+        if multiplicative {
+            seasonal[t % m] = p.gamma * (y / base) + (1.0 - p.gamma) * s;
+        } else if m > 1 {
+            seasonal[t % m] = p.gamma * (y - base) + (1.0 - p.gamma) * s;
+        }
+    }
+
+    DampedFit {
+        level,
+        trend,
+        seasonal,
+        fitted,
+    }
+}
+
+/// Smoothing parameters of a damped-trend run with fixed `phi` minimising the
+/// in-sample one-step SSE.
+///
+/// Coordinate descent: each round runs a golden-section search over
+/// [0.01, 0.99] for alpha, beta and (when seasonal) gamma in turn.
+fn damped_params(values: &[f64], period: usize, mode: SeasonalMode, phi: f64) -> DampedParams {
+    let sse = |p: DampedParams| {
+        let fitted = damped_holt_run(values, period, mode, p).fitted;
+        let sse = sum_squared_errors(values, &fitted);
+        if sse.is_finite() {
+            sse
+        } else {
+            f64::INFINITY
+        }
+    };
+
+    let mut p = DampedParams {
+        alpha: 0.3,
+        beta: 0.1,
+        gamma: if period > 1 { 0.1 } else { 0.0 },
+        phi,
+    };
+    for _ in 0..4 {
+        p.alpha = golden_section_min(|alpha| sse(DampedParams { alpha, ..p }), 0.01, 0.99, 1e-4);
+        p.beta = golden_section_min(|beta| sse(DampedParams { beta, ..p }), 0.01, 0.99, 1e-4);
+        if period > 1 {
+            p.gamma =
+                golden_section_min(|gamma| sse(DampedParams { gamma, ..p }), 0.01, 0.99, 1e-4);
+        }
+    }
+    p
+}
+
+/// Reject series a multiplicative seasonal model cannot fit.
+fn check_multiplicative_values(values: &[f64]) -> Result<()> {
+    if values.iter().any(|&v| v <= 0.0) {
+        return Err(ForecastError::InvalidInput(
+            "Multiplicative HoltWinters requires strictly positive values; \
+             use seasonal_mode 'additive' for series with zeros or negatives"
+                .to_string(),
+        ));
+    }
+    Ok(())
 }
 
-fn forecast_optimized_theta(
+/// Holt / Holt-Winters with a damped trend.
+///
+/// Without `phi`, the library ETS model with a damped trend estimates all
+/// parameters (AAdN for Holt, AAdA / MAdM for additive / multiplicative
+/// HoltWinters). With a fixed `phi`, the damped-trend recursions run in-house
+/// and only the smoothing parameters are estimated. Forecasts are
+/// `level + trend * Σ_{i=1..h} phi^i`, plus or times the seasonal term.
+fn fit_damped_holt(
     values: &[f64],
-    horizon: usize,
     period: usize,
-) -> Result<ForecastOutput> {
+    mode: SeasonalMode,
+    phi: Option<f64>,
+    model_name: &str,
+) -> Result<ModelFit> {
+    if let Some(phi) = phi {
+        if phi.is_nan() || phi <= 0.0 || phi > 1.0 {
+            return Err(ForecastError::InvalidParameter {
+                param: "phi".to_string(),
+                value: phi.to_string(),
+                reason: "must be in (0, 1]".to_string(),
+            });
+        }
+    }
+
+    let m = period.max(1);
+    if m > 1 && mode == SeasonalMode::Multiplicative {
+        check_multiplicative_values(values)?;
+    }
+    let needed = if m > 1 { 2 * m } else { 2 };
+    if values.len() < needed {
+        return Err(ForecastError::InsufficientData {
+            needed,
+            got: values.len(),
+        });
+    }
+
+    let Some(phi) = phi else {
+        let notation = match (m > 1, mode) {
+            (false, _) => "AAdN",
+            (true, SeasonalMode::Additive) => "AAdA",
+            (true, SeasonalMode::Multiplicative) => "MAdM",
+        };
+        let spec = ETSSpec::from_notation(notation).map_err(|e| {
+            ForecastError::ComputationError(format!("Invalid ETS spec '{}': {}", notation, e))
+        })?;
+        let mut fit = fit_ets_spec(values, m, &spec)?;
+        fit.model_name = model_name.to_string();
+        return Ok(fit);
+    };
+
+    let params = damped_params(values, m, mode, phi);
+    let fit = damped_holt_run(values, m, mode, params);
+
+    let n = values.len();
+    let seasonal = (0..m).map(|i| fit.seasonal[(n + i) % m]).collect();
+    let mut model_fit = ModelFit::new(
+        ModelState::Damped {
+            level: fit.level,
+            trend: fit.trend,
+            phi,
+            seasonal,
+            multiplicative: m > 1 && mode == SeasonalMode::Multiplicative,
+        },
+        fit.fitted,
+    );
+    model_fit.model_name = model_name.to_string();
+    Ok(model_fit)
+}
+
+/// Standard Theta Method (Assimakopoulos & Nikolopoulos, 2000).
+///
+/// When `period > 1`, at least two full periods are available and all values
+/// are positive, the series is deseasonalized with multiplicative indices from
+/// a classical decomposition. The theta=0 line is the linear regression on
+/// time and the theta=2 line (`2 * y - line`) is extrapolated with SES, its
+/// smoothing parameter chosen by in-sample SSE. Forecasts and fitted values
+/// average the two lines with equal weights and are reseasonalized.
+fn fit_theta(values: &[f64], period: usize) -> Result<ModelFit> {
+    let n = values.len();
+    if n < 2 {
+        return Err(ForecastError::InsufficientData { needed: 2, got: n });
+    }
+
+    let index = if period > 1 && n >= 2 * period && values.iter().all(|&v| v > 0.0) {
+        multiplicative_seasonal_indices(values, period)
+    } else {
+        vec![1.0]
+    };
+    let m = index.len();
+    let deseasonalized: Vec<f64> = values
+        .iter()
+        .enumerate()
+        .map(|(t, v)| v / index[t % m])
+        .collect();
+
+    // theta=0 line: y = intercept + slope * t
+    let t_mean = (n - 1) as f64 / 2.0;
+    let y_mean = deseasonalized.iter().sum::<f64>() / n as f64;
+    let (sxy, sxx) = deseasonalized
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(sxy, sxx), (t, y)| {
+            let dt = t as f64 - t_mean;
+            (sxy + dt * (y - y_mean), sxx + dt * dt)
+        });
+    let slope = sxy / sxx;
+    let intercept = y_mean - slope * t_mean;
+    let line: Vec<f64> = (0..n).map(|t| intercept + slope * t as f64).collect();
+
+    // theta=2 line, extrapolated with SES
+    let theta2: Vec<f64> = deseasonalized
+        .iter()
+        .zip(line.iter())
+        .map(|(y, l)| 2.0 * y - l)
+        .collect();
+    let alpha = ses_optimal_alpha(&theta2);
+    let ses = ses_fitted(&theta2, alpha);
+    let level = alpha * theta2[n - 1] + (1.0 - alpha) * ses[n - 1];
+
+    let fitted = line
+        .iter()
+        .zip(ses.iter())
+        .enumerate()
+        .map(|(t, (l, s))| 0.5 * (l + s) * index[t % m])
+        .collect();
+    let index = (0..m).map(|i| index[(n + i) % m]).collect();
+
+    let mut fit = ModelFit::new(
+        ModelState::Theta {
+            intercept: intercept + slope * (n - 1) as f64,
+            slope,
+            level,
+            index,
+        },
+        fitted,
+    );
+    fit.model_name = "Theta".to_string();
+    Ok(fit)
+}
+
+/// Multiplicative seasonal indices from a classical decomposition.
+///
+/// Each index averages the ratios of the series to its centred moving average
+/// at one seasonal position, skipping the edges without a full window; the
+/// indices are normalised to a mean of one.
+fn multiplicative_seasonal_indices(values: &[f64], period: usize) -> Vec<f64> {
+    let trend = crate::decomposition::centered_moving_average(values, period);
+    let half = period / 2;
+    let mut sums = vec![0.0; period];
+    let mut counts = vec![0usize; period];
+    for t in half..values.len() - half {
+        if trend[t] > 0.0 {
+            sums[t % period] += values[t] / trend[t];
+            counts[t % period] += 1;
+        }
+    }
+
+    let raw: Vec<f64> = sums
+        .iter()
+        .zip(counts.iter())
+        .map(|(&s, &c)| if c > 0 { s / c as f64 } else { 1.0 })
+        .collect();
+    let mean = raw.iter().sum::<f64>() / period as f64;
+    raw.iter().map(|r| r / mean).collect()
+}
+
+/// Optimized Theta Method: same two-line decomposition as [`fit_theta`],
+/// with theta and the SES smoothing parameter chosen by in-sample MSE.
+fn fit_optimized_theta(values: &[f64], period: usize) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = if period > 1 {
         OptimizedTheta::seasonal(period)
@@ -1170,10 +2091,10 @@ fn forecast_optimized_theta(
     model.fit(&ts).map_err(|e| {
         ForecastError::ComputationError(format!("OptimizedTheta fit failed: {}", e))
     })?;
-    extract_forecast(&model, horizon, "OptimizedTheta")
+    Ok(ModelFit::library(model, "OptimizedTheta"))
 }
 
-fn forecast_dynamic_theta(values: &[f64], horizon: usize, period: usize) -> Result<ForecastOutput> {
+fn fit_dynamic_theta(values: &[f64], period: usize) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = if period > 1 {
         DynamicTheta::seasonal(period)
@@ -1183,14 +2104,10 @@ fn forecast_dynamic_theta(values: &[f64], horizon: usize, period: usize) -> Resu
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("DynamicTheta fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "DynamicTheta")
+    Ok(ModelFit::library(model, "DynamicTheta"))
 }
 
-fn forecast_dynamic_optimized_theta(
-    values: &[f64],
-    horizon: usize,
-    period: usize,
-) -> Result<ForecastOutput> {
+fn fit_dynamic_optimized_theta(values: &[f64], period: usize) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = if period > 1 {
         DynamicTheta::seasonal_optimized(period)
@@ -1200,42 +2117,30 @@ fn forecast_dynamic_optimized_theta(
     model.fit(&ts).map_err(|e| {
         ForecastError::ComputationError(format!("DynamicOptimizedTheta fit failed: {}", e))
     })?;
-    extract_forecast(&model, horizon, "DynamicOptimizedTheta")
+    Ok(ModelFit::library(model, "DynamicOptimizedTheta"))
 }
 
-fn forecast_seasonal_es_lib(
-    values: &[f64],
-    horizon: usize,
-    period: usize,
-) -> Result<ForecastOutput> {
+fn fit_seasonal_es(values: &[f64], period: usize) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let p = period.max(2);
     let mut model = SeasonalESModel::new(p);
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("SeasonalES fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "SeasonalES")
+    Ok(ModelFit::library(model, "SeasonalES"))
 }
 
-fn forecast_seasonal_es_optimized(
-    values: &[f64],
-    horizon: usize,
-    period: usize,
-) -> Result<ForecastOutput> {
+fn fit_seasonal_es_optimized(values: &[f64], period: usize) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let p = period.max(2);
     let mut model = SeasonalESModel::optimized(p);
     model.fit(&ts).map_err(|e| {
         ForecastError::ComputationError(format!("SeasonalESOptimized fit failed: {}", e))
     })?;
-    extract_forecast(&model, horizon, "SeasonalESOptimized")
+    Ok(ModelFit::library(model, "SeasonalESOptimized"))
 }
 
-fn forecast_seasonal_window_average(
-    values: &[f64],
-    horizon: usize,
-    period: usize,
-) -> Result<ForecastOutput> {
+fn fit_seasonal_window_average(values: &[f64], period: usize) -> Result<ModelFit> {
     use anofox_forecast::models::baseline::SeasonalWindowAverage as SWA;
     let ts = make_timeseries(values)?;
     let p = period.max(2).min(values.len());
@@ -1244,8 +2149,26 @@ fn forecast_seasonal_window_average(
     model.fit(&ts).map_err(|e| {
         ForecastError::ComputationError(format!("SeasonalWindowAverage fit failed: {}", e))
     })?;
-    extract_forecast(&model, horizon, "SeasonalWindowAverage")
-}
+    let mut fit = ModelFit::library(model, "SeasonalWindowAverage");
+    if fit.fitted.is_none() {
+        // Fitted values are the running seasonal averages at each position
+        let mut seasonal_sum = vec![0.0; p];
+        let mut seasonal_count = vec![0usize; p];
+        let mut fitted = Vec::with_capacity(values.len());
+        for (i, &val) in values.iter().enumerate() {
+            let pos = i % p;
+            if seasonal_count[pos] > 0 {
+                fitted.push(seasonal_sum[pos] / seasonal_count[pos] as f64);
+            } else {
+                fitted.push(val); // No prior data, use actual
+            }
+            seasonal_sum[pos] += val;
+            seasonal_count[pos] += 1;
+        }
+        fit.fitted = Some(fitted);
+    }
+    Ok(fit)
+}
 
 /// Validate ETS notation format.
 /// Valid ETS notations follow the pattern: [E][T][S] or [E][Td][S]
@@ -1275,12 +2198,72 @@ fn is_valid_ets_notation(notation: &str) -> bool {
     }
 }
 
-fn forecast_ets(
+/// AIC and BIC of a fitted ETS model from its one-step-ahead fitted values.
+///
+/// Uses the Gaussian likelihood of the innovations (relative errors for
+/// multiplicative-error models, as in Hyndman et al.). The parameter count
+/// covers the smoothing parameters, the damping parameter, the initial
+/// states and the innovation variance. Only used when the library model does
+/// not report its own criteria.
+fn ets_information_criteria(
     values: &[f64],
-    horizon: usize,
+    fitted: &[f64],
+    notation: &str,
     period: usize,
-    ets_spec: Option<&str>,
-) -> Result<ForecastOutput> {
+) -> Option<(f64, f64)> {
+    let n = values.len();
+    if n == 0 || fitted.len() != n {
+        return None;
+    }
+
+    let chars: Vec<char> = notation.chars().collect();
+    let multiplicative_error = chars.first() == Some(&'M');
+    let has_trend = chars.get(1).is_some_and(|&c| c != 'N');
+    let damped = chars.len() == 4;
+    let seasonal = chars.last().is_some_and(|&c| c != 'N') && period > 1;
+
+    let mut k = 2; // alpha + initial level
+    if has_trend {
+        k += 2; // beta + initial slope
+    }
+    if damped {
+        k += 1; // phi
+    }
+    if seasonal {
+        k += period; // gamma + (period - 1) free initial seasonal states
+    }
+    k += 1; // innovation variance
+
+    let mut sse = 0.0;
+    let mut log_scale = 0.0;
+    for (&y, &f) in values.iter().zip(fitted.iter()) {
+        if !y.is_finite() || !f.is_finite() {
+            return None;
+        }
+        let e = if multiplicative_error {
+            if f.abs() < f64::EPSILON {
+                return None;
+            }
+            log_scale += f.abs().ln();
+            (y - f) / f
+        } else {
+            y - f
+        };
+        sse += e * e;
+    }
+
+    let n_f = n as f64;
+    let sigma2 = sse / n_f;
+    if sigma2 <= 0.0 {
+        return None;
+    }
+
+    let log_lik = -0.5 * n_f * ((2.0 * std::f64::consts::PI * sigma2).ln() + 1.0) - log_scale;
+    let k_f = k as f64;
+    Some((-2.0 * log_lik + 2.0 * k_f, -2.0 * log_lik + k_f * n_f.ln()))
+}
+
+fn fit_ets(values: &[f64], period: usize, ets_spec: Option<&str>) -> Result<ModelFit> {
     // Parse and validate ETS specification if provided
     if let Some(notation) = ets_spec {
         // Validate the notation format
@@ -1316,33 +2299,46 @@ fn forecast_ets(
         // User explicitly requested this spec — if it fails to fit,
         // return ComputationError so the group is skipped (null forecast),
         // not an InvalidInput error that would abort the whole pipeline.
-        return forecast_with_ets_spec(values, horizon, period, &parsed_spec).map_err(|e| {
+        let mut fit = fit_ets_spec(values, period, &parsed_spec).map_err(|e| {
             ForecastError::ComputationError(format!(
                 "ETS model '{}' failed to fit: {}",
                 notation, e
             ))
-        });
+        })?;
+
+        // Approximate from the fitted values when the library reports none
+        if fit.aic.is_none() || fit.bic.is_none() {
+            if let Some((aic, bic)) = fit
+                .fitted
+                .as_deref()
+                .and_then(|fitted| ets_information_criteria(values, fitted, notation, period))
+            {
+                fit.aic = Some(aic);
+                fit.bic = Some(bic);
+            }
+        }
+        return Ok(fit);
     }
 
     // No explicit spec: use library ETS implementations based on data characteristics
-    let mut result = if period > 1 && values.len() >= 2 * period {
-        forecast_holt_winters_lib(values, horizon, period)
+    let (mut fit, fallback) = if period > 1 && values.len() >= 2 * period {
+        (
+            fit_holt_winters(values, period, SeasonalMode::Additive)?,
+            "additive HoltWinters",
+        )
     } else if values.len() >= 10 {
-        forecast_holt_lib(values, horizon)
+        (fit_holt(values)?, "Holt")
     } else {
-        forecast_ses_fixed(values, horizon)
-    }?;
-    result.model_name = "ETS".to_string();
-    Ok(result)
+        (fit_ses_fixed(values)?, "SES (fewer than 10 observations)")
+    };
+    fit.model_name = "ETS".to_string();
+    fit.warnings
+        .push(format!("ETS without ets_spec fell back to {}", fallback));
+    Ok(fit)
 }
 
-/// Forecast using the anofox-forecast ETS model with explicit spec.
-fn forecast_with_ets_spec(
-    values: &[f64],
-    horizon: usize,
-    period: usize,
-    spec: &ETSSpec,
-) -> Result<ForecastOutput> {
+/// Fit the anofox-forecast ETS model with explicit spec.
+fn fit_ets_spec(values: &[f64], period: usize, spec: &ETSSpec) -> Result<ModelFit> {
     // Determine seasonal period for the model
     let seasonal_period = if spec.has_seasonal() && period > 1 {
         period
@@ -1361,37 +2357,21 @@ fn forecast_with_ets_spec(
         .fit(&time_series)
         .map_err(|e| ForecastError::ComputationError(format!("Failed to fit ETS model: {}", e)))?;
 
-    // Generate forecasts
-    let forecast = forecaster.predict(horizon).map_err(|e| {
-        ForecastError::ComputationError(format!("Failed to generate ETS forecasts: {}", e))
-    })?;
-
-    // Extract point forecasts (univariate - first dimension)
-    let point = forecast.point().first().cloned().unwrap_or_default();
-
-    // Get fitted values
-    let fitted = forecaster.fitted_values().map(|v| v.to_vec());
+    // Information criteria from the model's own likelihood
+    let (aic, bic) = (forecaster.aic(), forecaster.bic());
 
     // Get model name from spec
     let model_name = format!("ETS({})", spec.short_name());
-
-    Ok(ForecastOutput {
-        point,
-        lower: vec![],
-        upper: vec![],
-        fitted,
-        residuals: None,
-        model_name,
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    let mut fit = ModelFit::library(forecaster, &model_name);
+    fit.aic = aic;
+    fit.bic = bic;
+    Ok(fit)
 }
 
-fn forecast_arima(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_arima(values: &[f64]) -> ModelFit {
     // Simplified ARIMA(1,1,1) - AR(1) on differenced series
     if values.len() < 5 {
-        return forecast_naive(values, horizon);
+        return fit_naive(values);
     }
 
     // Difference the series
@@ -1401,49 +2381,26 @@ fn forecast_arima(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
     let mean_diff = diff.iter().sum::<f64>() / diff.len() as f64;
     let ar_coef = 0.5; // Simplified: fixed AR coefficient
 
-    let last_val = *values
+    let last = *values
         .last()
         .expect("values.len() >= 5 validated by caller");
     let last_diff = *diff.last().expect("diff non-empty when values.len() >= 5");
 
-    let mut point = Vec::with_capacity(horizon);
-    let mut prev_diff = last_diff;
-    let mut cumsum = last_val;
-
-    for _ in 0..horizon {
-        let next_diff = mean_diff + ar_coef * (prev_diff - mean_diff);
-        cumsum += next_diff;
-        point.push(cumsum);
-        prev_diff = next_diff;
-    }
-
-    Ok(ForecastOutput {
-        point,
-        lower: vec![],
-        upper: vec![],
-        fitted: None,
-        residuals: None,
-        model_name: String::new(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    ModelFit::new(
+        ModelState::Arima {
+            last,
+            last_diff,
+            mean_diff,
+            ar_coef,
+        },
+        arima_fitted(values),
+    )
 }
 
 /// AutoARIMA: Automatic ARIMA model selection using AIC-based search.
 /// Uses the proper AutoARIMA implementation from anofox-forecast library.
-fn forecast_auto_arima(values: &[f64], horizon: usize, period: usize) -> Result<ForecastOutput> {
-    use chrono::{Duration, TimeZone, Utc};
-
-    // Create timestamps for TimeSeries (required by anofox-forecast)
-    let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-    let timestamps: Vec<_> = (0..values.len())
-        .map(|i| base + Duration::hours(i as i64))
-        .collect();
-
-    let ts = TimeSeries::univariate(timestamps, values.to_vec()).map_err(|e| {
-        ForecastError::ComputationError(format!("Failed to create TimeSeries: {}", e))
-    })?;
+fn fit_auto_arima(values: &[f64], period: usize) -> Result<ModelFit> {
+    let ts = make_timeseries(values)?;
 
     // Configure AutoARIMA with seasonal period if provided
     let config = if period > 1 {
@@ -1459,11 +2416,6 @@ fn forecast_auto_arima(values: &[f64], horizon: usize, period: usize) -> Result<
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("AutoARIMA fit failed: {}", e)))?;
 
-    // Get forecasts
-    let forecast = model
-        .predict(horizon)
-        .map_err(|e| ForecastError::ComputationError(format!("AutoARIMA predict failed: {}", e)))?;
-
     // Get the selected order for model name
     // The model_scores() contains sorted (best first) results
     let model_name = if let Some(order) = model.selected_full_order() {
@@ -1492,32 +2444,7 @@ fn forecast_auto_arima(values: &[f64], horizon: usize, period: usize) -> Result<
         "AutoARIMA".to_string()
     };
 
-    // Extract point forecasts (primary dimension)
-    let point = forecast.primary().to_vec();
-
-    // Extract confidence intervals (first dimension for univariate)
-    let lower = forecast
-        .lower()
-        .and_then(|intervals| intervals.first())
-        .cloned()
-        .unwrap_or_default();
-    let upper = forecast
-        .upper()
-        .and_then(|intervals| intervals.first())
-        .cloned()
-        .unwrap_or_default();
-
-    Ok(ForecastOutput {
-        point,
-        lower,
-        upper,
-        fitted: model.fitted_values().map(|v| v.to_vec()),
-        residuals: model.residuals().map(|v| v.to_vec()),
-        model_name,
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    Ok(ModelFit::library(model, &model_name))
 }
 
 /// Parse a model pool string into the `ModelPool` enum.
@@ -1540,12 +2467,7 @@ fn parse_model_pool(s: &str) -> Result<ModelPool> {
 /// Uses the proper AutoETS implementation from anofox-forecast library.
 /// Falls back to simplified ETS if the library panics (e.g. constant series
 /// causing NaN in optimizer — see #192).
-fn forecast_auto_ets(
-    values: &[f64],
-    horizon: usize,
-    period: usize,
-    model_pool: Option<&str>,
-) -> Result<ForecastOutput> {
+fn fit_auto_ets(values: &[f64], period: usize, model_pool: Option<&str>) -> Result<ModelFit> {
     use std::panic::{catch_unwind, AssertUnwindSafe};
 
     // Parse model_pool before entering catch_unwind (error reporting is cleaner)
@@ -1554,18 +2476,8 @@ fn forecast_auto_ets(
         None => None,
     };
 
-    let lib_result = catch_unwind(AssertUnwindSafe(|| -> Result<ForecastOutput> {
-        use chrono::{Duration, TimeZone, Utc};
-
-        // Create timestamps for TimeSeries (required by anofox-forecast)
-        let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-        let timestamps: Vec<_> = (0..values.len())
-            .map(|i| base + Duration::hours(i as i64))
-            .collect();
-
-        let ts = TimeSeries::univariate(timestamps, values.to_vec()).map_err(|e| {
-            ForecastError::ComputationError(format!("Failed to create TimeSeries: {}", e))
-        })?;
+    let lib_result = catch_unwind(AssertUnwindSafe(|| -> Result<ModelFit> {
+        let ts = make_timeseries(values)?;
 
         // Configure AutoETS with seasonal period if provided
         let mut config = if period > 1 {
@@ -1585,11 +2497,6 @@ fn forecast_auto_ets(
             .fit(&ts)
             .map_err(|e| ForecastError::ComputationError(format!("AutoETS fit failed: {}", e)))?;
 
-        // Get forecasts
-        let forecast = model.predict(horizon).map_err(|e| {
-            ForecastError::ComputationError(format!("AutoETS predict failed: {}", e))
-        })?;
-
         // Get the selected spec for model name
         let model_name = if let Some(spec) = model.selected_spec() {
             format!(
@@ -1600,61 +2507,37 @@ fn forecast_auto_ets(
             "AutoETS".to_string()
         };
 
-        // Extract point forecasts (primary dimension)
-        let point = forecast.primary().to_vec();
-
-        // Extract confidence intervals (first dimension for univariate)
-        let lower = forecast
-            .lower()
-            .and_then(|intervals| intervals.first())
-            .cloned()
-            .unwrap_or_default();
-        let upper = forecast
-            .upper()
-            .and_then(|intervals| intervals.first())
-            .cloned()
-            .unwrap_or_default();
-
-        Ok(ForecastOutput {
-            point,
-            lower,
-            upper,
-            fitted: model.fitted_values().map(|v| v.to_vec()),
-            residuals: model.residuals().map(|v| v.to_vec()),
-            model_name,
-            aic: None,
-            bic: None,
-            mse: None,
-        })
+        Ok(ModelFit::library(model, &model_name))
     }));
 
     match lib_result {
-        Ok(Ok(output)) => Ok(output),
+        Ok(Ok(fit)) => Ok(fit),
         Ok(Err(_)) | Err(_) => {
             // Library error or panic (e.g. constant series → NaN optimizer → unwrap panic).
             // Fall back to simplified ETS which handles edge cases gracefully.
-            let mut fallback = forecast_ets(values, horizon, period, None)?;
+            let mut fallback = fit_ets(values, period, None)?;
             fallback.model_name = "AutoETS".to_string();
+            fallback.warnings =
+                vec!["AutoETS model search failed; fell back to simplified ETS".to_string()];
             Ok(fallback)
         }
     }
 }
 
 /// Laplace: streaming distributional shell over EMA / drift / AR(1) / damped-Holt
-/// (and optional seasonal) leaves. Returns a point + interval forecast; the
-/// full mixture parameters are surfaced by the `ts_forecast_dist_by` table
-/// function (PR B of the distributional series).
-fn forecast_laplace(
+/// (and optional seasonal) leaves. Forecasts come from the model's interval
+/// prediction at `confidence_level`; the full mixture parameters are surfaced
+/// by the `ts_forecast_dist_by` table function (PR B of the distributional series).
+fn fit_laplace(
     values: &[f64],
-    horizon: usize,
     period: usize,
     variant: LaplaceVariant,
     seasonal_batch_init: bool,
     confidence_level: f64,
-) -> Result<ForecastOutput> {
+) -> Result<ModelFit> {
     use std::panic::{catch_unwind, AssertUnwindSafe};
 
-    let lib_result = catch_unwind(AssertUnwindSafe(|| -> Result<ForecastOutput> {
+    let lib_result = catch_unwind(AssertUnwindSafe(|| -> Result<ModelFit> {
         let ts = make_timeseries(values)?;
 
         let mut model = match variant {
@@ -1676,49 +2559,26 @@ fn forecast_laplace(
             .fit(&ts)
             .map_err(|e| ForecastError::ComputationError(format!("Laplace fit failed: {e}")))?;
 
-        // predict_with_intervals uses a symmetric two-sided level; clamp to
-        // [0.5, 0.999] to stay within the LaplaceForecaster's supported range.
-        let level = confidence_level.clamp(0.5, 0.999);
-        let forecast = model
-            .predict_with_intervals(horizon, level)
-            .map_err(|e| ForecastError::ComputationError(format!("Laplace predict failed: {e}")))?;
-
-        let point = forecast.primary().to_vec();
-        let lower = forecast
-            .lower()
-            .and_then(|intervals| intervals.first())
-            .cloned()
-            .unwrap_or_default();
-        let upper = forecast
-            .upper()
-            .and_then(|intervals| intervals.first())
-            .cloned()
-            .unwrap_or_default();
-
         let model_name = match (period > 1, seasonal_batch_init && period > 1) {
             (true, true) => format!("Laplace({},seasonal={},batch_init)", variant.tag(), period),
             (true, false) => format!("Laplace({},seasonal={})", variant.tag(), period),
             (false, _) => format!("Laplace({})", variant.tag()),
         };
 
-        Ok(ForecastOutput {
-            point,
-            lower,
-            upper,
-            fitted: None,
-            residuals: None,
-            model_name,
-            aic: None,
-            bic: None,
-            mse: None,
-        })
+        let mut fit = ModelFit::library(model, &model_name);
+        if let ModelState::Library { level, .. } = &mut fit.state {
+            // predict_with_intervals uses a symmetric two-sided level; clamp to
+            // [0.5, 0.999] to stay within the LaplaceForecaster's supported range.
+            *level = Some(confidence_level.clamp(0.5, 0.999));
+        }
+        Ok(fit)
     }));
 
     match lib_result {
-        Ok(Ok(output)) => Ok(output),
+        Ok(Ok(fit)) => Ok(fit),
         Ok(Err(e)) => Err(e),
         Err(_) => Err(ForecastError::ComputationError(
-            "Laplace forecast panicked (likely constant or near-empty series)".to_string(),
+            "Laplace fit panicked (likely constant or near-empty series)".to_string(),
         )),
     }
 }
@@ -2018,18 +2878,8 @@ pub fn forecast_explain(
 
 /// AutoTheta: Automatic selection of best Theta variant (STM, OTM, DSTM, DOTM).
 /// Uses the proper AutoTheta implementation from anofox-forecast library.
-fn forecast_auto_theta(values: &[f64], horizon: usize, period: usize) -> Result<ForecastOutput> {
-    use chrono::{Duration, TimeZone, Utc};
-
-    // Create timestamps for TimeSeries (required by anofox-forecast)
-    let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-    let timestamps: Vec<_> = (0..values.len())
-        .map(|i| base + Duration::hours(i as i64))
-        .collect();
-
-    let ts = TimeSeries::univariate(timestamps, values.to_vec()).map_err(|e| {
-        ForecastError::ComputationError(format!("Failed to create TimeSeries: {}", e))
-    })?;
+fn fit_auto_theta(values: &[f64], period: usize) -> Result<ModelFit> {
+    let ts = make_timeseries(values)?;
 
     // Configure AutoTheta with seasonal period if provided
     let mut model = if period > 1 {
@@ -2043,11 +2893,6 @@ fn forecast_auto_theta(values: &[f64], horizon: usize, period: usize) -> Result<
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("AutoTheta fit failed: {}", e)))?;
 
-    // Get forecasts
-    let forecast = model
-        .predict(horizon)
-        .map_err(|e| ForecastError::ComputationError(format!("AutoTheta predict failed: {}", e)))?;
-
     // Get the selected model type for model name
     let model_name = if let Some(selected) = model.selected_model() {
         format!("AutoTheta({})", selected)
@@ -2055,52 +2900,13 @@ fn forecast_auto_theta(values: &[f64], horizon: usize, period: usize) -> Result<
         "AutoTheta".to_string()
     };
 
-    // Extract point forecasts (primary dimension)
-    let point = forecast.primary().to_vec();
-
-    // Extract confidence intervals (first dimension for univariate)
-    let lower = forecast
-        .lower()
-        .and_then(|intervals| intervals.first())
-        .cloned()
-        .unwrap_or_default();
-    let upper = forecast
-        .upper()
-        .and_then(|intervals| intervals.first())
-        .cloned()
-        .unwrap_or_default();
-
-    Ok(ForecastOutput {
-        point,
-        lower,
-        upper,
-        fitted: model.fitted_values().map(|v| v.to_vec()),
-        residuals: model.residuals().map(|v| v.to_vec()),
-        model_name,
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    Ok(ModelFit::library(model, &model_name))
 }
 
 /// AutoTBATS: Automatic TBATS configuration selection.
 /// Uses the proper AutoTBATS implementation from anofox-forecast library.
-fn forecast_auto_tbats(
-    values: &[f64],
-    horizon: usize,
-    periods: &[usize],
-) -> Result<ForecastOutput> {
-    use chrono::{Duration, TimeZone, Utc};
-
-    // Create timestamps for TimeSeries (required by anofox-forecast)
-    let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-    let timestamps: Vec<_> = (0..values.len())
-        .map(|i| base + Duration::hours(i as i64))
-        .collect();
-
-    let ts = TimeSeries::univariate(timestamps, values.to_vec()).map_err(|e| {
-        ForecastError::ComputationError(format!("Failed to create TimeSeries: {}", e))
-    })?;
+fn fit_auto_tbats(values: &[f64], periods: &[usize]) -> Result<ModelFit> {
+    let ts = make_timeseries(values)?;
 
     // Configure AutoTBATS with seasonal periods
     let mut model = AutoTBATS::new(periods.to_vec());
@@ -2110,11 +2916,6 @@ fn forecast_auto_tbats(
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("AutoTBATS fit failed: {}", e)))?;
 
-    // Get forecasts
-    let forecast = model
-        .predict(horizon)
-        .map_err(|e| ForecastError::ComputationError(format!("AutoTBATS predict failed: {}", e)))?;
-
     // Get the selected configuration for model name
     let model_name = if let Some(config) = model.selected_config() {
         format!("AutoTBATS({})", config)
@@ -2122,47 +2923,11 @@ fn forecast_auto_tbats(
         "AutoTBATS".to_string()
     };
 
-    // Extract point forecasts (primary dimension)
-    let point = forecast.primary().to_vec();
-
-    // Extract confidence intervals (first dimension for univariate)
-    let lower = forecast
-        .lower()
-        .and_then(|intervals| intervals.first())
-        .cloned()
-        .unwrap_or_default();
-    let upper = forecast
-        .upper()
-        .and_then(|intervals| intervals.first())
-        .cloned()
-        .unwrap_or_default();
-
-    Ok(ForecastOutput {
-        point,
-        lower,
-        upper,
-        fitted: model.fitted_values().map(|v| v.to_vec()),
-        residuals: model.residuals().map(|v| v.to_vec()),
-        model_name,
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    Ok(ModelFit::library(model, &model_name))
 }
 
-fn forecast_mfles(values: &[f64], horizon: usize, periods: &[usize]) -> Result<ForecastOutput> {
-    use chrono::{Duration, TimeZone, Utc};
-
-    // Create timestamps for TimeSeries (required by anofox-forecast)
-    let base = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-    let timestamps: Vec<_> = (0..values.len())
-        .map(|i| base + Duration::hours(i as i64))
-        .collect();
-
-    let ts = TimeSeries::univariate(timestamps, values.to_vec()).map_err(|e| {
-        ForecastError::ComputationError(format!("Failed to create TimeSeries: {}", e))
-    })?;
-
+fn fit_mfles(values: &[f64], periods: &[usize]) -> Result<ModelFit> {
+    let ts = make_timeseries(values)?;
     let mut model = MFLES::new(periods.to_vec());
 
     // Fit the model
@@ -2170,56 +2935,25 @@ fn forecast_mfles(values: &[f64], horizon: usize, periods: &[usize]) -> Result<F
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("MFLES fit failed: {}", e)))?;
 
-    // Get forecasts
-    let forecast = model
-        .predict(horizon)
-        .map_err(|e| ForecastError::ComputationError(format!("MFLES predict failed: {}", e)))?;
-
-    // Extract point forecasts
-    let point = forecast.primary().to_vec();
-
-    // Extract confidence intervals
-    let lower = forecast
-        .lower()
-        .and_then(|intervals| intervals.first())
-        .cloned()
-        .unwrap_or_default();
-    let upper = forecast
-        .upper()
-        .and_then(|intervals| intervals.first())
-        .cloned()
-        .unwrap_or_default();
-
-    Ok(ForecastOutput {
-        point,
-        lower,
-        upper,
-        fitted: model.fitted_values().map(|v| v.to_vec()),
-        residuals: model.residuals().map(|v| v.to_vec()),
-        // Empty model_name: the caller uses enum name (MFLES or AutoMFLES)
-        model_name: String::new(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    Ok(ModelFit::library(model, "MFLES"))
 }
 
-fn forecast_mstl_lib(values: &[f64], horizon: usize, periods: &[usize]) -> Result<ForecastOutput> {
+fn fit_mstl(values: &[f64], periods: &[usize]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = MSTLForecaster::new(periods.to_vec());
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("MSTL fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "MSTL")
+    Ok(ModelFit::library(model, "MSTL"))
 }
 
-fn forecast_tbats_lib(values: &[f64], horizon: usize, periods: &[usize]) -> Result<ForecastOutput> {
+fn fit_tbats(values: &[f64], periods: &[usize]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = TBATSModel::new(periods.to_vec());
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("TBATS fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "TBATS")
+    Ok(ModelFit::library(model, "TBATS"))
 }
 
 /// Helper to create a TimeSeries from values (hourly timestamps).
@@ -2233,94 +2967,136 @@ fn make_timeseries(values: &[f64]) -> Result<TimeSeries> {
         .map_err(|e| ForecastError::ComputationError(format!("Failed to create TimeSeries: {}", e)))
 }
 
-/// Helper to extract ForecastOutput from a fitted Forecaster model.
-/// `name_override`: use this instead of model.name() to match our ModelType enum names.
-fn extract_forecast(
-    model: &dyn Forecaster,
-    horizon: usize,
-    name_override: &str,
-) -> Result<ForecastOutput> {
-    let forecast = model.predict(horizon).map_err(|e| {
-        ForecastError::ComputationError(format!("{} predict failed: {}", name_override, e))
-    })?;
-
-    let point = forecast.primary().to_vec();
-    let lower = forecast
-        .lower()
-        .and_then(|i| i.first())
-        .cloned()
-        .unwrap_or_default();
-    let upper = forecast
-        .upper()
-        .and_then(|i| i.first())
-        .cloned()
-        .unwrap_or_default();
-
-    Ok(ForecastOutput {
-        point,
-        lower,
-        upper,
-        fitted: model.fitted_values().map(|v| v.to_vec()),
-        residuals: model.residuals().map(|v| v.to_vec()),
-        model_name: name_override.to_string(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
-}
-
-fn forecast_croston_classic(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_croston_classic(values: &[f64]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = Croston::new();
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("Croston fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "CrostonClassic")
+    Ok(ModelFit::library(model, "CrostonClassic"))
 }
 
-fn forecast_croston_optimized(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_croston_optimized(values: &[f64]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = Croston::new().optimized();
     model.fit(&ts).map_err(|e| {
         ForecastError::ComputationError(format!("CrostonOptimized fit failed: {}", e))
     })?;
-    extract_forecast(&model, horizon, "CrostonOptimized")
+    Ok(ModelFit::library(model, "CrostonOptimized"))
 }
 
-fn forecast_croston_sba(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_croston_sba(values: &[f64]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = Croston::new().sba();
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("CrostonSBA fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "CrostonSBA")
+    Ok(ModelFit::library(model, "CrostonSBA"))
 }
 
-fn forecast_tsb(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_tsb(values: &[f64]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = TSB::new();
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("TSB fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "TSB")
+    Ok(ModelFit::library(model, "TSB"))
 }
 
-fn forecast_adida(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_adida(values: &[f64]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = ADIDA::new();
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("ADIDA fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "ADIDA")
+    Ok(ModelFit::library(model, "ADIDA"))
 }
 
-fn forecast_imapa(values: &[f64], horizon: usize) -> Result<ForecastOutput> {
+fn fit_imapa(values: &[f64]) -> Result<ModelFit> {
     let ts = make_timeseries(values)?;
     let mut model = IMAPA::new();
     model
         .fit(&ts)
         .map_err(|e| ForecastError::ComputationError(format!("IMAPA fit failed: {}", e)))?;
-    extract_forecast(&model, horizon, "IMAPA")
+    Ok(ModelFit::library(model, "IMAPA"))
+}
+
+/// Smoothed Poisson rate for count data.
+///
+/// Returns `(alpha, one-step-ahead rates, final rate)`. The smoothing
+/// parameter is chosen on a grid by minimising the one-step-ahead Poisson
+/// negative log-likelihood.
+fn count_ses_fit(values: &[f64]) -> (f64, Vec<f64>, f64) {
+    let run = |alpha: f64| -> (Vec<f64>, f64, f64) {
+        let mut level = values[0].max(0.0);
+        let mut rates = Vec::with_capacity(values.len());
+        let mut nll = 0.0;
+        for &y in values {
+            rates.push(level);
+            let lambda = level.max(1e-6);
+            nll += lambda - y * lambda.ln();
+            level = alpha * y + (1.0 - alpha) * level;
+        }
+        (rates, level, nll)
+    };
+
+    let mut best = (0.1, f64::INFINITY);
+    for step in 1..=19 {
+        let alpha = step as f64 * 0.05;
+        let (_, _, nll) = run(alpha);
+        if nll < best.1 {
+            best = (alpha, nll);
+        }
+    }
+
+    let (rates, level, _) = run(best.0);
+    (best.0, rates, level.max(0.0))
+}
+
+/// Smallest `k` such that `P(X <= k) >= p` for `X ~ Poisson(lambda)`.
+///
+/// The pmf recurrence is evaluated in log space so large rates do not
+/// underflow `exp(-lambda)`.
+fn poisson_quantile(lambda: f64, p: f64) -> f64 {
+    if lambda <= 0.0 {
+        return 0.0;
+    }
+    let ln_lambda = lambda.ln();
+    let mut log_pmf = -lambda;
+    let mut cdf = log_pmf.exp();
+    let mut k = 0.0;
+    let limit = lambda + 20.0 * lambda.sqrt() + 20.0;
+    while cdf < p && k < limit {
+        k += 1.0;
+        log_pmf += ln_lambda - f64::ln(k);
+        cdf += log_pmf.exp();
+    }
+    k
+}
+
+/// CountSES: exponential smoothing of a Poisson rate for integer count data.
+///
+/// Point forecasts are the Poisson median of the smoothed rate and the
+/// interval bounds (see [`count_intervals`]) are Poisson quantiles, so both
+/// respect the discrete, non-negative support.
+fn fit_count_ses(values: &[f64]) -> Result<ModelFit> {
+    if values.iter().any(|&v| v < 0.0) {
+        return Err(ForecastError::InvalidInput(
+            "CountSES requires non-negative count data".to_string(),
+        ));
+    }
+
+    let (_, rates, lambda) = count_ses_fit(values);
+    Ok(ModelFit::new(ModelState::Count { lambda }, rates))
+}
+
+/// Poisson prediction interval of a CountSES rate `lambda` at `confidence_level`.
+fn count_intervals(lambda: f64, horizon: usize, confidence_level: f64) -> (Vec<f64>, Vec<f64>) {
+    let tail = (1.0 - confidence_level.clamp(0.0, 1.0)) / 2.0;
+    (
+        vec![poisson_quantile(lambda, tail); horizon],
+        vec![poisson_quantile(lambda, 1.0 - tail); horizon],
+    )
 }
 
 // ============================================================================
@@ -2400,118 +3176,117 @@ fn apply_regression(coeffs: &[f64], future_x: &[Vec<f64>], horizon: usize) -> Ve
 ///
 /// Approach: Regress y on X, then forecast the residuals with ARIMA,
 /// and add back the exogenous effect for forecast horizon.
-fn forecast_arima_with_exog(
-    values: &[f64],
-    horizon: usize,
-    exog: &ExogenousData,
-) -> Result<ForecastOutput> {
+fn fit_arima_with_exog(values: &[f64], horizon: usize, exog: &ExogenousData) -> Result<ModelFit> {
     // Fit regression: y = X*beta + residuals
     let (coeffs, residuals) = fit_ols_regression(values, &exog.historical);
 
-    // Forecast residuals with ARIMA
-    let residual_forecast = forecast_arima(&residuals, horizon)?;
+    // Fit ARIMA on the residuals
+    let residual_fit = fit_arima(&residuals);
 
     // Calculate exogenous effect for future
     let exog_effect = apply_regression(&coeffs, &exog.future, horizon);
 
-    // Combine: forecast = residual_forecast + exog_effect
-    let point: Vec<f64> = residual_forecast
-        .point
-        .iter()
-        .zip(exog_effect.iter())
-        .map(|(r, e)| r + e)
-        .collect();
-
-    Ok(ForecastOutput {
-        point,
-        lower: vec![],
-        upper: vec![],
-        fitted: None,
-        residuals: None,
-        model_name: "ARIMAX".to_string(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    Ok(ModelFit::regression(
+        values,
+        &residuals,
+        residual_fit,
+        exog_effect,
+        "ARIMAX",
+    ))
 }
 
 /// Theta forecast with exogenous variables.
 ///
 /// Approach: Similar to ARIMAX - regress, forecast residuals, add back exog effect.
-fn forecast_theta_with_exog(
-    values: &[f64],
-    horizon: usize,
-    exog: &ExogenousData,
-) -> Result<ForecastOutput> {
+fn fit_theta_with_exog(values: &[f64], horizon: usize, exog: &ExogenousData) -> Result<ModelFit> {
     // Fit regression
     let (coeffs, residuals) = fit_ols_regression(values, &exog.historical);
 
-    // Forecast residuals with Theta (STM for exog path)
-    let residual_forecast = forecast_theta_stm(&residuals, horizon, 1)?;
+    // Fit Theta on the residuals (STM for exog path)
+    let residual_fit = fit_theta(&residuals, 1)?;
 
     // Calculate exogenous effect for future
     let exog_effect = apply_regression(&coeffs, &exog.future, horizon);
 
-    // Combine
-    let point: Vec<f64> = residual_forecast
-        .point
-        .iter()
-        .zip(exog_effect.iter())
-        .map(|(r, e)| r + e)
-        .collect();
-
-    Ok(ForecastOutput {
-        point,
-        lower: vec![],
-        upper: vec![],
-        fitted: None,
-        residuals: None,
-        model_name: "ThetaX".to_string(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+    Ok(ModelFit::regression(
+        values,
+        &residuals,
+        residual_fit,
+        exog_effect,
+        "ThetaX",
+    ))
 }
 
 /// MFLES forecast with exogenous variables.
-fn forecast_mfles_with_exog(
+fn fit_mfles_with_exog(
     values: &[f64],
     horizon: usize,
     periods: &[usize],
     exog: &ExogenousData,
-) -> Result<ForecastOutput> {
+) -> Result<ModelFit> {
     // Fit regression
     let (coeffs, residuals) = fit_ols_regression(values, &exog.historical);
 
-    // Forecast residuals with MFLES
-    let residual_forecast = forecast_mfles(&residuals, horizon, periods)?;
+    // Fit MFLES on the residuals
+    let residual_fit = fit_mfles(&residuals, periods)?;
 
     // Calculate exogenous effect for future
     let exog_effect = apply_regression(&coeffs, &exog.future, horizon);
 
-    // Combine
-    let point: Vec<f64> = residual_forecast
-        .point
-        .iter()
-        .zip(exog_effect.iter())
-        .map(|(r, e)| r + e)
-        .collect();
+    Ok(ModelFit::regression(
+        values,
+        &residuals,
+        residual_fit,
+        exog_effect,
+        "MFLESX",
+    ))
+}
 
-    Ok(ForecastOutput {
-        point,
-        lower: vec![],
-        upper: vec![],
-        fitted: None,
-        residuals: None,
-        model_name: "MFLESX".to_string(),
-        aic: None,
-        bic: None,
-        mse: None,
-    })
+/// Exponential smoothing forecast with exogenous variables (ETSX / HoltWintersX).
+///
+/// Approach: Same as ARIMAX - regress, forecast residuals with the smoothing
+/// model, add back exog effect. Residuals are centred, so HoltWinters always
+/// uses additive seasonality here; `options.damped` and `options.phi` apply.
+fn fit_ets_with_exog(
+    values: &[f64],
+    period: usize,
+    options: &ForecastOptions,
+    exog: &ExogenousData,
+) -> Result<ModelFit> {
+    // Fit regression
+    let (coeffs, residuals) = fit_ols_regression(values, &exog.historical);
+
+    // Fit the smoothing model on the residuals
+    let (residual_fit, model_name) = if options.model == ModelType::HoltWinters {
+        let fit = if options.damped {
+            fit_damped_holt(
+                &residuals,
+                period,
+                SeasonalMode::Additive,
+                options.phi,
+                "HoltWinters",
+            )?
+        } else {
+            fit_holt_winters(&residuals, period, SeasonalMode::Additive)?
+        };
+        (fit, "HoltWintersX")
+    } else {
+        (fit_ets(&residuals, period, None)?, "ETSX")
+    };
+
+    // Calculate exogenous effect for future
+    let exog_effect = apply_regression(&coeffs, &exog.future, options.horizon);
+
+    Ok(ModelFit::regression(
+        values,
+        &residuals,
+        residual_fit,
+        exog_effect,
+        model_name,
+    ))
 }
 
 /// Check if model is an auto-selection model
-#[cfg(test)]
 fn is_auto_model(model: ModelType) -> bool {
     matches!(
         model,
@@ -2524,122 +3299,468 @@ fn is_auto_model(model: ModelType) -> bool {
     )
 }
 
-#[cfg(test)]
-fn select_best_model(values: &[f64], period: usize) -> ModelType {
-    // Simple model selection based on series characteristics
+/// Backtest candidate models on a holdout tail and return the best one.
+///
+/// The holdout is the last `horizon` observations (capped at a quarter of the
+/// series). Each candidate is fit on the remaining training part and scored by
+/// MASE against a seasonal naive baseline; RMSE is used when MASE is undefined
+/// (perfect baseline). Returns `None` when the series is too short to hold out
+/// a tail or no candidate could be fit.
+fn select_model_by_holdout(
+    values: &[f64],
+    options: &ForecastOptions,
+    period: usize,
+) -> Option<(ModelType, f64)> {
     let n = values.len();
+    let holdout = options.horizon.min(n / 4);
+    let seasonal = period > 1;
+    let min_train = if seasonal { 2 * period } else { 8 };
+    if holdout == 0 || n - holdout < min_train {
+        return None;
+    }
 
-    if n < 10 {
-        return ModelType::Naive;
+    let (train, test) = values.split_at(n - holdout);
+
+    let mut candidates = vec![
+        options.model,
+        ModelType::Naive,
+        ModelType::SESOptimized,
+        ModelType::Holt,
+        ModelType::Theta,
+    ];
+    if seasonal {
+        candidates.extend([ModelType::SeasonalNaive, ModelType::HoltWinters]);
+    }
+
+    let baseline: Vec<f64> = fit_seasonal_naive(train, period).forecast(holdout).ok()?;
+
+    let mut best: Option<(ModelType, f64)> = None;
+    for candidate in candidates {
+        let Ok(point) =
+            fit_with_model(train, candidate, period, options).and_then(|fit| fit.forecast(holdout))
+        else {
+            continue;
+        };
+        if point.len() != holdout || point.iter().any(|v| !v.is_finite()) {
+            continue;
+        }
+
+        let score = match crate::metrics::mase(test, &point, &baseline) {
+            Ok(s) if s.is_finite() => s,
+            _ => match crate::metrics::rmse(test, &point) {
+                Ok(s) => s,
+                Err(_) => continue,
+            },
+        };
+
+        if best.is_none_or(|(_, b)| score < b) {
+            best = Some((candidate, score));
+        }
+    }
+
+    best
+}
+
+/// Rolling-origin cross-validation result.
+///
+/// Per-window vectors have `n_windows` entries (oldest origin first);
+/// per-horizon vectors have `horizon` entries (step 1 first).
+#[derive(Debug, Clone, Default)]
+pub struct CrossValResult {
+    /// Number of forecast origins evaluated
+    pub n_windows: usize,
+    /// Forecast horizon of each window
+    pub horizon: usize,
+    /// Training length (forecast origin) of each window
+    pub train_lengths: Vec<usize>,
+    /// MAE of each window
+    pub window_mae: Vec<f64>,
+    /// RMSE of each window
+    pub window_rmse: Vec<f64>,
+    /// MASE of each window (NaN when the naive scale is zero)
+    pub window_mase: Vec<f64>,
+    /// MAE at each horizon step, averaged over windows
+    pub horizon_mae: Vec<f64>,
+    /// RMSE at each horizon step, pooled over windows
+    pub horizon_rmse: Vec<f64>,
+    /// MASE at each horizon step, averaged over windows
+    pub horizon_mase: Vec<f64>,
+    /// MAE averaged over all windows
+    pub mae: f64,
+    /// RMSE pooled over all windows and steps
+    pub rmse: f64,
+    /// MASE averaged over all windows
+    pub mase: f64,
+}
+
+/// Rolling-origin (expanding window) cross-validation.
+///
+/// The last window forecasts the final `options.horizon` observations; each
+/// earlier window moves the forecast origin back by `step`. Every window is
+/// fit on the full prefix up to its origin with [`forecast`], so all
+/// `options` (model, seasonality, ...) apply. MASE is scaled by the in-sample
+/// naive MAE of the training prefix, lagged by the seasonal period the
+/// window's fit used (the detected one under `auto_detect_seasonality`).
+///
+/// NULLs are handled as in [`forecast`]: each training prefix is passed on
+/// as is and interpolated there, while test observations and the MASE scale
+/// use the interpolated series.
+pub fn cross_validate(
+    values: &[Option<f64>],
+    options: &ForecastOptions,
+    n_windows: usize,
+    step: usize,
+) -> Result<CrossValResult> {
+    let horizon = options.horizon;
+    if horizon == 0 {
+        return Err(ForecastError::InvalidParameter {
+            param: "horizon".to_string(),
+            value: "0".to_string(),
+            reason: "must be at least 1".to_string(),
+        });
+    }
+    if n_windows == 0 || step == 0 {
+        return Err(ForecastError::InvalidParameter {
+            param: if n_windows == 0 { "n_windows" } else { "step" }.to_string(),
+            value: "0".to_string(),
+            reason: "must be at least 1".to_string(),
+        });
+    }
+
+    let n = values.len();
+    let needed = horizon + (n_windows - 1) * step + 2;
+    if n < needed {
+        return Err(ForecastError::InsufficientData { needed, got: n });
     }
 
-    // Check for seasonality
-    let has_seasonality = period > 1 && n >= 2 * period;
+    let mut result = CrossValResult {
+        n_windows,
+        horizon,
+        ..Default::default()
+    };
+    let mut abs_errors: Vec<Vec<f64>> = Vec::with_capacity(n_windows);
+    let mut sq_errors: Vec<Vec<f64>> = Vec::with_capacity(n_windows);
+    let mut scales: Vec<f64> = Vec::with_capacity(n_windows);
+    let filled = fill_nulls_interpolate(values);
+
+    for w in 0..n_windows {
+        let origin = n - horizon - (n_windows - 1 - w) * step;
+        // Interpolate the prefix on its own so the scale never sees the test window
+        let train = fill_nulls_interpolate(&values[..origin]);
+        let test = &filled[origin..origin + horizon];
+
+        let output = forecast(&values[..origin], options)?;
+        if output.point.len() < horizon {
+            return Err(ForecastError::ComputationError(format!(
+                "Window {} produced {} forecasts, expected {}",
+                w,
+                output.point.len(),
+                horizon
+            )));
+        }
 
-    // Check for trend
-    let first_half_mean = values[..n / 2].iter().sum::<f64>() / (n / 2) as f64;
-    let second_half_mean = values[n / 2..].iter().sum::<f64>() / (n - n / 2) as f64;
-    let has_trend = (second_half_mean - first_half_mean).abs()
-        > (values
+        let abs_err: Vec<f64> = test
             .iter()
-            .map(|v| (v - first_half_mean).abs())
-            .sum::<f64>()
-            / n as f64)
-            * 0.5;
+            .zip(output.point.iter())
+            .map(|(a, f)| (a - f).abs())
+            .collect();
+        let sq_err: Vec<f64> = abs_err.iter().map(|e| e * e).collect();
+
+        let lag = output.seasonal_period.unwrap_or(1).max(1);
+        let m = if train.len() > lag { lag } else { 1 };
+        let naive: Vec<f64> = train.windows(m + 1).map(|x| (x[m] - x[0]).abs()).collect();
+        let scale = if naive.is_empty() {
+            f64::NAN
+        } else {
+            let s = naive.iter().sum::<f64>() / naive.len() as f64;
+            if s > f64::EPSILON {
+                s
+            } else {
+                f64::NAN
+            }
+        };
+
+        let mae = abs_err.iter().sum::<f64>() / horizon as f64;
+        result.train_lengths.push(origin);
+        result.window_mae.push(mae);
+        result
+            .window_rmse
+            .push((sq_err.iter().sum::<f64>() / horizon as f64).sqrt());
+        result.window_mase.push(mae / scale);
+
+        abs_errors.push(abs_err);
+        sq_errors.push(sq_err);
+        scales.push(scale);
+    }
+
+    let windows = n_windows as f64;
+    for i in 0..horizon {
+        result
+            .horizon_mae
+            .push(abs_errors.iter().map(|e| e[i]).sum::<f64>() / windows);
+        result
+            .horizon_rmse
+            .push((sq_errors.iter().map(|e| e[i]).sum::<f64>() / windows).sqrt());
+        result.horizon_mase.push(
+            abs_errors
+                .iter()
+                .zip(scales.iter())
+                .map(|(e, s)| e[i] / s)
+                .sum::<f64>()
+                / windows,
+        );
+    }
+
+    result.mae = result.window_mae.iter().sum::<f64>() / windows;
+    result.rmse = (sq_errors.iter().flatten().sum::<f64>() / (windows * horizon as f64)).sqrt();
+    result.mase = result.window_mase.iter().sum::<f64>() / windows;
+
+    Ok(result)
+}
+
+/// Seasonal strength above which a seasonal model is chosen.
+const SELECT_SEASONAL_THRESHOLD: f64 = 0.5;
+
+/// Trend strength above which a trend model (Holt / HoltWinters) is chosen.
+const SELECT_STRONG_TREND: f64 = 0.6;
+
+/// Trend strength below which a non-seasonal series is treated as noise.
+const SELECT_WEAK_TREND: f64 = 0.3;
+
+/// Outcome of [`select_best_model`] with the strengths that drove the choice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelSelection {
+    /// Selected model
+    pub model: ModelType,
+    /// Trend strength (0 to 1)
+    pub trend_strength: f64,
+    /// Seasonal strength of the linearly detrended series (0 to 1; 0 when
+    /// `period` is below 2 or fewer than two full periods are available)
+    pub seasonal_strength: f64,
+}
+
+/// Pick a simple model from the trend and seasonal strength of the series.
+///
+/// | Seasonal strength | Trend strength | Model |
+/// |-------------------|----------------|-------|
+/// | strong | strong | HoltWinters |
+/// | strong | weak | SeasonalNaive |
+/// | weak | strong | Holt |
+/// | weak | moderate | Theta |
+/// | weak | weak | Naive |
+///
+/// Series shorter than 10 observations always get Naive.
+pub fn select_best_model(values: &[f64], period: usize) -> ModelSelection {
+    let n = values.len();
+    if n < 10 {
+        return ModelSelection {
+            model: ModelType::Naive,
+            trend_strength: 0.0,
+            seasonal_strength: 0.0,
+        };
+    }
+
+    let seasonal_usable = period > 1 && n >= 2 * period;
+    let seasonal_strength = if seasonal_usable {
+        crate::detrending::detrend_linear(values)
+            .and_then(|d| {
+                crate::seasonality::seasonal_strength(
+                    &d.detrended,
+                    period as f64,
+                    crate::seasonality::StrengthMethod::Variance,
+                )
+            })
+            .unwrap_or(0.0)
+    } else {
+        0.0
+    };
+    let trend_period = if seasonal_usable { period as f64 } else { 1.0 };
+    let trend_strength =
+        crate::seasonality::trend_strength_at_period(values, trend_period).unwrap_or(0.0);
+
+    let seasonal = seasonal_strength > SELECT_SEASONAL_THRESHOLD;
+    let model = if seasonal && trend_strength > SELECT_STRONG_TREND {
+        ModelType::HoltWinters
+    } else if seasonal {
+        ModelType::SeasonalNaive
+    } else if trend_strength > SELECT_STRONG_TREND {
+        ModelType::Holt
+    } else if trend_strength > SELECT_WEAK_TREND {
+        ModelType::Theta
+    } else {
+        ModelType::Naive
+    };
 
-    match (has_trend, has_seasonality) {
-        (true, true) => ModelType::HoltWinters,
-        (true, false) => ModelType::Holt,
-        (false, true) => ModelType::SeasonalNaive,
-        (false, false) => ModelType::SES,
+    ModelSelection {
+        model,
+        trend_strength,
+        seasonal_strength,
+    }
+}
+
+/// How the forecast-error variance grows with the horizon, relative to the
+/// one-step variance `sigma^2`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum VarianceGrowth {
+    /// Random walk: `h`
+    RandomWalk,
+    /// Random walk with a drift estimated from `n` differences: `h * (1 + h / n)`
+    Drift { n: usize },
+    /// Simple exponential smoothing: `1 + (h - 1) * alpha^2`
+    Ses { alpha: f64 },
+    /// Holt's linear trend: `1 + Σ_{j=1..h-1} alpha^2 * (1 + j * beta)^2`
+    Holt { alpha: f64, beta: f64 },
+    /// Seasonal random walk: `floor((h - 1) / period) + 1`
+    Seasonal { period: usize },
+}
+
+impl VarianceGrowth {
+    /// Variance multiplier at `h` steps ahead (1-based).
+    fn factor(&self, h: usize) -> f64 {
+        let hf = h as f64;
+        match *self {
+            VarianceGrowth::RandomWalk => hf,
+            VarianceGrowth::Drift { n } => hf * (1.0 + hf / n as f64),
+            VarianceGrowth::Ses { alpha } => 1.0 + (hf - 1.0) * alpha * alpha,
+            VarianceGrowth::Holt { alpha, beta } => {
+                1.0 + (1..h)
+                    .map(|j| (alpha * (1.0 + j as f64 * beta)).powi(2))
+                    .sum::<f64>()
+            }
+            VarianceGrowth::Seasonal { period } => ((h - 1) / period + 1) as f64,
+        }
     }
 }
 
+/// Normal prediction intervals around `forecasts`.
+///
+/// The one-step standard error is the root mean square of the in-sample
+/// `residuals` (or their MAD-based [`robust_scale`] when `robust`) and grows
+/// with the horizon according to `growth`.
 fn calculate_confidence_intervals(
     forecasts: &[f64],
-    historical: &[f64],
+    residuals: &[f64],
     confidence: f64,
+    robust: bool,
+    growth: VarianceGrowth,
 ) -> (Vec<f64>, Vec<f64>) {
     // Calculate residual standard error
-    let mean = historical.iter().sum::<f64>() / historical.len() as f64;
-    let variance =
-        historical.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / historical.len() as f64;
-    let std_error = variance.sqrt();
-
-    // Z-score for confidence level
-    let z = match confidence {
-        c if c >= 0.99 => 2.576,
-        c if c >= 0.95 => 1.96,
-        c if c >= 0.90 => 1.645,
-        c if c >= 0.80 => 1.28,
-        _ => 1.0,
+    let finite: Vec<f64> = residuals
+        .iter()
+        .copied()
+        .filter(|r| r.is_finite())
+        .collect();
+    let std_error = if finite.is_empty() {
+        0.0
+    } else if robust {
+        robust_scale(&finite)
+    } else {
+        (finite.iter().map(|r| r * r).sum::<f64>() / finite.len() as f64).sqrt()
     };
 
+    // Two-sided standard normal quantile for the confidence level
+    let z = Normal::standard().inverse_cdf(0.5 + confidence / 2.0);
+
     let lower: Vec<f64> = forecasts
         .iter()
         .enumerate()
-        .map(|(i, &f)| f - z * std_error * ((i + 1) as f64).sqrt())
+        .map(|(i, &f)| f - z * std_error * growth.factor(i + 1).sqrt())
         .collect();
 
     let upper: Vec<f64> = forecasts
         .iter()
         .enumerate()
-        .map(|(i, &f)| f + z * std_error * ((i + 1) as f64).sqrt())
+        .map(|(i, &f)| f + z * std_error * growth.factor(i + 1).sqrt())
         .collect();
 
     (lower, upper)
 }
 
-fn calculate_fitted_values(values: &[f64], model: ModelType, period: usize) -> Vec<f64> {
-    match model {
-        ModelType::Naive => {
-            let mut fitted = vec![values[0]];
-            fitted.extend(values[..values.len() - 1].iter().cloned());
-            fitted
-        }
-        ModelType::SeasonalNaive => {
-            let p = period.max(1).min(values.len());
-            let mut fitted = vec![values[0]; p.min(values.len())];
-            for i in p..values.len() {
-                fitted.push(values[i - p]);
-            }
-            fitted
-        }
-        ModelType::SeasonalWindowAverage => {
-            // Fitted values are the seasonal averages at each position
-            let p = period.max(1).min(values.len());
-            let mut seasonal_sum = vec![0.0; p];
-            let mut seasonal_count = vec![0usize; p];
-            let mut fitted = Vec::with_capacity(values.len());
-
-            for (i, &val) in values.iter().enumerate() {
-                let pos = i % p;
-                // Fitted value is the current average for this seasonal position
-                if seasonal_count[pos] > 0 {
-                    fitted.push(seasonal_sum[pos] / seasonal_count[pos] as f64);
-                } else {
-                    fitted.push(val); // No prior data, use actual
-                }
-                // Update running average
-                seasonal_sum[pos] += val;
-                seasonal_count[pos] += 1;
-            }
-            fitted
-        }
-        _ => {
-            // SES fitted values
-            let alpha = 0.3;
-            let mut fitted = Vec::with_capacity(values.len());
-            let mut level = values[0];
-            fitted.push(level);
-
-            for &v in values.iter().skip(1) {
-                fitted.push(level);
-                level = alpha * v + (1.0 - alpha) * level;
-            }
-            fitted
-        }
+/// Number of simulated paths for bootstrap prediction intervals.
+const BOOTSTRAP_PATHS: usize = 1000;
+
+/// Fixed seed so bootstrap intervals are reproducible across calls.
+const BOOTSTRAP_SEED: u64 = 42;
+
+/// Prediction intervals from resampled in-sample residuals.
+///
+/// Each simulated path accumulates resampled residuals along the horizon;
+/// the bounds are the empirical quantiles of the paths, so skewed residuals
+/// give asymmetric intervals.
+fn residual_bootstrap_intervals(
+    forecasts: &[f64],
+    residuals: &[f64],
+    confidence: f64,
+) -> Result<(Vec<f64>, Vec<f64>)> {
+    let result = crate::bootstrap::bootstrap_intervals(
+        residuals,
+        forecasts,
+        BOOTSTRAP_PATHS,
+        confidence,
+        Some(BOOTSTRAP_SEED),
+    )?;
+    Ok((result.lower, result.upper))
+}
+
+/// One-step fitted values of the simplified ARIMA(1,1,1) in [`fit_arima`].
+///
+/// `fitted[t] = values[t-1] + mean_diff + 0.5 * (diff[t-2] - mean_diff)`; the
+/// first point uses the actual value and the second omits the AR term.
+fn arima_fitted(values: &[f64]) -> Vec<f64> {
+    if values.len() < 5 {
+        let mut fitted = vec![values[0]];
+        fitted.extend(values[..values.len() - 1].iter().cloned());
+        return fitted;
+    }
+
+    let diff: Vec<f64> = values.windows(2).map(|w| w[1] - w[0]).collect();
+    let mean_diff = diff.iter().sum::<f64>() / diff.len() as f64;
+    let ar_coef = 0.5;
+
+    let mut fitted = Vec::with_capacity(values.len());
+    fitted.push(values[0]);
+    fitted.push(values[0] + mean_diff);
+    fitted.extend(
+        values[1..values.len() - 1]
+            .iter()
+            .zip(diff.iter())
+            .map(|(prev, d)| prev + mean_diff + ar_coef * (d - mean_diff)),
+    );
+    fitted
+}
+
+/// One-step-ahead SES fitted values for a fixed smoothing parameter.
+fn ses_fitted(values: &[f64], alpha: f64) -> Vec<f64> {
+    let mut fitted = Vec::with_capacity(values.len());
+    let mut level = values[0];
+    fitted.push(level);
+
+    for &v in values.iter().skip(1) {
+        fitted.push(level);
+        level = alpha * v + (1.0 - alpha) * level;
     }
+    fitted
+}
+
+/// SES smoothing parameter minimising the in-sample one-step SSE.
+///
+/// Golden-section search over [0.01, 0.99].
+fn ses_optimal_alpha(values: &[f64]) -> f64 {
+    golden_section_min(
+        |alpha| sum_squared_errors(values, &ses_fitted(values, alpha)),
+        0.01,
+        0.99,
+        1e-4,
+    )
+}
+
+/// Sum of squared differences between `values` and `fitted`.
+fn sum_squared_errors(values: &[f64], fitted: &[f64]) -> f64 {
+    values
+        .iter()
+        .zip(fitted.iter())
+        .map(|(a, f)| (a - f).powi(2))
+        .sum()
 }
 
 /// List all available model names (32 models matching C++ extension).
@@ -2686,6 +3807,8 @@ pub fn list_models() -> Vec<String> {
         "ADIDA",
         "IMAPA",
         "TSB",
+        // Count Data Models (1)
+        "CountSES",
         // Distributional Models (1)
         "Laplace",
     ]
@@ -2795,6 +3918,49 @@ mod tests {
         assert!(result.point.iter().all(|v| v.is_finite()));
     }
 
+    #[test]
+    fn test_forecast_mstl_dual_seasonal() {
+        // Hourly data with daily (24) and weekly (168) cycles
+        let truth = |t: usize| -> f64 {
+            let t = t as f64;
+            100.0
+                + 10.0 * (2.0 * std::f64::consts::PI * t / 24.0).sin()
+                + 6.0 * (2.0 * std::f64::consts::PI * t / 168.0).sin()
+        };
+        let n = 168 * 4;
+        let horizon = 48;
+        let values: Vec<Option<f64>> = (0..n).map(|t| Some(truth(t))).collect();
+
+        let mae = |periods: Vec<usize>| -> f64 {
+            let options = ForecastOptions {
+                model: ModelType::MSTL,
+                horizon,
+                seasonal_period: 24,
+                seasonal_periods: periods,
+                auto_detect_seasonality: false,
+                ..Default::default()
+            };
+            let result = forecast(&values, &options).unwrap();
+            assert_eq!(result.point.len(), horizon);
+            result
+                .point
+                .iter()
+                .enumerate()
+                .map(|(h, p)| (p - truth(n + h)).abs())
+                .sum::<f64>()
+                / horizon as f64
+        };
+
+        let daily_only = mae(vec![]);
+        let daily_weekly = mae(vec![24, 168]);
+        assert!(
+            daily_weekly < daily_only,
+            "dual-seasonal MAE {} vs daily-only MAE {}",
+            daily_weekly,
+            daily_only
+        );
+    }
+
     #[test]
     fn test_forecast_tbats() {
         let values: Vec<Option<f64>> = (0..24)
@@ -2849,6 +4015,88 @@ mod tests {
         assert!(result.point.iter().all(|v| (*v - first).abs() < 1e-10));
     }
 
+    #[test]
+    fn test_croston_sba_differs_from_croston() {
+        let values = [
+            0.0, 0.0, 5.0, 0.0, 0.0, 0.0, 3.0, 0.0, 4.0, 0.0, 0.0, 6.0, 0.0, 2.0, 0.0, 0.0,
+        ];
+
+        let classic = fit_croston_classic(&values).unwrap().forecast(3).unwrap();
+        let sba = fit_croston_sba(&values).unwrap().forecast(3).unwrap();
+
+        // SBA scales Croston by the (1 - alpha / 2) bias correction
+        assert!(sba[0] > 0.0);
+        assert!(
+            sba[0] < classic[0],
+            "SBA {} vs Croston {}",
+            sba[0],
+            classic[0]
+        );
+    }
+
+    #[test]
+    fn test_adida_differs_from_croston() {
+        // Sparse demand with a mean inter-demand interval of ~3 periods
+        let values: Vec<Option<f64>> = [
+            0.0, 0.0, 5.0, 0.0, 0.0, 0.0, 3.0, 0.0, 4.0, 0.0, 0.0, 6.0, 0.0, 0.0, 2.0, 0.0, 0.0,
+            0.0, 7.0, 0.0,
+        ]
+        .iter()
+        .map(|&v| Some(v))
+        .collect();
+
+        let run = |model| {
+            let options = ForecastOptions {
+                model,
+                horizon: 4,
+                ..Default::default()
+            };
+            forecast(&values, &options).unwrap()
+        };
+
+        let adida = run(ModelType::ADIDA);
+        let croston = run(ModelType::CrostonClassic);
+        assert_eq!(adida.model_name, "ADIDA");
+        assert!(adida.point.iter().all(|v| v.is_finite() && *v >= 0.0));
+        assert!(
+            (adida.point[0] - croston.point[0]).abs() > 1e-9,
+            "ADIDA should not reproduce Croston: {} vs {}",
+            adida.point[0],
+            croston.point[0]
+        );
+    }
+
+    #[test]
+    fn test_tsb_decays_after_demand_stops() {
+        let active = [0.0, 4.0, 0.0, 5.0, 3.0, 0.0, 6.0, 0.0, 4.0, 5.0, 0.0, 3.0];
+        let run = |model, trailing_zeros: usize| {
+            let values: Vec<Option<f64>> = active
+                .iter()
+                .copied()
+                .chain(std::iter::repeat_n(0.0, trailing_zeros))
+                .map(Some)
+                .collect();
+            let options = ForecastOptions {
+                model,
+                horizon: 3,
+                ..Default::default()
+            };
+            forecast(&values, &options).unwrap().point[0]
+        };
+
+        // Croston only updates on non-zero demand, so it ignores obsolescence
+        let croston_short = run(ModelType::CrostonClassic, 4);
+        let croston_long = run(ModelType::CrostonClassic, 16);
+        assert!((croston_short - croston_long).abs() < 1e-9);
+
+        // TSB updates the demand probability every period and decays
+        let tsb_short = run(ModelType::TSB, 4);
+        let tsb_long = run(ModelType::TSB, 16);
+        assert!(tsb_long < tsb_short, "{} vs {}", tsb_long, tsb_short);
+        assert!(tsb_long < croston_long);
+        assert!(tsb_long >= 0.0);
+    }
+
     /// Regression test for #167: every ModelType must return a model_name that
     /// either equals or starts with model.name(). Guards against shared helpers
     /// hardcoding a wrong model name.
@@ -2911,6 +4159,8 @@ mod tests {
             (ModelType::ADIDA, &intermittent),
             (ModelType::IMAPA, &intermittent),
             (ModelType::TSB, &intermittent),
+            // Count Data
+            (ModelType::CountSES, &intermittent),
         ];
 
         for (model_type, data) in &exact_cases {
@@ -2976,6 +4226,94 @@ mod tests {
         assert!(result.point.iter().all(|v| v.is_finite()));
     }
 
+    #[test]
+    fn test_forecast_theta_extrapolates_trend() {
+        // The theta=0 regression line carries the drift, so a clean linear
+        // series must not produce a flat (SES-only) forecast.
+        let values: Vec<Option<f64>> = (0..30).map(|i| Some(10.0 + i as f64 * 2.0)).collect();
+
+        for model in [ModelType::Theta, ModelType::OptimizedTheta] {
+            let options = ForecastOptions {
+                model,
+                horizon: 6,
+                seasonal_period: 1,
+                auto_detect_seasonality: false,
+                ..Default::default()
+            };
+
+            let result = forecast(&values, &options).unwrap();
+            assert_eq!(result.model_name, model.name());
+            assert!(
+                result.point.windows(2).all(|w| w[1] > w[0]),
+                "{} forecast should follow the trend: {:?}",
+                model.name(),
+                result.point
+            );
+        }
+    }
+
+    #[test]
+    fn test_theta_reseasonalizes_forecast() {
+        let factors = [0.8, 1.2, 1.0, 1.0];
+        let values: Vec<f64> = (0..32)
+            .map(|t| (10.0 + 0.5 * t as f64) * factors[t % 4])
+            .collect();
+
+        let fit = fit_theta(&values, 4).unwrap();
+        let point = fit.forecast(4).unwrap();
+
+        // The next step starts a new cycle at the 0.8 position
+        assert!(point[1] > point[0], "{:?}", point);
+        assert!(point[1] > point[2], "{:?}", point);
+        let ratio = point[1] / point[0];
+        assert!((ratio - 1.5).abs() < 0.1, "ratio {}", ratio);
+    }
+
+    #[test]
+    fn test_theta_beats_ses_drift_average_in_sample() {
+        // Noisy linear trend
+        let mut state: u64 = 5;
+        let values: Vec<f64> = (0..60)
+            .map(|i| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                let e = (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5;
+                10.0 + 2.0 * i as f64 + 2.0 * e
+            })
+            .collect();
+
+        let mse = |fitted: &[f64]| -> f64 {
+            let pairs: Vec<(f64, f64)> = values
+                .iter()
+                .zip(fitted)
+                .skip(1)
+                .filter(|(_, f)| f.is_finite())
+                .map(|(&v, &f)| (v, f))
+                .collect();
+            pairs.iter().map(|(v, f)| (v - f).powi(2)).sum::<f64>() / pairs.len() as f64
+        };
+
+        let theta = fit_theta(&values, 1).unwrap();
+        let theta_mse = mse(&theta.fitted.expect("Theta reports fitted values"));
+
+        // Crude alternative: average of SES(0.3) and random-walk-with-drift fitted values
+        let slope = (values[values.len() - 1] - values[0]) / (values.len() - 1) as f64;
+        let mut drift = vec![values[0]];
+        drift.extend(values[..values.len() - 1].iter().map(|v| v + slope));
+        let average: Vec<f64> = ses_fitted(&values, 0.3)
+            .iter()
+            .zip(&drift)
+            .map(|(s, d)| 0.5 * (s + d))
+            .collect();
+        let average_mse = mse(&average);
+
+        assert!(
+            theta_mse < average_mse,
+            "Theta mse {} vs SES/drift average mse {}",
+            theta_mse,
+            average_mse
+        );
+    }
+
     #[test]
     fn test_forecast_laplace_variants() {
         // Trend + weekly-ish seasonality series that all three Laplace
@@ -3141,106 +4479,991 @@ mod tests {
             })
             .collect();
 
-        let model = select_best_model(&values, 12);
-        assert_eq!(model, ModelType::HoltWinters);
+        let selection = select_best_model(&values, 12);
+        assert_eq!(selection.model, ModelType::HoltWinters);
+        assert!(selection.trend_strength > SELECT_STRONG_TREND);
+        assert!(selection.seasonal_strength > SELECT_SEASONAL_THRESHOLD);
+    }
+
+    #[test]
+    fn test_select_best_model_pure_trend() {
+        let values: Vec<f64> = (0..60)
+            .map(|i| 20.0 + 1.5 * i as f64 + (i as f64 * 2.1).sin())
+            .collect();
+        let selection = select_best_model(&values, 1);
+        assert_eq!(selection.model, ModelType::Holt);
+        assert_eq!(selection.seasonal_strength, 0.0);
+    }
+
+    #[test]
+    fn test_select_best_model_pure_seasonal() {
+        let values: Vec<f64> = (0..72)
+            .map(|i| 50.0 + 10.0 * (std::f64::consts::TAU * i as f64 / 12.0).sin())
+            .collect();
+        let selection = select_best_model(&values, 12);
+        assert_eq!(selection.model, ModelType::SeasonalNaive);
+        assert!(selection.trend_strength < SELECT_STRONG_TREND);
+    }
+
+    #[test]
+    fn test_select_best_model_white_noise() {
+        // Deterministic LCG noise
+        let mut state: u64 = 12345;
+        let values: Vec<f64> = (0..100)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5
+            })
+            .collect();
+        let selection = select_best_model(&values, 1);
+        assert_eq!(selection.model, ModelType::Naive);
+        assert!(selection.trend_strength < SELECT_WEAK_TREND);
+    }
+
+    #[test]
+    fn test_select_best_model_short_series() {
+        let values: Vec<f64> = vec![1.0, 2.0, 3.0];
+        let selection = select_best_model(&values, 1);
+        assert_eq!(selection.model, ModelType::Naive);
+    }
+
+    #[test]
+    fn test_calculate_confidence_intervals() {
+        let forecasts = vec![100.0, 105.0, 110.0];
+        let residuals: Vec<f64> = (0..20)
+            .map(|i| if i % 2 == 0 { 2.0 } else { -2.0 })
+            .collect();
+
+        let (lower, upper) = calculate_confidence_intervals(
+            &forecasts,
+            &residuals,
+            0.95,
+            false,
+            VarianceGrowth::RandomWalk,
+        );
+
+        assert_eq!(lower.len(), 3);
+        assert_eq!(upper.len(), 3);
+        // Lower bounds should be below forecasts
+        assert!(lower.iter().zip(&forecasts).all(|(l, f)| l < f));
+        // Upper bounds should be above forecasts
+        assert!(upper.iter().zip(&forecasts).all(|(u, f)| u > f));
+        // Intervals should widen with horizon
+        let width_1 = upper[0] - lower[0];
+        let width_3 = upper[2] - lower[2];
+        assert!(width_3 > width_1);
+        // The one-step band is scaled by the residuals, not the series spread
+        assert!((width_1 - 2.0 * 1.96 * 2.0).abs() < 1e-3);
     }
 
-    #[test]
-    fn test_select_best_model_short_series() {
-        let values: Vec<f64> = vec![1.0, 2.0, 3.0];
-        let model = select_best_model(&values, 1);
-        assert_eq!(model, ModelType::Naive);
+    #[test]
+    fn test_intervals_scale_with_residuals_not_level() {
+        // Steep trend with tiny wiggles: the drift model fits almost exactly,
+        // so the bands must stay narrow although the series spans ~200 units
+        let values: Vec<Option<f64>> = (0..100)
+            .map(|i| Some(10.0 + 2.0 * i as f64 + if i % 2 == 0 { 0.01 } else { -0.01 }))
+            .collect();
+        let options = ForecastOptions {
+            model: ModelType::RandomWalkDrift,
+            horizon: 3,
+            auto_detect_seasonality: false,
+            ..Default::default()
+        };
+        let result = forecast(&values, &options).unwrap();
+        let width = result.upper[0] - result.lower[0];
+        assert!(width > 0.0 && width < 0.2, "{}", width);
+    }
+
+    #[test]
+    fn test_ses_variance_growth_matches_simulation() {
+        // Simulate SES paths from a known level with uniform(-0.5, 0.5) shocks
+        // and compare the empirical h-step error variance to the analytic one.
+        let alpha = 0.5;
+        let sigma2 = 1.0 / 12.0;
+        let horizon = 8;
+        let paths = 20_000;
+        let mut state: u64 = 2024;
+        let mut sum_sq = vec![0.0; horizon];
+        for _ in 0..paths {
+            let mut level = 0.0;
+            for sq in sum_sq.iter_mut() {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                let e = (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5;
+                *sq += (level + e).powi(2);
+                level += alpha * e;
+            }
+        }
+
+        let growth = VarianceGrowth::Ses { alpha };
+        for (i, sq) in sum_sq.iter().enumerate() {
+            let empirical = sq / paths as f64 / sigma2;
+            let analytic = growth.factor(i + 1);
+            assert!(
+                (empirical / analytic - 1.0).abs() < 0.05,
+                "h={}: simulated {} vs analytic {}",
+                i + 1,
+                empirical,
+                analytic
+            );
+        }
+    }
+
+    #[test]
+    fn test_variance_growth_by_model() {
+        let values: Vec<f64> = (0..25).map(|i| i as f64).collect();
+
+        let naive = fit_naive(&values).growth;
+        assert_eq!(naive.factor(4), 4.0);
+
+        // Drift adds the variance of the estimated slope
+        let drift = fit_drift(&values).growth;
+        assert!((drift.factor(4) - 4.0 * (1.0 + 4.0 / 24.0)).abs() < 1e-12);
+
+        // Seasonal naive only grows once per full season
+        let seasonal = fit_seasonal_naive(&values, 12).growth;
+        assert_eq!(seasonal.factor(1), 1.0);
+        assert_eq!(seasonal.factor(12), 1.0);
+        assert_eq!(seasonal.factor(13), 2.0);
+
+        // SES intervals widen much more slowly than a random walk's
+        let ses = VarianceGrowth::Ses { alpha: 0.3 };
+        assert!((ses.factor(5) - 1.36).abs() < 1e-12);
+
+        // Holt adds the trend uncertainty: 1 + (0.5 * 1.2)^2 + (0.5 * 1.4)^2
+        let holt = VarianceGrowth::Holt {
+            alpha: 0.5,
+            beta: 0.2,
+        };
+        assert!((holt.factor(3) - 1.85).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_robust_intervals_ignore_spikes() {
+        let forecasts = vec![10.0, 10.0, 10.0];
+        let mut residuals: Vec<f64> = (0..40).map(|i| (i % 4) as f64 - 1.5).collect();
+        residuals[10] = 490.0;
+        residuals[30] = -410.0;
+
+        let (lower_sd, upper_sd) = calculate_confidence_intervals(
+            &forecasts,
+            &residuals,
+            0.95,
+            false,
+            VarianceGrowth::RandomWalk,
+        );
+        let (lower_mad, upper_mad) = calculate_confidence_intervals(
+            &forecasts,
+            &residuals,
+            0.95,
+            true,
+            VarianceGrowth::RandomWalk,
+        );
+
+        // Spikes inflate the variance-based scale but barely move the MAD.
+        assert!(upper_mad[0] - lower_mad[0] < (upper_sd[0] - lower_sd[0]) / 5.0);
+        assert!(lower_mad.iter().zip(&forecasts).all(|(l, f)| l < f));
+        assert!(upper_mad.iter().zip(&forecasts).all(|(u, f)| u > f));
+    }
+
+    #[test]
+    fn test_robust_intervals_use_residuals_not_level() {
+        // A level shift inflates the MAD of the values, but the naive model's
+        // residuals are a single jump among small alternating steps
+        let values: Vec<Option<f64>> = (0..60)
+            .map(|i| {
+                let level = if i < 30 { 0.0 } else { 100.0 };
+                Some(level + if i % 2 == 0 { 0.1 } else { -0.1 })
+            })
+            .collect();
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            horizon: 2,
+            auto_detect_seasonality: false,
+            robust_intervals: true,
+            ..Default::default()
+        };
+        let result = forecast(&values, &options).unwrap();
+        let width = result.upper[0] - result.lower[0];
+        assert!(width > 0.0 && width < 2.0, "{}", width);
+    }
+
+    #[test]
+    fn test_bootstrap_intervals_skewed_residuals() {
+        // Random walk whose steps are mostly -1 with an occasional +9 jump:
+        // zero-mean but strongly right-skewed naive residuals.
+        let mut level = 100.0;
+        let values: Vec<Option<f64>> = (0..120)
+            .map(|i| {
+                level += if i % 10 == 9 { 9.0 } else { -1.0 };
+                Some(level)
+            })
+            .collect();
+
+        let run = |interval_method| {
+            let options = ForecastOptions {
+                model: ModelType::Naive,
+                horizon: 3,
+                interval_method,
+                ..Default::default()
+            };
+            forecast(&values, &options).unwrap()
+        };
+
+        let normal = run(PredictionIntervalMethod::Normal);
+        let below = normal.point[0] - normal.lower[0];
+        let above = normal.upper[0] - normal.point[0];
+        assert!((below - above).abs() < 1e-9);
+
+        let boot = run(PredictionIntervalMethod::Bootstrap);
+        let below = boot.point[0] - boot.lower[0];
+        let above = boot.upper[0] - boot.point[0];
+        assert!(
+            above > 2.0 * below,
+            "expected right-skewed interval, got -{} / +{}",
+            below,
+            above
+        );
+        assert!(boot.lower.iter().zip(&boot.upper).all(|(l, u)| l <= u));
+    }
+
+    #[test]
+    fn test_prediction_interval_method_parse() {
+        assert_eq!(
+            PredictionIntervalMethod::parse("").unwrap(),
+            PredictionIntervalMethod::Normal
+        );
+        assert_eq!(
+            PredictionIntervalMethod::parse("Bootstrap").unwrap(),
+            PredictionIntervalMethod::Bootstrap
+        );
+        assert!(PredictionIntervalMethod::parse("conformal").is_err());
+    }
+
+    #[test]
+    fn test_auto_select_by_holdout() {
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some(100.0 + 2.0 * i as f64 + ((i * 7 + 3) % 5) as f64))
+            .collect();
+
+        let options = ForecastOptions {
+            model: ModelType::AutoETS,
+            horizon: 6,
+            seasonal_period: 1,
+            auto_detect_seasonality: false,
+            auto_select_by_holdout: true,
+            ..Default::default()
+        };
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.point.len(), 6);
+        let score = result.holdout_score.expect("holdout score reported");
+        assert!(score.is_finite() && score >= 0.0);
+        // A trending series must not be won by the flat Naive benchmark
+        assert_ne!(result.model_name, "Naive");
+
+        // Non-auto models ignore the flag
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            ..options
+        };
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.model_name, "Naive");
+        assert!(result.holdout_score.is_none());
+    }
+
+    #[test]
+    fn test_auto_select_by_holdout_short_series_uses_strengths() {
+        // Too short to hold out a tail with two full periods left to train on
+        let clean: Vec<f64> = (0..14)
+            .map(|i| 50.0 + [8.0, -3.0, 5.0, -9.0, 2.0, -3.0][i % 6] + (i % 2) as f64 * 0.1)
+            .collect();
+        let values: Vec<Option<f64>> = clean.iter().copied().map(Some).collect();
+        let options = ForecastOptions {
+            model: ModelType::AutoETS,
+            horizon: 6,
+            seasonal_period: 6,
+            auto_detect_seasonality: false,
+            auto_select_by_holdout: true,
+            ..Default::default()
+        };
+        assert!(select_model_by_holdout(&clean, &options, 6).is_none());
+
+        let selection = select_best_model(&clean, 6);
+        assert_eq!(selection.model, ModelType::SeasonalNaive);
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.model_name, "SeasonalNaive");
+        assert!(result.holdout_score.is_none());
+    }
+
+    #[test]
+    fn test_is_auto_model() {
+        assert!(is_auto_model(ModelType::AutoETS));
+        assert!(is_auto_model(ModelType::AutoARIMA));
+        assert!(is_auto_model(ModelType::AutoTheta));
+        assert!(is_auto_model(ModelType::AutoMFLES));
+        assert!(is_auto_model(ModelType::AutoMSTL));
+        assert!(is_auto_model(ModelType::AutoTBATS));
+        assert!(!is_auto_model(ModelType::Naive));
+        assert!(!is_auto_model(ModelType::HoltWinters));
+    }
+
+    #[test]
+    fn test_forecast_count_ses() {
+        let values: Vec<Option<f64>> = [2.0, 0.0, 3.0, 1.0, 4.0, 2.0, 0.0, 3.0, 2.0, 1.0, 5.0, 2.0]
+            .iter()
+            .map(|&v| Some(v))
+            .collect();
+
+        let options = ForecastOptions {
+            model: ModelType::CountSES,
+            horizon: 4,
+            confidence_level: 0.95,
+            include_fitted: true,
+            ..Default::default()
+        };
+
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.model_name, "CountSES");
+        assert_eq!(result.point.len(), 4);
+        for i in 0..4 {
+            // Integer-valued, non-negative support
+            assert_eq!(result.point[i], result.point[i].round());
+            assert_eq!(result.lower[i], result.lower[i].round());
+            assert_eq!(result.upper[i], result.upper[i].round());
+            assert!(result.lower[i] >= 0.0);
+            assert!(result.lower[i] <= result.point[i]);
+            assert!(result.point[i] <= result.upper[i]);
+        }
+        assert_eq!(result.fitted.unwrap().len(), values.len());
+
+        // Negative values are not counts
+        let negative = vec![Some(1.0), Some(-1.0), Some(2.0)];
+        assert!(forecast(&negative, &options).is_err());
+    }
+
+    #[test]
+    fn test_poisson_quantile() {
+        // Poisson(2): P(X<=0)=0.135, P(X<=1)=0.406, P(X<=2)=0.677, P(X<=5)=0.983
+        assert_eq!(poisson_quantile(2.0, 0.1), 0.0);
+        assert_eq!(poisson_quantile(2.0, 0.5), 2.0);
+        assert_eq!(poisson_quantile(2.0, 0.975), 5.0);
+        assert_eq!(poisson_quantile(0.0, 0.975), 0.0);
+        // Large rates stay finite and centred near lambda
+        let median = poisson_quantile(5000.0, 0.5);
+        assert!((median - 5000.0).abs() <= 1.0);
+    }
+
+    #[test]
+    fn test_model_type_from_str() {
+        assert_eq!("Naive".parse::<ModelType>().unwrap(), ModelType::Naive);
+        assert_eq!("naive".parse::<ModelType>().unwrap(), ModelType::Naive);
+        assert_eq!(
+            "HoltWinters".parse::<ModelType>().unwrap(),
+            ModelType::HoltWinters
+        );
+        assert_eq!("hw".parse::<ModelType>().unwrap(), ModelType::HoltWinters);
+        assert_eq!(
+            "croston".parse::<ModelType>().unwrap(),
+            ModelType::CrostonClassic
+        );
+        assert_eq!(
+            "count_ses".parse::<ModelType>().unwrap(),
+            ModelType::CountSES
+        );
+        assert!("invalid_model".parse::<ModelType>().is_err());
+    }
+
+    #[test]
+    fn test_unknown_model_error_lists_valid_models() {
+        match "ETZ".parse::<ModelType>() {
+            Err(ForecastError::InvalidModel(msg)) => {
+                assert!(msg.starts_with("Unknown model: 'ETZ'"), "{}", msg);
+                for name in list_models() {
+                    assert!(msg.contains(&name), "{} missing from {}", name, msg);
+                }
+            }
+            other => panic!("expected InvalidModel, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_forecast_with_nulls() {
+        // Test that NULL values are handled via interpolation
+        let values: Vec<Option<f64>> = vec![
+            Some(1.0),
+            Some(2.0),
+            None,
+            Some(4.0),
+            Some(5.0),
+            None,
+            Some(7.0),
+        ];
+
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            horizon: 3,
+            ..Default::default()
+        };
+
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.point.len(), 3);
+        assert!(result.point.iter().all(|v| v.is_finite()));
+    }
+
+    #[test]
+    fn test_forecast_with_fitted_and_residuals() {
+        let values: Vec<Option<f64>> = (0..15).map(|i| Some(i as f64 * 2.0)).collect();
+
+        let options = ForecastOptions {
+            model: ModelType::SES,
+            horizon: 3,
+            include_fitted: true,
+            include_residuals: true,
+            ..Default::default()
+        };
+
+        let result = forecast(&values, &options).unwrap();
+        assert!(result.fitted.is_some());
+        assert!(result.residuals.is_some());
+        assert_eq!(result.fitted.as_ref().unwrap().len(), 15);
+        assert_eq!(result.residuals.as_ref().unwrap().len(), 15);
+        assert!(result.mse.is_some());
+    }
+
+    #[test]
+    fn test_holt_winters_fitted_values_match_manual_recursion() {
+        let clean: Vec<f64> = (0..48)
+            .map(|i| {
+                100.0
+                    + 0.5 * i as f64
+                    + 10.0 * (std::f64::consts::TAU * i as f64 / 12.0).sin()
+                    + ((i * 7) % 5) as f64 * 0.3
+            })
+            .collect();
+        let values: Vec<Option<f64>> = clean.iter().copied().map(Some).collect();
+        let options = ForecastOptions {
+            model: ModelType::HoltWinters,
+            horizon: 6,
+            seasonal_period: 12,
+            auto_detect_seasonality: false,
+            damped: true,
+            phi: Some(1.0),
+            include_fitted: true,
+            include_residuals: true,
+            ..Default::default()
+        };
+        let result = forecast(&values, &options).unwrap();
+        let fitted = result.fitted.as_ref().unwrap();
+        let residuals = result.residuals.as_ref().unwrap();
+
+        // Additive Holt-Winters written out by hand with the selected
+        // smoothing parameters, initialised from the first two periods.
+        let p = damped_params(&clean, 12, SeasonalMode::Additive, 1.0);
+        let first = clean[..12].iter().sum::<f64>() / 12.0;
+        let second = clean[12..24].iter().sum::<f64>() / 12.0;
+        let mut level = first;
+        let mut trend = (second - first) / 12.0;
+        let mut seasonal: Vec<f64> = clean[..12].iter().map(|v| v - first).collect();
+        let mut expected = Vec::new();
+        for (t, &y) in clean.iter().enumerate() {
+            let s = seasonal[t % 12];
+            expected.push(level + trend + s);
+            let new_level = p.alpha * (y - s) + (1.0 - p.alpha) * (level + trend);
+            let new_trend = p.beta * (new_level - level) + (1.0 - p.beta) * trend;
+            seasonal[t % 12] = p.gamma * (y - level - trend) + (1.0 - p.gamma) * s;
+            level = new_level;
+            trend = new_trend;
+        }
+
+        assert_eq!(fitted.len(), expected.len());
+        for (f, e) in fitted.iter().zip(expected.iter()) {
+            assert!((f - e).abs() < 1e-9, "{} vs {}", f, e);
+        }
+        for ((a, e), r) in clean.iter().zip(expected.iter()).zip(residuals.iter()) {
+            assert!((a - e - r).abs() < 1e-9);
+        }
+        let manual_mse = clean
+            .iter()
+            .zip(expected.iter())
+            .map(|(a, e)| (a - e).powi(2))
+            .sum::<f64>()
+            / clean.len() as f64;
+        assert!((result.mse.unwrap() - manual_mse).abs() < 1e-9);
+
+        // Forecasts continue the final state
+        for (h, point) in result.point.iter().enumerate() {
+            let e = level + (h + 1) as f64 * trend + seasonal[(48 + h) % 12];
+            assert!((point - e).abs() < 1e-9, "{} vs {}", point, e);
+        }
+    }
+
+    #[test]
+    fn test_arima_fitted_values() {
+        let values = vec![1.0, 3.0, 4.0, 7.0, 8.0, 12.0];
+        let fitted = arima_fitted(&values);
+        let mean_diff = (12.0 - 1.0) / 5.0;
+        assert_eq!(fitted.len(), values.len());
+        assert_eq!(fitted[0], 1.0);
+        assert!((fitted[1] - (1.0 + mean_diff)).abs() < 1e-12);
+        // fitted[4] = values[3] + mean_diff + 0.5 * (diff[2] - mean_diff)
+        assert!((fitted[4] - (7.0 + mean_diff + 0.5 * (3.0 - mean_diff))).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ses_optimized_beats_fixed_alpha() {
+        // Level shifts every few steps: the optimal alpha is close to 1
+        let values: Vec<Option<f64>> = (0..40)
+            .map(|i| Some(((i / 3) as f64 * 7.0).sin() * 50.0 + 100.0))
+            .collect();
+        let clean: Vec<f64> = values.iter().map(|v| v.unwrap()).collect();
+        assert!(ses_optimal_alpha(&clean) > 0.8);
+
+        let run = |model| {
+            let options = ForecastOptions {
+                model,
+                horizon: 3,
+                include_fitted: true,
+                ..Default::default()
+            };
+            forecast(&values, &options).unwrap()
+        };
+        let fixed = run(ModelType::SES);
+        let optimized = run(ModelType::SESOptimized);
+        assert_eq!(optimized.model_name, "SESOptimized");
+        assert!(
+            optimized.mse.unwrap() < fixed.mse.unwrap(),
+            "{:?} vs {:?}",
+            optimized.mse,
+            fixed.mse
+        );
+    }
+
+    #[test]
+    fn test_damped_holt_flattens() {
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some(10.0 + 2.0 * i as f64 + (i as f64 * 1.3).sin()))
+            .collect();
+        let run = |damped, phi| {
+            let options = ForecastOptions {
+                model: ModelType::Holt,
+                horizon: 40,
+                damped,
+                phi,
+                ..Default::default()
+            };
+            forecast(&values, &options).unwrap()
+        };
+
+        let undamped = run(false, None);
+        let damped = run(true, Some(0.8));
+        assert_eq!(damped.model_name, "Holt");
+
+        let step = |p: &[f64], i: usize| p[i + 1] - p[i];
+        // Undamped keeps climbing at the fitted slope
+        assert!(step(&undamped.point, 38) > 0.5 * step(&undamped.point, 0));
+        // Damped increments shrink geometrically towards a plateau
+        assert!(step(&damped.point, 38) < 0.01 * step(&damped.point, 0));
+        assert!(damped.point[39] < undamped.point[39]);
+
+        // phi is estimated when not given
+        let estimated = run(true, None);
+        assert!(estimated.point.iter().all(|v| v.is_finite()));
+
+        let options = ForecastOptions {
+            model: ModelType::Holt,
+            damped: true,
+            phi: Some(1.5),
+            ..Default::default()
+        };
+        assert!(forecast(&values, &options).is_err());
+    }
+
+    #[test]
+    fn test_damped_holt_winters() {
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some(50.0 + i as f64 + 5.0 * [0.0, 3.0, -1.0, -2.0][i % 4]))
+            .collect();
+        let options = ForecastOptions {
+            model: ModelType::HoltWinters,
+            horizon: 8,
+            seasonal_period: 4,
+            auto_detect_seasonality: false,
+            damped: true,
+            phi: Some(0.9),
+            ..Default::default()
+        };
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.model_name, "HoltWinters");
+        // The seasonal pattern repeats in the forecast (peak at position 1)
+        assert!(result.point[1] > result.point[0]);
+        assert!(result.point[5] > result.point[4]);
+    }
+
+    #[test]
+    fn test_damped_multiplicative_holt_winters() {
+        let factors = [0.8, 1.3, 1.0, 0.9];
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some((50.0 + i as f64) * factors[i % 4]))
+            .collect();
+        let options = ForecastOptions {
+            model: ModelType::HoltWinters,
+            horizon: 8,
+            seasonal_period: 4,
+            auto_detect_seasonality: false,
+            damped: true,
+            phi: Some(0.9),
+            seasonal_mode: SeasonalMode::Multiplicative,
+            include_fitted: true,
+            ..Default::default()
+        };
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.model_name, "HoltWinters");
+        // The seasonal factors scale the forecast: peak / trough close to 1.3 / 0.8
+        let ratio = result.point[1] / result.point[0];
+        assert!((ratio - 1.3 / 0.8).abs() < 0.15, "ratio {}", ratio);
+        assert!(result.mse.unwrap() < 1.0, "mse {:?}", result.mse);
+
+        let zeros: Vec<Option<f64>> = (0..48).map(|i| Some((i % 4) as f64)).collect();
+        assert!(forecast(&zeros, &options).is_err());
+    }
+
+    #[test]
+    fn test_forecast_with_exog_honours_damping() {
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some(10.0 + 2.0 * i as f64 + (i as f64 * 1.3).sin()))
+            .collect();
+        let options = ForecastOptions {
+            model: ModelType::Holt,
+            horizon: 12,
+            seasonal_period: 1,
+            auto_detect_seasonality: false,
+            damped: true,
+            phi: Some(0.8),
+            ..Default::default()
+        };
+
+        let plain = forecast(&values, &options).unwrap();
+        let exog = forecast_with_exog(&values, &options.into()).unwrap();
+        assert_eq!(plain.point, exog.point);
+    }
+
+    #[test]
+    fn test_forecast_ensemble_between_members() {
+        // Trending series: drift forecasts sit a constant slope above naive
+        let values: Vec<Option<f64>> = (0..30).map(|i| Some(10.0 + 2.0 * i as f64)).collect();
+        let member = |model| {
+            let options = ForecastOptions {
+                model,
+                horizon: 4,
+                ..Default::default()
+            };
+            forecast(&values, &options).unwrap()
+        };
+        let naive = member(ModelType::Naive);
+        let drift = member(ModelType::RandomWalkDrift);
+
+        let models = [ModelType::Naive, ModelType::RandomWalkDrift];
+        let ensemble = forecast_ensemble(&values, &models, 4, None).unwrap();
+        assert_eq!(ensemble.model_name, "Ensemble(Naive,RandomWalkDrift)");
+        for i in 0..4 {
+            assert!((ensemble.point[i] - (naive.point[i] + drift.point[i]) / 2.0).abs() < 1e-9);
+            assert!(ensemble.point[i] > naive.point[i] && ensemble.point[i] < drift.point[i]);
+            assert!(ensemble.lower[i] <= naive.lower[i].min(drift.lower[i]));
+            assert!(ensemble.upper[i] >= naive.upper[i].max(drift.upper[i]));
+        }
+
+        let weighted = forecast_ensemble(&values, &models, 4, Some(&[3.0, 1.0])).unwrap();
+        let expected = 0.75 * naive.point[0] + 0.25 * drift.point[0];
+        assert!((weighted.point[0] - expected).abs() < 1e-9);
+
+        // A zero-weight drift member must not widen the naive intervals
+        let silenced = forecast_ensemble(&values, &models, 4, Some(&[1.0, 0.0])).unwrap();
+        assert!(drift.upper[3] > naive.upper[3]);
+        assert_eq!(silenced.point, naive.point);
+        assert_eq!(silenced.lower, naive.lower);
+        assert_eq!(silenced.upper, naive.upper);
+
+        assert!(forecast_ensemble(&values, &[], 4, None).is_err());
+        assert!(forecast_ensemble(&values, &models, 4, Some(&[1.0])).is_err());
+        assert!(forecast_ensemble(&values, &models, 4, Some(&[-1.0, 2.0])).is_err());
+    }
+
+    #[test]
+    fn test_log_transform_exponential_growth() {
+        // 4% growth per step with small multiplicative noise
+        let data: Vec<f64> = (0..72)
+            .map(|i| 10.0 * 1.04_f64.powi(i) * (0.03 * (i as f64 * 1.7).sin()).exp())
+            .collect();
+        let (train, test) = data.split_at(60);
+        let values: Vec<Option<f64>> = train.iter().map(|&v| Some(v)).collect();
+        let run = |log_transform| {
+            let options = ForecastOptions {
+                model: ModelType::RandomWalkDrift,
+                horizon: test.len(),
+                auto_detect_seasonality: false,
+                log_transform,
+                ..Default::default()
+            };
+            forecast(&values, &options).unwrap()
+        };
+        let mae = |point: &[f64]| {
+            point
+                .iter()
+                .zip(test)
+                .map(|(p, a)| (p - a).abs())
+                .sum::<f64>()
+                / test.len() as f64
+        };
+
+        let plain = run(false);
+        let logged = run(true);
+        assert!(
+            mae(&logged.point) < mae(&plain.point) / 2.0,
+            "log MAE {} vs plain MAE {}",
+            mae(&logged.point),
+            mae(&plain.point)
+        );
+        assert!(logged
+            .lower
+            .iter()
+            .zip(&logged.point)
+            .zip(&logged.upper)
+            .all(|((l, p), u)| 0.0 < *l && l < p && p < u));
+    }
+
+    #[test]
+    fn test_log_transform_rejects_non_positive() {
+        let values = vec![Some(1.0), Some(2.0), None, Some(0.0), Some(3.0)];
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            horizon: 2,
+            log_transform: true,
+            ..Default::default()
+        };
+        assert!(forecast(&values, &options).is_err());
+    }
+
+    #[test]
+    fn test_holt_winters_seasonal_mode() {
+        // Zero-crossing seasonal series (e.g. temperature anomalies)
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some(8.0 * (std::f64::consts::TAU * i as f64 / 12.0).sin() + 0.05 * i as f64))
+            .collect();
+        let run = |seasonal_mode| {
+            let options = ForecastOptions {
+                model: ModelType::HoltWinters,
+                horizon: 12,
+                seasonal_period: 12,
+                auto_detect_seasonality: false,
+                seasonal_mode,
+                ..Default::default()
+            };
+            forecast(&values, &options)
+        };
+
+        let additive = run(SeasonalMode::Additive).unwrap();
+        assert!(additive
+            .point
+            .iter()
+            .all(|v| v.is_finite() && v.abs() < 20.0));
+        // The forecast keeps the seasonal swing through zero
+        assert!(additive.point.iter().any(|&v| v > 0.0));
+        assert!(additive.point.iter().any(|&v| v < 0.0));
+
+        assert!(matches!(
+            run(SeasonalMode::Multiplicative),
+            Err(ForecastError::InvalidInput(_))
+        ));
+        assert_eq!(
+            SeasonalMode::parse("Multiplicative").unwrap(),
+            SeasonalMode::Multiplicative
+        );
+        assert!(SeasonalMode::parse("log").is_err());
+    }
+
+    #[test]
+    fn test_multiplicative_holt_winters_rejects_zeros() {
+        // Non-negative seasonal demand that hits zero once per cycle
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| {
+                Some(if i % 12 == 0 {
+                    0.0
+                } else {
+                    10.0 + (i % 12) as f64
+                })
+            })
+            .collect();
+        let options = ForecastOptions {
+            model: ModelType::HoltWinters,
+            horizon: 12,
+            seasonal_period: 12,
+            auto_detect_seasonality: false,
+            seasonal_mode: SeasonalMode::Multiplicative,
+            ..Default::default()
+        };
+
+        match forecast(&values, &options) {
+            Err(ForecastError::InvalidInput(msg)) => {
+                assert!(msg.contains("strictly positive"), "{}", msg);
+                // The message points to the working alternative
+                assert!(msg.contains("additive"), "{}", msg);
+            }
+            other => panic!("expected InvalidInput, got {:?}", other.map(|r| r.point)),
+        }
     }
 
     #[test]
-    fn test_calculate_confidence_intervals() {
-        let forecasts = vec![100.0, 105.0, 110.0];
-        let historical: Vec<f64> = (0..20).map(|i| 50.0 + i as f64).collect();
+    fn test_explicit_seasonal_period_longer_than_half_series_rejected() {
+        let values: Vec<Option<f64>> = (0..30).map(|i| Some(10.0 + (i % 7) as f64)).collect();
+        let options = ForecastOptions {
+            model: ModelType::HoltWinters,
+            horizon: 4,
+            seasonal_period: 52,
+            auto_detect_seasonality: false,
+            ..Default::default()
+        };
 
-        let (lower, upper) = calculate_confidence_intervals(&forecasts, &historical, 0.95);
+        match forecast(&values, &options) {
+            Err(ForecastError::InvalidInput(msg)) => {
+                assert!(msg.contains("52"), "{}", msg);
+                assert!(msg.contains("at most 15"), "{}", msg);
+            }
+            other => panic!("expected InvalidInput, got {:?}", other.map(|r| r.point)),
+        }
 
-        assert_eq!(lower.len(), 3);
-        assert_eq!(upper.len(), 3);
-        // Lower bounds should be below forecasts
-        assert!(lower.iter().zip(&forecasts).all(|(l, f)| l < f));
-        // Upper bounds should be above forecasts
-        assert!(upper.iter().zip(&forecasts).all(|(u, f)| u > f));
-        // Intervals should widen with horizon
-        let width_1 = upper[0] - lower[0];
-        let width_3 = upper[2] - lower[2];
-        assert!(width_3 > width_1);
+        // A period that fits at least twice is accepted
+        let options = ForecastOptions {
+            seasonal_period: 7,
+            ..options
+        };
+        assert!(forecast(&values, &options).is_ok());
     }
 
     #[test]
-    fn test_is_auto_model() {
-        assert!(is_auto_model(ModelType::AutoETS));
-        assert!(is_auto_model(ModelType::AutoARIMA));
-        assert!(is_auto_model(ModelType::AutoTheta));
-        assert!(is_auto_model(ModelType::AutoMFLES));
-        assert!(is_auto_model(ModelType::AutoMSTL));
-        assert!(is_auto_model(ModelType::AutoTBATS));
-        assert!(!is_auto_model(ModelType::Naive));
-        assert!(!is_auto_model(ModelType::HoltWinters));
+    fn test_model_capabilities() {
+        assert!(ModelType::ARIMA.capabilities().supports_exog);
+        assert!(!ModelType::Naive.capabilities().supports_exog);
+        assert!(ModelType::TSB.capabilities().is_intermittent);
+        assert!(!ModelType::TSB.capabilities().supports_seasonal);
+        assert!(ModelType::HoltWinters.capabilities().supports_seasonal);
+        for name in list_models() {
+            let model: ModelType = name.parse().unwrap();
+            assert!(model.capabilities().produces_intervals, "{}", name);
+        }
     }
 
     #[test]
-    fn test_model_type_from_str() {
-        assert_eq!("Naive".parse::<ModelType>().unwrap(), ModelType::Naive);
-        assert_eq!("naive".parse::<ModelType>().unwrap(), ModelType::Naive);
-        assert_eq!(
-            "HoltWinters".parse::<ModelType>().unwrap(),
-            ModelType::HoltWinters
-        );
-        assert_eq!("hw".parse::<ModelType>().unwrap(), ModelType::HoltWinters);
-        assert_eq!(
-            "croston".parse::<ModelType>().unwrap(),
-            ModelType::CrostonClassic
-        );
-        assert!("invalid_model".parse::<ModelType>().is_err());
+    fn test_ets_with_exog_follows_regressor_spike() {
+        let n = 48;
+        let promo: Vec<f64> = (0..n).map(|i| if i % 7 == 3 { 1.0 } else { 0.0 }).collect();
+        let values: Vec<Option<f64>> = promo
+            .iter()
+            .enumerate()
+            .map(|(i, p)| Some(100.0 + (i as f64 * 0.9).sin() + 30.0 * p))
+            .collect();
+        let future = vec![0.0, 0.0, 1.0, 0.0, 0.0];
+
+        for (model, name) in [
+            (ModelType::ETS, "ETSX"),
+            (ModelType::HoltWinters, "HoltWintersX"),
+        ] {
+            let options = ForecastOptionsExog {
+                model,
+                horizon: 5,
+                seasonal_period: 4,
+                exog: Some(ExogenousData::new(
+                    vec![promo.clone()],
+                    vec![future.clone()],
+                )),
+                ..Default::default()
+            };
+            let result = forecast_with_exog(&values, &options).unwrap();
+            assert_eq!(result.model_name, name);
+            assert!(
+                result.point[2] > result.point[1] + 20.0,
+                "{:?}",
+                result.point
+            );
+            assert!(
+                result.point[2] > result.point[3] + 20.0,
+                "{:?}",
+                result.point
+            );
+        }
     }
 
     #[test]
-    fn test_forecast_with_nulls() {
-        // Test that NULL values are handled via interpolation
-        let values: Vec<Option<f64>> = vec![
-            Some(1.0),
-            Some(2.0),
-            None,
-            Some(4.0),
-            Some(5.0),
-            None,
-            Some(7.0),
-        ];
+    fn test_forecast_reports_seasonal_period() {
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some(50.0 + 10.0 * (i as f64 * std::f64::consts::TAU / 12.0).sin()))
+            .collect();
 
         let options = ForecastOptions {
-            model: ModelType::Naive,
-            horizon: 3,
+            model: ModelType::HoltWinters,
+            horizon: 6,
+            seasonal_period: 12,
             ..Default::default()
         };
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.seasonal_period, Some(12));
 
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            horizon: 6,
+            ..Default::default()
+        };
         let result = forecast(&values, &options).unwrap();
-        assert_eq!(result.point.len(), 3);
-        assert!(result.point.iter().all(|v| v.is_finite()));
+        assert_eq!(result.seasonal_period, Some(1));
     }
 
     #[test]
-    fn test_forecast_with_fitted_and_residuals() {
-        let values: Vec<Option<f64>> = (0..15).map(|i| Some(i as f64 * 2.0)).collect();
+    fn test_forecast_batch_matches_individual() {
+        let series: Vec<Vec<Option<f64>>> = [30, 45, 2]
+            .iter()
+            .map(|&n| (0..n).map(|i| Some(10.0 + (i % 7) as f64)).collect())
+            .collect();
+        let refs: Vec<&[Option<f64>]> = series.iter().map(|s| s.as_slice()).collect();
 
         let options = ForecastOptions {
             model: ModelType::SES,
-            horizon: 3,
-            include_fitted: true,
-            include_residuals: true,
+            horizon: 4,
             ..Default::default()
         };
+        let results = forecast_batch(&refs, &options, 0);
 
-        let result = forecast(&values, &options).unwrap();
-        assert!(result.fitted.is_some());
-        assert!(result.residuals.is_some());
-        assert_eq!(result.fitted.as_ref().unwrap().len(), 15);
-        assert_eq!(result.residuals.as_ref().unwrap().len(), 15);
-        assert!(result.mse.is_some());
+        assert_eq!(results.len(), 3);
+        for (values, result) in series.iter().zip(&results).take(2) {
+            let single = forecast(values, &options).unwrap();
+            assert_eq!(result.as_ref().unwrap().point, single.point);
+        }
+        // Too short to forecast; does not affect the other series
+        assert!(results[2].is_err());
+    }
+
+    #[test]
+    fn test_online_ses_matches_batch() {
+        let values: Vec<f64> = (0..200)
+            .map(|i| 20.0 + ((i * 13) % 17) as f64 - 0.02 * i as f64)
+            .collect();
+
+        let mut online = OnlineSes::new(0.3).unwrap();
+        assert!(online.forecast(3).is_empty());
+        for &v in &values {
+            online.update(v);
+        }
+        online.update(f64::NAN);
+        assert_eq!(online.n_observations(), values.len());
+
+        // After 200 steps the initial level carries weight 0.7^200, so the
+        // online level must agree with the batch fit however it initialises.
+        let batch = fit_ses_fixed(&values).unwrap().forecast(3).unwrap();
+        let level = online.level().unwrap();
+        for (o, b) in online.forecast(3).iter().zip(&batch) {
+            assert!((o - b).abs() < 1e-9, "online {} vs batch {}", o, b);
+        }
+        assert!((level - batch[0]).abs() < 1e-9);
+
+        assert!(OnlineSes::new(0.0).is_err());
+        assert!(OnlineSes::new(1.5).is_err());
     }
 
     #[test]
@@ -3341,6 +5564,175 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ets_spec_reports_aic_bic() {
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some(100.0 + 0.5 * i as f64 + ((i * 7) % 5) as f64))
+            .collect();
+
+        let options = ForecastOptions {
+            model: ModelType::ETS,
+            ets_spec: Some("ANN".to_string()),
+            horizon: 3,
+            ..Default::default()
+        };
+
+        let result = forecast(&values, &options).unwrap();
+        assert!(result.aic.is_some_and(f64::is_finite));
+        assert!(result.bic.is_some_and(f64::is_finite));
+    }
+
+    #[test]
+    fn test_ets_information_criteria_penalty() {
+        let values: Vec<f64> = (0..24).map(|i| 10.0 + (i % 4) as f64).collect();
+        let fitted: Vec<f64> = values.iter().map(|v| v + 0.5).collect();
+
+        // Same fit, more parameters: higher AIC and BIC
+        let (aic_simple, bic_simple) =
+            ets_information_criteria(&values, &fitted, "ANN", 4).unwrap();
+        let (aic_full, bic_full) = ets_information_criteria(&values, &fitted, "AAdA", 4).unwrap();
+        assert!(aic_full > aic_simple);
+        assert!(bic_full > bic_simple);
+        // ANN has alpha, level and sigma; AAdA adds beta, slope, phi, gamma and 3 seasonal states
+        assert!((aic_full - aic_simple - 2.0 * 7.0).abs() < 1e-9);
+
+        // Mismatched lengths cannot be scored
+        assert!(ets_information_criteria(&values, &fitted[1..], "ANN", 1).is_none());
+    }
+
+    #[test]
+    fn test_fit_predict_matches_forecast() {
+        let values: Vec<Option<f64>> = (0..36)
+            .map(|i| {
+                Some(50.0 + i as f64 + 5.0 * ((i % 12) as f64 / 12.0 * std::f64::consts::TAU).sin())
+            })
+            .collect();
+
+        for model in [
+            ModelType::Naive,
+            ModelType::SESOptimized,
+            ModelType::HoltWinters,
+            ModelType::Theta,
+            ModelType::AutoETS,
+        ] {
+            let options = ForecastOptions {
+                model,
+                horizon: 6,
+                seasonal_period: 12,
+                include_fitted: true,
+                include_residuals: true,
+                ..Default::default()
+            };
+
+            let one_shot = forecast(&values, &options).unwrap();
+            let fitted = fit(&values, &options).unwrap();
+            let two_step = fitted.predict(options.horizon).unwrap();
+
+            assert_eq!(one_shot.point, two_step.point, "{:?}", model);
+            assert_eq!(one_shot.lower, two_step.lower, "{:?}", model);
+            assert_eq!(one_shot.upper, two_step.upper, "{:?}", model);
+            assert_eq!(one_shot.model_name, two_step.model_name, "{:?}", model);
+            assert_eq!(one_shot.fitted, two_step.fitted, "{:?}", model);
+            assert_eq!(one_shot.residuals.as_deref(), Some(fitted.residuals()));
+            assert_eq!(fitted.fitted_values().len(), values.len());
+        }
+    }
+
+    #[test]
+    fn test_fitted_model_predict_other_horizon() {
+        let values: Vec<Option<f64>> = (0..20).map(|i| Some(i as f64)).collect();
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            horizon: 3,
+            ..Default::default()
+        };
+
+        let fitted = fit(&values, &options).unwrap();
+        assert_eq!(fitted.model(), ModelType::Naive);
+        assert_eq!(fitted.predict(5).unwrap().point, vec![19.0; 5]);
+    }
+
+    #[test]
+    fn test_cross_validate_naive() {
+        // Linear series: naive errors grow with the horizon step
+        let values: Vec<Option<f64>> = (0..20).map(|i| Some(i as f64)).collect();
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            horizon: 3,
+            ..Default::default()
+        };
+
+        let cv = cross_validate(&values, &options, 4, 2).unwrap();
+        assert_eq!(cv.n_windows, 4);
+        assert_eq!(cv.train_lengths, vec![11, 13, 15, 17]);
+        assert_eq!(cv.horizon_mae, vec![1.0, 2.0, 3.0]);
+        assert!((cv.mae - 2.0).abs() < 1e-12);
+        // In-sample naive MAE is 1, so MASE equals MAE
+        assert!((cv.mase - 2.0).abs() < 1e-12);
+        assert!(cv
+            .window_rmse
+            .iter()
+            .all(|r| (r - (14.0_f64 / 3.0).sqrt()).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_cross_validate_nulls_are_interpolated() {
+        // NULLs inside the training prefixes and a test window of a linear series
+        let mut values: Vec<Option<f64>> = (0..20).map(|i| Some(i as f64)).collect();
+        values[5] = None;
+        values[18] = None;
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            horizon: 3,
+            ..Default::default()
+        };
+
+        let cv = cross_validate(&values, &options, 4, 2).unwrap();
+        assert_eq!(cv.train_lengths, vec![11, 13, 15, 17]);
+        assert_eq!(cv.horizon_mae, vec![1.0, 2.0, 3.0]);
+        assert!((cv.mase - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cross_validate_mase_uses_detected_period() {
+        // Period-4 pattern on a trend: seasonal naive is off by one season of
+        // trend, exactly its in-sample seasonal-naive MAE, so MASE is 1
+        let pattern = [1.0, 5.0, 9.0, 3.0];
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some(pattern[i % 4] + 0.5 * i as f64))
+            .collect();
+        let options = ForecastOptions {
+            model: ModelType::SeasonalNaive,
+            horizon: 4,
+            seasonal_period: 0,
+            auto_detect_seasonality: true,
+            ..Default::default()
+        };
+
+        let cv = cross_validate(&values, &options, 3, 4).unwrap();
+        assert!((cv.mae - 2.0).abs() < 1e-9);
+        assert!(cv.window_mase.iter().all(|m| (m - 1.0).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_cross_validate_errors() {
+        let values: Vec<Option<f64>> = (0..10).map(|i| Some(i as f64)).collect();
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            horizon: 3,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            cross_validate(&values, &options, 5, 2),
+            Err(ForecastError::InsufficientData { .. })
+        ));
+        assert!(matches!(
+            cross_validate(&values, &options, 0, 1),
+            Err(ForecastError::InvalidParameter { .. })
+        ));
+    }
+
     #[test]
     fn test_ets_without_spec_falls_back() {
         // ETS without explicit spec should still fall back to simplified implementation
@@ -3361,6 +5753,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_confidence_levels_nested_bands() {
+        let values: Vec<Option<f64>> = (0..60)
+            .map(|i| Some(50.0 + 0.5 * i as f64 + 3.0 * ((i * 7) % 5) as f64))
+            .collect();
+        let options = ForecastOptions {
+            model: ModelType::Holt,
+            horizon: 8,
+            confidence_level: 0.95,
+            confidence_levels: vec![0.8, 0.95],
+            ..Default::default()
+        };
+
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.intervals.len(), 2);
+        let (lower_80, upper_80) = &result.intervals[0];
+        let (lower_95, upper_95) = &result.intervals[1];
+        assert_eq!(lower_80.len(), options.horizon);
+        let bands_95 = lower_95.iter().zip(upper_95);
+        for ((l80, u80), (l95, u95)) in lower_80.iter().zip(upper_80).zip(bands_95) {
+            assert!(u95 - l95 > u80 - l80);
+            assert!(l95 < l80 && u80 < u95);
+        }
+        // The level matching confidence_level reproduces the main interval
+        assert_eq!(lower_95, &result.lower);
+        assert_eq!(upper_95, &result.upper);
+
+        let invalid = ForecastOptions {
+            confidence_levels: vec![0.8, 1.5],
+            ..options
+        };
+        assert!(matches!(
+            forecast(&values, &invalid),
+            Err(ForecastError::InvalidParameter { .. })
+        ));
+    }
+
+    #[test]
+    fn test_arbitrary_confidence_levels_are_distinct() {
+        let values: Vec<Option<f64>> = (0..40).map(|i| Some(20.0 + ((i * 7) % 5) as f64)).collect();
+        let options = ForecastOptions {
+            model: ModelType::Naive,
+            horizon: 4,
+            confidence_levels: vec![0.6, 0.7, 0.85],
+            auto_detect_seasonality: false,
+            ..Default::default()
+        };
+
+        let result = forecast(&values, &options).unwrap();
+        for pair in result.intervals.windows(2) {
+            let ((inner_lower, inner_upper), (outer_lower, outer_upper)) = (&pair[0], &pair[1]);
+            for h in 0..options.horizon {
+                assert!(outer_lower[h] < inner_lower[h]);
+                assert!(inner_upper[h] < outer_upper[h]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ets_fallback_records_warning() {
+        let values: Vec<Option<f64>> = (0..48)
+            .map(|i| Some(100.0 + i as f64 + 5.0 * ((i % 12) as f64 - 5.5).abs()))
+            .collect();
+        let options = ForecastOptions {
+            model: ModelType::ETS,
+            ets_spec: None,
+            horizon: 6,
+            seasonal_period: 12,
+            auto_detect_seasonality: false,
+            ..Default::default()
+        };
+
+        let result = forecast(&values, &options).unwrap();
+        assert_eq!(result.warnings.len(), 1, "{:?}", result.warnings);
+        assert!(
+            result.warnings[0].contains("HoltWinters"),
+            "{:?}",
+            result.warnings
+        );
+
+        // An explicit spec is honoured without a warning
+        let options = ForecastOptions {
+            ets_spec: Some("AAA".to_string()),
+            ..options
+        };
+        let result = forecast(&values, &options).unwrap();
+        assert!(result.warnings.is_empty(), "{:?}", result.warnings);
+    }
+
     #[test]
     fn test_auto_arima_uses_proper_library() {
         // Test that AutoARIMA uses the anofox-forecast library's AutoARIMA implementation
@@ -3467,6 +5948,55 @@ mod tests {
         println!("Simple ARIMA forecasts: {:?}", simple_result.point);
     }
 
+    #[test]
+    fn test_auto_arima_selects_ar_terms_for_ar2() {
+        // Stationary AR(2): y_t = 0.5 y_{t-1} + 0.3 y_{t-2} + e_t around level 50
+        let mut state: u64 = 11;
+        let mut y = vec![0.0, 0.0];
+        for t in 2..300 {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let e = (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5;
+            let next = 0.5 * y[t - 1] + 0.3 * y[t - 2] + e;
+            y.push(next);
+        }
+        let values: Vec<f64> = y.iter().map(|v| 50.0 + v).collect();
+
+        let auto = fit_auto_arima(&values, 1).unwrap();
+
+        // "AutoARIMA(p,d,q)": the search must keep autoregressive terms
+        let order = auto
+            .model_name
+            .trim_start_matches("AutoARIMA(")
+            .split([',', ')'])
+            .next()
+            .and_then(|p| p.parse::<usize>().ok())
+            .unwrap_or_else(|| panic!("no order in '{}'", auto.model_name));
+        assert!(order > 0, "selected {}", auto.model_name);
+
+        // And fit better in-sample than the fixed ARIMA(1,1,1)
+        let auto_mse = {
+            let residuals: Vec<f64> = values
+                .iter()
+                .zip(auto.fitted.expect("AutoARIMA reports fitted values"))
+                .map(|(v, f)| v - f)
+                .filter(|r| r.is_finite())
+                .collect();
+            residuals.iter().map(|r| r * r).sum::<f64>() / residuals.len() as f64
+        };
+        let fixed_mse = values
+            .iter()
+            .zip(arima_fitted(&values))
+            .map(|(v, f)| (v - f).powi(2))
+            .sum::<f64>()
+            / values.len() as f64;
+        assert!(
+            auto_mse < fixed_mse,
+            "AutoARIMA mse {} vs ARIMA(1,1,1) mse {}",
+            auto_mse,
+            fixed_mse
+        );
+    }
+
     #[test]
     fn test_mfles_uses_proper_implementation() {
         // Verify MFLES uses the proper anofox-forecast MFLES model
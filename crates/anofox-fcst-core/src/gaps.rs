@@ -383,6 +383,12 @@ pub fn fill_forward_legacy(
 
 /// Detect the frequency of a time series in seconds.
 pub fn detect_frequency(dates: &[i64]) -> Result<i64> {
+    let diffs = positive_intervals(dates)?;
+    Ok(modal_interval(&diffs))
+}
+
+/// Positive intervals between consecutive sorted dates.
+fn positive_intervals(dates: &[i64]) -> Result<Vec<i64>> {
     if dates.len() < 2 {
         return Err(ForecastError::InsufficientData {
             needed: 2,
@@ -406,19 +412,98 @@ pub fn detect_frequency(dates: &[i64]) -> Result<i64> {
         ));
     }
 
-    // Find the most common difference (mode)
+    Ok(diffs)
+}
+
+/// Most common interval; `diffs` must not be empty.
+fn modal_interval(diffs: &[i64]) -> i64 {
     let mut counts = std::collections::HashMap::new();
-    for d in &diffs {
+    for d in diffs {
         *counts.entry(*d).or_insert(0) += 1;
     }
 
-    let mode = counts
+    counts
         .into_iter()
         .max_by_key(|(_, count)| *count)
         .map(|(diff, _)| diff)
-        .unwrap();
+        .unwrap()
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Classify an interval in seconds as a calendar frequency label.
+///
+/// Month, quarter and year lengths vary, so those labels accept the full
+/// range of calendar lengths (28-31, 89-92 and 365-366 days). Intervals that
+/// match no label are reported as `"other"`.
+fn frequency_label(interval_seconds: i64) -> &'static str {
+    let days = |d: i64| d * SECONDS_PER_DAY;
+    match interval_seconds {
+        1 => "secondly",
+        60 => "minutely",
+        3_600 => "hourly",
+        s if s == days(1) => "daily",
+        s if s == days(7) => "weekly",
+        s if (days(28)..=days(31)).contains(&s) => "monthly",
+        s if (days(89)..=days(92)).contains(&s) => "quarterly",
+        s if (days(365)..=days(366)).contains(&s) => "yearly",
+        _ => "other",
+    }
+}
+
+/// Detect the frequency of a time series in seconds together with a
+/// human-readable label ("hourly", "daily", "weekly", "monthly", ...).
+///
+/// See [`detect_frequency`] for how the modal interval is found.
+pub fn detect_frequency_label(dates: &[i64]) -> Result<(i64, String)> {
+    let freq = detect_frequency(dates)?;
+    Ok((freq, frequency_label(freq).to_string()))
+}
+
+/// Minimum share of intervals matching the modal one for a series to count
+/// as regular.
+const REGULAR_MATCH_FRACTION: f64 = 0.9;
+
+/// Regularity of the spacing between timestamps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrequencyReport {
+    /// Most common interval between observations in seconds
+    pub modal_interval: i64,
+    /// Fraction of intervals matching the modal one
+    pub match_fraction: f64,
+    /// Smallest interval in seconds
+    pub min_interval: i64,
+    /// Largest interval in seconds
+    pub max_interval: i64,
+    /// Whether at least 90% of intervals match the modal one
+    pub is_regular: bool,
+}
 
-    Ok(mode)
+/// Detect the frequency of a time series and report how regular it is.
+///
+/// An interval matches the modal one if it is equal to it or, for monthly,
+/// quarterly and yearly spacing, falls in the same calendar range (e.g. 28-31
+/// days). Gaps and jittered timestamps lower the match fraction; a series is
+/// regular when at least 90% of intervals match.
+pub fn detect_frequency_report(dates: &[i64]) -> Result<FrequencyReport> {
+    let diffs = positive_intervals(dates)?;
+    let mode = modal_interval(&diffs);
+    let mode_label = frequency_label(mode);
+    let calendar = matches!(mode_label, "monthly" | "quarterly" | "yearly");
+
+    let matching = diffs
+        .iter()
+        .filter(|&&d| d == mode || (calendar && frequency_label(d) == mode_label))
+        .count();
+    let match_fraction = matching as f64 / diffs.len() as f64;
+
+    Ok(FrequencyReport {
+        modal_interval: mode,
+        match_fraction,
+        min_interval: *diffs.iter().min().unwrap(),
+        max_interval: *diffs.iter().max().unwrap(),
+        is_regular: match_fraction >= REGULAR_MATCH_FRACTION,
+    })
 }
 
 #[cfg(test)]
@@ -469,6 +554,47 @@ mod tests {
         assert_eq!(filled_values[2], Some(300.0));
     }
 
+    fn ymd_micros(year: i32, month: u32, day: u32) -> i64 {
+        datetime_to_micros(
+            chrono::NaiveDate::from_ymd_opt(year, month, day)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_fill_gaps_monthly_across_year_and_leap_february() {
+        // Nov 2023 -> Apr 2024: Dec, Jan, Feb (29 days) and Mar are missing
+        let dates = vec![ymd_micros(2023, 11, 1), ymd_micros(2024, 4, 1)];
+        let values = vec![Some(1.0), Some(6.0)];
+
+        let (filled_dates, filled_values) =
+            fill_gaps(&dates, &values, 0, FrequencyType::Monthly).unwrap();
+
+        assert_eq!(
+            filled_dates,
+            vec![
+                ymd_micros(2023, 11, 1),
+                ymd_micros(2023, 12, 1),
+                ymd_micros(2024, 1, 1),
+                ymd_micros(2024, 2, 1),
+                ymd_micros(2024, 3, 1),
+                ymd_micros(2024, 4, 1),
+            ]
+        );
+        assert_eq!(
+            filled_values,
+            vec![Some(1.0), None, None, None, None, Some(6.0)]
+        );
+
+        // Consecutive months around the leap day need no filling
+        let dates = vec![ymd_micros(2024, 2, 1), ymd_micros(2024, 3, 1)];
+        let (filled_dates, _) =
+            fill_gaps(&dates, &[Some(1.0), Some(2.0)], 0, FrequencyType::Monthly).unwrap();
+        assert_eq!(filled_dates, dates);
+    }
+
     #[test]
     fn test_fill_gaps_quarterly() {
         // Test quarterly gap filling
@@ -523,4 +649,55 @@ mod tests {
         let freq = detect_frequency(&dates).unwrap();
         assert_eq!(freq, 100);
     }
+
+    #[test]
+    fn test_detect_frequency_label() {
+        let day = 86_400_i64;
+
+        let daily: Vec<i64> = (0..10).map(|i| i * day).collect();
+        assert_eq!(
+            detect_frequency_label(&daily).unwrap(),
+            (day, "daily".to_string())
+        );
+
+        let weekly: Vec<i64> = (0..10).map(|i| i * 7 * day).collect();
+        assert_eq!(detect_frequency_label(&weekly).unwrap().1, "weekly");
+
+        // First of each month in 2023: 28, 30 and 31 day intervals
+        let monthly: Vec<i64> = (1..=12)
+            .map(|m| {
+                chrono::NaiveDate::from_ymd_opt(2023, m, 1)
+                    .unwrap()
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap()
+                    .and_utc()
+                    .timestamp()
+            })
+            .collect();
+        assert_eq!(detect_frequency_label(&monthly).unwrap().1, "monthly");
+
+        let odd: Vec<i64> = (0..10).map(|i| i * 3 * day).collect();
+        assert_eq!(detect_frequency_label(&odd).unwrap().1, "other");
+    }
+
+    #[test]
+    fn test_detect_frequency_report() {
+        let hour = 3_600_i64;
+
+        let regular: Vec<i64> = (0..50).map(|i| i * hour).collect();
+        let report = detect_frequency_report(&regular).unwrap();
+        assert!(report.is_regular);
+        assert_eq!(report.modal_interval, hour);
+        assert_eq!(report.match_fraction, 1.0);
+        assert_eq!((report.min_interval, report.max_interval), (hour, hour));
+
+        // Hourly timestamps jittered by up to +/- 2 minutes
+        let jittered: Vec<i64> = (0..50)
+            .map(|i| i * hour + ((i * i * 37 + i * 11) % 241) - 120)
+            .collect();
+        let report = detect_frequency_report(&jittered).unwrap();
+        assert!(!report.is_regular);
+        assert!(report.match_fraction < 0.5);
+        assert!(report.min_interval < hour && report.max_interval > hour);
+    }
 }
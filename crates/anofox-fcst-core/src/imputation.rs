@@ -115,6 +115,139 @@ pub fn fill_nulls_interpolate(values: &[Option<f64>]) -> Vec<f64> {
     result
 }
 
+/// Fill NULL values using seasonally adjusted linear interpolation.
+///
+/// A classical additive decomposition is estimated from the observed points:
+/// the trend is a centred moving average of length `period` (computed on the
+/// linearly interpolated series) and the seasonal index of each phase is the
+/// mean detrended value over the observed points of that phase. Gaps are
+/// linearly interpolated on the seasonally adjusted series and the seasonal
+/// index is added back, so a missing peak or trough keeps its shape.
+///
+/// Falls back to `fill_nulls_interpolate` when `period < 2` or when fewer than
+/// two full periods of observations are available.
+pub fn fill_nulls_seasonal(values: &[Option<f64>], period: usize) -> Vec<f64> {
+    let n_valid = values.iter().filter(|v| v.is_some()).count();
+    if period < 2 || n_valid < 2 * period {
+        return fill_nulls_interpolate(values);
+    }
+
+    let trend =
+        crate::decomposition::centered_moving_average(&fill_nulls_interpolate(values), period);
+
+    let mut phase_sum = vec![0.0; period];
+    let mut phase_count = vec![0usize; period];
+    for (i, v) in values.iter().enumerate() {
+        if let Some(x) = v {
+            phase_sum[i % period] += x - trend[i];
+            phase_count[i % period] += 1;
+        }
+    }
+
+    let mut seasonal: Vec<f64> = phase_sum
+        .iter()
+        .zip(phase_count.iter())
+        .map(|(&s, &c)| if c > 0 { s / c as f64 } else { 0.0 })
+        .collect();
+    let seasonal_mean = seasonal.iter().sum::<f64>() / period as f64;
+    for s in &mut seasonal {
+        *s -= seasonal_mean;
+    }
+
+    let adjusted: Vec<Option<f64>> = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| v.map(|x| x - seasonal[i % period]))
+        .collect();
+
+    fill_nulls_interpolate(&adjusted)
+        .into_iter()
+        .enumerate()
+        .map(|(i, a)| values[i].unwrap_or(a + seasonal[i % period]))
+        .collect()
+}
+
+/// Fill NULL values with natural cubic spline interpolation.
+///
+/// The spline passes through every observed point with continuous first and
+/// second derivatives and zero curvature at the first and last observation.
+/// Leading and trailing NULLs are held flat at the nearest observed value, as
+/// in `fill_nulls_interpolate`, since extrapolating a cubic quickly diverges.
+///
+/// Falls back to linear interpolation when fewer than four values are observed.
+pub fn fill_nulls_spline(values: &[Option<f64>]) -> Vec<f64> {
+    let (xs, ys): (Vec<f64>, Vec<f64>) = values
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|y| (i as f64, y)))
+        .unzip();
+
+    if xs.len() < 4 {
+        return fill_nulls_interpolate(values);
+    }
+
+    let m = natural_spline_second_derivatives(&xs, &ys);
+    let first = xs[0] as usize;
+    let last = xs[xs.len() - 1] as usize;
+
+    let mut segment = 0;
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            if let Some(y) = v {
+                return *y;
+            }
+            if i < first {
+                return ys[0];
+            }
+            if i > last {
+                return ys[ys.len() - 1];
+            }
+
+            let x = i as f64;
+            while xs[segment + 1] < x {
+                segment += 1;
+            }
+            let k = segment;
+            let h = xs[k + 1] - xs[k];
+            let t0 = x - xs[k];
+            let t1 = xs[k + 1] - x;
+            m[k] * t1.powi(3) / (6.0 * h)
+                + m[k + 1] * t0.powi(3) / (6.0 * h)
+                + (ys[k] / h - m[k] * h / 6.0) * t1
+                + (ys[k + 1] / h - m[k + 1] * h / 6.0) * t0
+        })
+        .collect()
+}
+
+/// Second derivatives of the natural cubic spline through `(xs, ys)`.
+///
+/// Solves the tridiagonal system for the interior knots with the Thomas
+/// algorithm; the end values are zero.
+fn natural_spline_second_derivatives(xs: &[f64], ys: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    let h: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+    let mut m = vec![0.0; n];
+
+    // Forward sweep over interior knots 1..n-1
+    let mut c_prime = vec![0.0; n];
+    let mut d_prime = vec![0.0; n];
+    for i in 1..n - 1 {
+        let rhs = 6.0 * ((ys[i + 1] - ys[i]) / h[i] - (ys[i] - ys[i - 1]) / h[i - 1]);
+        let denom = 2.0 * (h[i - 1] + h[i]) - h[i - 1] * c_prime[i - 1];
+        c_prime[i] = h[i] / denom;
+        d_prime[i] = (rhs - h[i - 1] * d_prime[i - 1]) / denom;
+    }
+
+    // Back substitution
+    for i in (1..n - 1).rev() {
+        m[i] = d_prime[i] - c_prime[i] * m[i + 1];
+    }
+
+    m
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,4 +297,81 @@ mod tests {
         assert_relative_eq!(result[2], 3.0, epsilon = 0.001);
         assert_relative_eq!(result[3], 4.0, epsilon = 0.001);
     }
+
+    #[test]
+    fn test_fill_nulls_seasonal_recovers_peak() {
+        let period = 12;
+        let truth: Vec<f64> = (0..60)
+            .map(|i| 10.0 + 5.0 * (std::f64::consts::TAU * i as f64 / period as f64).sin())
+            .collect();
+        // Remove the peak of the third cycle and its neighbours
+        let mut values: Vec<Option<f64>> = truth.iter().copied().map(Some).collect();
+        for v in values.iter_mut().take(29).skip(26) {
+            *v = None;
+        }
+
+        let seasonal = fill_nulls_seasonal(&values, period);
+        let linear = fill_nulls_interpolate(&values);
+
+        let seasonal_err: f64 = (26..29).map(|i| (seasonal[i] - truth[i]).abs()).sum();
+        let linear_err: f64 = (26..29).map(|i| (linear[i] - truth[i]).abs()).sum();
+
+        assert!(seasonal_err < 0.5, "seasonal error {}", seasonal_err);
+        assert!(seasonal_err * 10.0 < linear_err);
+        assert_relative_eq!(seasonal[0], truth[0], epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fill_nulls_spline_recovers_cubic() {
+        let f = |x: f64| 0.01 * x.powi(3) - 0.3 * x.powi(2) + 2.0 * x + 1.0;
+        let gaps = [14, 15, 20];
+        let values: Vec<Option<f64>> = (0..31)
+            .map(|i| (!gaps.contains(&i)).then(|| f(i as f64)))
+            .collect();
+
+        let result = fill_nulls_spline(&values);
+        for &g in &gaps {
+            assert_relative_eq!(result[g], f(g as f64), epsilon = 1e-4);
+        }
+
+        // No kinks: second differences across the gap follow the cubic's
+        for i in 13..17 {
+            let second_diff = result[i - 1] - 2.0 * result[i] + result[i + 1];
+            let expected = f(i as f64 - 1.0) - 2.0 * f(i as f64) + f(i as f64 + 1.0);
+            assert_relative_eq!(second_diff, expected, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_fill_nulls_spline_edges_and_fallback() {
+        let values = vec![
+            None,
+            Some(1.0),
+            Some(4.0),
+            None,
+            Some(16.0),
+            Some(25.0),
+            None,
+        ];
+        let result = fill_nulls_spline(&values);
+        assert_eq!(result[0], 1.0);
+        assert_eq!(result[6], 25.0);
+        assert!(result[3] > 4.0 && result[3] < 16.0);
+
+        let sparse = vec![Some(1.0), None, None, Some(4.0)];
+        assert_eq!(fill_nulls_spline(&sparse), fill_nulls_interpolate(&sparse));
+    }
+
+    #[test]
+    fn test_fill_nulls_seasonal_falls_back_to_linear() {
+        let values = vec![Some(1.0), None, None, Some(4.0)];
+        assert_eq!(
+            fill_nulls_seasonal(&values, 12),
+            fill_nulls_interpolate(&values)
+        );
+        assert_eq!(
+            fill_nulls_seasonal(&values, 1),
+            fill_nulls_interpolate(&values)
+        );
+    }
 }
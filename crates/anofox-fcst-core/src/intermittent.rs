@@ -0,0 +1,258 @@
+//! Intermittent-demand diagnostics.
+//!
+//! Croston's method forecasts sparse demand as the ratio of a smoothed demand
+//! size to a smoothed inter-arrival interval. The forecasting models expose
+//! only that ratio; this module returns both components, which inventory
+//! planning uses separately (e.g. for safety stock).
+//!
+//! It also classifies demand patterns into the Syntetos-Boylan quadrants
+//! (smooth, intermittent, erratic, lumpy), which indicate whether a
+//! Croston-type model is appropriate.
+//!
+//! # Example Usage
+//!
+//! ```
+//! use anofox_fcst_core::intermittent::croston_components;
+//!
+//! let values = [0.0, 0.0, 5.0, 0.0, 3.0, 0.0, 0.0, 4.0];
+//! let c = croston_components(&values, None).unwrap();
+//! assert!((c.forecast - c.demand / c.interval).abs() < 1e-12);
+//! ```
+
+use crate::error::{ForecastError, Result};
+
+/// Default Croston smoothing parameter.
+const DEFAULT_ALPHA: f64 = 0.1;
+
+/// ADI cut-off between frequent and intermittent demand (Syntetos et al., 2005).
+const ADI_CUTOFF: f64 = 1.32;
+
+/// CV² cut-off between regular and erratic demand sizes (Syntetos et al., 2005).
+const CV2_CUTOFF: f64 = 0.49;
+
+/// Smoothed components of Croston's method.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrostonComponents {
+    /// Smoothed non-zero demand size
+    pub demand: f64,
+    /// Smoothed interval between non-zero demands (in periods)
+    pub interval: f64,
+    /// Per-period demand forecast `demand / interval`
+    pub forecast: f64,
+}
+
+/// Compute the Croston demand-size and interval components.
+///
+/// Both components are initialised from the first non-zero demand (whose
+/// interval counts from the start of the series) and updated with simple
+/// exponential smoothing at every later non-zero demand. With the default
+/// alpha this is the recursion behind the `CrostonClassic` model, so
+/// `forecast` equals its point forecast.
+///
+/// # Arguments
+/// * `values` - Non-negative demand history
+/// * `alpha` - Smoothing parameter in (0, 1] (None for 0.1)
+///
+/// # Returns
+/// * `Result<CrostonComponents>` - Smoothed demand, interval and their ratio
+pub fn croston_components(values: &[f64], alpha: Option<f64>) -> Result<CrostonComponents> {
+    let alpha = alpha.unwrap_or(DEFAULT_ALPHA);
+    if alpha.is_nan() || alpha <= 0.0 || alpha > 1.0 {
+        return Err(ForecastError::InvalidParameter {
+            param: "alpha".to_string(),
+            value: alpha.to_string(),
+            reason: "Alpha must be in (0, 1]".to_string(),
+        });
+    }
+    if values.iter().any(|v| !v.is_finite() || *v < 0.0) {
+        return Err(ForecastError::InvalidInput(
+            "Demand values must be finite and non-negative".to_string(),
+        ));
+    }
+
+    let mut levels: Option<(f64, f64)> = None;
+    let mut periods_since_demand = 0.0;
+    for &v in values {
+        periods_since_demand += 1.0;
+        if v > 0.0 {
+            levels = Some(match levels {
+                None => (v, periods_since_demand),
+                Some((demand, interval)) => (
+                    demand + alpha * (v - demand),
+                    interval + alpha * (periods_since_demand - interval),
+                ),
+            });
+            periods_since_demand = 0.0;
+        }
+    }
+
+    let (demand, interval) = levels
+        .ok_or_else(|| ForecastError::InvalidInput("Series has no non-zero demand".to_string()))?;
+
+    Ok(CrostonComponents {
+        demand,
+        interval,
+        forecast: demand / interval,
+    })
+}
+
+/// Syntetos-Boylan demand pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DemandClass {
+    /// Frequent demand with regular sizes: standard smoothing models work
+    Smooth,
+    /// Infrequent demand with regular sizes: Croston/SBA
+    Intermittent,
+    /// Frequent demand with highly variable sizes
+    Erratic,
+    /// Infrequent demand with highly variable sizes: SBA/TSB
+    Lumpy,
+}
+
+impl DemandClass {
+    /// Lowercase name of the class.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DemandClass::Smooth => "smooth",
+            DemandClass::Intermittent => "intermittent",
+            DemandClass::Erratic => "erratic",
+            DemandClass::Lumpy => "lumpy",
+        }
+    }
+}
+
+/// Demand classification with the statistics it is based on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DemandClassification {
+    /// Average demand interval: periods per non-zero demand
+    pub adi: f64,
+    /// Squared coefficient of variation of the non-zero demand sizes
+    pub cv2: f64,
+    /// Quadrant from the ADI (cut-off 1.32) and CV² (cut-off 0.49)
+    pub class: DemandClass,
+}
+
+/// Classify a demand history into the Syntetos-Boylan quadrants.
+///
+/// # Arguments
+/// * `values` - Non-negative demand history
+///
+/// # Returns
+/// * `Result<DemandClassification>` - ADI, CV² and the resulting class
+pub fn classify_demand(values: &[f64]) -> Result<DemandClassification> {
+    if values.iter().any(|v| !v.is_finite() || *v < 0.0) {
+        return Err(ForecastError::InvalidInput(
+            "Demand values must be finite and non-negative".to_string(),
+        ));
+    }
+
+    let sizes: Vec<f64> = values.iter().copied().filter(|v| *v > 0.0).collect();
+    if sizes.is_empty() {
+        return Err(ForecastError::InvalidInput(
+            "Series has no non-zero demand".to_string(),
+        ));
+    }
+
+    let adi = values.len() as f64 / sizes.len() as f64;
+    let mean = sizes.iter().sum::<f64>() / sizes.len() as f64;
+    let variance = sizes.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / sizes.len() as f64;
+    let cv2 = variance / (mean * mean);
+
+    let class = match (adi >= ADI_CUTOFF, cv2 >= CV2_CUTOFF) {
+        (false, false) => DemandClass::Smooth,
+        (true, false) => DemandClass::Intermittent,
+        (false, true) => DemandClass::Erratic,
+        (true, true) => DemandClass::Lumpy,
+    };
+
+    Ok(DemandClassification { adi, cv2, class })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_croston_components_sparse_series() {
+        let values = [0.0, 0.0, 5.0, 0.0, 0.0, 0.0, 3.0, 0.0, 4.0, 0.0, 0.0, 6.0];
+        let c = croston_components(&values, None).unwrap();
+
+        assert!(c.demand > 0.0);
+        assert!(c.interval > 0.0);
+        assert!((c.forecast - c.demand / c.interval).abs() < 1e-12);
+
+        // Demand sizes lie within the observed range, intervals within 2..=4
+        assert!((3.0..=6.0).contains(&c.demand));
+        assert!((2.0..=4.0).contains(&c.interval));
+    }
+
+    #[test]
+    fn test_croston_components_match_croston_classic_forecast() {
+        use crate::forecast::{forecast, ForecastOptions, ModelType};
+
+        let values = [0.0, 0.0, 5.0, 0.0, 0.0, 0.0, 3.0, 0.0, 4.0, 0.0, 0.0, 6.0];
+        let c = croston_components(&values, None).unwrap();
+
+        let series: Vec<Option<f64>> = values.iter().copied().map(Some).collect();
+        let options = ForecastOptions {
+            model: ModelType::CrostonClassic,
+            horizon: 3,
+            auto_detect_seasonality: false,
+            ..Default::default()
+        };
+        let result = forecast(&series, &options).unwrap();
+        for point in &result.point {
+            assert!(
+                (point - c.demand / c.interval).abs() < 1e-9,
+                "{} vs {:?}",
+                point,
+                c
+            );
+        }
+    }
+
+    #[test]
+    fn test_croston_components_alpha_one_tracks_last_demand() {
+        let values = [0.0, 2.0, 0.0, 0.0, 7.0];
+        let c = croston_components(&values, Some(1.0)).unwrap();
+        assert_eq!(c.demand, 7.0);
+        assert_eq!(c.interval, 3.0);
+    }
+
+    #[test]
+    fn test_classify_demand_quadrants() {
+        let smooth = [10.0, 12.0, 9.0, 11.0, 10.0, 13.0, 9.0, 10.0];
+        assert_eq!(classify_demand(&smooth).unwrap().class, DemandClass::Smooth);
+
+        let erratic = [1.0, 20.0, 2.0, 35.0, 1.0, 3.0, 40.0, 2.0];
+        assert_eq!(
+            classify_demand(&erratic).unwrap().class,
+            DemandClass::Erratic
+        );
+
+        let intermittent = [0.0, 5.0, 0.0, 0.0, 6.0, 0.0, 5.0, 0.0, 0.0, 4.0];
+        assert_eq!(
+            classify_demand(&intermittent).unwrap().class,
+            DemandClass::Intermittent
+        );
+
+        let lumpy = [0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 40.0, 0.0, 2.0, 0.0, 0.0, 30.0];
+        let result = classify_demand(&lumpy).unwrap();
+        assert_eq!(result.class, DemandClass::Lumpy);
+        assert!((result.adi - 3.0).abs() < 1e-12);
+        assert!(result.cv2 > 0.49);
+    }
+
+    #[test]
+    fn test_classify_demand_invalid_input() {
+        assert!(classify_demand(&[0.0, 0.0]).is_err());
+        assert!(classify_demand(&[1.0, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn test_croston_components_invalid_input() {
+        assert!(croston_components(&[0.0, 0.0, 0.0], None).is_err());
+        assert!(croston_components(&[1.0, -1.0], None).is_err());
+        assert!(croston_components(&[1.0, 2.0], Some(0.0)).is_err());
+    }
+}
@@ -3,9 +3,11 @@
 //! This crate provides the Rust implementation of time series analysis
 //! and forecasting functions.
 
+pub mod anomaly;
 pub mod bootstrap;
 pub mod changepoint;
 pub mod conformal;
+pub mod correlation;
 pub mod decomposition;
 pub mod detrending;
 pub mod error;
@@ -14,23 +16,32 @@ pub mod filter;
 pub mod forecast;
 pub mod gaps;
 pub mod imputation;
+pub mod intermittent;
 pub mod metrics;
+mod parallel;
 pub mod peaks;
 pub mod periods;
 pub mod quality;
+pub mod regressors;
+pub mod rolling;
 pub mod seasonality;
+pub mod stationarity;
 pub mod stats;
+pub mod transform;
 
 // Re-exports for convenience
+pub use anomaly::{detect_anomalies, AnomalyResult};
 pub use bootstrap::{
     bootstrap_intervals, bootstrap_quantiles, BootstrapIntervalsResult, BootstrapQuantilesResult,
 };
 pub use changepoint::{
     detect_changepoints, detect_changepoints_bocpd, BocpdResult, ChangepointResult, CostFunction,
+    PeltState,
 };
 pub use conformal::{
     // New Learn/Apply API (v2)
     conformal_apply,
+    conformal_apply_quantiles,
     conformal_coverage,
     conformal_evaluate,
     // Legacy API (still available)
@@ -42,6 +53,8 @@ pub use conformal::{
     conformal_predict_multi,
     conformal_quantile,
     conformalize,
+    conformalize_quantiles,
+    cqr_scores,
     interval_width,
     mean_interval_width,
     winkler_score,
@@ -55,28 +68,47 @@ pub use conformal::{
     ConformalStrategy,
     PredictionIntervals,
 };
-pub use decomposition::{mstl_decompose, InsufficientDataMode, MstlDecomposition};
+pub use correlation::{acf, pacf};
+pub use decomposition::{
+    mstl_decompose, mstl_decompose_multiplicative, seasonally_adjust, InsufficientDataMode,
+    MstlDecomposition,
+};
 pub use detrending::{
     decompose, decompose_additive, decompose_multiplicative, detrend, detrend_auto, detrend_diff,
     detrend_linear, detrend_loess, detrend_polynomial, DecomposeMethod, DecomposeResult,
     DetrendMethod, DetrendResult,
 };
 pub use error::{ForecastError, Result};
-pub use features::{extract_features, list_features, validate_feature_params};
+pub use features::{
+    extract_features, extract_features_batch, extract_features_subset,
+    extract_features_with_params, list_features, validate_feature_params, FeatureParams,
+};
 pub use filter::{
-    diff, drop_edge_zeros, drop_leading_zeros, drop_trailing_zeros, is_constant, is_short,
+    diff, drop_edge_zeros, drop_leading_zeros, drop_trailing_zeros, is_constant, is_short, undiff,
 };
 pub use forecast::{
-    forecast, forecast_explain, forecast_inspect, forecast_with_exog, list_models, ExogenousData,
-    ForecastOptions, ForecastOptionsExog, ForecastOutput, LaplaceVariant, ModelType,
+    cross_validate, fit, forecast, forecast_batch, forecast_ensemble, forecast_explain,
+    forecast_inspect, forecast_with_exog, list_models, select_best_model, CrossValResult,
+    ExogenousData, FittedModel, ForecastOptions, ForecastOptionsExog, ForecastOutput,
+    LaplaceVariant, ModelCapabilities, ModelSelection, ModelType, OnlineSes,
+    PredictionIntervalMethod, SeasonalMode,
+};
+pub use gaps::{
+    detect_frequency, detect_frequency_label, detect_frequency_report, fill_forward, fill_gaps,
+    FrequencyReport,
 };
-pub use gaps::{detect_frequency, fill_forward, fill_gaps};
 pub use imputation::{
     fill_nulls_backward, fill_nulls_const, fill_nulls_forward, fill_nulls_interpolate,
-    fill_nulls_mean,
+    fill_nulls_mean, fill_nulls_seasonal, fill_nulls_spline,
+};
+pub use intermittent::{
+    classify_demand, croston_components, CrostonComponents, DemandClass, DemandClassification,
 };
 pub use metrics::{
-    bias, coverage, mae, mape, mase, mqloss, mse, quantile_loss, r2, rmae, rmse, smape,
+    bias, ccc, coverage, crps, gmrae, maape, mae, mape, mase, mase_scaled, mdape,
+    metrics_by_horizon, mqloss, mqloss_by_level, mse, msis, owa, point_metrics, quantile_coverage,
+    quantile_loss, r2, rmae, rmse, rmsse, seasonal_naive_errors, smape, smdape, theil_u2,
+    tracking_signal, PointMetrics, StepMetrics,
 };
 pub use peaks::{
     analyze_peak_timing, detect_peaks, detect_peaks_default, get_peak_indices, get_peak_values,
@@ -85,23 +117,29 @@ pub use peaks::{
 pub use periods::{
     aic_comparison, autoperiod, cfd_autoperiod, detect_multiple_periods_ts, detect_periods,
     detect_periods_with_validation, estimate_period_acf_ts, estimate_period_fft_ts,
-    estimate_period_regression_ts, lomb_scargle, matrix_profile_period, sazed_period, ssa_period,
-    stl_period, AicPeriodResult, AutoperiodResult, DetectedPeriod, LombScargleResult,
+    estimate_period_regression_ts, lomb_scargle, matrix_profile_period, sazed_period, snap_period,
+    ssa_period, stl_period, AicPeriodResult, AutoperiodResult, DetectedPeriod, LombScargleResult,
     MatrixProfilePeriodResult, MultiPeriodResult, PeriodMethod, SazedPeriodResult,
     SinglePeriodResult, SsaPeriodResult, StlPeriodResult, DEFAULT_TOLERANCE,
 };
 pub use quality::{
-    compute_data_quality, generate_quality_report, DataQuality, QualityReport, QualityThresholds,
+    compute_data_quality, compute_data_quality_with_thresholds, generate_quality_report,
+    DataQuality, QualityReport, QualityThresholds,
 };
+pub use regressors::{event_dummies, event_indicators, fourier_terms, fourier_terms_future};
+pub use rolling::{rolling, rolling_max, rolling_mean, rolling_min, rolling_std, RollingAgg};
 pub use seasonality::{
-    analyze_seasonality, classify_seasonality, detect_amplitude_modulation, detect_seasonality,
-    detect_seasonality_changes, instantaneous_period, seasonal_strength,
-    seasonal_strength_spectral, seasonal_strength_variance, seasonal_strength_wavelet,
-    seasonal_strength_windowed, AmplitudeModulationResult, AmplitudeModulationType,
+    analyze_seasonality, analyze_seasonality_with_strength, classify_seasonality,
+    detect_amplitude_modulation, detect_seasonality, detect_seasonality_changes,
+    instantaneous_period, seasonal_strength, seasonal_strength_spectral,
+    seasonal_strength_variance, seasonal_strength_wavelet, seasonal_strength_windowed,
+    trend_strength_at_period, AmplitudeModulationResult, AmplitudeModulationType,
     ChangeDetectionResult, ChangePointType, InstantaneousPeriodResult, SeasonalType,
     SeasonalityAnalysis, SeasonalityChangePoint, SeasonalityClassification, StrengthMethod,
 };
+pub use stationarity::{adf_test, kpss_test, StationarityResult};
 pub use stats::{
-    compute_ts_stats, compute_ts_stats_with_dates, compute_ts_stats_with_dates_and_type,
-    FrequencyType, TsStats,
+    compute_ts_stats, compute_ts_stats_rolling, compute_ts_stats_with_dates,
+    compute_ts_stats_with_dates_and_type, FrequencyType, TsStats, TsStatsRolling,
 };
+pub use transform::{box_cox, estimate_box_cox_lambda, inv_box_cox};
@@ -1,11 +1,12 @@
 //! Evaluation metrics for time series forecasting.
 //!
-//! This module provides 12 standard metrics for evaluating forecast accuracy:
+//! This module provides standard metrics for evaluating forecast accuracy:
 //!
 //! - **Scale-dependent metrics**: MAE, MSE, RMSE, Bias
-//! - **Percentage metrics**: MAPE, sMAPE
-//! - **Scaled metrics**: MASE, rMAE
-//! - **Other metrics**: R², Quantile Loss, MQ-Loss, Coverage
+//! - **Percentage metrics**: MAPE, sMAPE, MdAPE, sMdAPE, MAAPE
+//! - **Scaled metrics**: MASE, RMSSE, rMAE, GMRAE, Theil's U2, MSIS, OWA
+//! - **Other metrics**: R², CCC, Quantile Loss, MQ-Loss, CRPS, Coverage, Tracking Signal
+//! - **Per-step metrics**: MAE, RMSE and bias by forecast horizon
 //!
 //! # Metric Selection Guide
 //!
@@ -19,18 +20,21 @@
 //! | R² | Need explained variance proportion |
 
 use crate::error::{ForecastError, Result};
+use crate::stats::median;
 
 /// Calculates Mean Absolute Error between actual and predicted values.
 ///
 /// MAE measures the average magnitude of errors without considering direction.
-/// It gives equal weight to all individual differences.
+/// It gives equal weight to all individual differences. Pairs where either
+/// value is NaN (SQL NULL) are skipped.
 ///
 /// # Arguments
 /// * `actual` - Slice of actual observed values
 /// * `forecast` - Slice of forecasted/predicted values
 ///
 /// # Returns
-/// The mean absolute error, or an error if inputs are invalid
+/// The mean absolute error, or an error if inputs are invalid or no
+/// non-NaN pairs remain
 ///
 /// # Formula
 /// MAE = (1/n) * Σ|actual_i - forecast_i|
@@ -44,37 +48,31 @@ use crate::error::{ForecastError, Result};
 /// assert!((error - 0.166).abs() < 0.01);
 /// ```
 pub fn mae(actual: &[f64], forecast: &[f64]) -> Result<f64> {
-    validate_inputs(actual, forecast)?;
-    let sum: f64 = actual
-        .iter()
-        .zip(forecast.iter())
-        .map(|(a, f)| (a - f).abs())
-        .sum();
-    Ok(sum / actual.len() as f64)
+    let pairs = valid_pairs(actual, forecast)?;
+    let sum: f64 = pairs.iter().map(|(a, f)| (a - f).abs()).sum();
+    Ok(sum / pairs.len() as f64)
 }
 
 /// Calculates Mean Squared Error between actual and predicted values.
 ///
 /// MSE penalizes larger errors more heavily than smaller ones due to squaring.
-/// Useful when large errors are particularly undesirable.
+/// Useful when large errors are particularly undesirable. Pairs where either
+/// value is NaN (SQL NULL) are skipped.
 ///
 /// # Arguments
 /// * `actual` - Slice of actual observed values
 /// * `forecast` - Slice of forecasted/predicted values
 ///
 /// # Returns
-/// The mean squared error, or an error if inputs are invalid
+/// The mean squared error, or an error if inputs are invalid or no
+/// non-NaN pairs remain
 ///
 /// # Formula
 /// MSE = (1/n) * Σ(actual_i - forecast_i)²
 pub fn mse(actual: &[f64], forecast: &[f64]) -> Result<f64> {
-    validate_inputs(actual, forecast)?;
-    let sum: f64 = actual
-        .iter()
-        .zip(forecast.iter())
-        .map(|(a, f)| (a - f).powi(2))
-        .sum();
-    Ok(sum / actual.len() as f64)
+    let pairs = valid_pairs(actual, forecast)?;
+    let sum: f64 = pairs.iter().map(|(a, f)| (a - f).powi(2)).sum();
+    Ok(sum / pairs.len() as f64)
 }
 
 /// Calculates Root Mean Squared Error between actual and predicted values.
@@ -101,6 +99,8 @@ pub fn rmse(actual: &[f64], forecast: &[f64]) -> Result<f64> {
 /// Useful for comparing forecast accuracy across different scales.
 /// Note: Returns NaN if all actual values are zero.
 ///
+/// Pairs where either value is NaN (SQL NULL) are skipped.
+///
 /// # Arguments
 /// * `actual` - Slice of actual observed values (non-zero values used)
 /// * `forecast` - Slice of forecasted/predicted values
@@ -111,14 +111,10 @@ pub fn rmse(actual: &[f64], forecast: &[f64]) -> Result<f64> {
 /// # Formula
 /// MAPE = (100/n) * Σ|actual_i - forecast_i| / |actual_i|
 pub fn mape(actual: &[f64], forecast: &[f64]) -> Result<f64> {
-    validate_inputs(actual, forecast)?;
-    let sum: f64 = actual
-        .iter()
-        .zip(forecast.iter())
-        .filter(|(a, _)| a.abs() > f64::EPSILON)
-        .map(|(a, f)| ((a - f) / a).abs())
-        .sum();
-    let count = actual.iter().filter(|a| a.abs() > f64::EPSILON).count();
+    let pairs = valid_pairs(actual, forecast)?;
+    let nonzero = || pairs.iter().filter(|(a, _)| a.abs() > f64::EPSILON);
+    let sum: f64 = nonzero().map(|(a, f)| ((a - f) / a).abs()).sum();
+    let count = nonzero().count();
     if count == 0 {
         return Ok(f64::NAN);
     }
@@ -128,7 +124,8 @@ pub fn mape(actual: &[f64], forecast: &[f64]) -> Result<f64> {
 /// Calculates Symmetric Mean Absolute Percentage Error.
 ///
 /// sMAPE is a symmetric version of MAPE that treats over- and under-predictions
-/// equally. Values range from 0% (perfect) to 200% (maximum error).
+/// equally. Values range from 0% (perfect) to 200% (maximum error). Pairs
+/// where either value is NaN (SQL NULL) are skipped.
 ///
 /// # Arguments
 /// * `actual` - Slice of actual observed values
@@ -140,28 +137,128 @@ pub fn mape(actual: &[f64], forecast: &[f64]) -> Result<f64> {
 /// # Formula
 /// sMAPE = (100/n) * Σ 2|actual_i - forecast_i| / (|actual_i| + |forecast_i|)
 pub fn smape(actual: &[f64], forecast: &[f64]) -> Result<f64> {
+    let pairs = valid_pairs(actual, forecast)?;
+    let nonzero = || {
+        pairs
+            .iter()
+            .filter(|(a, f)| a.abs() + f.abs() > f64::EPSILON)
+    };
+    let sum: f64 = nonzero()
+        .map(|(a, f)| 2.0 * (a - f).abs() / (a.abs() + f.abs()))
+        .sum();
+    let count = nonzero().count();
+    if count == 0 {
+        return Ok(f64::NAN);
+    }
+    Ok(sum / count as f64 * 100.0)
+}
+
+/// Calculates Median Absolute Percentage Error.
+///
+/// The median is unaffected by a few huge percentage errors on near-zero
+/// actuals, which dominate MAPE. Observations with a zero actual are skipped.
+///
+/// # Arguments
+/// * `actual` - Slice of actual observed values
+/// * `forecast` - Slice of forecasted/predicted values
+///
+/// # Returns
+/// The MdAPE as a percentage, or an error if inputs are invalid or every
+/// actual is zero
+///
+/// # Formula
+/// MdAPE = 100 * median(|actual_i - forecast_i| / |actual_i|)
+pub fn mdape(actual: &[f64], forecast: &[f64]) -> Result<f64> {
     validate_inputs(actual, forecast)?;
-    let sum: f64 = actual
+    let errors: Vec<f64> = actual
+        .iter()
+        .zip(forecast.iter())
+        .filter(|(a, _)| a.abs() > f64::EPSILON)
+        .map(|(a, f)| ((a - f) / a).abs())
+        .collect();
+    Ok(median_of_nonempty(errors, "MdAPE")? * 100.0)
+}
+
+/// Calculates Symmetric Median Absolute Percentage Error.
+///
+/// Median counterpart of sMAPE. Observations with a zero actual are skipped.
+///
+/// # Arguments
+/// * `actual` - Slice of actual observed values
+/// * `forecast` - Slice of forecasted/predicted values
+///
+/// # Returns
+/// The sMdAPE as a percentage (0-200), or an error if inputs are invalid or
+/// every actual is zero
+///
+/// # Formula
+/// sMdAPE = 100 * median(2|actual_i - forecast_i| / (|actual_i| + |forecast_i|))
+pub fn smdape(actual: &[f64], forecast: &[f64]) -> Result<f64> {
+    validate_inputs(actual, forecast)?;
+    let errors: Vec<f64> = actual
         .iter()
         .zip(forecast.iter())
-        .filter(|(a, f)| a.abs() + f.abs() > f64::EPSILON)
+        .filter(|(a, _)| a.abs() > f64::EPSILON)
         .map(|(a, f)| 2.0 * (a - f).abs() / (a.abs() + f.abs()))
-        .sum();
-    let count = actual
+        .collect();
+    Ok(median_of_nonempty(errors, "sMdAPE")? * 100.0)
+}
+
+/// Calculates Mean Arctangent Absolute Percentage Error.
+///
+/// MAAPE maps each absolute percentage error through `atan`, so it is bounded
+/// in `[0, π/2]` and stays defined on zero actuals, which makes it suitable
+/// for intermittent demand. For small errors it behaves like MAPE / 100.
+///
+/// A zero actual contributes `atan(∞) = π/2` unless the forecast is also
+/// zero, in which case it contributes 0.
+///
+/// # Arguments
+/// * `actual` - Slice of actual observed values
+/// * `forecast` - Slice of forecasted/predicted values
+///
+/// # Returns
+/// The MAAPE in radians, or an error if inputs are invalid
+///
+/// # Formula
+/// MAAPE = (1/n) * Σ atan(|actual_i - forecast_i| / |actual_i|)
+pub fn maape(actual: &[f64], forecast: &[f64]) -> Result<f64> {
+    validate_inputs(actual, forecast)?;
+    let sum: f64 = actual
         .iter()
         .zip(forecast.iter())
-        .filter(|(a, f)| a.abs() + f.abs() > f64::EPSILON)
-        .count();
-    if count == 0 {
-        return Ok(f64::NAN);
+        .map(|(a, f)| {
+            let error = (a - f).abs();
+            if a.abs() > f64::EPSILON {
+                (error / a.abs()).atan()
+            } else if error > f64::EPSILON {
+                std::f64::consts::FRAC_PI_2
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    Ok(sum / actual.len() as f64)
+}
+
+/// Median of per-observation errors; errors when nothing was kept.
+fn median_of_nonempty(errors: Vec<f64>, metric: &str) -> Result<f64> {
+    if errors.is_empty() {
+        return Err(ForecastError::ComputationError(format!(
+            "{} undefined: all actual values are zero",
+            metric
+        )));
     }
-    Ok(sum / count as f64 * 100.0)
+    Ok(median(&errors))
 }
 
 /// Mean Absolute Scaled Error
 ///
 /// C++ API compatible: takes actual, predicted, and baseline arrays.
 /// MASE = MAE(actual, predicted) / MAE(actual, baseline)
+///
+/// To scale by the in-sample seasonal naive error of the training series
+/// instead, use [`mase_scaled`].
 pub fn mase(actual: &[f64], forecast: &[f64], baseline: &[f64]) -> Result<f64> {
     validate_inputs(actual, forecast)?;
 
@@ -186,7 +283,272 @@ pub fn mase(actual: &[f64], forecast: &[f64], baseline: &[f64]) -> Result<f64> {
     Ok(forecast_mae / baseline_mae)
 }
 
+/// In-sample seasonal naive errors of a training series.
+///
+/// Returns `train[i] - train[i - period]` for every `i >= period`; `period = 1`
+/// gives the ordinary naive errors. The mean absolute value of these errors
+/// is the standard MASE scale.
+///
+/// # Returns
+/// `train.len() - period` errors, or an error if `period` is zero or the
+/// series has no observation past the first season
+pub fn seasonal_naive_errors(train: &[f64], period: usize) -> Result<Vec<f64>> {
+    if period == 0 {
+        return Err(ForecastError::InvalidParameter {
+            param: "period".to_string(),
+            value: "0".to_string(),
+            reason: "must be at least 1".to_string(),
+        });
+    }
+    if train.len() <= period {
+        return Err(ForecastError::InsufficientData {
+            needed: period + 1,
+            got: train.len(),
+        });
+    }
+
+    Ok(train[period..]
+        .iter()
+        .zip(train.iter())
+        .map(|(current, previous)| current - previous)
+        .collect())
+}
+
+/// Mean absolute seasonal naive error of `train`, the MASE and MSIS scale.
+fn seasonal_naive_scale(train: &[f64], period: usize, metric: &str) -> Result<f64> {
+    let naive_errors = seasonal_naive_errors(train, period)?;
+    let scale = naive_errors.iter().map(|e| e.abs()).sum::<f64>() / naive_errors.len() as f64;
+    if scale.abs() < f64::EPSILON {
+        return Err(ForecastError::ComputationError(format!(
+            "{} undefined: training series has zero seasonal naive error",
+            metric
+        )));
+    }
+    Ok(scale)
+}
+
+/// Mean Absolute Scaled Error against the in-sample seasonal naive error
+///
+/// The Hyndman & Koehler MASE: the forecast MAE divided by the mean absolute
+/// [`seasonal_naive_errors`] of `train`. `period = 1` gives the non-seasonal
+/// MASE.
+///
+/// # Formula
+/// MASE = mean(|actual_t - forecast_t|) / mean(|train_t - train_{t-m}|)
+pub fn mase_scaled(actual: &[f64], forecast: &[f64], train: &[f64], period: usize) -> Result<f64> {
+    let forecast_mae = mae(actual, forecast)?;
+    Ok(forecast_mae / seasonal_naive_scale(train, period, "MASE")?)
+}
+
+/// Mean Scaled Interval Score (M4 competition interval metric)
+///
+/// The mean Winkler (interval) score of the `(1 - alpha)` prediction
+/// intervals, scaled by the in-sample seasonal naive MAE of `train` (the
+/// MASE denominator, see [`seasonal_naive_errors`]). Lower is better.
+///
+/// # Formula
+/// MSIS = mean((u - l) + (2/α)(l - y)·1{y < l} + (2/α)(y - u)·1{y > u})
+///        / mean(|train_t - train_{t-m}|)
+pub fn msis(
+    actual: &[f64],
+    lower: &[f64],
+    upper: &[f64],
+    train: &[f64],
+    period: usize,
+    alpha: f64,
+) -> Result<f64> {
+    if !(alpha > 0.0 && alpha < 1.0) {
+        return Err(ForecastError::InvalidParameter {
+            param: "alpha".to_string(),
+            value: alpha.to_string(),
+            reason: "must be in (0, 1)".to_string(),
+        });
+    }
+
+    let score = crate::conformal::winkler_score(actual, lower, upper, alpha)?;
+
+    Ok(score / seasonal_naive_scale(train, period, "MSIS")?)
+}
+
+/// Overall Weighted Average (M4 competition headline metric)
+///
+/// Averages the sMAPE and MASE of the forecast relative to the Naive2
+/// benchmark: OWA < 1 beats Naive2, OWA = 1 matches it.
+///
+/// The Naive2 benchmark is computed internally from `train`: a naive
+/// forecast of the seasonally adjusted series, re-seasonalised with
+/// classical multiplicative seasonal indices. Seasonal adjustment is only
+/// applied when `period > 1`, the series has at least three full seasons and
+/// is strictly positive, and the lag-`period` autocorrelation is significant
+/// at the 90% level (as in M4); otherwise Naive2 is the plain naive forecast.
+/// Both MASE terms share the in-sample scale, so their ratio is the ratio of
+/// the MAEs.
+///
+/// # Formula
+/// OWA = 0.5 * (sMAPE / sMAPE_naive2 + MASE / MASE_naive2)
+pub fn owa(actual: &[f64], forecast: &[f64], train: &[f64], period: usize) -> Result<f64> {
+    validate_inputs(actual, forecast)?;
+    if train.is_empty() {
+        return Err(ForecastError::InsufficientData { needed: 1, got: 0 });
+    }
+
+    let naive2 = naive2_forecast(train, period.max(1), actual.len());
+    let smape_naive2 = smape(actual, &naive2)?;
+    let mae_naive2 = mae(actual, &naive2)?;
+    if !(smape_naive2 > f64::EPSILON && mae_naive2 > f64::EPSILON) {
+        return Err(ForecastError::ComputationError(
+            "OWA undefined: the Naive2 benchmark is exact".to_string(),
+        ));
+    }
+
+    Ok(0.5 * (smape(actual, forecast)? / smape_naive2 + mae(actual, forecast)? / mae_naive2))
+}
+
+/// Naive2 benchmark: naive forecast on the seasonally adjusted series.
+fn naive2_forecast(train: &[f64], period: usize, horizon: usize) -> Vec<f64> {
+    let n = train.len();
+    let last = train[n - 1];
+
+    let indices = if period > 1
+        && n >= 3 * period
+        && train.iter().all(|&v| v > 0.0)
+        && is_seasonal_at(train, period)
+    {
+        multiplicative_seasonal_indices(train, period)
+    } else {
+        None
+    };
+
+    match indices {
+        Some(idx) => {
+            let level = last / idx[(n - 1) % period];
+            (0..horizon)
+                .map(|h| level * idx[(n + h) % period])
+                .collect()
+        }
+        None => vec![last; horizon],
+    }
+}
+
+/// M4 seasonality test: lag-`period` autocorrelation outside the 90% band.
+fn is_seasonal_at(values: &[f64], period: usize) -> bool {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let denom: f64 = values.iter().map(|v| (v - mean).powi(2)).sum();
+    if denom < f64::EPSILON {
+        return false;
+    }
+    let acf = |lag: usize| -> f64 {
+        values[lag..]
+            .iter()
+            .zip(values.iter())
+            .map(|(a, b)| (a - mean) * (b - mean))
+            .sum::<f64>()
+            / denom
+    };
+
+    let sum_sq: f64 = (1..period).map(|k| acf(k).powi(2)).sum();
+    let limit = 1.645 * ((1.0 + 2.0 * sum_sq) / n).sqrt();
+    acf(period).abs() > limit
+}
+
+/// Classical multiplicative seasonal indices (centred moving average ratios),
+/// normalised to average 1.
+fn multiplicative_seasonal_indices(values: &[f64], period: usize) -> Option<Vec<f64>> {
+    let n = values.len();
+    let half = period / 2;
+    let trend = crate::decomposition::centered_moving_average(values, period);
+    let mut sums = vec![0.0; period];
+    let mut counts = vec![0usize; period];
+
+    for t in half..n.saturating_sub(half) {
+        sums[t % period] += values[t] / trend[t];
+        counts[t % period] += 1;
+    }
+
+    if counts.contains(&0) {
+        return None;
+    }
+    let raw: Vec<f64> = sums
+        .iter()
+        .zip(counts.iter())
+        .map(|(s, &c)| s / c as f64)
+        .collect();
+    let mean = raw.iter().sum::<f64>() / period as f64;
+    Some(raw.iter().map(|r| r / mean).collect())
+}
+
+/// Root Mean Squared Scaled Error (M5 competition metric)
+///
+/// RMSSE = sqrt(MSE(actual, forecast) / mean(diff(baseline)^2)), where
+/// `baseline` is the training series and the denominator is the in-sample
+/// mean squared one-step naive error.
+pub fn rmsse(actual: &[f64], forecast: &[f64], baseline: &[f64]) -> Result<f64> {
+    validate_inputs(actual, forecast)?;
+
+    if baseline.len() < 2 {
+        return Err(ForecastError::InsufficientData {
+            needed: 2,
+            got: baseline.len(),
+        });
+    }
+
+    let scale: f64 = baseline
+        .windows(2)
+        .map(|w| (w[1] - w[0]).powi(2))
+        .sum::<f64>()
+        / (baseline.len() - 1) as f64;
+
+    if scale.abs() < f64::EPSILON {
+        return Err(ForecastError::ComputationError(
+            "RMSSE undefined: baseline has zero naive error".to_string(),
+        ));
+    }
+
+    Ok((mse(actual, forecast)? / scale).sqrt())
+}
+
+/// Theil's U2 statistic
+///
+/// Compares the forecast against the naive (random walk) forecast that
+/// predicts each actual with the previous one. U2 < 1 means the forecast
+/// beats the naive forecast, U2 = 1 matches it and U2 > 1 is worse.
+///
+/// The first observation has no naive prediction and is excluded from both
+/// sums.
+///
+/// # Formula
+/// U2 = sqrt(Σ_{t≥1} (forecast_t - actual_t)²) / sqrt(Σ_{t≥1} (actual_{t-1} - actual_t)²)
+pub fn theil_u2(actual: &[f64], forecast: &[f64]) -> Result<f64> {
+    validate_inputs(actual, forecast)?;
+
+    if actual.len() < 2 {
+        return Err(ForecastError::InsufficientData {
+            needed: 2,
+            got: actual.len(),
+        });
+    }
+
+    let forecast_ss: f64 = actual[1..]
+        .iter()
+        .zip(forecast[1..].iter())
+        .map(|(a, f)| (f - a).powi(2))
+        .sum();
+    let naive_ss: f64 = actual.windows(2).map(|w| (w[0] - w[1]).powi(2)).sum();
+
+    if naive_ss.abs() < f64::EPSILON {
+        return Err(ForecastError::ComputationError(
+            "Theil's U2 undefined: naive forecast has zero error".to_string(),
+        ));
+    }
+
+    Ok((forecast_ss / naive_ss).sqrt())
+}
+
 /// R-squared (Coefficient of Determination)
+///
+/// R² is undefined when the actual series is constant (zero total sum of
+/// squares); this is reported as a `ComputationError` rather than a silent NaN.
 pub fn r2(actual: &[f64], forecast: &[f64]) -> Result<f64> {
     validate_inputs(actual, forecast)?;
 
@@ -201,12 +563,53 @@ pub fn r2(actual: &[f64], forecast: &[f64]) -> Result<f64> {
     let ss_tot: f64 = actual.iter().map(|a| (a - mean).powi(2)).sum();
 
     if ss_tot.abs() < f64::EPSILON {
-        return Ok(f64::NAN);
+        return Err(ForecastError::ComputationError(
+            "R² undefined: actual series is constant".to_string(),
+        ));
     }
 
     Ok(1.0 - ss_res / ss_tot)
 }
 
+/// Lin's concordance correlation coefficient.
+///
+/// Measures agreement with the identity line: unlike correlation or R², a
+/// forecast that tracks the actuals but is shifted or rescaled scores below 1.
+///
+/// # Formula
+/// CCC = 2·cov(a, f) / (var(a) + var(f) + (mean(a) - mean(f))²)
+/// using population (1/n) moments.
+pub fn ccc(actual: &[f64], forecast: &[f64]) -> Result<f64> {
+    validate_inputs(actual, forecast)?;
+    if actual.len() < 2 {
+        return Err(ForecastError::InsufficientData {
+            needed: 2,
+            got: actual.len(),
+        });
+    }
+
+    let n = actual.len() as f64;
+    let mean_a = actual.iter().sum::<f64>() / n;
+    let mean_f = forecast.iter().sum::<f64>() / n;
+    let var_a = actual.iter().map(|a| (a - mean_a).powi(2)).sum::<f64>() / n;
+    let var_f = forecast.iter().map(|f| (f - mean_f).powi(2)).sum::<f64>() / n;
+    let cov = actual
+        .iter()
+        .zip(forecast.iter())
+        .map(|(a, f)| (a - mean_a) * (f - mean_f))
+        .sum::<f64>()
+        / n;
+
+    let denom = var_a + var_f + (mean_a - mean_f).powi(2);
+    if denom.abs() < f64::EPSILON {
+        return Err(ForecastError::ComputationError(
+            "CCC undefined: actual and forecast are identical constants".to_string(),
+        ));
+    }
+
+    Ok(2.0 * cov / denom)
+}
+
 /// Calculates Forecast Bias (mean error).
 ///
 /// Bias indicates systematic over- or under-prediction.
@@ -228,6 +631,29 @@ pub fn bias(actual: &[f64], forecast: &[f64]) -> Result<f64> {
     Ok(sum / actual.len() as f64)
 }
 
+/// Calculates the tracking signal used to monitor forecast bias.
+///
+/// The running sum of errors divided by the mean absolute deviation. Values
+/// outside ±4 traditionally indicate a biased model that needs re-fitting.
+/// Positive values mean the forecast is too low. A perfect forecast has
+/// zero deviation and returns 0.
+///
+/// # Arguments
+/// * `actual` - Slice of actual observed values
+/// * `forecast` - Slice of forecasted/predicted values
+///
+/// # Formula
+/// TS = Σ(actual_i - forecast_i) / ((1/n) * Σ|actual_i - forecast_i|)
+pub fn tracking_signal(actual: &[f64], forecast: &[f64]) -> Result<f64> {
+    validate_inputs(actual, forecast)?;
+    let sum_error: f64 = actual.iter().zip(forecast.iter()).map(|(a, f)| a - f).sum();
+    let mad = mae(actual, forecast)?;
+    if mad < f64::EPSILON {
+        return Ok(0.0);
+    }
+    Ok(sum_error / mad)
+}
+
 /// Relative Mean Absolute Error
 ///
 /// C++ API compatible: compares two model predictions.
@@ -256,6 +682,53 @@ pub fn rmae(actual: &[f64], pred1: &[f64], pred2: &[f64]) -> Result<f64> {
     Ok(pred1_mae / pred2_mae)
 }
 
+/// Smallest relative error used by [`gmrae`] so a perfect forecast does not
+/// send the log to minus infinity.
+const GMRAE_MIN_RATIO: f64 = 1e-10;
+
+/// Geometric Mean Relative Absolute Error
+///
+/// Compares the forecast with a benchmark (usually naive) forecast per
+/// observation. GMRAE < 1 means the forecast beats the benchmark.
+///
+/// Observations where the benchmark is exact have an undefined ratio and are
+/// skipped; ratios are clamped below at 1e-10 to avoid log(0).
+///
+/// # Formula
+/// GMRAE = exp(mean(ln(|actual_i - forecast_i| / |actual_i - baseline_i|)))
+pub fn gmrae(actual: &[f64], forecast: &[f64], baseline: &[f64]) -> Result<f64> {
+    validate_inputs(actual, forecast)?;
+
+    if actual.len() != baseline.len() {
+        return Err(ForecastError::InvalidInput(format!(
+            "Actual and baseline arrays must have the same length: {} vs {}",
+            actual.len(),
+            baseline.len()
+        )));
+    }
+
+    let log_ratios: Vec<f64> = actual
+        .iter()
+        .zip(forecast.iter())
+        .zip(baseline.iter())
+        .filter_map(|((a, f), b)| {
+            let benchmark_error = (a - b).abs();
+            if benchmark_error < f64::EPSILON {
+                return None;
+            }
+            Some(((a - f).abs() / benchmark_error).max(GMRAE_MIN_RATIO).ln())
+        })
+        .collect();
+
+    if log_ratios.is_empty() {
+        return Err(ForecastError::ComputationError(
+            "GMRAE undefined: baseline matches every actual value".to_string(),
+        ));
+    }
+
+    Ok((log_ratios.iter().sum::<f64>() / log_ratios.len() as f64).exp())
+}
+
 /// Calculates Quantile Loss (Pinball Loss) for probabilistic forecasts.
 ///
 /// Quantile loss penalizes over- and under-predictions asymmetrically
@@ -316,12 +789,79 @@ pub fn mqloss(actual: &[f64], forecasts: &[Vec<f64>], quantiles: &[f64]) -> Resu
         ));
     }
 
-    let mut total_loss = 0.0;
-    for (forecast, &q) in forecasts.iter().zip(quantiles.iter()) {
-        total_loss += quantile_loss(actual, forecast, q)?;
+    let losses = mqloss_by_level(actual, forecasts, quantiles)?;
+    Ok(losses.iter().sum::<f64>() / quantiles.len() as f64)
+}
+
+/// Calculates the quantile (pinball) loss separately for each quantile level.
+///
+/// The per-level breakdown of [`mqloss`]: a level whose loss stands out from
+/// its neighbours points to a miscalibrated quantile.
+///
+/// # Arguments
+/// * `actual` - Slice of actual observed values
+/// * `forecasts` - Vector of forecasts, one per quantile level
+/// * `levels` - Quantile levels in [0, 1]
+///
+/// # Returns
+/// One quantile loss per level, aligned with `levels`, or an error if inputs
+/// are invalid
+pub fn mqloss_by_level(actual: &[f64], forecasts: &[Vec<f64>], levels: &[f64]) -> Result<Vec<f64>> {
+    if forecasts.len() != levels.len() {
+        return Err(ForecastError::InvalidInput(
+            "Number of forecasts must match number of quantiles".to_string(),
+        ));
     }
 
-    Ok(total_loss / quantiles.len() as f64)
+    forecasts
+        .iter()
+        .zip(levels.iter())
+        .map(|(forecast, &q)| quantile_loss(actual, forecast, q))
+        .collect()
+}
+
+/// Calculates the Continuous Ranked Probability Score from quantile forecasts.
+///
+/// CRPS is approximated by the integral of the pinball loss over the quantile
+/// levels, i.e. twice the quantile loss averaged across levels. For a point
+/// forecast it reduces to the MAE, so values are on the scale of the data.
+///
+/// # Arguments
+/// * `actual` - Slice of actual observed values
+/// * `quantile_forecasts` - Vector of forecasts, one per quantile level
+/// * `levels` - Quantile levels, strictly increasing in (0, 1)
+///
+/// # Returns
+/// The CRPS averaged over observations, or an error if inputs are invalid
+///
+/// # Formula
+/// CRPS ≈ (2/K) * Σ_k QL(actual, q_k, τ_k)
+pub fn crps(actual: &[f64], quantile_forecasts: &[Vec<f64>], levels: &[f64]) -> Result<f64> {
+    if levels.is_empty() {
+        return Err(ForecastError::InvalidInput(
+            "At least one quantile level is required".to_string(),
+        ));
+    }
+
+    if quantile_forecasts.len() != levels.len() {
+        return Err(ForecastError::InvalidInput(
+            "Number of forecasts must match number of quantile levels".to_string(),
+        ));
+    }
+
+    if levels.iter().any(|&l| l <= 0.0 || l >= 1.0) {
+        return Err(ForecastError::InvalidInput(
+            "Quantile levels must be in (0, 1)".to_string(),
+        ));
+    }
+
+    if levels.windows(2).any(|w| w[1] <= w[0]) {
+        return Err(ForecastError::InvalidInput(
+            "Quantile levels must be sorted in increasing order".to_string(),
+        ));
+    }
+
+    Ok(2.0 * mqloss(actual, quantile_forecasts, levels)?)
 }
 
 /// Calculates Prediction Interval Coverage.
@@ -361,6 +901,189 @@ pub fn coverage(actual: &[f64], lower: &[f64], upper: &[f64]) -> Result<f64> {
     Ok(covered as f64 / actual.len() as f64)
 }
 
+/// Calculates the empirical coverage of each quantile forecast.
+///
+/// For each level this is the fraction of actuals at or below the quantile
+/// forecast; a well-calibrated 0.9 quantile covers about 90% from below.
+/// Comparing the result to `levels` gives a reliability diagram.
+///
+/// # Arguments
+/// * `actual` - Slice of actual observed values
+/// * `forecasts` - Vector of forecasts, one per quantile level
+/// * `levels` - Quantile levels in [0, 1]
+///
+/// # Returns
+/// One coverage proportion (0.0 to 1.0) per level, aligned with `levels`, or
+/// an error if inputs are invalid
+///
+/// # Formula
+/// Coverage_q = (1/n) * Σ I(actual_i <= forecast_q,i)
+pub fn quantile_coverage(
+    actual: &[f64],
+    forecasts: &[Vec<f64>],
+    levels: &[f64],
+) -> Result<Vec<f64>> {
+    if forecasts.len() != levels.len() {
+        return Err(ForecastError::InvalidInput(
+            "Number of forecasts must match number of quantiles".to_string(),
+        ));
+    }
+
+    forecasts
+        .iter()
+        .zip(levels.iter())
+        .map(|(forecast, &q)| {
+            validate_inputs(actual, forecast)?;
+            if !(0.0..=1.0).contains(&q) {
+                return Err(ForecastError::InvalidInput(
+                    "Quantile must be between 0 and 1".to_string(),
+                ));
+            }
+            let below = actual
+                .iter()
+                .zip(forecast.iter())
+                .filter(|(a, f)| a <= f)
+                .count();
+            Ok(below as f64 / actual.len() as f64)
+        })
+        .collect()
+}
+
+/// Standard point-accuracy metrics computed over one common subset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointMetrics {
+    /// Number of aligned, finite (actual, forecast) pairs used
+    pub n: usize,
+    /// Mean Absolute Error
+    pub mae: f64,
+    /// Mean Squared Error
+    pub mse: f64,
+    /// Root Mean Squared Error
+    pub rmse: f64,
+    /// Mean Absolute Percentage Error (NaN if all actuals are zero)
+    pub mape: f64,
+    /// Symmetric Mean Absolute Percentage Error
+    pub smape: f64,
+    /// Mean bias (forecast - actual)
+    pub bias: f64,
+    /// Coefficient of determination (NaN when the actuals are constant)
+    pub r2: f64,
+}
+
+/// Computes MAE, MSE, RMSE, MAPE, sMAPE, bias and R² in one call.
+///
+/// Pairs where either the actual or the forecast is non-finite (NaN/Inf)
+/// are dropped once up front, so every metric in the bundle is computed
+/// over exactly the same observations.
+///
+/// # Arguments
+/// * `actual` - Slice of actual observed values
+/// * `forecast` - Slice of forecasted/predicted values
+///
+/// # Returns
+/// A [`PointMetrics`] bundle, or an error if the lengths differ or no finite
+/// pairs remain
+pub fn point_metrics(actual: &[f64], forecast: &[f64]) -> Result<PointMetrics> {
+    if actual.len() != forecast.len() {
+        return Err(ForecastError::InvalidInput(format!(
+            "Actual and forecast arrays must have the same length: {} vs {}",
+            actual.len(),
+            forecast.len()
+        )));
+    }
+
+    let (a, f): (Vec<f64>, Vec<f64>) = actual
+        .iter()
+        .zip(forecast.iter())
+        .filter(|(a, f)| a.is_finite() && f.is_finite())
+        .map(|(a, f)| (*a, *f))
+        .unzip();
+
+    let mse = mse(&a, &f)?;
+    Ok(PointMetrics {
+        n: a.len(),
+        mae: mae(&a, &f)?,
+        mse,
+        rmse: mse.sqrt(),
+        mape: mape(&a, &f)?,
+        smape: smape(&a, &f)?,
+        bias: bias(&a, &f)?,
+        r2: r2(&a, &f).unwrap_or(f64::NAN),
+    })
+}
+
+/// Accuracy of one forecast step across rolling origins.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepMetrics {
+    /// Steps ahead (1-based)
+    pub step: usize,
+    /// Number of origins that reach this step
+    pub n: usize,
+    /// Mean Absolute Error
+    pub mae: f64,
+    /// Root Mean Squared Error
+    pub rmse: f64,
+    /// Mean bias (forecast - actual)
+    pub bias: f64,
+}
+
+/// Computes MAE, RMSE and bias separately for each forecast step.
+///
+/// Each inner vector holds one forecast origin's 1..h-step-ahead values, as
+/// produced by rolling-origin evaluation. Origins may be shorter than the
+/// longest one (e.g. near the end of the series); each step is scored over
+/// the origins that reach it.
+///
+/// # Arguments
+/// * `actual` - Actual values, one vector per origin
+/// * `forecast` - Forecasts, one vector per origin
+///
+/// # Returns
+/// One [`StepMetrics`] per step, or an error if the shapes differ or there
+/// are no values
+pub fn metrics_by_horizon(actual: &[Vec<f64>], forecast: &[Vec<f64>]) -> Result<Vec<StepMetrics>> {
+    if actual.len() != forecast.len() {
+        return Err(ForecastError::InvalidInput(format!(
+            "Actual and forecast must have the same number of origins: {} vs {}",
+            actual.len(),
+            forecast.len()
+        )));
+    }
+    for (i, (a, f)) in actual.iter().zip(forecast.iter()).enumerate() {
+        if a.len() != f.len() {
+            return Err(ForecastError::InvalidInput(format!(
+                "Origin {} has {} actual values but {} forecasts",
+                i,
+                a.len(),
+                f.len()
+            )));
+        }
+    }
+
+    let horizon = actual.iter().map(|a| a.len()).max().unwrap_or(0);
+    if horizon == 0 {
+        return Err(ForecastError::InsufficientData { needed: 1, got: 0 });
+    }
+
+    (0..horizon)
+        .map(|h| {
+            let (a, f): (Vec<f64>, Vec<f64>) = actual
+                .iter()
+                .zip(forecast.iter())
+                .filter(|(a, _)| a.len() > h)
+                .map(|(a, f)| (a[h], f[h]))
+                .unzip();
+            Ok(StepMetrics {
+                step: h + 1,
+                n: a.len(),
+                mae: mae(&a, &f)?,
+                rmse: rmse(&a, &f)?,
+                bias: bias(&a, &f)?,
+            })
+        })
+        .collect()
+}
+
 fn validate_inputs(actual: &[f64], forecast: &[f64]) -> Result<()> {
     if actual.len() != forecast.len() {
         return Err(ForecastError::InvalidInput(format!(
@@ -375,6 +1098,26 @@ fn validate_inputs(actual: &[f64], forecast: &[f64]) -> Result<()> {
     Ok(())
 }
 
+/// Validates the inputs and keeps the pairs where neither value is NaN.
+///
+/// NULLs arrive from SQL as NaN, so dropping them here gives the metrics
+/// aggregate-function semantics. Errors if no pairs remain.
+fn valid_pairs(actual: &[f64], forecast: &[f64]) -> Result<Vec<(f64, f64)>> {
+    validate_inputs(actual, forecast)?;
+    let pairs: Vec<(f64, f64)> = actual
+        .iter()
+        .zip(forecast.iter())
+        .filter(|(a, f)| !a.is_nan() && !f.is_nan())
+        .map(|(a, f)| (*a, *f))
+        .collect();
+    if pairs.is_empty() {
+        return Err(ForecastError::InvalidInput(
+            "No actual/forecast pairs without NaN".to_string(),
+        ));
+    }
+    Ok(pairs)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,6 +1147,48 @@ mod tests {
         assert_relative_eq!(result, 1.0, epsilon = 0.001);
     }
 
+    #[test]
+    fn test_rmsse_equals_one_for_naive_sized_errors() {
+        // Training series steps by 1, so the naive MSE is 1
+        let baseline = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let actual = vec![10.0, 11.0, 12.0];
+        let forecast = vec![11.0, 10.0, 13.0];
+        let result = rmsse(&actual, &forecast, &baseline).unwrap();
+        assert_relative_eq!(result, 1.0, epsilon = 1e-12);
+
+        let half = vec![10.5, 10.5, 12.5];
+        assert_relative_eq!(rmsse(&actual, &half, &baseline).unwrap(), 0.5);
+    }
+
+    #[test]
+    fn test_rmsse_errors() {
+        let actual = vec![1.0, 2.0];
+        let forecast = vec![1.0, 2.0];
+        assert!(matches!(
+            rmsse(&actual, &forecast, &[1.0]),
+            Err(ForecastError::InsufficientData { needed: 2, got: 1 })
+        ));
+        assert!(matches!(
+            rmsse(&actual, &forecast, &[3.0, 3.0, 3.0]),
+            Err(ForecastError::ComputationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_r2_constant_actual_is_error() {
+        let actual = vec![3.0, 3.0, 3.0];
+        let forecast = vec![2.0, 3.0, 4.0];
+        match r2(&actual, &forecast) {
+            Err(ForecastError::ComputationError(msg)) => assert!(msg.contains("constant")),
+            other => panic!("expected ComputationError, got {:?}", other),
+        }
+
+        // The bundle stays usable and marks R² as undefined
+        let m = point_metrics(&actual, &forecast).unwrap();
+        assert!(m.r2.is_nan());
+        assert_relative_eq!(m.mae, 2.0 / 3.0);
+    }
+
     #[test]
     fn test_coverage() {
         let actual = vec![1.0, 2.0, 3.0, 4.0, 5.0];
@@ -573,6 +1358,145 @@ mod tests {
         assert!(result >= 0.0);
     }
 
+    #[test]
+    fn test_crps_decreases_as_quantiles_tighten() {
+        let actual = vec![10.0, 12.0, 11.0, 13.0, 12.5];
+        let levels = vec![0.1, 0.25, 0.5, 0.75, 0.9];
+        let offsets = [-1.0, -0.5, 0.0, 0.5, 1.0];
+
+        let spread = |scale: f64| -> Vec<Vec<f64>> {
+            offsets
+                .iter()
+                .map(|o| actual.iter().map(|a| a + o * scale).collect())
+                .collect()
+        };
+
+        let wide = crps(&actual, &spread(4.0), &levels).unwrap();
+        let medium = crps(&actual, &spread(2.0), &levels).unwrap();
+        let tight = crps(&actual, &spread(0.5), &levels).unwrap();
+
+        assert!(wide > medium);
+        assert!(medium > tight);
+        assert!(tight > 0.0);
+    }
+
+    #[test]
+    fn test_crps_point_forecast_equals_mae() {
+        let actual = vec![1.0, 2.0, 3.0];
+        let forecast = vec![1.5, 1.0, 3.0];
+        let result = crps(&actual, std::slice::from_ref(&forecast), &[0.5]).unwrap();
+        assert_relative_eq!(result, mae(&actual, &forecast).unwrap(), epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_crps_invalid_levels() {
+        let actual = vec![1.0, 2.0];
+        let forecasts = vec![vec![1.0, 2.0], vec![1.0, 2.0]];
+        assert!(crps(&actual, &forecasts, &[0.9, 0.1]).is_err());
+        assert!(crps(&actual, &forecasts, &[0.0, 0.5]).is_err());
+        assert!(crps(&actual, &forecasts, &[0.5, 1.0]).is_err());
+        assert!(crps(&actual, &forecasts, &[0.5]).is_err());
+        assert!(crps(&actual, &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_median_percentage_errors_ignore_outlier() {
+        let actual = vec![100.0, 200.0, 50.0, 80.0, 0.01];
+        // 10% error everywhere except a huge miss on the near-zero actual
+        let forecast = vec![110.0, 180.0, 55.0, 88.0, 5.0];
+
+        let mape_value = mape(&actual, &forecast).unwrap();
+        let mdape_value = mdape(&actual, &forecast).unwrap();
+        let smdape_value = smdape(&actual, &forecast).unwrap();
+
+        assert!(mape_value > 1000.0);
+        assert_relative_eq!(mdape_value, 10.0, epsilon = 1e-9);
+        assert!(smdape_value < 11.0);
+    }
+
+    #[test]
+    fn test_median_percentage_errors_skip_zero_actuals() {
+        let actual = vec![0.0, 10.0, 20.0];
+        let forecast = vec![5.0, 11.0, 22.0];
+        assert_relative_eq!(mdape(&actual, &forecast).unwrap(), 10.0, epsilon = 1e-9);
+
+        assert!(mdape(&[0.0, 0.0], &[1.0, 2.0]).is_err());
+        assert!(smdape(&[0.0, 0.0], &[1.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn test_maape_bounded_with_zero_actuals() {
+        let actual = vec![0.0, 0.0, 3.0, 0.0, 5.0];
+        let forecast = vec![2.0, 0.0, 1.0, 7.0, 500.0];
+        let result = maape(&actual, &forecast).unwrap();
+        assert!((0.0..=std::f64::consts::FRAC_PI_2).contains(&result));
+
+        let worst = maape(&[0.0, 0.0], &[1.0, 2.0]).unwrap();
+        assert_relative_eq!(worst, std::f64::consts::FRAC_PI_2, epsilon = 1e-12);
+        assert_relative_eq!(maape(&[0.0], &[0.0]).unwrap(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_maape_agrees_with_mape_ranking() {
+        let actual = vec![100.0, 120.0, 90.0, 110.0];
+        let close = vec![101.0, 118.0, 91.0, 111.0];
+        let far = vec![104.0, 114.0, 95.0, 104.0];
+
+        assert!(maape(&actual, &close).unwrap() < maape(&actual, &far).unwrap());
+        assert!(mape(&actual, &close).unwrap() < mape(&actual, &far).unwrap());
+        // Small errors: atan(x) ≈ x
+        assert_relative_eq!(
+            maape(&actual, &close).unwrap() * 100.0,
+            mape(&actual, &close).unwrap(),
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn test_theil_u2_reference_points() {
+        let actual = vec![10.0, 12.0, 11.0, 15.0, 14.0];
+        assert_relative_eq!(theil_u2(&actual, &actual).unwrap(), 0.0, epsilon = 1e-12);
+
+        // The naive forecast itself; the first value is never scored
+        let naive = vec![0.0, 10.0, 12.0, 11.0, 15.0];
+        assert_relative_eq!(theil_u2(&actual, &naive).unwrap(), 1.0, epsilon = 1e-12);
+
+        let close = vec![10.0, 11.5, 11.2, 14.6, 14.1];
+        assert!(theil_u2(&actual, &close).unwrap() < 1.0);
+    }
+
+    #[test]
+    fn test_theil_u2_invalid() {
+        assert!(matches!(
+            theil_u2(&[1.0], &[1.0]),
+            Err(ForecastError::InsufficientData { needed: 2, got: 1 })
+        ));
+        assert!(theil_u2(&[3.0, 3.0, 3.0], &[3.0, 2.0, 4.0]).is_err());
+    }
+
+    #[test]
+    fn test_tracking_signal() {
+        let actual = vec![10.0, 12.0, 11.0, 13.0, 12.0, 14.0];
+
+        let unbiased = vec![11.0, 11.0, 12.0, 12.0, 13.0, 13.0];
+        assert_relative_eq!(
+            tracking_signal(&actual, &unbiased).unwrap(),
+            0.0,
+            epsilon = 1e-12
+        );
+
+        // Consistently low by 1: each error adds one MAD to the signal
+        let low: Vec<f64> = actual.iter().map(|a| a - 1.0).collect();
+        assert_relative_eq!(
+            tracking_signal(&actual, &low).unwrap(),
+            6.0,
+            epsilon = 1e-12
+        );
+        assert!(tracking_signal(&actual, &low).unwrap() > 4.0);
+
+        assert_eq!(tracking_signal(&actual, &actual).unwrap(), 0.0);
+    }
+
     #[test]
     fn test_coverage_partial() {
         let actual = vec![1.0, 2.0, 3.0, 10.0, 5.0]; // 10.0 is outside
@@ -600,4 +1524,304 @@ mod tests {
 
         assert!(mae(&actual, &forecast).is_err());
     }
+
+    #[test]
+    fn test_point_metrics_matches_individual_metrics() {
+        let actual = vec![100.0, 200.0, 300.0, 400.0];
+        let forecast = vec![110.0, 180.0, 330.0, 390.0];
+        let m = point_metrics(&actual, &forecast).unwrap();
+
+        assert_eq!(m.n, 4);
+        assert_relative_eq!(m.mae, mae(&actual, &forecast).unwrap());
+        assert_relative_eq!(m.mse, mse(&actual, &forecast).unwrap());
+        assert_relative_eq!(m.rmse, rmse(&actual, &forecast).unwrap());
+        assert_relative_eq!(m.mape, mape(&actual, &forecast).unwrap());
+        assert_relative_eq!(m.smape, smape(&actual, &forecast).unwrap());
+        assert_relative_eq!(m.bias, bias(&actual, &forecast).unwrap());
+        assert_relative_eq!(m.r2, r2(&actual, &forecast).unwrap());
+    }
+
+    #[test]
+    fn test_point_metrics_drops_non_finite_pairs() {
+        let actual = vec![1.0, f64::NAN, 3.0, 4.0];
+        let forecast = vec![1.5, 2.0, f64::INFINITY, 5.0];
+        let m = point_metrics(&actual, &forecast).unwrap();
+
+        assert_eq!(m.n, 2);
+        assert_relative_eq!(m.mae, 0.75);
+        assert_relative_eq!(m.bias, 0.75);
+    }
+
+    #[test]
+    fn test_metrics_skip_nan_pairs() {
+        let actual = vec![1.0, f64::NAN, 2.0, 4.0, 5.0];
+        let forecast = vec![2.0, 3.0, f64::NAN, 2.0, 5.0];
+
+        // Valid pairs: (1, 2), (4, 2), (5, 5)
+        assert_relative_eq!(mae(&actual, &forecast).unwrap(), 1.0);
+        assert_relative_eq!(mse(&actual, &forecast).unwrap(), 5.0 / 3.0);
+        assert_relative_eq!(rmse(&actual, &forecast).unwrap(), (5.0f64 / 3.0).sqrt());
+        assert_relative_eq!(mape(&actual, &forecast).unwrap(), 150.0 / 3.0);
+        assert_relative_eq!(
+            smape(&actual, &forecast).unwrap(),
+            (2.0 / 3.0 + 2.0 / 3.0) / 3.0 * 100.0
+        );
+
+        // Equal to the metrics on the valid pairs alone
+        assert_relative_eq!(
+            mae(&actual, &forecast).unwrap(),
+            mae(&[1.0, 4.0, 5.0], &[2.0, 2.0, 5.0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_metrics_all_nan_pairs_error() {
+        let actual = vec![f64::NAN, 2.0];
+        let forecast = vec![1.0, f64::NAN];
+        assert!(mae(&actual, &forecast).is_err());
+        assert!(mse(&actual, &forecast).is_err());
+        assert!(mape(&actual, &forecast).is_err());
+        assert!(smape(&actual, &forecast).is_err());
+    }
+
+    #[test]
+    fn test_mqloss_by_level_flags_biased_quantile() {
+        let actual: Vec<f64> = (0..50).map(|i| 10.0 + (i % 5) as f64).collect();
+        let levels = [0.1, 0.5, 0.9];
+        let calibrated: Vec<Vec<f64>> = vec![
+            actual.iter().map(|a| a - 1.0).collect(),
+            actual.clone(),
+            actual.iter().map(|a| a + 1.0).collect(),
+        ];
+        let mut biased = calibrated.clone();
+        biased[2] = actual.iter().map(|a| a + 10.0).collect();
+
+        let base = mqloss_by_level(&actual, &calibrated, &levels).unwrap();
+        let losses = mqloss_by_level(&actual, &biased, &levels).unwrap();
+        assert_eq!(losses.len(), levels.len());
+
+        assert_relative_eq!(losses[0], base[0]);
+        assert_relative_eq!(losses[1], base[1]);
+        assert!(losses[2] > 5.0 * base[2]);
+
+        // mqloss is the mean of the per-level losses
+        let mean = losses.iter().sum::<f64>() / losses.len() as f64;
+        assert_relative_eq!(mqloss(&actual, &biased, &levels).unwrap(), mean);
+    }
+
+    #[test]
+    fn test_quantile_coverage_tracks_levels() {
+        // Evenly spread uniform(0, 1) draws; the q-quantile forecast is q itself
+        let golden = (5f64.sqrt() - 1.0) / 2.0;
+        let actual: Vec<f64> = (1..=1000).map(|i| (i as f64 * golden).fract()).collect();
+        let levels = [0.1, 0.25, 0.5, 0.75, 0.9];
+        let forecasts: Vec<Vec<f64>> = levels.iter().map(|&q| vec![q; actual.len()]).collect();
+
+        let cov = quantile_coverage(&actual, &forecasts, &levels).unwrap();
+        assert_eq!(cov.len(), levels.len());
+        for (c, q) in cov.iter().zip(levels.iter()) {
+            assert_relative_eq!(*c, *q, epsilon = 0.01);
+        }
+
+        assert!(quantile_coverage(&actual, &forecasts[..2], &levels).is_err());
+    }
+
+    #[test]
+    fn test_point_metrics_errors() {
+        assert!(point_metrics(&[1.0, 2.0], &[1.0]).is_err());
+        assert!(point_metrics(&[f64::NAN], &[1.0]).is_err());
+    }
+
+    #[test]
+    fn test_metrics_by_horizon_error_grows() {
+        // Error grows linearly with the step: forecast = actual + step
+        let actual: Vec<Vec<f64>> = (0..5)
+            .map(|o| (0..4).map(|h| (o * 10 + h) as f64).collect())
+            .collect();
+        let forecast: Vec<Vec<f64>> = actual
+            .iter()
+            .map(|a| {
+                a.iter()
+                    .enumerate()
+                    .map(|(h, v)| v + (h + 1) as f64)
+                    .collect()
+            })
+            .collect();
+
+        let steps = metrics_by_horizon(&actual, &forecast).unwrap();
+        assert_eq!(steps.len(), 4);
+        for (h, step) in steps.iter().enumerate() {
+            assert_eq!(step.step, h + 1);
+            assert_eq!(step.n, 5);
+            assert_relative_eq!(step.mae, (h + 1) as f64, epsilon = 1e-10);
+            assert_relative_eq!(step.bias, (h + 1) as f64, epsilon = 1e-10);
+        }
+        assert!(steps.windows(2).all(|w| w[1].mae > w[0].mae));
+    }
+
+    #[test]
+    fn test_metrics_by_horizon_ragged_origins() {
+        let actual = vec![vec![1.0, 2.0, 3.0], vec![1.0]];
+        let forecast = vec![vec![2.0, 2.0, 3.0], vec![3.0]];
+        let steps = metrics_by_horizon(&actual, &forecast).unwrap();
+        assert_eq!(steps[0].n, 2);
+        assert_eq!(steps[2].n, 1);
+        assert_relative_eq!(steps[0].mae, 1.5, epsilon = 1e-10);
+
+        assert!(metrics_by_horizon(&actual, &forecast[..1]).is_err());
+        assert!(metrics_by_horizon(&[vec![1.0]], &[vec![]]).is_err());
+    }
+
+    #[test]
+    fn test_ccc() {
+        let actual = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_relative_eq!(ccc(&actual, &actual).unwrap(), 1.0, epsilon = 1e-10);
+
+        // Perfectly correlated but shifted: penalized for the location shift
+        let shifted: Vec<f64> = actual.iter().map(|a| a + 2.0).collect();
+        let value = ccc(&actual, &shifted).unwrap();
+        assert!(value < 1.0);
+        // var = 2, shift² = 4 -> 2*2 / (2 + 2 + 4)
+        assert_relative_eq!(value, 0.5, epsilon = 1e-10);
+
+        assert!(ccc(&[1.0], &[1.0]).is_err());
+        assert!(ccc(&[2.0, 2.0], &[2.0, 2.0]).is_err());
+    }
+
+    #[test]
+    fn test_seasonal_naive_errors() {
+        let train = vec![1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 10.0];
+        let errors = seasonal_naive_errors(&train, 3).unwrap();
+        assert_eq!(errors.len(), train.len() - 3);
+        assert_eq!(errors, vec![3.0, 4.0, 5.0, 6.0]);
+
+        let naive = seasonal_naive_errors(&train, 1).unwrap();
+        assert_eq!(naive.len(), train.len() - 1);
+        assert_relative_eq!(naive[0], 1.0);
+
+        assert!(seasonal_naive_errors(&train, 0).is_err());
+        assert!(seasonal_naive_errors(&train, 7).is_err());
+    }
+
+    #[test]
+    fn test_mase_scaled_uses_seasonal_naive_errors() {
+        let train = vec![10.0, 20.0, 30.0, 12.0, 23.0, 31.0, 14.0, 26.0, 35.0];
+        let actual = vec![15.0, 25.0, 33.0];
+        let forecast = vec![14.0, 27.0, 33.0];
+
+        let naive = seasonal_naive_errors(&train, 3).unwrap();
+        let scale = naive.iter().map(|e| e.abs()).sum::<f64>() / naive.len() as f64;
+        let expected = mae(&actual, &forecast).unwrap() / scale;
+
+        let result = mase_scaled(&actual, &forecast, &train, 3).unwrap();
+        assert_relative_eq!(result, expected, epsilon = 1e-12);
+        // Seasonal naive MAE is 2.5, forecast MAE is 1.0
+        assert_relative_eq!(result, 0.4, epsilon = 1e-12);
+
+        assert!(mase_scaled(&actual, &forecast, &[5.0; 9], 3).is_err());
+        assert!(mase_scaled(&actual, &forecast, &train, 0).is_err());
+    }
+
+    #[test]
+    fn test_msis() {
+        // Naive in-sample MAE of the training series is 1
+        let train = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let actual = vec![7.0, 8.0, 9.0];
+
+        let wide = msis(
+            &actual,
+            &[5.0, 6.0, 7.0],
+            &[9.0, 10.0, 11.0],
+            &train,
+            1,
+            0.05,
+        )
+        .unwrap();
+        let tight = msis(
+            &actual,
+            &[6.0, 7.0, 8.0],
+            &[8.0, 9.0, 10.0],
+            &train,
+            1,
+            0.05,
+        )
+        .unwrap();
+        assert_relative_eq!(wide, 4.0, epsilon = 1e-10);
+        assert_relative_eq!(tight, 2.0, epsilon = 1e-10);
+        assert!(tight < wide);
+
+        // Missed actual: width 2 + (2 / 0.05) * 1
+        let missed = msis(&[7.0], &[8.0], &[10.0], &train, 1, 0.05).unwrap();
+        assert_relative_eq!(missed, 42.0, epsilon = 1e-10);
+
+        assert!(msis(&actual, &actual, &actual, &[3.0, 3.0, 3.0], 1, 0.05).is_err());
+        assert!(msis(&actual, &actual, &actual, &train, 1, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_gmrae() {
+        let actual = vec![10.0, 12.0, 11.0, 13.0];
+        let baseline = vec![9.0, 10.0, 12.0, 11.0];
+        assert_relative_eq!(
+            gmrae(&actual, &baseline, &baseline).unwrap(),
+            1.0,
+            epsilon = 1e-10
+        );
+
+        // Half the benchmark error everywhere
+        let forecast: Vec<f64> = actual
+            .iter()
+            .zip(baseline.iter())
+            .map(|(a, b)| a - (a - b) / 2.0)
+            .collect();
+        assert_relative_eq!(
+            gmrae(&actual, &forecast, &baseline).unwrap(),
+            0.5,
+            epsilon = 1e-10
+        );
+
+        // A perfect forecast is clamped rather than producing log(0)
+        assert!(gmrae(&actual, &actual, &baseline).unwrap() > 0.0);
+
+        assert!(gmrae(&actual, &forecast, &actual).is_err());
+        assert!(gmrae(&actual, &forecast, &baseline[..2]).is_err());
+    }
+
+    #[test]
+    fn test_owa() {
+        // Positive monthly series with a strong multiplicative season
+        let train: Vec<f64> = (0..72)
+            .map(|i| {
+                (100.0 + 0.5 * i as f64)
+                    * (1.0 + 0.3 * (i as f64 * std::f64::consts::TAU / 12.0).sin())
+            })
+            .collect();
+        let actual: Vec<f64> = (72..78)
+            .map(|i| {
+                (100.0 + 0.5 * i as f64)
+                    * (1.0 + 0.3 * (i as f64 * std::f64::consts::TAU / 12.0).sin())
+            })
+            .collect();
+
+        let naive2 = naive2_forecast(&train, 12, actual.len());
+        // Seasonal adjustment kicked in: Naive2 is not a flat line
+        assert!(naive2.iter().any(|v| (v - naive2[0]).abs() > 1.0));
+        assert_relative_eq!(
+            owa(&actual, &naive2, &train, 12).unwrap(),
+            1.0,
+            epsilon = 1e-10
+        );
+
+        // A forecast with half the Naive2 error scores below 1
+        let better: Vec<f64> = actual
+            .iter()
+            .zip(naive2.iter())
+            .map(|(a, n)| a + (n - a) / 2.0)
+            .collect();
+        assert!(owa(&actual, &better, &train, 12).unwrap() < 1.0);
+
+        // Non-seasonal: Naive2 is the last value
+        assert_eq!(naive2_forecast(&[1.0, 2.0, 3.0], 1, 2), vec![3.0, 3.0]);
+        assert!(owa(&[3.0, 3.0], &[2.0, 4.0], &[1.0, 2.0, 3.0], 1).is_err());
+    }
 }
@@ -0,0 +1,137 @@
+//! Batch execution helpers.
+//!
+//! With the `parallel` feature, batch operations run on the rayon thread
+//! pool; without it they run sequentially. Results are in input order either
+//! way, so both paths produce identical output.
+
+/// Rayon pool with `n_threads` workers, built on first use and kept for the
+/// lifetime of the process so repeated batch calls reuse the same threads.
+#[cfg(feature = "parallel")]
+fn thread_pool(n_threads: usize) -> Option<std::sync::Arc<rayon::ThreadPool>> {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex, OnceLock};
+
+    static POOLS: OnceLock<Mutex<HashMap<usize, Arc<rayon::ThreadPool>>>> = OnceLock::new();
+
+    let mut pools = POOLS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if let Some(pool) = pools.get(&n_threads) {
+        return Some(Arc::clone(pool));
+    }
+    let pool = Arc::new(
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(n_threads)
+            .build()
+            .ok()?,
+    );
+    pools.insert(n_threads, Arc::clone(&pool));
+    Some(pool)
+}
+
+/// Apply `f` to every item, in parallel when the `parallel` feature is on.
+///
+/// `n_threads` is a hint for the pool size; 0 uses the rayon default. Pools
+/// for other sizes are cached per size. It is ignored in sequential builds.
+#[cfg(feature = "parallel")]
+pub(crate) fn map_batch<T, R, F>(items: &[T], n_threads: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    use rayon::prelude::*;
+
+    let run = || -> Vec<R> { items.par_iter().map(&f).collect() };
+    if n_threads == 0 {
+        return run();
+    }
+    match thread_pool(n_threads) {
+        Some(pool) => pool.install(run),
+        None => run(),
+    }
+}
+
+/// Apply `f` to every item sequentially (`parallel` feature disabled).
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn map_batch<T, R, F>(items: &[T], _n_threads: usize, f: F) -> Vec<R>
+where
+    F: Fn(&T) -> R,
+{
+    items.iter().map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::{extract_features, extract_features_batch};
+    use crate::forecast::{forecast, forecast_batch, ForecastOptions, ModelType};
+
+    fn panel() -> Vec<Vec<f64>> {
+        (0..8)
+            .map(|s| {
+                (0..(24 + 5 * s))
+                    .map(|i| 10.0 * s as f64 + ((i * (s + 3)) % 11) as f64)
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_map_batch_preserves_order() {
+        let items: Vec<usize> = (0..100).collect();
+        for n_threads in [0, 1, 4] {
+            let squares = map_batch(&items, n_threads, |x| x * x);
+            let expected: Vec<usize> = items.iter().map(|x| x * x).collect();
+            assert_eq!(squares, expected);
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_thread_pools_are_reused() {
+        let first = thread_pool(3).unwrap();
+        let second = thread_pool(3).unwrap();
+        assert!(std::sync::Arc::ptr_eq(&first, &second));
+        assert_eq!(first.current_num_threads(), 3);
+        assert!(!std::sync::Arc::ptr_eq(&first, &thread_pool(2).unwrap()));
+    }
+
+    #[test]
+    fn test_batch_paths_match_sequential() {
+        let panel = panel();
+        let series: Vec<Vec<Option<f64>>> = panel
+            .iter()
+            .map(|s| s.iter().copied().map(Some).collect())
+            .collect();
+        let refs: Vec<&[Option<f64>]> = series.iter().map(|s| s.as_slice()).collect();
+        let options = ForecastOptions {
+            model: ModelType::SES,
+            horizon: 5,
+            ..Default::default()
+        };
+
+        for n_threads in [0, 1, 3] {
+            let batch = forecast_batch(&refs, &options, n_threads);
+            for (values, result) in series.iter().zip(&batch) {
+                let single = forecast(values, &options).unwrap();
+                let result = result.as_ref().unwrap();
+                assert_eq!(result.point, single.point);
+                assert_eq!(result.lower, single.lower);
+                assert_eq!(result.upper, single.upper);
+            }
+        }
+
+        let value_refs: Vec<&[f64]> = panel.iter().map(|s| s.as_slice()).collect();
+        let batch = extract_features_batch(&value_refs, 2);
+        for (values, features) in panel.iter().zip(&batch) {
+            let single = extract_features(values).unwrap();
+            let features = features.as_ref().unwrap();
+            assert_eq!(features.len(), single.len());
+            for (name, value) in &single {
+                assert_eq!(features[name].to_bits(), value.to_bits(), "{}", name);
+            }
+        }
+    }
+}
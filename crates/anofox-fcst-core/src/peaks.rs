@@ -70,6 +70,38 @@ impl From<FdarsPeakDetectionResult> for PeakDetectionResult {
     }
 }
 
+impl PeakDetectionResult {
+    /// Drop peaks whose value is below `min_height`.
+    ///
+    /// Inter-peak distances and the mean period are recomputed from the
+    /// remaining peaks.
+    pub fn with_min_height(self, min_height: f64) -> Self {
+        if self.peaks.iter().all(|p| p.value >= min_height) {
+            return self;
+        }
+
+        let peaks: Vec<Peak> = self
+            .peaks
+            .into_iter()
+            .filter(|p| p.value >= min_height)
+            .collect();
+        let inter_peak_distances: Vec<f64> =
+            peaks.windows(2).map(|w| w[1].time - w[0].time).collect();
+        let mean_period = if inter_peak_distances.is_empty() {
+            f64::NAN
+        } else {
+            inter_peak_distances.iter().sum::<f64>() / inter_peak_distances.len() as f64
+        };
+
+        Self {
+            n_peaks: peaks.len(),
+            peaks,
+            inter_peak_distances,
+            mean_period,
+        }
+    }
+}
+
 /// Result of peak timing variability analysis.
 #[derive(Debug, Clone)]
 pub struct PeakTimingResult {
@@ -314,6 +346,34 @@ mod tests {
         assert!(result2.n_peaks > 0, "Should detect peaks in {:?}", values2);
     }
 
+    #[test]
+    fn test_peaks_with_min_height() {
+        let peak = |index: usize, value: f64| Peak {
+            index,
+            time: index as f64,
+            value,
+            prominence: value,
+        };
+        let detected = PeakDetectionResult {
+            peaks: vec![peak(2, 10.0), peak(5, 3.0), peak(8, 10.0)],
+            n_peaks: 3,
+            inter_peak_distances: vec![3.0, 3.0],
+            mean_period: 3.0,
+        };
+
+        let result = detected.with_min_height(5.0);
+
+        assert_eq!(result.n_peaks, 2);
+        assert_eq!(result.peaks.len(), 2);
+        assert!(result.peaks.iter().all(|p| p.index != 5));
+        assert_eq!(
+            result.peaks.iter().map(|p| p.index).collect::<Vec<_>>(),
+            vec![2, 8]
+        );
+        assert_eq!(result.inter_peak_distances, vec![6.0]);
+        assert!((result.mean_period - 6.0).abs() < 1e-10);
+    }
+
     #[test]
     fn test_invalid_period() {
         let values = generate_seasonal_series(120, 12.0, 5.0);
@@ -3,6 +3,7 @@
 //! This module wraps fdars-core's period detection functions for use with
 //! time series data in DuckDB.
 
+use crate::correlation::acf_at_lag;
 use crate::decomposition::{mstl_decompose, InsufficientDataMode};
 use crate::error::{ForecastError, Result};
 use fdars_core::seasonal::{
@@ -396,27 +397,6 @@ pub fn detect_multiple_periods_ts(
     })
 }
 
-/// Compute ACF at a specific lag.
-fn acf_at_lag(values: &[f64], lag: usize) -> f64 {
-    let n = values.len();
-    if lag >= n {
-        return 0.0;
-    }
-
-    let mean: f64 = values.iter().sum::<f64>() / n as f64;
-    let variance: f64 = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
-
-    if variance.abs() < f64::EPSILON {
-        return 0.0;
-    }
-
-    let mut sum = 0.0;
-    for i in 0..(n - lag) {
-        sum += (values[i] - mean) * (values[i + lag] - mean);
-    }
-    sum / variance
-}
-
 /// Autoperiod: FFT period detection with ACF validation.
 ///
 /// Uses FFT to find candidate periods, then validates each candidate
@@ -1012,7 +992,7 @@ pub fn stl_period(
 
     for &period in &candidates {
         // Perform STL decomposition for this period
-        match mstl_decompose(values, &[period as i32], InsufficientDataMode::Trend) {
+        match mstl_decompose(values, &[period as i32], InsufficientDataMode::Trend, false) {
             Ok(decomp) if decomp.decomposition_applied => {
                 // Calculate seasonal strength
                 // Seasonal strength = 1 - Var(remainder) / Var(remainder + seasonal)
@@ -1420,6 +1400,20 @@ fn validate_period(
     }
 }
 
+/// Snap a detected (possibly fractional) period to an expected integer period.
+///
+/// Returns the expected period with the smallest relative deviation
+/// `|detected - e| / e`, provided it is within `tolerance`, or `None` when no
+/// expected period is close enough (e.g. 12.67 snaps to 12 at tolerance 0.1,
+/// 10.0 does not).
+pub fn snap_period(detected: f64, expected: &[usize], tolerance: f64) -> Option<usize> {
+    let expected: Vec<f64> = expected.iter().map(|&e| e as f64).collect();
+    match validate_period(detected, Some(&expected), tolerance) {
+        (true, Some(matched), _) => Some(matched as usize),
+        _ => None,
+    }
+}
+
 /// Detect periods using the specified method with optional expected period validation.
 ///
 /// # Arguments
@@ -1881,6 +1875,21 @@ mod tests {
         assert_eq!(result.method, "cfd_autoperiod");
     }
 
+    #[test]
+    fn test_period_method_names_from_ffi() {
+        // `anofox_ts_detect_periods` selects the method by name
+        let parse = |s: &str| s.parse::<PeriodMethod>().unwrap();
+        assert_eq!(parse("lomb_scargle"), PeriodMethod::LombScargle);
+        assert_eq!(parse("Lomb-Scargle"), PeriodMethod::LombScargle);
+        assert_eq!(parse("autoperiod"), PeriodMethod::Autoperiod);
+        assert_eq!(parse("ssa"), PeriodMethod::Ssa);
+        assert_eq!(parse("unknown"), PeriodMethod::Fft);
+
+        let values = generate_seasonal_series(120, 12.0, 5.0);
+        let result = detect_periods(&values, parse("lomb_scargle"), None, None).unwrap();
+        assert_eq!(result.method, "lomb_scargle");
+    }
+
     #[test]
     fn test_detect_periods_autoperiod() {
         let values = generate_seasonal_series(120, 12.0, 5.0);
@@ -2008,6 +2017,16 @@ mod tests {
         assert_eq!(result.method, "ssa");
     }
 
+    #[test]
+    fn test_snap_period() {
+        assert_eq!(snap_period(12.674, &[7, 12, 365], 0.1), Some(12));
+        assert_eq!(snap_period(10.0, &[12], 0.1), None);
+        // The closest expected period wins when several are within tolerance
+        assert_eq!(snap_period(7.4, &[6, 7, 8], 0.2), Some(7));
+        assert_eq!(snap_period(12.0, &[], 0.1), None);
+        assert_eq!(snap_period(f64::NAN, &[12], 0.1), None);
+    }
+
     #[test]
     fn test_validate_period_helper() {
         // Test exact match
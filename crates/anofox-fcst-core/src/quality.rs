@@ -21,6 +21,8 @@ pub struct DataQuality {
     pub n_missing: usize,
     /// Whether the series is constant
     pub is_constant: bool,
+    /// Whether the series passes all threshold checks
+    pub passed: bool,
 }
 
 /// Quality report thresholds.
@@ -58,21 +60,61 @@ pub struct QualityReport {
     pub n_missing_issues: usize,
     /// Number of constant series
     pub n_constant: usize,
+    /// Number of series shorter than the minimum length
+    pub n_short: usize,
+    /// Number of series below the minimum non-zero ratio
+    pub n_sparse: usize,
     /// Total series analyzed
     pub n_total: usize,
+    /// Human-readable recommendations, one per detected issue type
+    pub recommendations: Vec<String>,
+}
+
+/// Outcome of each threshold check for one series.
+#[derive(Debug, Clone, Copy)]
+struct ThresholdChecks {
+    gap_issue: bool,
+    missing_issue: bool,
+    constant: bool,
+    short: bool,
+    sparse: bool,
+}
+
+impl ThresholdChecks {
+    /// Whether the series passes every check.
+    fn passed(&self) -> bool {
+        !(self.gap_issue || self.missing_issue || self.constant || self.short || self.sparse)
+    }
 }
 
 /// Compute data quality metrics for a series.
 pub fn compute_data_quality(values: &[Option<f64>], dates: Option<&[i64]>) -> Result<DataQuality> {
-    let n = values.len();
+    compute_data_quality_with_thresholds(values, dates, &QualityThresholds::default())
+}
 
-    if n == 0 {
-        return Ok(DataQuality::default());
-    }
+/// Compute data quality metrics for a series, judging pass/fail against
+/// custom thresholds.
+///
+/// The dimension scores do not depend on the thresholds; only `passed` does.
+pub fn compute_data_quality_with_thresholds(
+    values: &[Option<f64>],
+    dates: Option<&[i64]>,
+    thresholds: &QualityThresholds,
+) -> Result<DataQuality> {
+    Ok(assess_quality(values, dates, thresholds).0)
+}
+
+/// Score a series and run every threshold check on it.
+fn assess_quality(
+    values: &[Option<f64>],
+    dates: Option<&[i64]>,
+    thresholds: &QualityThresholds,
+) -> (DataQuality, ThresholdChecks) {
+    let n = values.len();
 
     // Count missing values
     let n_missing = values.iter().filter(|v| v.is_none()).count();
-    let _missing_ratio = n_missing as f64 / n as f64;
+    let missing_ratio = n_missing as f64 / n.max(1) as f64;
 
     // Count gaps in dates
     let n_gaps = if let Some(d) = dates {
@@ -80,7 +122,7 @@ pub fn compute_data_quality(values: &[Option<f64>], dates: Option<&[i64]>) -> Re
     } else {
         0
     };
-    let _gap_ratio = n_gaps as f64 / n.max(1) as f64;
+    let gap_ratio = n_gaps as f64 / n.max(1) as f64;
 
     // Check if constant
     let non_null: Vec<f64> = values.iter().filter_map(|v| *v).collect();
@@ -91,6 +133,24 @@ pub fn compute_data_quality(values: &[Option<f64>], dates: Option<&[i64]>) -> Re
         non_null.iter().all(|v| (v - first).abs() < f64::EPSILON)
     };
 
+    let nonzero_ratio = if non_null.is_empty() {
+        0.0
+    } else {
+        non_null.iter().filter(|&&v| v != 0.0).count() as f64 / non_null.len() as f64
+    };
+
+    let checks = ThresholdChecks {
+        gap_issue: gap_ratio > thresholds.max_gap_ratio,
+        missing_issue: missing_ratio > thresholds.max_missing_ratio,
+        constant: is_constant,
+        short: n < thresholds.min_length,
+        sparse: nonzero_ratio < thresholds.min_nonzero_ratio,
+    };
+
+    if n == 0 {
+        return (DataQuality::default(), checks);
+    }
+
     // Calculate scores
     let structural_score = calculate_structural_score(&non_null, n_missing);
     let temporal_score = calculate_temporal_score(n_gaps, n);
@@ -100,7 +160,7 @@ pub fn compute_data_quality(values: &[Option<f64>], dates: Option<&[i64]>) -> Re
     let overall_score =
         (structural_score + temporal_score + magnitude_score + behavioral_score) / 4.0;
 
-    Ok(DataQuality {
+    let quality = DataQuality {
         structural_score,
         temporal_score,
         magnitude_score,
@@ -109,10 +169,15 @@ pub fn compute_data_quality(values: &[Option<f64>], dates: Option<&[i64]>) -> Re
         n_gaps,
         n_missing,
         is_constant,
-    })
+        passed: checks.passed(),
+    };
+    (quality, checks)
 }
 
 /// Generate a quality report for multiple series.
+///
+/// A series counts as passed under exactly the same checks as
+/// [`compute_data_quality_with_thresholds`].
 pub fn generate_quality_report(
     series_list: &[Vec<Option<f64>>],
     thresholds: &QualityThresholds,
@@ -123,35 +188,58 @@ pub fn generate_quality_report(
     };
 
     for values in series_list {
-        let quality = compute_data_quality(values, None).unwrap_or_default();
-
-        let n = values.len();
-        let gap_ratio = quality.n_gaps as f64 / n.max(1) as f64;
-        let missing_ratio = quality.n_missing as f64 / n.max(1) as f64;
+        let (_, checks) = assess_quality(values, None, thresholds);
+
+        report.n_gap_issues += usize::from(checks.gap_issue);
+        report.n_missing_issues += usize::from(checks.missing_issue);
+        report.n_constant += usize::from(checks.constant);
+        report.n_short += usize::from(checks.short);
+        report.n_sparse += usize::from(checks.sparse);
+        report.n_passed += usize::from(checks.passed());
+    }
 
-        let has_gap_issues = gap_ratio > thresholds.max_gap_ratio;
-        let has_missing_issues = missing_ratio > thresholds.max_missing_ratio;
+    report.recommendations = build_recommendations(&report, thresholds);
+    report
+}
 
-        if has_gap_issues {
-            report.n_gap_issues += 1;
-        }
-        if has_missing_issues {
-            report.n_missing_issues += 1;
-        }
-        if quality.is_constant {
-            report.n_constant += 1;
-        }
+fn build_recommendations(report: &QualityReport, thresholds: &QualityThresholds) -> Vec<String> {
+    let mut recommendations = Vec::new();
 
-        if !has_gap_issues
-            && !has_missing_issues
-            && !quality.is_constant
-            && n >= thresholds.min_length
-        {
-            report.n_passed += 1;
-        }
+    if report.n_gap_issues > 0 {
+        recommendations.push(format!(
+            "{} series exceed the gap ratio limit of {:.0}%: fill gaps before forecasting",
+            report.n_gap_issues,
+            thresholds.max_gap_ratio * 100.0
+        ));
+    }
+    if report.n_missing_issues > 0 {
+        recommendations.push(format!(
+            "{} series exceed the missing ratio limit of {:.0}%: impute NULL values before forecasting",
+            report.n_missing_issues,
+            thresholds.max_missing_ratio * 100.0
+        ));
+    }
+    if report.n_constant > 0 {
+        recommendations.push(format!(
+            "{} series are constant: use a Naive model or exclude them",
+            report.n_constant
+        ));
+    }
+    if report.n_short > 0 {
+        recommendations.push(format!(
+            "{} series have fewer than {} observations: prefer simple models such as Naive or SES",
+            report.n_short, thresholds.min_length
+        ));
+    }
+    if report.n_sparse > 0 {
+        recommendations.push(format!(
+            "{} series have fewer than {:.0}% non-zero values: use an intermittent demand model such as Croston",
+            report.n_sparse,
+            thresholds.min_nonzero_ratio * 100.0
+        ));
     }
 
-    report
+    recommendations
 }
 
 // Helper functions
@@ -310,6 +398,105 @@ mod tests {
         assert!(!quality.is_constant);
     }
 
+    #[test]
+    fn test_quality_report_recommendations() {
+        let series = vec![
+            (1..=12).map(|v| Some(v as f64)).collect::<Vec<_>>(),
+            vec![Some(3.0); 12],
+            vec![Some(1.0), Some(2.0), Some(3.0)],
+        ];
+
+        let report = generate_quality_report(&series, &QualityThresholds::default());
+        assert_eq!(report.n_total, 3);
+        assert_eq!(report.n_passed, 1);
+        assert_eq!(report.n_constant, 1);
+        assert_eq!(report.n_short, 1);
+        assert_eq!(report.recommendations.len(), 2);
+        assert!(report.recommendations[0].contains("constant"));
+
+        let clean = generate_quality_report(&series[..1], &QualityThresholds::default());
+        assert!(clean.recommendations.is_empty());
+    }
+
+    #[test]
+    fn test_custom_thresholds_flip_pass() {
+        let mut values: Vec<Option<f64>> = (1..=20).map(|v| Some(v as f64)).collect();
+        values[3] = None;
+        values[7] = None;
+
+        // 10% missing passes the default 20% limit
+        let default = compute_data_quality(&values, None).unwrap();
+        assert!(default.passed);
+
+        let strict = QualityThresholds {
+            max_missing_ratio: 0.05,
+            ..Default::default()
+        };
+        let custom = compute_data_quality_with_thresholds(&values, None, &strict).unwrap();
+        assert!(!custom.passed);
+        assert_eq!(custom.overall_score, default.overall_score);
+    }
+
+    #[test]
+    fn test_min_nonzero_ratio_is_honoured() {
+        // 30% non-zero values: below the default 50% limit
+        let values: Vec<Option<f64>> = (0..20)
+            .map(|i| Some(if i % 10 < 3 { i as f64 + 1.0 } else { 0.0 }))
+            .collect();
+
+        let quality = compute_data_quality(&values, None).unwrap();
+        assert!(!quality.passed);
+
+        let lenient = QualityThresholds {
+            min_nonzero_ratio: 0.2,
+            ..Default::default()
+        };
+        assert!(
+            compute_data_quality_with_thresholds(&values, None, &lenient)
+                .unwrap()
+                .passed
+        );
+
+        let report = generate_quality_report(std::slice::from_ref(&values), &Default::default());
+        assert_eq!(report.n_sparse, 1);
+        assert_eq!(report.n_passed, 0);
+        assert!(report.recommendations[0].contains("non-zero"));
+    }
+
+    #[test]
+    fn test_report_and_series_agree_on_passed() {
+        let strict = QualityThresholds {
+            max_missing_ratio: 0.05,
+            min_length: 15,
+            ..Default::default()
+        };
+        let mut missing: Vec<Option<f64>> = (1..=20).map(|v| Some(v as f64)).collect();
+        missing[3] = None;
+        missing[9] = None;
+        let series = vec![
+            (1..=20).map(|v| Some(v as f64)).collect::<Vec<_>>(),
+            missing,
+            (1..=12).map(|v| Some(v as f64)).collect(),
+            vec![Some(0.0), Some(1.0), Some(0.0), Some(0.0)]
+                .into_iter()
+                .cycle()
+                .take(20)
+                .collect(),
+        ];
+
+        let report = generate_quality_report(&series, &strict);
+        let n_passed = series
+            .iter()
+            .filter(|s| {
+                compute_data_quality_with_thresholds(s, None, &strict)
+                    .unwrap()
+                    .passed
+            })
+            .count();
+        assert_eq!(report.n_passed, n_passed);
+        assert_eq!(report.n_passed, 1);
+    }
+
     #[test]
     fn test_constant_series() {
         let values: Vec<Option<f64>> = vec![Some(5.0); 10];
@@ -0,0 +1,274 @@
+//! Helpers for building exogenous regressor columns.
+//!
+//! The returned columns are aligned to the series dates and can be passed
+//! directly as `ExogenousData` rows.
+
+use crate::error::{ForecastError, Result};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+/// Microseconds per day, the unit of `window_days` in [`event_dummies`].
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+/// Flag each point that lies between `lo` and `hi` (inclusive) after some event.
+///
+/// Points and events share one unit, so this serves both observation offsets
+/// and calendar windows.
+fn flag_near_events(points: &[i64], sorted_events: &[i64], lo: i64, hi: i64) -> Vec<f64> {
+    points
+        .iter()
+        .map(|&p| {
+            // First event that is at most `hi` before this point
+            let idx = sorted_events.partition_point(|&e| e < p.saturating_sub(hi));
+            match sorted_events.get(idx) {
+                Some(&e) if e <= p.saturating_sub(lo) => 1.0,
+                _ => 0.0,
+            }
+        })
+        .collect()
+}
+
+/// Build holiday/event indicator columns aligned to `dates`.
+///
+/// Returns `window_before + window_after + 1` columns, one per offset from
+/// `-window_before` to `+window_after` observations relative to an event.
+/// Column `window_before` flags the event itself; earlier columns flag the
+/// lead-up and later columns the after-effect. Negative windows are treated
+/// as zero.
+///
+/// Events that do not coincide with one of `dates` are ignored, so pass the
+/// full date grid (history followed by the forecast horizon) to obtain both
+/// the historical and the future regressors in one call.
+///
+/// # Arguments
+/// * `dates` - Series timestamps (microseconds since epoch)
+/// * `event_dates` - Event timestamps in the same unit
+/// * `window_before` - Number of observations before each event to flag
+/// * `window_after` - Number of observations after each event to flag
+pub fn event_indicators(
+    dates: &[i64],
+    event_dates: &[i64],
+    window_before: i64,
+    window_after: i64,
+) -> Result<Vec<Vec<f64>>> {
+    let before = window_before.max(0);
+    let after = window_after.max(0);
+    before
+        .checked_add(after)
+        .and_then(|w| w.checked_add(1))
+        .and_then(|w| usize::try_from(w).ok())
+        .ok_or_else(|| ForecastError::InvalidParameter {
+            param: "window_before + window_after".to_string(),
+            value: format!("{} + {}", window_before, window_after),
+            reason: "Window is too large".to_string(),
+        })?;
+
+    // Work in observation positions: events become the index of their date
+    let position: HashMap<i64, i64> = dates
+        .iter()
+        .enumerate()
+        .map(|(i, &d)| (d, i as i64))
+        .collect();
+    let mut events: Vec<i64> = event_dates
+        .iter()
+        .filter_map(|e| position.get(e).copied())
+        .collect();
+    events.sort_unstable();
+    let rows: Vec<i64> = (0..dates.len() as i64).collect();
+
+    Ok((-before..=after)
+        .map(|offset| flag_near_events(&rows, &events, offset, offset))
+        .collect())
+}
+
+/// Build a single event dummy column aligned to `dates`.
+///
+/// A date is flagged with 1.0 when it lies within `window_days` calendar days
+/// of any event, and 0.0 otherwise. Unlike [`event_indicators`], events do not
+/// need to coincide with one of `dates` and all events share one column, so
+/// the regression estimates a single pooled event effect. A negative window
+/// is treated as zero (only exact matches are flagged).
+///
+/// # Arguments
+/// * `dates` - Series timestamps (microseconds since epoch)
+/// * `event_dates` - Event timestamps in the same unit
+/// * `window_days` - Half-width of the flagged window around each event, in days
+pub fn event_dummies(dates: &[i64], event_dates: &[i64], window_days: i64) -> Vec<Vec<f64>> {
+    let reach = window_days.max(0).saturating_mul(MICROS_PER_DAY);
+    let mut events = event_dates.to_vec();
+    events.sort_unstable();
+
+    vec![flag_near_events(dates, &events, -reach, reach)]
+}
+
+/// Sine/cosine columns for observations `start..start + len`.
+fn fourier_columns(start: usize, len: usize, period: f64, k: usize) -> Result<Vec<Vec<f64>>> {
+    if !period.is_finite() || period <= 0.0 {
+        return Err(ForecastError::InvalidParameter {
+            param: "period".to_string(),
+            value: period.to_string(),
+            reason: "Period must be positive".to_string(),
+        });
+    }
+    if k == 0 || 2.0 * k as f64 > period {
+        return Err(ForecastError::InvalidParameter {
+            param: "k".to_string(),
+            value: k.to_string(),
+            reason: format!("Must be between 1 and period / 2 ({})", period / 2.0),
+        });
+    }
+
+    let mut columns = Vec::with_capacity(2 * k);
+    for harmonic in 1..=k {
+        let omega = 2.0 * PI * harmonic as f64 / period;
+        let angles = (start..start + len).map(|t| omega * t as f64);
+        columns.push(angles.clone().map(f64::sin).collect());
+        columns.push(angles.map(f64::cos).collect());
+    }
+    Ok(columns)
+}
+
+/// Build Fourier seasonality regressors over the series length.
+///
+/// Returns `2k` columns ordered `sin(2π·1·t/period), cos(2π·1·t/period), ...,
+/// sin(2π·k·t/period), cos(2π·k·t/period)` for `t = 0..n`. The period may be
+/// fractional (e.g. 365.25 for yearly seasonality in daily data).
+///
+/// # Arguments
+/// * `n` - Number of observations
+/// * `period` - Seasonal period in observations
+/// * `k` - Number of harmonics (at most `period / 2`)
+pub fn fourier_terms(n: usize, period: f64, k: usize) -> Result<Vec<Vec<f64>>> {
+    fourier_columns(0, n, period, k)
+}
+
+/// Continue [`fourier_terms`] over the forecast horizon.
+///
+/// Returns the same `2k` columns for `t = n..n + horizon`, suitable as the
+/// `future` rows of `ExogenousData`.
+///
+/// # Arguments
+/// * `n` - Number of historical observations
+/// * `horizon` - Number of future observations
+/// * `period` - Seasonal period in observations
+/// * `k` - Number of harmonics (at most `period / 2`)
+pub fn fourier_terms_future(
+    n: usize,
+    horizon: usize,
+    period: f64,
+    k: usize,
+) -> Result<Vec<Vec<f64>>> {
+    fourier_columns(n, horizon, period, k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400_000_000;
+
+    #[test]
+    fn test_event_indicators_no_window() {
+        let dates: Vec<i64> = (0..5).map(|i| i * DAY).collect();
+        let cols = event_indicators(&dates, &[2 * DAY], 0, 0).unwrap();
+
+        assert_eq!(cols.len(), 1);
+        assert_eq!(cols[0], vec![0.0, 0.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_event_indicators_lead_lag() {
+        let dates: Vec<i64> = (0..6).map(|i| i * DAY).collect();
+        let cols = event_indicators(&dates, &[DAY, 10 * DAY], 2, 1).unwrap();
+
+        // Offsets -2, -1, 0, +1
+        assert_eq!(cols.len(), 4);
+        // Lead of 2 falls before the series start and is dropped
+        assert_eq!(cols[0], vec![0.0; 6]);
+        assert_eq!(cols[1], vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(cols[2], vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(cols[3], vec![0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_event_dummies_window() {
+        let dates: Vec<i64> = (0..10).map(|i| i * DAY).collect();
+        // The second event falls between grid points (day 7.5)
+        let cols = event_dummies(&dates, &[2 * DAY, 7 * DAY + DAY / 2], 1);
+
+        assert_eq!(cols.len(), 1);
+        assert_eq!(
+            cols[0],
+            vec![0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn test_event_dummies_exact_match_only() {
+        let dates: Vec<i64> = (0..5).map(|i| i * DAY).collect();
+        let cols = event_dummies(&dates, &[3 * DAY], -2);
+        assert_eq!(cols[0], vec![0.0, 0.0, 0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_fourier_terms_period_and_orthogonality() {
+        let period = 12.0;
+        let n = 48;
+        let cols = fourier_terms(n, period, 3).unwrap();
+        assert_eq!(cols.len(), 6);
+        assert!(cols.iter().all(|c| c.len() == n));
+
+        // Each column repeats every period
+        for col in &cols {
+            assert!(col
+                .iter()
+                .zip(&col[12..])
+                .all(|(a, b)| (a - b).abs() < 1e-9));
+        }
+
+        // Over whole cycles the columns are mutually orthogonal
+        for (i, a) in cols.iter().enumerate() {
+            for (j, b) in cols.iter().enumerate().skip(i + 1) {
+                let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+                assert!(dot.abs() < 1e-9, "columns {} and {}: {}", i, j, dot);
+            }
+        }
+
+        // First harmonic starts at sin(0) = 0, cos(0) = 1
+        assert_eq!(cols[0][0], 0.0);
+        assert_eq!(cols[1][0], 1.0);
+    }
+
+    #[test]
+    fn test_fourier_terms_future_continues_history() {
+        let all = fourier_terms(30, 7.5, 2).unwrap();
+        let history = fourier_terms(20, 7.5, 2).unwrap();
+        let future = fourier_terms_future(20, 10, 7.5, 2).unwrap();
+
+        for ((a, h), f) in all.iter().zip(&history).zip(&future) {
+            assert_eq!(&a[..20], &h[..]);
+            assert_eq!(&a[20..], &f[..]);
+        }
+    }
+
+    #[test]
+    fn test_fourier_terms_invalid() {
+        assert!(fourier_terms(10, 0.0, 1).is_err());
+        assert!(fourier_terms(10, 12.0, 0).is_err());
+        assert!(fourier_terms(10, 12.0, 7).is_err());
+    }
+
+    #[test]
+    fn test_event_indicators_empty() {
+        let cols = event_indicators(&[], &[DAY], 1, 1).unwrap();
+        assert_eq!(cols.len(), 3);
+        assert!(cols.iter().all(|c| c.is_empty()));
+    }
+
+    #[test]
+    fn test_event_indicators_window_overflow() {
+        let dates: Vec<i64> = (0..5).map(|i| i * DAY).collect();
+        assert!(event_indicators(&dates, &[DAY], i64::MAX, 1).is_err());
+        assert!(event_indicators(&dates, &[DAY], 1, i64::MAX).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+//! Rolling-window statistics.
+//!
+//! Each function returns a vector aligned to the input where position `i`
+//! summarises the trailing window `values[i + 1 - window..=i]`. NaN values
+//! are treated as missing and skipped; a position is NaN when its window
+//! holds fewer than `min_periods` observations.
+
+/// Aggregation applied over each rolling window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RollingAgg {
+    /// Arithmetic mean
+    #[default]
+    Mean,
+    /// Sample standard deviation (n - 1 denominator)
+    Std,
+    /// Minimum
+    Min,
+    /// Maximum
+    Max,
+}
+
+/// Compute a rolling aggregation.
+///
+/// # Arguments
+/// * `values` - Series values (NaN = missing)
+/// * `window` - Window length; 0 yields an all-NaN result
+/// * `min_periods` - Minimum observations required in a window (at least 1)
+/// * `agg` - Aggregation to apply
+pub fn rolling(values: &[f64], window: usize, min_periods: usize, agg: RollingAgg) -> Vec<f64> {
+    if window == 0 {
+        return vec![f64::NAN; values.len()];
+    }
+
+    let min_periods = min_periods.max(1);
+    (0..values.len())
+        .map(|i| {
+            let start = (i + 1).saturating_sub(window);
+            let observed: Vec<f64> = values[start..=i]
+                .iter()
+                .copied()
+                .filter(|v| !v.is_nan())
+                .collect();
+            if observed.len() < min_periods {
+                return f64::NAN;
+            }
+            aggregate(&observed, agg)
+        })
+        .collect()
+}
+
+/// Rolling mean over a trailing window.
+pub fn rolling_mean(values: &[f64], window: usize, min_periods: usize) -> Vec<f64> {
+    rolling(values, window, min_periods, RollingAgg::Mean)
+}
+
+/// Rolling sample standard deviation over a trailing window.
+///
+/// Windows with a single observation are NaN regardless of `min_periods`.
+pub fn rolling_std(values: &[f64], window: usize, min_periods: usize) -> Vec<f64> {
+    rolling(values, window, min_periods, RollingAgg::Std)
+}
+
+/// Rolling minimum over a trailing window.
+pub fn rolling_min(values: &[f64], window: usize, min_periods: usize) -> Vec<f64> {
+    rolling(values, window, min_periods, RollingAgg::Min)
+}
+
+/// Rolling maximum over a trailing window.
+pub fn rolling_max(values: &[f64], window: usize, min_periods: usize) -> Vec<f64> {
+    rolling(values, window, min_periods, RollingAgg::Max)
+}
+
+fn aggregate(observed: &[f64], agg: RollingAgg) -> f64 {
+    let n = observed.len() as f64;
+    match agg {
+        RollingAgg::Mean => observed.iter().sum::<f64>() / n,
+        RollingAgg::Std => {
+            if observed.len() < 2 {
+                return f64::NAN;
+            }
+            let mean = observed.iter().sum::<f64>() / n;
+            (observed.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+        }
+        RollingAgg::Min => observed.iter().copied().fold(f64::INFINITY, f64::min),
+        RollingAgg::Max => observed.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_rolling_mean_min_periods() {
+        let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        let result = rolling_mean(&values, 3, 3);
+        assert!(result[0].is_nan());
+        assert!(result[1].is_nan());
+        assert_relative_eq!(result[2], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(result[4], 4.0, epsilon = 1e-12);
+
+        let partial = rolling_mean(&values, 3, 1);
+        assert_relative_eq!(partial[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(partial[1], 1.5, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_rolling_std_min_max() {
+        let values = vec![4.0, 1.0, 7.0, 3.0];
+        let std = rolling_std(&values, 2, 2);
+        assert!(std[0].is_nan());
+        assert_relative_eq!(std[1], (4.5_f64).sqrt(), epsilon = 1e-12);
+
+        assert_eq!(rolling_min(&values, 2, 1), vec![4.0, 1.0, 1.0, 3.0]);
+        assert_eq!(rolling_max(&values, 2, 1), vec![4.0, 4.0, 7.0, 7.0]);
+    }
+
+    #[test]
+    fn test_rolling_skips_missing() {
+        let values = vec![1.0, f64::NAN, 3.0, f64::NAN, f64::NAN];
+        let result = rolling_mean(&values, 3, 2);
+        assert!(result[1].is_nan());
+        assert_relative_eq!(result[2], 2.0, epsilon = 1e-12);
+        // Only one observation left in the last window
+        assert!(result[4].is_nan());
+        assert_eq!(rolling_mean(&values, 0, 1).len(), values.len());
+    }
+}
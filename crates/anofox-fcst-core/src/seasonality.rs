@@ -380,6 +380,20 @@ pub fn detect_seasonality(values: &[f64], max_period: Option<usize>) -> Result<V
 pub fn analyze_seasonality(
     values: &[f64],
     max_period: Option<usize>,
+) -> Result<SeasonalityAnalysis> {
+    analyze_seasonality_with_strength(values, max_period, None)
+}
+
+/// Analyze seasonality, measuring the strength of each detected period with
+/// `strength_method`.
+///
+/// `None` keeps the default estimator of [`analyze_seasonality`] (the
+/// autocorrelation at the period lag). Wavelet strength copes best with
+/// amplitude-modulated seasonality.
+pub fn analyze_seasonality_with_strength(
+    values: &[f64],
+    max_period: Option<usize>,
+    strength_method: Option<StrengthMethod>,
 ) -> Result<SeasonalityAnalysis> {
     let periods = detect_seasonality(values, max_period)?;
 
@@ -400,6 +414,12 @@ pub fn analyze_seasonality(
 
     let mut strengths = Vec::with_capacity(periods.len());
     for &period in &periods {
+        if let Some(method) = strength_method {
+            let strength = seasonal_strength(values, period as f64, method)?;
+            strengths.push(strength.clamp(0.0, 1.0));
+            continue;
+        }
+
         let lag = period as usize;
         if lag >= values.len() {
             strengths.push(0.0);
@@ -460,6 +480,81 @@ fn compute_trend_strength(values: &[f64]) -> f64 {
     (ss_xy.powi(2) / (ss_xx * ss_yy)).sqrt().clamp(0.0, 1.0)
 }
 
+/// Compute trend strength at a fixed seasonal period.
+///
+/// Performs a classical additive decomposition (centered moving average of
+/// length `period` for the trend, phase means of the detrended series for the
+/// seasonal component) and returns `max(0, 1 - Var(R) / Var(T + R))`
+/// (Wang, Smith & Hyndman, 2006). For `period < 2` there is no seasonal
+/// component to remove and the regression-based trend strength is returned.
+///
+/// # Arguments
+/// * `values` - Time series values
+/// * `period` - Seasonal period (rounded to the nearest integer)
+///
+/// # Returns
+/// Trend strength (0 to 1)
+pub fn trend_strength_at_period(values: &[f64], period: f64) -> Result<f64> {
+    if period <= 0.0 {
+        return Err(ForecastError::InvalidParameter {
+            param: "period".to_string(),
+            value: period.to_string(),
+            reason: "Period must be positive".to_string(),
+        });
+    }
+
+    let p = period.round() as usize;
+    if p < 2 {
+        return Ok(compute_trend_strength(values));
+    }
+
+    let n = values.len();
+    if n < 2 * p {
+        return Err(ForecastError::InsufficientData {
+            needed: 2 * p,
+            got: n,
+        });
+    }
+
+    // Centered moving average (2 x p MA for even periods)
+    let half = p / 2;
+    let trend = crate::decomposition::centered_moving_average(values, p);
+
+    // Seasonal component: centered phase means of the detrended series
+    let mut phase_sum = vec![0.0; p];
+    let mut phase_count = vec![0usize; p];
+    for i in half..n - half {
+        phase_sum[i % p] += values[i] - trend[i];
+        phase_count[i % p] += 1;
+    }
+    let mut seasonal: Vec<f64> = phase_sum
+        .iter()
+        .zip(&phase_count)
+        .map(|(s, &c)| if c > 0 { s / c as f64 } else { 0.0 })
+        .collect();
+    let seasonal_mean = seasonal.iter().sum::<f64>() / p as f64;
+    seasonal.iter_mut().for_each(|s| *s -= seasonal_mean);
+
+    let mut remainder = Vec::with_capacity(n - 2 * half);
+    let mut deseasonalized = Vec::with_capacity(n - 2 * half);
+    for i in half..n - half {
+        let r = values[i] - trend[i] - seasonal[i % p];
+        remainder.push(r);
+        deseasonalized.push(trend[i] + r);
+    }
+
+    let var = |xs: &[f64]| {
+        let m = xs.iter().sum::<f64>() / xs.len() as f64;
+        xs.iter().map(|x| (x - m).powi(2)).sum::<f64>() / xs.len() as f64
+    };
+    let var_deseasonalized = var(&deseasonalized);
+    if var_deseasonalized < f64::EPSILON {
+        return Ok(0.0);
+    }
+
+    Ok((1.0 - var(&remainder) / var_deseasonalized).clamp(0.0, 1.0))
+}
+
 // ============================================================================
 // fdars-core wrapped functions
 // ============================================================================
@@ -671,8 +766,8 @@ pub fn classify_seasonality(
 
     let result = fdars_classify_seasonality(
         values,
-        n,
         1,
+        n,
         &argvals,
         period,
         strength_threshold,
@@ -797,8 +892,8 @@ pub fn detect_amplitude_modulation(
 
     let result = fdars_detect_amplitude_modulation_wavelet(
         values,
-        n,
         1,
+        n,
         &argvals,
         period,
         mod_thresh,
@@ -844,6 +939,55 @@ mod tests {
         assert!(analysis.seasonal_strength > 0.1);
     }
 
+    #[test]
+    fn test_analyze_seasonality_strength_methods() {
+        // Seasonal amplitude grows from 1 to 13 over the series
+        let values: Vec<f64> = (0..144)
+            .map(|i| (1.0 + i as f64 / 12.0) * (2.0 * PI * i as f64 / 12.0).sin())
+            .collect();
+
+        let default = analyze_seasonality(&values, Some(24)).unwrap();
+        let unchanged = analyze_seasonality_with_strength(&values, Some(24), None).unwrap();
+        assert_eq!(default.strengths, unchanged.strengths);
+
+        for method in [
+            StrengthMethod::Variance,
+            StrengthMethod::Spectral,
+            StrengthMethod::Wavelet,
+        ] {
+            let analysis =
+                analyze_seasonality_with_strength(&values, Some(24), Some(method)).unwrap();
+            assert_eq!(analysis.periods, default.periods);
+            let expected =
+                seasonal_strength(&values, analysis.primary_period as f64, method).unwrap();
+            assert!((analysis.seasonal_strength - expected.clamp(0.0, 1.0)).abs() < 1e-12);
+            assert!(
+                analysis.seasonal_strength > 0.1,
+                "{:?}: {}",
+                method,
+                analysis.seasonal_strength
+            );
+        }
+    }
+
+    #[test]
+    fn test_trend_strength_at_period() {
+        let trending: Vec<f64> = (0..96)
+            .map(|i| 0.5 * i as f64 + 3.0 * (2.0 * PI * i as f64 / 12.0).sin())
+            .collect();
+        let strong = trend_strength_at_period(&trending, 12.0).unwrap();
+        assert!(strong > 0.9, "expected strong trend, got {}", strong);
+
+        let flat: Vec<f64> = (0..96)
+            .map(|i| 3.0 * (2.0 * PI * i as f64 / 12.0).sin() + ((i * 7 + 3) % 11) as f64 * 0.1)
+            .collect();
+        let weak = trend_strength_at_period(&flat, 12.0).unwrap();
+        assert!(weak < 0.5, "expected weak trend, got {}", weak);
+
+        assert!(trend_strength_at_period(&flat[..20], 12.0).is_err());
+        assert!(trend_strength_at_period(&flat, 0.0).is_err());
+    }
+
     #[test]
     fn test_seasonal_strength_variance() {
         let values = generate_seasonal_series(120, 12.0, 5.0);
@@ -883,38 +1027,86 @@ mod tests {
     #[test]
     fn test_classify_seasonality() {
         let values = generate_seasonal_series(120, 12.0, 5.0);
-        let result = classify_seasonality(&values, 12.0, None, None);
+        let result = classify_seasonality(&values, 12.0, None, None).unwrap();
 
-        // Just verify function runs without error
-        assert!(result.is_ok());
+        assert!(result.is_seasonal);
+        assert_ne!(result.classification, SeasonalType::NonSeasonal);
+
+        // Deterministic pseudo-random noise carries no seasonal signal
+        let mut state: u64 = 12345;
+        let noise: Vec<f64> = (0..120)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                ((state >> 33) as f64 / (1u64 << 31) as f64) - 0.5
+            })
+            .collect();
+        let result = classify_seasonality(&noise, 12.0, None, None).unwrap();
+
+        assert!(!result.is_seasonal);
+        assert_eq!(result.classification, SeasonalType::NonSeasonal);
     }
 
     #[test]
     fn test_detect_seasonality_changes() {
-        // Create series with changing seasonality
+        // Strong seasonality that gives way to noise halfway through. Strength is a
+        // variance ratio, so a pure amplitude change on a clean sine is not a change.
+        let mut state: u64 = 42;
         let values: Vec<f64> = (0..240)
             .map(|i| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                let noise = ((state >> 33) as f64 / (1u64 << 31) as f64) - 0.5;
                 if i < 120 {
-                    5.0 * (2.0 * PI * i as f64 / 12.0).sin()
+                    5.0 * (2.0 * PI * i as f64 / 12.0).sin() + 0.1 * noise
                 } else {
-                    0.5 * (2.0 * PI * i as f64 / 12.0).sin() // Weaker seasonality
+                    noise
                 }
             })
             .collect();
 
-        let result = detect_seasonality_changes(&values, 12.0, Some(0.3), None, None);
+        let result = detect_seasonality_changes(&values, 12.0, Some(0.3), None, None).unwrap();
 
-        // Just verify function runs without error
-        assert!(result.is_ok());
+        assert_eq!(result.strength_curve.len(), values.len());
+        let change = result
+            .change_points
+            .iter()
+            .find(|cp| cp.change_type == ChangePointType::Cessation)
+            .expect("cessation of seasonality should be flagged");
+        assert!((84..=156).contains(&change.index));
+        assert!(change.strength_before > change.strength_after);
     }
 
     #[test]
     fn test_instantaneous_period() {
         let values = generate_seasonal_series(120, 12.0, 5.0);
-        let result = instantaneous_period(&values);
+        let result = instantaneous_period(&values).unwrap();
 
-        // Just verify function runs without error
-        assert!(result.is_ok());
+        assert_eq!(result.period.len(), values.len());
+        assert_eq!(result.frequency.len(), values.len());
+        assert_eq!(result.amplitude.len(), values.len());
+    }
+
+    #[test]
+    fn test_instantaneous_period_chirp() {
+        // Linear chirp whose frequency falls from 1/8 to 1/24, so the period lengthens
+        let n = 240;
+        let (f0, f1) = (1.0 / 8.0, 1.0 / 24.0);
+        let values: Vec<f64> = (0..n)
+            .map(|i| {
+                let t = i as f64;
+                (2.0 * PI * (f0 * t + (f1 - f0) * t * t / (2.0 * n as f64))).sin()
+            })
+            .collect();
+
+        let result = instantaneous_period(&values).unwrap();
+
+        // Compare interior windows to stay clear of Hilbert edge effects
+        let window_mean = |range: std::ops::Range<usize>| {
+            let len = range.len() as f64;
+            result.period[range].iter().sum::<f64>() / len
+        };
+        let early = window_mean(30..90);
+        let late = window_mean(150..210);
+        assert!(late > early, "early {early}, late {late}");
     }
 
     #[test]
@@ -933,6 +1125,26 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_detect_amplitude_modulation_stable_vs_growing() {
+        let constant = generate_seasonal_series(240, 12.0, 5.0);
+        let result = detect_amplitude_modulation(&constant, 12.0, None, None).unwrap();
+
+        assert!(result.is_seasonal);
+        assert!(!result.has_modulation);
+        assert_eq!(result.modulation_type, AmplitudeModulationType::Stable);
+
+        // Amplitude grows roughly tenfold over the series
+        let growing: Vec<f64> = (0..240)
+            .map(|i| (0.01 * i as f64).exp() * (2.0 * PI * i as f64 / 12.0).sin())
+            .collect();
+        let result = detect_amplitude_modulation(&growing, 12.0, None, None).unwrap();
+
+        assert!(result.has_modulation);
+        assert_eq!(result.modulation_type, AmplitudeModulationType::Emerging);
+        assert!(result.amplitude_trend > 0.0);
+    }
+
     #[test]
     fn test_invalid_period() {
         let values = generate_seasonal_series(120, 12.0, 5.0);
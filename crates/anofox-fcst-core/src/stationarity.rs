@@ -0,0 +1,392 @@
+//! Unit-root and stationarity tests.
+//!
+//! This module provides two complementary tests used to decide whether a
+//! series needs differencing:
+//!
+//! - **ADF** (Augmented Dickey-Fuller): null hypothesis is a unit root, so a
+//!   small p-value indicates stationarity
+//! - **KPSS** (Kwiatkowski-Phillips-Schmidt-Shin): null hypothesis is level
+//!   stationarity, so a small p-value indicates a unit root
+//!
+//! # Example Usage
+//!
+//! ```
+//! use anofox_fcst_core::stationarity::adf_test;
+//!
+//! // Bounded, mean-reverting sequence
+//! let values: Vec<f64> = (0..200).map(|i| ((i * 37) % 17) as f64).collect();
+//! let result = adf_test(&values, Some(2)).unwrap();
+//! assert!(result.is_stationary);
+//! ```
+
+use crate::error::{ForecastError, Result};
+use statrs::function::erf::erfc;
+
+/// Significance level used for the `is_stationary` decision.
+const SIGNIFICANCE: f64 = 0.05;
+
+/// Result of a stationarity test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StationarityResult {
+    /// Test statistic
+    pub statistic: f64,
+    /// Approximate p-value
+    pub p_value: f64,
+    /// Number of lags used (augmentation lags for ADF, bandwidth for KPSS)
+    pub n_lags: usize,
+    /// Whether the series is judged stationary at the 5% level
+    pub is_stationary: bool,
+}
+
+/// Schwert's rule of thumb for the maximum lag: `floor(12 * (n / 100)^(1/4))`.
+fn schwert_lag(n: usize) -> usize {
+    (12.0 * (n as f64 / 100.0).powf(0.25)).floor() as usize
+}
+
+fn validate_values(values: &[f64], min_len: usize) -> Result<()> {
+    if values.len() < min_len {
+        return Err(ForecastError::InsufficientData {
+            needed: min_len,
+            got: values.len(),
+        });
+    }
+    if values.iter().any(|v| !v.is_finite()) {
+        return Err(ForecastError::InvalidInput(
+            "Values must be finite".to_string(),
+        ));
+    }
+    let first = values[0];
+    if values.iter().all(|v| (v - first).abs() < f64::EPSILON) {
+        return Err(ForecastError::InvalidInput(
+            "Series is constant".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Standard normal CDF.
+fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// Invert a small symmetric positive-definite matrix by Gauss-Jordan elimination.
+fn invert(matrix: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let k = matrix.len();
+    let mut a: Vec<Vec<f64>> = matrix
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            let mut extended = row.clone();
+            extended.extend((0..k).map(|j| if i == j { 1.0 } else { 0.0 }));
+            extended
+        })
+        .collect();
+
+    for col in 0..k {
+        let pivot = (col..k).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+
+        let scale = a[col][col];
+        for v in a[col].iter_mut() {
+            *v /= scale;
+        }
+        let pivot_row = a[col].clone();
+        for (i, row) in a.iter_mut().enumerate() {
+            if i != col {
+                let factor = row[col];
+                for (v, p) in row.iter_mut().zip(&pivot_row) {
+                    *v -= factor * p;
+                }
+            }
+        }
+    }
+
+    Some(a.into_iter().map(|row| row[k..].to_vec()).collect())
+}
+
+/// Fitted ADF regression for a given number of augmentation lags.
+struct AdfFit {
+    /// t-statistic of the lagged level coefficient
+    t_stat: f64,
+    /// Sum of squared residuals
+    ssr: f64,
+    /// Number of observations used
+    nobs: usize,
+}
+
+/// Regress `dy[t]` on `[1, y[t], dy[t-1], ..., dy[t-lags]]` for `t >= start`.
+fn adf_regression(values: &[f64], diffs: &[f64], lags: usize, start: usize) -> Result<AdfFit> {
+    let k = lags + 2;
+    let rows: Vec<(Vec<f64>, f64)> = (start..diffs.len())
+        .map(|t| {
+            let mut x = Vec::with_capacity(k);
+            x.push(1.0);
+            x.push(values[t]);
+            x.extend((1..=lags).map(|i| diffs[t - i]));
+            (x, diffs[t])
+        })
+        .collect();
+    let nobs = rows.len();
+
+    let mut xtx = vec![vec![0.0; k]; k];
+    let mut xty = vec![0.0; k];
+    for (x, y) in &rows {
+        for ((xi, xty_i), xtx_row) in x.iter().zip(xty.iter_mut()).zip(xtx.iter_mut()) {
+            *xty_i += xi * y;
+            for (xj, cell) in x.iter().zip(xtx_row.iter_mut()) {
+                *cell += xi * xj;
+            }
+        }
+    }
+
+    let inverse = invert(&xtx)
+        .ok_or_else(|| ForecastError::ComputationError("ADF regression is singular".to_string()))?;
+    let beta: Vec<f64> = inverse
+        .iter()
+        .map(|row| row.iter().zip(&xty).map(|(a, b)| a * b).sum())
+        .collect();
+
+    let ssr: f64 = rows
+        .iter()
+        .map(|(x, y)| {
+            let fitted: f64 = x.iter().zip(&beta).map(|(a, b)| a * b).sum();
+            (y - fitted).powi(2)
+        })
+        .sum();
+
+    let sigma2 = ssr / (nobs - k) as f64;
+    let se = (sigma2 * inverse[1][1]).sqrt();
+    if !se.is_finite() || se <= 0.0 {
+        return Err(ForecastError::ComputationError(
+            "ADF regression has zero residual variance".to_string(),
+        ));
+    }
+
+    Ok(AdfFit {
+        t_stat: beta[1] / se,
+        ssr,
+        nobs,
+    })
+}
+
+/// MacKinnon (1994) approximate p-value for the constant-only ADF statistic.
+fn adf_p_value(tau: f64) -> f64 {
+    const TAU_MAX: f64 = 2.74;
+    const TAU_MIN: f64 = -18.83;
+    const TAU_STAR: f64 = -1.61;
+    const SMALL_P: [f64; 3] = [2.1659, 1.4412, 0.038269];
+    const LARGE_P: [f64; 4] = [1.7339, 0.93202, -0.12745, -0.010368];
+
+    if tau > TAU_MAX {
+        return 1.0;
+    }
+    if tau < TAU_MIN {
+        return 0.0;
+    }
+    let coeffs: &[f64] = if tau <= TAU_STAR { &SMALL_P } else { &LARGE_P };
+    let z = coeffs.iter().rev().fold(0.0, |acc, c| acc * tau + c);
+    normal_cdf(z)
+}
+
+/// Augmented Dickey-Fuller unit-root test (constant, no trend).
+///
+/// Fits `Δy_t = α + γ·y_{t-1} + Σ β_i·Δy_{t-i} + ε_t` and tests `γ = 0`.
+/// When `max_lag` is `None`, the number of augmentation lags is chosen by AIC
+/// between 0 and Schwert's `12·(n/100)^¼`; otherwise exactly `max_lag` lags
+/// are used.
+///
+/// # Arguments
+/// * `values` - Time series values
+/// * `max_lag` - Number of augmentation lags (None for automatic selection)
+///
+/// # Returns
+/// * `Result<StationarityResult>` - Test statistic, MacKinnon p-value, lags used
+///   and whether the unit root is rejected at the 5% level
+pub fn adf_test(values: &[f64], max_lag: Option<usize>) -> Result<StationarityResult> {
+    let n = values.len();
+    // Each lag costs one observation and one parameter; keep a few degrees of freedom
+    let lag_limit = n.saturating_sub(5) / 2;
+    let lags = max_lag.unwrap_or_else(|| schwert_lag(n).min(lag_limit));
+    validate_values(values, lags.saturating_mul(2).saturating_add(5))?;
+
+    let diffs: Vec<f64> = values.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let lags = if max_lag.is_some() {
+        lags
+    } else {
+        // Compare candidate orders on the common sample
+        let mut best = (f64::INFINITY, 0);
+        for p in 0..=lags {
+            let fit = adf_regression(values, &diffs, p, lags)?;
+            let nobs = fit.nobs as f64;
+            let aic = nobs * (fit.ssr / nobs).ln() + 2.0 * (p + 2) as f64;
+            if aic < best.0 {
+                best = (aic, p);
+            }
+        }
+        best.1
+    };
+
+    let fit = adf_regression(values, &diffs, lags, lags)?;
+    let p_value = adf_p_value(fit.t_stat);
+
+    Ok(StationarityResult {
+        statistic: fit.t_stat,
+        p_value,
+        n_lags: lags,
+        is_stationary: p_value < SIGNIFICANCE,
+    })
+}
+
+/// KPSS level-stationarity test.
+///
+/// The long-run variance uses a Bartlett kernel with `n_lags` bandwidth
+/// (Schwert's `12·(n/100)^¼` when `None`). The p-value is interpolated from
+/// the Kwiatkowski et al. (1992) table and clamped to [0.01, 0.10].
+///
+/// # Arguments
+/// * `values` - Time series values
+/// * `n_lags` - Bartlett bandwidth (None for automatic selection)
+///
+/// # Returns
+/// * `Result<StationarityResult>` - Test statistic, p-value, bandwidth used and
+///   whether level stationarity is retained at the 5% level
+pub fn kpss_test(values: &[f64], n_lags: Option<usize>) -> Result<StationarityResult> {
+    const CRITICAL: [(f64, f64); 4] = [(0.347, 0.10), (0.463, 0.05), (0.574, 0.025), (0.739, 0.01)];
+
+    validate_values(values, 3)?;
+    let n = values.len();
+    let lags = n_lags.unwrap_or_else(|| schwert_lag(n)).min(n - 1);
+
+    let mean = values.iter().sum::<f64>() / n as f64;
+    let resid: Vec<f64> = values.iter().map(|v| v - mean).collect();
+
+    let mut long_run: f64 = resid.iter().map(|e| e * e).sum();
+    for s in 1..=lags {
+        let weight = 1.0 - s as f64 / (lags + 1) as f64;
+        let cov: f64 = resid[s..].iter().zip(&resid).map(|(a, b)| a * b).sum();
+        long_run += 2.0 * weight * cov;
+    }
+    long_run /= n as f64;
+
+    let mut partial = 0.0;
+    let eta = resid
+        .iter()
+        .map(|e| {
+            partial += e;
+            partial * partial
+        })
+        .sum::<f64>()
+        / (n as f64 * n as f64 * long_run);
+
+    let p_value = if eta <= CRITICAL[0].0 {
+        CRITICAL[0].1
+    } else if eta >= CRITICAL[3].0 {
+        CRITICAL[3].1
+    } else {
+        let i = CRITICAL.windows(2).position(|w| eta < w[1].0).unwrap_or(2);
+        let (x0, p0) = CRITICAL[i];
+        let (x1, p1) = CRITICAL[i + 1];
+        p0 + (eta - x0) / (x1 - x0) * (p1 - p0)
+    };
+
+    Ok(StationarityResult {
+        statistic: eta,
+        p_value,
+        n_lags: lags,
+        is_stationary: p_value > SIGNIFICANCE,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise(n: usize, seed: u64) -> Vec<f64> {
+        let mut state = seed;
+        (0..n)
+            .map(|_| {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5
+            })
+            .collect()
+    }
+
+    fn random_walk(n: usize, seed: u64) -> Vec<f64> {
+        let mut level = 0.0;
+        noise(n, seed)
+            .into_iter()
+            .map(|e| {
+                level += e;
+                level
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_adf_white_noise_is_stationary() {
+        let result = adf_test(&noise(500, 42), None).unwrap();
+        assert!(result.statistic < -2.86, "statistic {}", result.statistic);
+        assert!(result.p_value < 0.05);
+        assert!(result.is_stationary);
+    }
+
+    #[test]
+    fn test_adf_random_walk_is_not_stationary() {
+        let result = adf_test(&random_walk(500, 42), None).unwrap();
+        assert!(result.p_value > 0.05, "p-value {}", result.p_value);
+        assert!(!result.is_stationary);
+    }
+
+    #[test]
+    fn test_adf_fixed_lag() {
+        let result = adf_test(&noise(200, 7), Some(3)).unwrap();
+        assert_eq!(result.n_lags, 3);
+    }
+
+    #[test]
+    fn test_adf_p_value_critical_points() {
+        // MacKinnon critical values for the constant-only case
+        assert!((adf_p_value(-3.43) - 0.01).abs() < 0.002);
+        assert!((adf_p_value(-2.86) - 0.05).abs() < 0.005);
+        assert!((adf_p_value(-2.57) - 0.10).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_kpss_white_noise_is_stationary() {
+        let result = kpss_test(&noise(500, 42), None).unwrap();
+        assert!(result.is_stationary, "statistic {}", result.statistic);
+    }
+
+    #[test]
+    fn test_kpss_drifting_walk_is_not_stationary() {
+        // A driftless walk can wander back to its mean, so add a drift that
+        // keeps it away from its starting level whatever the seed
+        for seed in 1..=10 {
+            let walk: Vec<f64> = random_walk(500, seed)
+                .into_iter()
+                .enumerate()
+                .map(|(i, v)| v + 0.1 * i as f64)
+                .collect();
+            let result = kpss_test(&walk, None).unwrap();
+            assert!(
+                result.statistic > 0.463,
+                "seed {}: statistic {}",
+                seed,
+                result.statistic
+            );
+            assert!(!result.is_stationary, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_stationarity_invalid_input() {
+        assert!(adf_test(&[1.0, 2.0, 3.0], None).is_err());
+        assert!(adf_test(&[1.0; 50], None).is_err());
+        assert!(adf_test(&noise(20, 1), Some(10)).is_err());
+        assert!(kpss_test(&[1.0, f64::NAN, 3.0, 4.0], None).is_err());
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! Provides ts_stats functionality that computes 24 metrics per series.
 
-use crate::error::Result;
+use crate::error::{ForecastError, Result};
+use crate::rolling::{rolling, rolling_mean, RollingAgg};
 use chrono::{Datelike, NaiveDateTime};
 
 /// Frequency type for calendar vs fixed frequencies.
@@ -278,6 +279,65 @@ pub fn compute_ts_stats(series: &[Option<f64>]) -> Result<TsStats> {
     })
 }
 
+/// Rolling-window time series statistics, one vector per statistic.
+///
+/// Entry `i` of every vector summarises the trailing window ending at `i`.
+#[derive(Debug, Clone, Default)]
+pub struct TsStatsRolling {
+    /// Window mean
+    pub mean: Vec<f64>,
+    /// Window sample standard deviation (NaN with fewer than two values)
+    pub std_dev: Vec<f64>,
+    /// Window minimum
+    pub min: Vec<f64>,
+    /// Window maximum
+    pub max: Vec<f64>,
+    /// Number of NULL values in the window
+    pub n_nulls: Vec<usize>,
+}
+
+/// Compute time series statistics over a trailing window at every position.
+///
+/// Position `i` summarises `series[i + 1 - window..=i]` (shorter at the start
+/// of the series), so drift in the mean or spread shows up over time. NULL
+/// and NaN values are skipped; a window without any value yields NaN.
+///
+/// # Arguments
+/// * `series` - A slice of optional f64 values (None represents NULL)
+/// * `window` - Trailing window length (at least 1)
+///
+/// # Returns
+/// * `Result<TsStatsRolling>` - Statistics for each position's window
+pub fn compute_ts_stats_rolling(series: &[Option<f64>], window: usize) -> Result<TsStatsRolling> {
+    if window == 0 {
+        return Err(ForecastError::InvalidParameter {
+            param: "window".to_string(),
+            value: window.to_string(),
+            reason: "Window must be at least 1".to_string(),
+        });
+    }
+
+    let values: Vec<f64> = series.iter().map(|v| v.unwrap_or(f64::NAN)).collect();
+
+    // Running NULL count: window i holds nulls_before[i + 1] - nulls_before[start]
+    let mut nulls_before = Vec::with_capacity(series.len() + 1);
+    nulls_before.push(0usize);
+    for v in series {
+        nulls_before.push(nulls_before[nulls_before.len() - 1] + usize::from(v.is_none()));
+    }
+    let n_nulls = (0..series.len())
+        .map(|i| nulls_before[i + 1] - nulls_before[(i + 1).saturating_sub(window)])
+        .collect();
+
+    Ok(TsStatsRolling {
+        mean: rolling(&values, window, 1, RollingAgg::Mean),
+        std_dev: rolling(&values, window, 1, RollingAgg::Std),
+        min: rolling(&values, window, 1, RollingAgg::Min),
+        max: rolling(&values, window, 1, RollingAgg::Max),
+        n_nulls,
+    })
+}
+
 /// Compute time series statistics with date information for gap detection.
 ///
 /// # Arguments
@@ -475,6 +535,63 @@ fn count_gaps_with_frequency(sorted_dates: &[i64], frequency_micros: i64) -> usi
         .count()
 }
 
+/// Median of a slice (NaN for empty input).
+// Note: is_multiple_of() is unstable and breaks WASM builds
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn median(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return f64::NAN;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Outlier-robust scale estimate: `1.4826 * median(|r - median(r)|)`.
+///
+/// The 1.4826 factor makes the MAD a consistent estimator of the standard
+/// deviation under normality, so it can be used as a drop-in replacement.
+pub(crate) fn robust_scale(residuals: &[f64]) -> f64 {
+    let center = median(residuals);
+    let abs_dev: Vec<f64> = residuals.iter().map(|r| (r - center).abs()).collect();
+    1.4826 * median(&abs_dev)
+}
+
+/// Minimise a unimodal function on `[lo, hi]` by golden-section search.
+///
+/// Stops once the bracket is narrower than `tol` and returns its midpoint.
+pub(crate) fn golden_section_min(mut f: impl FnMut(f64) -> f64, lo: f64, hi: f64, tol: f64) -> f64 {
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut lo, mut hi) = (lo, hi);
+    let mut x1 = hi - ratio * (hi - lo);
+    let mut x2 = lo + ratio * (hi - lo);
+    let mut f1 = f(x1);
+    let mut f2 = f(x2);
+
+    while hi - lo > tol {
+        if f1 > f2 {
+            lo = x1;
+            x1 = x2;
+            f1 = f2;
+            x2 = lo + ratio * (hi - lo);
+            f2 = f(x2);
+        } else {
+            hi = x2;
+            x2 = x1;
+            f2 = f1;
+            x1 = hi - ratio * (hi - lo);
+            f1 = f(x1);
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
 /// Compute percentile using linear interpolation.
 fn percentile(sorted: &[f64], p: f64) -> f64 {
     if sorted.is_empty() {
@@ -610,12 +727,10 @@ fn compute_stability(values: &[f64]) -> f64 {
     }
 
     let window = (values.len() / 5).max(3);
-    let mut rolling_means = Vec::new();
-
-    for i in 0..=(values.len() - window) {
-        let mean: f64 = values[i..i + window].iter().sum::<f64>() / window as f64;
-        rolling_means.push(mean);
-    }
+    let rolling_means: Vec<f64> = rolling_mean(values, window, window)
+        .into_iter()
+        .skip(window - 1)
+        .collect();
 
     if rolling_means.is_empty() {
         return f64::NAN;
@@ -797,6 +912,63 @@ mod tests {
     use super::*;
     use approx::assert_relative_eq;
 
+    #[test]
+    fn test_robust_scale_matches_sd_for_symmetric_data() {
+        // For {1..=5}, MAD = 1 so the robust scale is exactly 1.4826.
+        let values = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        assert!((robust_scale(&values) - 1.4826).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_golden_section_min() {
+        let x = golden_section_min(|x| (x - 0.7).powi(2) + 3.0, 0.0, 2.0, 1e-8);
+        assert_relative_eq!(x, 0.7, epsilon = 1e-6);
+
+        // A monotone function converges to the bracket edge
+        let x = golden_section_min(|x| x, 0.01, 0.99, 1e-6);
+        assert_relative_eq!(x, 0.01, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_rolling_stats_level_shift() {
+        // Level 10 for 30 points, then level 20, with one NULL after the shift
+        let mut series: Vec<Option<f64>> = (0..60)
+            .map(|i| Some(if i < 30 { 10.0 } else { 20.0 } + (i % 3) as f64))
+            .collect();
+        series[40] = None;
+
+        let stats = compute_ts_stats_rolling(&series, 10).unwrap();
+        assert_eq!(stats.mean.len(), series.len());
+        assert_eq!(stats.n_nulls.len(), series.len());
+
+        // The first window holds a single value
+        assert_relative_eq!(stats.mean[0], 10.0);
+        assert!(stats.std_dev[0].is_nan());
+
+        // Before the shift the window mean sits near 11, well after it near 21
+        assert!((stats.mean[29] - 11.0).abs() < 0.5);
+        assert!((stats.mean[59] - 21.0).abs() < 0.5);
+        assert!(stats.mean[35] > stats.mean[29] + 4.0);
+
+        // The shift widens the window range while both levels are inside
+        assert_relative_eq!(stats.min[34], 10.0);
+        assert_relative_eq!(stats.max[34], 22.0);
+
+        // The NULL is counted only by windows that contain it
+        assert_eq!(stats.n_nulls[39], 0);
+        assert_eq!(stats.n_nulls[40], 1);
+        assert_eq!(stats.n_nulls[49], 1);
+        assert_eq!(stats.n_nulls[50], 0);
+
+        // Windows match the whole-series statistics of their slice
+        let slice = compute_ts_stats(&series[38..48]).unwrap();
+        assert_relative_eq!(stats.mean[47], slice.mean, epsilon = 1e-12);
+        assert_relative_eq!(stats.std_dev[47], slice.std_dev, epsilon = 1e-12);
+        assert_eq!(stats.n_nulls[47], slice.n_nulls);
+
+        assert!(compute_ts_stats_rolling(&series, 0).is_err());
+    }
+
     #[test]
     fn test_basic_stats() {
         let series: Vec<Option<f64>> = vec![Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0)];
@@ -0,0 +1,189 @@
+//! Variance-stabilising transformations.
+//!
+//! Transform a series before forecasting and back-transform the point
+//! forecasts and interval bounds afterwards.
+
+use crate::error::{ForecastError, Result};
+use crate::stats::golden_section_min;
+
+/// Search range for the Box-Cox lambda estimate.
+const LAMBDA_RANGE: (f64, f64) = (-1.0, 2.0);
+
+/// Lambdas closer to zero than this use the log transform.
+const LAMBDA_EPS: f64 = 1e-8;
+
+/// Apply the Box-Cox transformation.
+///
+/// `y(λ) = (x^λ - 1) / λ` for `λ != 0` and `ln(x)` for `λ = 0`. All values
+/// must be strictly positive.
+///
+/// # Arguments
+/// * `values` - Series to transform
+/// * `lambda` - Transformation parameter; `None` estimates it by maximising
+///   the Box-Cox profile log-likelihood over [-1, 2]
+///
+/// # Returns
+/// The transformed series and the lambda used.
+pub fn box_cox(values: &[f64], lambda: Option<f64>) -> Result<(Vec<f64>, f64)> {
+    if values.is_empty() {
+        return Err(ForecastError::InsufficientData { needed: 1, got: 0 });
+    }
+
+    if values.iter().any(|&v| !v.is_finite() || v <= 0.0) {
+        return Err(ForecastError::InvalidInput(
+            "Box-Cox requires strictly positive finite values".to_string(),
+        ));
+    }
+
+    let lambda = match lambda {
+        Some(l) if !l.is_finite() => {
+            return Err(ForecastError::InvalidParameter {
+                param: "lambda".to_string(),
+                value: l.to_string(),
+                reason: "must be finite".to_string(),
+            });
+        }
+        Some(l) => l,
+        None => estimate_box_cox_lambda(values)?,
+    };
+
+    Ok((
+        values.iter().map(|&v| box_cox_value(v, lambda)).collect(),
+        lambda,
+    ))
+}
+
+/// Invert the Box-Cox transformation.
+///
+/// Values outside the range of the forward transform (where `λy + 1 <= 0`)
+/// map to the boundary: 0 for `λ > 0` and infinity for `λ < 0`. This keeps
+/// back-transformed lower interval bounds usable.
+pub fn inv_box_cox(transformed: &[f64], lambda: f64) -> Vec<f64> {
+    transformed
+        .iter()
+        .map(|&y| {
+            if lambda.abs() < LAMBDA_EPS {
+                return y.exp();
+            }
+            let base = lambda * y + 1.0;
+            if base <= 0.0 {
+                if lambda > 0.0 {
+                    0.0
+                } else {
+                    f64::INFINITY
+                }
+            } else {
+                base.powf(1.0 / lambda)
+            }
+        })
+        .collect()
+}
+
+/// Estimate the Box-Cox lambda by maximising the profile log-likelihood.
+///
+/// Requires at least two strictly positive values.
+pub fn estimate_box_cox_lambda(values: &[f64]) -> Result<f64> {
+    if values.len() < 2 {
+        return Err(ForecastError::InsufficientData {
+            needed: 2,
+            got: values.len(),
+        });
+    }
+
+    if values.iter().any(|&v| !v.is_finite() || v <= 0.0) {
+        return Err(ForecastError::InvalidInput(
+            "Box-Cox requires strictly positive finite values".to_string(),
+        ));
+    }
+
+    let log_sum: f64 = values.iter().map(|v| v.ln()).sum();
+    let log_likelihood = |lambda: f64| {
+        let n = values.len() as f64;
+        let transformed: Vec<f64> = values.iter().map(|&v| box_cox_value(v, lambda)).collect();
+        let mean = transformed.iter().sum::<f64>() / n;
+        let variance = transformed.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / n;
+        if variance <= 0.0 {
+            return f64::NEG_INFINITY;
+        }
+        -0.5 * n * variance.ln() + (lambda - 1.0) * log_sum
+    };
+
+    // The profile likelihood is unimodal in lambda
+    let (lo, hi) = LAMBDA_RANGE;
+    Ok(golden_section_min(
+        |lambda| -log_likelihood(lambda),
+        lo,
+        hi,
+        1e-6,
+    ))
+}
+
+fn box_cox_value(value: f64, lambda: f64) -> f64 {
+    if lambda.abs() < LAMBDA_EPS {
+        value.ln()
+    } else {
+        (value.powf(lambda) - 1.0) / lambda
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_box_cox_lambda_zero_is_log() {
+        let values = vec![1.0, 2.5, 10.0, 100.0];
+        let (transformed, lambda) = box_cox(&values, Some(0.0)).unwrap();
+        assert_eq!(lambda, 0.0);
+        for (t, v) in transformed.iter().zip(values.iter()) {
+            assert_relative_eq!(*t, v.ln(), epsilon = 1e-12);
+        }
+
+        // Tiny lambdas converge to the log transform
+        let (near_log, _) = box_cox(&values, Some(1e-6)).unwrap();
+        for (t, v) in near_log.iter().zip(values.iter()) {
+            assert_relative_eq!(*t, v.ln(), epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_box_cox_round_trip() {
+        let values = vec![3.0, 5.5, 2.0, 8.25, 13.0, 7.0];
+        for lambda in [-0.5, 0.0, 0.5, 1.0, 1.7] {
+            let (transformed, used) = box_cox(&values, Some(lambda)).unwrap();
+            let restored = inv_box_cox(&transformed, used);
+            for (r, v) in restored.iter().zip(values.iter()) {
+                assert_relative_eq!(*r, *v, epsilon = 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_box_cox_estimates_log_for_exponential_growth() {
+        // Multiplicative noise around exponential growth is stabilised by the log
+        let values: Vec<f64> = (0..60)
+            .map(|i| (0.05 * i as f64).exp() * (1.0 + 0.1 * (i as f64 * 2.3).sin()))
+            .collect();
+        let (transformed, lambda) = box_cox(&values, None).unwrap();
+        assert_eq!(transformed.len(), values.len());
+        assert!(lambda.abs() < 0.5, "expected lambda near 0, got {}", lambda);
+    }
+
+    #[test]
+    fn test_box_cox_invalid_input() {
+        assert!(box_cox(&[], Some(0.5)).is_err());
+        assert!(box_cox(&[1.0, 0.0, 2.0], Some(0.5)).is_err());
+        assert!(box_cox(&[1.0, -3.0], None).is_err());
+        assert!(box_cox(&[1.0, 2.0], Some(f64::NAN)).is_err());
+        assert!(box_cox(&[1.0], None).is_err());
+    }
+
+    #[test]
+    fn test_inv_box_cox_out_of_range() {
+        let restored = inv_box_cox(&[-10.0], 0.5);
+        assert_eq!(restored[0], 0.0);
+        let restored = inv_box_cox(&[10.0], -0.5);
+        assert!(restored[0].is_infinite());
+    }
+}
@@ -22,7 +22,9 @@ use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::ptr;
 
 // Re-export helper functions from submodules for internal use
-use allocation::{alloc_double_array, alloc_or_error, free_ptr, vec_to_c_double_array};
+use allocation::{
+    alloc_double_array, alloc_or_error, alloc_string_array, free_ptr, vec_to_c_double_array,
+};
 use conversion::to_option_usize;
 use error_handling::{check_null_pointers, init_error, set_error};
 
@@ -179,6 +181,68 @@ pub unsafe extern "C" fn anofox_ts_stats(
     }
 }
 
+/// Compute time series statistics over a trailing window at every position.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null (`validity` may be null).
+/// Free the result with `anofox_free_ts_stats_rolling_result`.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_stats_rolling(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    window: size_t,
+    out_result: *mut TsStatsRollingResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        anofox_fcst_core::compute_ts_stats_rolling(&series, window)
+    }));
+
+    match result {
+        Ok(Ok(stats)) => {
+            *out_result = TsStatsRollingResult {
+                length: stats.mean.len(),
+                ..Default::default()
+            };
+            let r = &mut *out_result;
+            let allocated = (|| -> Result<(), ()> {
+                let msg = "Failed to allocate rolling statistics";
+                r.mean = alloc_or_error(&stats.mean, out_error, msg)?;
+                r.std_dev = alloc_or_error(&stats.std_dev, out_error, msg)?;
+                r.min = alloc_or_error(&stats.min, out_error, msg)?;
+                r.max = alloc_or_error(&stats.max, out_error, msg)?;
+                r.n_nulls = alloc_or_error(&stats.n_nulls, out_error, msg)?;
+                Ok(())
+            })();
+            if allocated.is_err() {
+                anofox_free_ts_stats_rolling_result(out_result);
+                return false;
+            }
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
 /// Compute time series statistics with date information for gap detection.
 ///
 /// # Safety
@@ -466,6 +530,78 @@ pub unsafe extern "C" fn anofox_ts_smape(
     )
 }
 
+/// Median Absolute Percentage Error
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_mdape(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    out_result: *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    impl_metric_2arg(
+        actual,
+        actual_len,
+        forecast,
+        forecast_len,
+        out_result,
+        out_error,
+        anofox_fcst_core::mdape,
+    )
+}
+
+/// Symmetric Median Absolute Percentage Error
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_smdape(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    out_result: *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    impl_metric_2arg(
+        actual,
+        actual_len,
+        forecast,
+        forecast_len,
+        out_result,
+        out_error,
+        anofox_fcst_core::smdape,
+    )
+}
+
+/// Mean Arctangent Absolute Percentage Error (radians, bounded in [0, pi/2])
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_maape(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    out_result: *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    impl_metric_2arg(
+        actual,
+        actual_len,
+        forecast,
+        forecast_len,
+        out_result,
+        out_error,
+        anofox_fcst_core::maape,
+    )
+}
+
 /// R-squared (Coefficient of Determination)
 ///
 /// # Safety
@@ -490,6 +626,30 @@ pub unsafe extern "C" fn anofox_ts_r2(
     )
 }
 
+/// Lin's concordance correlation coefficient
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_ccc(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    out_result: *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    impl_metric_2arg(
+        actual,
+        actual_len,
+        forecast,
+        forecast_len,
+        out_result,
+        out_error,
+        anofox_fcst_core::ccc,
+    )
+}
+
 /// Bias (Mean Error)
 ///
 /// # Safety
@@ -514,6 +674,30 @@ pub unsafe extern "C" fn anofox_ts_bias(
     )
 }
 
+/// Tracking signal (cumulative error over MAD; |value| > 4 suggests bias)
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_tracking_signal(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    out_result: *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    impl_metric_2arg(
+        actual,
+        actual_len,
+        forecast,
+        forecast_len,
+        out_result,
+        out_error,
+        anofox_fcst_core::tracking_signal,
+    )
+}
+
 /// Relative MAE comparing two models.
 ///
 /// # Safety
@@ -567,12 +751,12 @@ pub unsafe extern "C" fn anofox_ts_rmae(
     }
 }
 
-/// Mean Absolute Scaled Error.
+/// Geometric Mean Relative Absolute Error against a benchmark forecast.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_mase(
+pub unsafe extern "C" fn anofox_ts_gmrae(
     actual: *const c_double,
     actual_len: size_t,
     forecast: *const c_double,
@@ -582,14 +766,15 @@ pub unsafe extern "C" fn anofox_ts_mase(
     out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
+    init_error(out_error);
 
-    if actual.is_null() || forecast.is_null() || baseline.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    let ptrs = &[
+        actual as *const core::ffi::c_void,
+        forecast as *const core::ffi::c_void,
+        baseline as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
         return false;
     }
 
@@ -597,7 +782,7 @@ pub unsafe extern "C" fn anofox_ts_mase(
         let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
         let forecast_vec = std::slice::from_raw_parts(forecast, forecast_len).to_vec();
         let baseline_vec = std::slice::from_raw_parts(baseline, baseline_len).to_vec();
-        anofox_fcst_core::mase(&actual_vec, &forecast_vec, &baseline_vec)
+        anofox_fcst_core::gmrae(&actual_vec, &forecast_vec, &baseline_vec)
     }));
 
     match result {
@@ -606,31 +791,28 @@ pub unsafe extern "C" fn anofox_ts_mase(
             true
         }
         Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
-/// Quantile loss function.
+/// Mean Absolute Scaled Error.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_quantile_loss(
+pub unsafe extern "C" fn anofox_ts_mase(
     actual: *const c_double,
     actual_len: size_t,
     forecast: *const c_double,
     forecast_len: size_t,
-    quantile: c_double,
+    baseline: *const c_double,
+    baseline_len: size_t,
     out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
@@ -638,7 +820,7 @@ pub unsafe extern "C" fn anofox_ts_quantile_loss(
         *out_error = AnofoxError::success();
     }
 
-    if actual.is_null() || forecast.is_null() || out_result.is_null() {
+    if actual.is_null() || forecast.is_null() || baseline.is_null() || out_result.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
@@ -648,7 +830,8 @@ pub unsafe extern "C" fn anofox_ts_quantile_loss(
     let result = catch_unwind(AssertUnwindSafe(|| {
         let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
         let forecast_vec = std::slice::from_raw_parts(forecast, forecast_len).to_vec();
-        anofox_fcst_core::quantile_loss(&actual_vec, &forecast_vec, quantile)
+        let baseline_vec = std::slice::from_raw_parts(baseline, baseline_len).to_vec();
+        anofox_fcst_core::mase(&actual_vec, &forecast_vec, &baseline_vec)
     }));
 
     match result {
@@ -671,61 +854,34 @@ pub unsafe extern "C" fn anofox_ts_quantile_loss(
     }
 }
 
-/// Multi-quantile loss function.
+/// Mean Absolute Scaled Error against the in-sample seasonal naive error of `train`.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
-/// quantiles is a 2D array: n_levels arrays, each of length actual_len.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_mqloss(
+pub unsafe extern "C" fn anofox_ts_mase_scaled(
     actual: *const c_double,
     actual_len: size_t,
-    quantiles: *const *const c_double, // Array of pointers to quantile forecast arrays
-    n_levels: size_t,
-    levels: *const c_double,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    train: *const c_double,
+    train_len: size_t,
+    period: size_t,
     out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
-
-    if actual.is_null() || quantiles.is_null() || levels.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
-        return false;
-    }
+    init_error(out_error);
 
-    if n_levels == 0 {
-        if !out_error.is_null() {
-            (*out_error).set_error(
-                ErrorCode::InvalidInput,
-                "Must have at least one quantile level",
-            );
-        }
+    if actual.is_null() || forecast.is_null() || train.is_null() || out_result.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
         return false;
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
-        let levels_vec = std::slice::from_raw_parts(levels, n_levels).to_vec();
-
-        // Build Vec<Vec<f64>> from the 2D array
-        let mut forecasts_vec: Vec<Vec<f64>> = Vec::with_capacity(n_levels);
-        for i in 0..n_levels {
-            let quantile_ptr = *quantiles.add(i);
-            if quantile_ptr.is_null() {
-                return Err(anofox_fcst_core::ForecastError::InvalidInput(format!(
-                    "Null pointer at quantile index {}",
-                    i
-                )));
-            }
-            let quantile_vec = std::slice::from_raw_parts(quantile_ptr, actual_len).to_vec();
-            forecasts_vec.push(quantile_vec);
-        }
-
-        anofox_fcst_core::mqloss(&actual_vec, &forecasts_vec, &levels_vec)
+        let forecast_vec = std::slice::from_raw_parts(forecast, forecast_len).to_vec();
+        let train_vec = std::slice::from_raw_parts(train, train_len).to_vec();
+        anofox_fcst_core::mase_scaled(&actual_vec, &forecast_vec, &train_vec, period)
     }));
 
     match result {
@@ -734,126 +890,112 @@ pub unsafe extern "C" fn anofox_ts_mqloss(
             true
         }
         Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
-/// Coverage of prediction intervals.
+/// In-sample seasonal naive errors (`train[i] - train[i - period]`), the
+/// MASE scaling errors. `period = 1` gives the ordinary naive errors.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_coverage(
-    actual: *const c_double,
-    actual_len: size_t,
-    lower: *const c_double,
-    upper: *const c_double,
-    out_result: *mut c_double,
+pub unsafe extern "C" fn anofox_ts_seasonal_naive_errors(
+    train: *const c_double,
+    length: size_t,
+    period: size_t,
+    out_values: *mut *mut c_double,
+    out_length: *mut size_t,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
+    init_error(out_error);
 
-    if actual.is_null() || lower.is_null() || upper.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    if train.is_null() || out_values.is_null() || out_length.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
         return false;
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
-        let lower_vec = std::slice::from_raw_parts(lower, actual_len).to_vec();
-        let upper_vec = std::slice::from_raw_parts(upper, actual_len).to_vec();
-        anofox_fcst_core::coverage(&actual_vec, &lower_vec, &upper_vec)
+        let train_vec = std::slice::from_raw_parts(train, length).to_vec();
+        anofox_fcst_core::seasonal_naive_errors(&train_vec, period)
     }));
 
     match result {
-        Ok(Ok(value)) => {
-            *out_result = value;
-            true
-        }
-        Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+        Ok(Ok(errors)) => {
+            *out_length = errors.len();
+            match alloc_or_error(&errors, out_error, "Failed to allocate naive errors") {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
+                }
+                Err(()) => false,
             }
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
-// ============================================================================
-// Seasonality Functions
-// ============================================================================
-
-/// Detect seasonal periods in a time series.
+/// Mean Scaled Interval Score of `(1 - alpha)` prediction intervals.
+///
+/// `train` is the training series; the interval score is scaled by its
+/// seasonal naive MAE with lag `period`.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// All pointer arguments must be valid and non-null. `actual`, `lower` and
+/// `upper` must have `length` elements, `train` must have `train_length`.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_seasonality(
-    values: *const c_double,
+pub unsafe extern "C" fn anofox_ts_msis(
+    actual: *const c_double,
+    lower: *const c_double,
+    upper: *const c_double,
     length: size_t,
-    max_period: c_int,
-    out_periods: *mut *mut c_int,
-    out_n_periods: *mut size_t,
+    train: *const c_double,
+    train_length: size_t,
+    period: size_t,
+    alpha: c_double,
+    out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     init_error(out_error);
 
     let ptrs = &[
-        values as *const core::ffi::c_void,
-        out_periods as *const core::ffi::c_void,
-        out_n_periods as *const core::ffi::c_void,
+        actual as *const core::ffi::c_void,
+        lower as *const core::ffi::c_void,
+        upper as *const core::ffi::c_void,
+        train as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
     ];
     if check_null_pointers(out_error, ptrs) {
         return false;
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        anofox_fcst_core::detect_seasonality(&values_vec, to_option_usize(max_period))
+        anofox_fcst_core::msis(
+            std::slice::from_raw_parts(actual, length),
+            std::slice::from_raw_parts(lower, length),
+            std::slice::from_raw_parts(upper, length),
+            std::slice::from_raw_parts(train, train_length),
+            period,
+            alpha,
+        )
     }));
 
     match result {
-        Ok(Ok(periods)) => {
-            let n = periods.len();
-            *out_n_periods = n;
-
-            if n > 0 {
-                let ptr = malloc(n * std::mem::size_of::<c_int>()) as *mut c_int;
-                if ptr.is_null() {
-                    set_error(
-                        out_error,
-                        ErrorCode::AllocationError,
-                        "Memory allocation failed",
-                    );
-                    return false;
-                }
-                for (i, &p) in periods.iter().enumerate() {
-                    *ptr.add(i) = p;
-                }
-                *out_periods = ptr;
-            } else {
-                *out_periods = ptr::null_mut();
-            }
+        Ok(Ok(value)) => {
+            *out_result = value;
             true
         }
         Ok(Err(e)) => {
@@ -867,25 +1009,31 @@ pub unsafe extern "C" fn anofox_ts_detect_seasonality(
     }
 }
 
-/// Analyze seasonality in a time series.
+/// Overall Weighted Average of sMAPE and MASE relative to the Naive2 benchmark.
+///
+/// Naive2 is computed from `train` (seasonally adjusted with lag `period`
+/// when the series is significantly seasonal).
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
-/// Note: timestamps parameter is for API compatibility but is ignored internally.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_analyze_seasonality(
-    _timestamps: *const i64, // Ignored, for C++ API compatibility
-    _timestamps_len: size_t, // Ignored
-    values: *const c_double,
-    length: size_t,
-    max_period: c_int,
-    out_result: *mut SeasonalityResult,
+pub unsafe extern "C" fn anofox_ts_owa(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    train: *const c_double,
+    train_len: size_t,
+    period: size_t,
+    out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     init_error(out_error);
 
     let ptrs = &[
-        values as *const core::ffi::c_void,
+        actual as *const core::ffi::c_void,
+        forecast as *const core::ffi::c_void,
+        train as *const core::ffi::c_void,
         out_result as *const core::ffi::c_void,
     ];
     if check_null_pointers(out_error, ptrs) {
@@ -893,41 +1041,17 @@ pub unsafe extern "C" fn anofox_ts_analyze_seasonality(
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        anofox_fcst_core::analyze_seasonality(&values_vec, to_option_usize(max_period))
+        anofox_fcst_core::owa(
+            std::slice::from_raw_parts(actual, actual_len),
+            std::slice::from_raw_parts(forecast, forecast_len),
+            std::slice::from_raw_parts(train, train_len),
+            period,
+        )
     }));
 
     match result {
-        Ok(Ok(analysis)) => {
-            let n = analysis.periods.len();
-
-            // Allocate and copy detected_periods
-            if n > 0 {
-                let periods_ptr = malloc(n * std::mem::size_of::<c_int>()) as *mut c_int;
-
-                if periods_ptr.is_null() {
-                    set_error(
-                        out_error,
-                        ErrorCode::AllocationError,
-                        "Memory allocation failed",
-                    );
-                    return false;
-                }
-
-                for (i, &p) in analysis.periods.iter().enumerate() {
-                    *periods_ptr.add(i) = p;
-                }
-
-                (*out_result).detected_periods = periods_ptr;
-            } else {
-                (*out_result).detected_periods = ptr::null_mut();
-            }
-
-            (*out_result).n_periods = n;
-            (*out_result).primary_period = analysis.primary_period;
-            (*out_result).seasonal_strength = analysis.seasonal_strength;
-            (*out_result).trend_strength = analysis.trend_strength;
-
+        Ok(Ok(value)) => {
+            *out_result = value;
             true
         }
         Ok(Err(e)) => {
@@ -941,27 +1065,28 @@ pub unsafe extern "C" fn anofox_ts_analyze_seasonality(
     }
 }
 
-// ============================================================================
-// Period Detection Functions (fdars-core integration)
-// ============================================================================
-
-/// Detect periods using specified method.
+/// Root Mean Squared Scaled Error.
+///
+/// `baseline` is the training series used for the naive-error scale.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_periods(
-    values: *const c_double,
-    length: size_t,
-    method: *const c_char,
-    out_result: *mut types::MultiPeriodResult,
+pub unsafe extern "C" fn anofox_ts_rmsse(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    baseline: *const c_double,
+    baseline_len: size_t,
+    out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_result.is_null() {
+    if actual.is_null() || forecast.is_null() || baseline.is_null() || out_result.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
@@ -969,40 +1094,15 @@ pub unsafe extern "C" fn anofox_ts_detect_periods(
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let method_str = if method.is_null() {
-            "fft"
-        } else {
-            CStr::from_ptr(method).to_str().unwrap_or("fft")
-        };
-        let period_method: anofox_fcst_core::PeriodMethod = method_str.parse().unwrap_or_default();
-        // Use defaults: max_period (None = 365), min_confidence (None = method-specific default)
-        anofox_fcst_core::detect_periods(&values_vec, period_method, None, None)
+        let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
+        let forecast_vec = std::slice::from_raw_parts(forecast, forecast_len).to_vec();
+        let baseline_vec = std::slice::from_raw_parts(baseline, baseline_len).to_vec();
+        anofox_fcst_core::rmsse(&actual_vec, &forecast_vec, &baseline_vec)
     }));
 
     match result {
-        Ok(Ok(multi_result)) => {
-            let n = multi_result.periods.len();
-            (*out_result).n_periods = n;
-            (*out_result).primary_period = multi_result.primary_period;
-            copy_string_to_buffer(&multi_result.method, &mut (*out_result).method);
-
-            if n > 0 {
-                let periods_ptr = malloc(n * std::mem::size_of::<types::DetectedPeriodFFI>())
-                    as *mut types::DetectedPeriodFFI;
-                for (i, dp) in multi_result.periods.iter().enumerate() {
-                    (*periods_ptr.add(i)).period = dp.period;
-                    (*periods_ptr.add(i)).confidence = dp.confidence;
-                    (*periods_ptr.add(i)).strength = dp.strength;
-                    (*periods_ptr.add(i)).amplitude = dp.amplitude;
-                    (*periods_ptr.add(i)).phase = dp.phase;
-                    (*periods_ptr.add(i)).iteration = dp.iteration;
-                }
-                (*out_result).periods = periods_ptr;
-            } else {
-                (*out_result).periods = ptr::null_mut();
-            }
-
+        Ok(Ok(value)) => {
+            *out_result = value;
             true
         }
         Ok(Err(e)) => {
@@ -1020,40 +1120,49 @@ pub unsafe extern "C" fn anofox_ts_detect_periods(
     }
 }
 
-/// Detect multiple periods in a time series using the specified method.
-///
-/// Returns a flattened result with parallel arrays for safer FFI.
-/// This version avoids memory issues when used through R's DuckDB bindings.
+/// Theil's U2 statistic (forecast vs one-step naive; < 1 beats naive)
 ///
-/// # Arguments
-/// * `max_period` - Maximum period to search (0 = use default of 365)
-/// * `min_confidence` - Minimum confidence threshold; periods below this are filtered out.
-///   Use negative value (e.g., -1.0) to use method-specific default.
-///   Use 0.0 to disable filtering. Use positive value for custom threshold.
-/// * `expected_periods` - Optional array of expected periods to validate against (NULL = no validation)
-/// * `n_expected` - Number of expected periods (0 if expected_periods is NULL)
-/// * `tolerance` - Relative tolerance for matching (negative = use default 0.1)
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_theil_u2(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    out_result: *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    impl_metric_2arg(
+        actual,
+        actual_len,
+        forecast,
+        forecast_len,
+        out_result,
+        out_error,
+        anofox_fcst_core::theil_u2,
+    )
+}
+
+/// Quantile loss function.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_periods_flat(
-    values: *const c_double,
-    length: size_t,
-    method: *const c_char,
-    max_period: size_t,
-    min_confidence: c_double,
-    expected_periods: *const c_double,
-    n_expected: size_t,
-    tolerance: c_double,
-    out_result: *mut types::FlatMultiPeriodResult,
+pub unsafe extern "C" fn anofox_ts_quantile_loss(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    quantile: c_double,
+    out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_result.is_null() {
+    if actual.is_null() || forecast.is_null() || out_result.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
@@ -1061,104 +1170,14 @@ pub unsafe extern "C" fn anofox_ts_detect_periods_flat(
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let method_str = if method.is_null() {
-            "fft"
-        } else {
-            CStr::from_ptr(method).to_str().unwrap_or("fft")
-        };
-        let period_method: anofox_fcst_core::PeriodMethod = method_str.parse().unwrap_or_default();
-        // Convert 0 to None (use default), otherwise Some(max_period)
-        let max_period_opt = if max_period == 0 {
-            None
-        } else {
-            Some(max_period)
-        };
-        // Convert min_confidence: negative = use default, 0 or positive = use value
-        let min_confidence_opt = if min_confidence < 0.0 || min_confidence.is_nan() {
-            None // Use method-specific default
-        } else {
-            Some(min_confidence) // 0.0 disables filtering, positive value is threshold
-        };
-
-        // Convert expected_periods: NULL or n_expected=0 means no validation
-        let expected_vec: Option<Vec<f64>> = if expected_periods.is_null() || n_expected == 0 {
-            None
-        } else {
-            Some(std::slice::from_raw_parts(expected_periods, n_expected).to_vec())
-        };
-
-        // Convert tolerance: negative = use default
-        let tolerance_opt = if tolerance < 0.0 || tolerance.is_nan() {
-            None
-        } else {
-            Some(tolerance)
-        };
-
-        anofox_fcst_core::detect_periods_with_validation(
-            &values_vec,
-            period_method,
-            max_period_opt,
-            min_confidence_opt,
-            expected_vec.as_deref(),
-            tolerance_opt,
-        )
-    }));
+        let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
+        let forecast_vec = std::slice::from_raw_parts(forecast, forecast_len).to_vec();
+        anofox_fcst_core::quantile_loss(&actual_vec, &forecast_vec, quantile)
+    }));
 
     match result {
-        Ok(Ok(multi_result)) => {
-            let n = multi_result.periods.len();
-            (*out_result).n_periods = n;
-            (*out_result).primary_period = multi_result.primary_period;
-            copy_string_to_buffer(&multi_result.method, &mut (*out_result).method);
-
-            if n > 0 {
-                // Allocate parallel arrays instead of struct array
-                let period_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let confidence_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let strength_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let amplitude_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let phase_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let iteration_ptr = malloc(n * std::mem::size_of::<size_t>()) as *mut size_t;
-                let matches_expected_ptr = malloc(n * std::mem::size_of::<bool>()) as *mut bool;
-                let matched_expected_ptr =
-                    malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let match_deviation_ptr =
-                    malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-
-                for (i, dp) in multi_result.periods.iter().enumerate() {
-                    *period_ptr.add(i) = dp.period;
-                    *confidence_ptr.add(i) = dp.confidence;
-                    *strength_ptr.add(i) = dp.strength;
-                    *amplitude_ptr.add(i) = dp.amplitude;
-                    *phase_ptr.add(i) = dp.phase;
-                    *iteration_ptr.add(i) = dp.iteration;
-                    *matches_expected_ptr.add(i) = dp.matches_expected;
-                    *matched_expected_ptr.add(i) = dp.matched_expected_period.unwrap_or(f64::NAN);
-                    *match_deviation_ptr.add(i) = dp.match_deviation.unwrap_or(f64::NAN);
-                }
-
-                (*out_result).period_values = period_ptr;
-                (*out_result).confidence_values = confidence_ptr;
-                (*out_result).strength_values = strength_ptr;
-                (*out_result).amplitude_values = amplitude_ptr;
-                (*out_result).phase_values = phase_ptr;
-                (*out_result).iteration_values = iteration_ptr;
-                (*out_result).matches_expected_values = matches_expected_ptr;
-                (*out_result).matched_expected_values = matched_expected_ptr;
-                (*out_result).match_deviation_values = match_deviation_ptr;
-            } else {
-                (*out_result).period_values = ptr::null_mut();
-                (*out_result).confidence_values = ptr::null_mut();
-                (*out_result).strength_values = ptr::null_mut();
-                (*out_result).amplitude_values = ptr::null_mut();
-                (*out_result).phase_values = ptr::null_mut();
-                (*out_result).iteration_values = ptr::null_mut();
-                (*out_result).matches_expected_values = ptr::null_mut();
-                (*out_result).matched_expected_values = ptr::null_mut();
-                (*out_result).match_deviation_values = ptr::null_mut();
-            }
-
+        Ok(Ok(value)) => {
+            *out_result = value;
             true
         }
         Ok(Err(e)) => {
@@ -1176,40 +1195,74 @@ pub unsafe extern "C" fn anofox_ts_detect_periods_flat(
     }
 }
 
-/// Estimate period using FFT.
+/// Build one forecast vector per quantile level from a 2D C array.
+unsafe fn read_quantile_forecasts(
+    quantiles: *const *const c_double,
+    n_levels: size_t,
+    length: size_t,
+) -> anofox_fcst_core::Result<Vec<Vec<f64>>> {
+    let mut forecasts_vec: Vec<Vec<f64>> = Vec::with_capacity(n_levels);
+    for i in 0..n_levels {
+        let quantile_ptr = *quantiles.add(i);
+        if quantile_ptr.is_null() {
+            return Err(anofox_fcst_core::ForecastError::InvalidInput(format!(
+                "Null pointer at quantile index {}",
+                i
+            )));
+        }
+        forecasts_vec.push(std::slice::from_raw_parts(quantile_ptr, length).to_vec());
+    }
+    Ok(forecasts_vec)
+}
+
+/// Multi-quantile loss function.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// quantiles is a 2D array: n_levels arrays, each of length actual_len.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_estimate_period_fft(
-    values: *const c_double,
-    length: size_t,
-    out_result: *mut types::SinglePeriodResult,
+pub unsafe extern "C" fn anofox_ts_mqloss(
+    actual: *const c_double,
+    actual_len: size_t,
+    quantiles: *const *const c_double, // Array of pointers to quantile forecast arrays
+    n_levels: size_t,
+    levels: *const c_double,
+    out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_result.is_null() {
+    if actual.is_null() || quantiles.is_null() || levels.is_null() || out_result.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
+    if n_levels == 0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(
+                ErrorCode::InvalidInput,
+                "Must have at least one quantile level",
+            );
+        }
+        return false;
+    }
+
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        anofox_fcst_core::estimate_period_fft_ts(&values_vec)
+        let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
+        let levels_vec = std::slice::from_raw_parts(levels, n_levels).to_vec();
+
+        let forecasts_vec = read_quantile_forecasts(quantiles, n_levels, actual_len)?;
+
+        anofox_fcst_core::mqloss(&actual_vec, &forecasts_vec, &levels_vec)
     }));
 
     match result {
-        Ok(Ok(single_result)) => {
-            (*out_result).period = single_result.period;
-            (*out_result).frequency = single_result.frequency;
-            (*out_result).power = single_result.power;
-            (*out_result).confidence = single_result.confidence;
-            copy_string_to_buffer(&single_result.method, &mut (*out_result).method);
+        Ok(Ok(value)) => {
+            *out_result = value;
             true
         }
         Ok(Err(e)) => {
@@ -1227,250 +1280,184 @@ pub unsafe extern "C" fn anofox_ts_estimate_period_fft(
     }
 }
 
-/// Estimate period using ACF.
+/// Quantile loss at each quantile level.
+///
+/// On success `out_values` holds `n_levels` losses aligned with `levels`;
+/// free it with `anofox_free_double_array`.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// quantiles is a 2D array: n_levels arrays, each of length actual_len.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_estimate_period_acf(
-    values: *const c_double,
-    length: size_t,
-    max_lag: c_int,
-    out_result: *mut types::SinglePeriodResult,
+pub unsafe extern "C" fn anofox_ts_mqloss_by_level(
+    actual: *const c_double,
+    actual_len: size_t,
+    quantiles: *const *const c_double, // Array of pointers to quantile forecast arrays
+    n_levels: size_t,
+    levels: *const c_double,
+    out_values: *mut *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
+    init_error(out_error);
+
+    if actual.is_null() || quantiles.is_null() || levels.is_null() || out_values.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
     }
 
-    if values.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    if n_levels == 0 {
+        set_error(
+            out_error,
+            ErrorCode::InvalidInput,
+            "Must have at least one quantile level",
+        );
         return false;
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let max_lag_opt = if max_lag > 0 {
-            Some(max_lag as usize)
-        } else {
-            None
-        };
-        anofox_fcst_core::estimate_period_acf_ts(&values_vec, max_lag_opt)
+        let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
+        let levels_vec = std::slice::from_raw_parts(levels, n_levels).to_vec();
+
+        let forecasts_vec = read_quantile_forecasts(quantiles, n_levels, actual_len)?;
+
+        anofox_fcst_core::mqloss_by_level(&actual_vec, &forecasts_vec, &levels_vec)
     }));
 
     match result {
-        Ok(Ok(single_result)) => {
-            (*out_result).period = single_result.period;
-            (*out_result).frequency = single_result.frequency;
-            (*out_result).power = single_result.power;
-            (*out_result).confidence = single_result.confidence;
-            copy_string_to_buffer(&single_result.method, &mut (*out_result).method);
-            true
+        Ok(Ok(losses)) => {
+            match alloc_or_error(&losses, out_error, "Failed to allocate quantile losses") {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
+                }
+                Err(()) => false,
+            }
         }
         Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
-/// Detect multiple periods in time series.
+/// Empirical coverage (fraction of actuals at or below the forecast) at each
+/// quantile level.
+///
+/// On success `out_values` holds `n_levels` proportions aligned with `levels`;
+/// free it with `anofox_free_double_array`.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// quantiles is a 2D array: n_levels arrays, each of length actual_len.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_multiple_periods(
-    values: *const c_double,
-    length: size_t,
-    max_periods: c_int,
-    min_confidence: c_double,
-    min_strength: c_double,
-    out_result: *mut types::MultiPeriodResult,
+pub unsafe extern "C" fn anofox_ts_quantile_coverage(
+    actual: *const c_double,
+    actual_len: size_t,
+    quantiles: *const *const c_double, // Array of pointers to quantile forecast arrays
+    n_levels: size_t,
+    levels: *const c_double,
+    out_values: *mut *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
+    init_error(out_error);
+
+    if actual.is_null() || quantiles.is_null() || levels.is_null() || out_values.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
     }
 
-    if values.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    if n_levels == 0 {
+        set_error(
+            out_error,
+            ErrorCode::InvalidInput,
+            "Must have at least one quantile level",
+        );
         return false;
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let max_p = if max_periods > 0 {
-            Some(max_periods as usize)
-        } else {
-            None
-        };
-        let min_c = if min_confidence > 0.0 {
-            Some(min_confidence)
-        } else {
-            None
-        };
-        let min_s = if min_strength > 0.0 {
-            Some(min_strength)
-        } else {
-            None
-        };
-        anofox_fcst_core::detect_multiple_periods_ts(&values_vec, max_p, min_c, min_s)
+        let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
+        let levels_vec = std::slice::from_raw_parts(levels, n_levels).to_vec();
+
+        let forecasts_vec = read_quantile_forecasts(quantiles, n_levels, actual_len)?;
+
+        anofox_fcst_core::quantile_coverage(&actual_vec, &forecasts_vec, &levels_vec)
     }));
 
     match result {
-        Ok(Ok(multi_result)) => {
-            let n = multi_result.periods.len();
-            (*out_result).n_periods = n;
-            (*out_result).primary_period = multi_result.primary_period;
-            copy_string_to_buffer(&multi_result.method, &mut (*out_result).method);
-
-            if n > 0 {
-                let periods_ptr = malloc(n * std::mem::size_of::<types::DetectedPeriodFFI>())
-                    as *mut types::DetectedPeriodFFI;
-                for (i, dp) in multi_result.periods.iter().enumerate() {
-                    (*periods_ptr.add(i)).period = dp.period;
-                    (*periods_ptr.add(i)).confidence = dp.confidence;
-                    (*periods_ptr.add(i)).strength = dp.strength;
-                    (*periods_ptr.add(i)).amplitude = dp.amplitude;
-                    (*periods_ptr.add(i)).phase = dp.phase;
-                    (*periods_ptr.add(i)).iteration = dp.iteration;
+        Ok(Ok(coverage)) => {
+            match alloc_or_error(&coverage, out_error, "Failed to allocate coverage") {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
                 }
-                (*out_result).periods = periods_ptr;
-            } else {
-                (*out_result).periods = ptr::null_mut();
+                Err(()) => false,
             }
-
-            true
         }
         Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
-/// Detect multiple periods in time series.
-///
-/// Returns a flattened result with parallel arrays for safer FFI.
-/// This version avoids memory issues when used through R's DuckDB bindings.
+/// Continuous Ranked Probability Score from quantile forecasts.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// quantiles is a 2D array: n_levels arrays, each of length actual_len.
+/// levels must be strictly increasing in (0, 1).
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_multiple_periods_flat(
-    values: *const c_double,
-    length: size_t,
-    max_periods: c_int,
-    min_confidence: c_double,
-    min_strength: c_double,
-    out_result: *mut types::FlatMultiPeriodResult,
+pub unsafe extern "C" fn anofox_ts_crps(
+    actual: *const c_double,
+    actual_len: size_t,
+    quantiles: *const *const c_double, // Array of pointers to quantile forecast arrays
+    n_levels: size_t,
+    levels: *const c_double,
+    out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_result.is_null() {
+    if actual.is_null() || quantiles.is_null() || levels.is_null() || out_result.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
-    let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let max_p = if max_periods > 0 {
-            Some(max_periods as usize)
-        } else {
-            None
-        };
-        let min_c = if min_confidence > 0.0 {
-            Some(min_confidence)
-        } else {
-            None
-        };
-        let min_s = if min_strength > 0.0 {
-            Some(min_strength)
-        } else {
-            None
-        };
-        anofox_fcst_core::detect_multiple_periods_ts(&values_vec, max_p, min_c, min_s)
-    }));
-
-    match result {
-        Ok(Ok(multi_result)) => {
-            let n = multi_result.periods.len();
-            (*out_result).n_periods = n;
-            (*out_result).primary_period = multi_result.primary_period;
-            copy_string_to_buffer(&multi_result.method, &mut (*out_result).method);
+    if n_levels == 0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(
+                ErrorCode::InvalidInput,
+                "Must have at least one quantile level",
+            );
+        }
+        return false;
+    }
 
-            if n > 0 {
-                // Allocate parallel arrays instead of struct array
-                let period_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let confidence_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let strength_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let amplitude_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let phase_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let iteration_ptr = malloc(n * std::mem::size_of::<size_t>()) as *mut size_t;
-                let matches_expected_ptr = malloc(n * std::mem::size_of::<bool>()) as *mut bool;
-                let matched_expected_ptr =
-                    malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let match_deviation_ptr =
-                    malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
+        let levels_vec = std::slice::from_raw_parts(levels, n_levels).to_vec();
 
-                for (i, dp) in multi_result.periods.iter().enumerate() {
-                    *period_ptr.add(i) = dp.period;
-                    *confidence_ptr.add(i) = dp.confidence;
-                    *strength_ptr.add(i) = dp.strength;
-                    *amplitude_ptr.add(i) = dp.amplitude;
-                    *phase_ptr.add(i) = dp.phase;
-                    *iteration_ptr.add(i) = dp.iteration;
-                    *matches_expected_ptr.add(i) = dp.matches_expected;
-                    *matched_expected_ptr.add(i) = dp.matched_expected_period.unwrap_or(f64::NAN);
-                    *match_deviation_ptr.add(i) = dp.match_deviation.unwrap_or(f64::NAN);
-                }
+        let forecasts_vec = read_quantile_forecasts(quantiles, n_levels, actual_len)?;
 
-                (*out_result).period_values = period_ptr;
-                (*out_result).confidence_values = confidence_ptr;
-                (*out_result).strength_values = strength_ptr;
-                (*out_result).amplitude_values = amplitude_ptr;
-                (*out_result).phase_values = phase_ptr;
-                (*out_result).iteration_values = iteration_ptr;
-                (*out_result).matches_expected_values = matches_expected_ptr;
-                (*out_result).matched_expected_values = matched_expected_ptr;
-                (*out_result).match_deviation_values = match_deviation_ptr;
-            } else {
-                (*out_result).period_values = ptr::null_mut();
-                (*out_result).confidence_values = ptr::null_mut();
-                (*out_result).strength_values = ptr::null_mut();
-                (*out_result).amplitude_values = ptr::null_mut();
-                (*out_result).phase_values = ptr::null_mut();
-                (*out_result).iteration_values = ptr::null_mut();
-                (*out_result).matches_expected_values = ptr::null_mut();
-                (*out_result).matched_expected_values = ptr::null_mut();
-                (*out_result).match_deviation_values = ptr::null_mut();
-            }
+        anofox_fcst_core::crps(&actual_vec, &forecasts_vec, &levels_vec)
+    }));
 
+    match result {
+        Ok(Ok(value)) => {
+            *out_result = value;
             true
         }
         Ok(Err(e)) => {
@@ -1488,23 +1475,24 @@ pub unsafe extern "C" fn anofox_ts_detect_multiple_periods_flat(
     }
 }
 
-/// Autoperiod: FFT period detection with ACF validation.
+/// Coverage of prediction intervals.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_autoperiod(
-    values: *const c_double,
-    length: size_t,
-    acf_threshold: c_double,
-    out_result: *mut types::AutoperiodResultFFI,
+pub unsafe extern "C" fn anofox_ts_coverage(
+    actual: *const c_double,
+    actual_len: size_t,
+    lower: *const c_double,
+    upper: *const c_double,
+    out_result: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_result.is_null() {
+    if actual.is_null() || lower.is_null() || upper.is_null() || out_result.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
@@ -1512,22 +1500,15 @@ pub unsafe extern "C" fn anofox_ts_autoperiod(
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let threshold = if acf_threshold > 0.0 {
-            Some(acf_threshold)
-        } else {
-            None
-        };
-        anofox_fcst_core::autoperiod(&values_vec, threshold)
+        let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
+        let lower_vec = std::slice::from_raw_parts(lower, actual_len).to_vec();
+        let upper_vec = std::slice::from_raw_parts(upper, actual_len).to_vec();
+        anofox_fcst_core::coverage(&actual_vec, &lower_vec, &upper_vec)
     }));
 
     match result {
-        Ok(Ok(ap_result)) => {
-            (*out_result).period = ap_result.period;
-            (*out_result).fft_confidence = ap_result.fft_confidence;
-            (*out_result).acf_validation = ap_result.acf_validation;
-            (*out_result).detected = ap_result.detected;
-            copy_string_to_buffer(&ap_result.method, &mut (*out_result).method);
+        Ok(Ok(value)) => {
+            *out_result = value;
             true
         }
         Ok(Err(e)) => {
@@ -1545,282 +1526,308 @@ pub unsafe extern "C" fn anofox_ts_autoperiod(
     }
 }
 
-/// CFD Autoperiod: First-differenced FFT with ACF validation.
+/// Compute the standard point-accuracy metrics (MAE, MSE, RMSE, MAPE, sMAPE,
+/// bias, R²) in a single call.
+///
+/// Non-finite (actual, forecast) pairs are dropped once, so all metrics in the
+/// bundle are computed over the same observations.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_cfd_autoperiod(
-    values: *const c_double,
-    length: size_t,
-    acf_threshold: c_double,
-    out_result: *mut types::AutoperiodResultFFI,
+pub unsafe extern "C" fn anofox_ts_metrics_all(
+    actual: *const c_double,
+    actual_len: size_t,
+    forecast: *const c_double,
+    forecast_len: size_t,
+    out_result: *mut MetricsBundle,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
+    init_error(out_error);
 
-    if values.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    let ptrs = &[
+        actual as *const core::ffi::c_void,
+        forecast as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
         return false;
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let threshold = if acf_threshold > 0.0 {
-            Some(acf_threshold)
-        } else {
-            None
-        };
-        anofox_fcst_core::cfd_autoperiod(&values_vec, threshold)
+        let actual_vec = std::slice::from_raw_parts(actual, actual_len).to_vec();
+        let forecast_vec = std::slice::from_raw_parts(forecast, forecast_len).to_vec();
+        anofox_fcst_core::point_metrics(&actual_vec, &forecast_vec)
     }));
 
     match result {
-        Ok(Ok(ap_result)) => {
-            (*out_result).period = ap_result.period;
-            (*out_result).fft_confidence = ap_result.fft_confidence;
-            (*out_result).acf_validation = ap_result.acf_validation;
-            (*out_result).detected = ap_result.detected;
-            copy_string_to_buffer(&ap_result.method, &mut (*out_result).method);
+        Ok(Ok(metrics)) => {
+            *out_result = metrics.into();
             true
         }
         Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
-/// Lomb-Scargle periodogram for period detection.
+/// Compute MAE, RMSE and bias separately for each forecast step.
 ///
-/// Optimal for detecting periodic signals in unevenly sampled data.
-/// More robust than FFT for noisy data and provides statistical significance.
+/// `actual` and `forecast` are 2D arrays of `n_origins` rows; row `i` holds
+/// `lengths[i]` values, the 1..h-step-ahead values of one forecast origin.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Each row must have
+/// `lengths[i]` elements.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_lomb_scargle(
-    values: *const c_double,
-    length: size_t,
-    min_period: c_double,
-    max_period: c_double,
-    n_frequencies: size_t,
-    out_result: *mut types::LombScargleResultFFI,
+pub unsafe extern "C" fn anofox_ts_metrics_by_horizon(
+    actual: *const *const c_double,
+    forecast: *const *const c_double,
+    lengths: *const size_t,
+    n_origins: size_t,
+    out_result: *mut HorizonMetricsResult,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
+    init_error(out_error);
 
-    if values.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    let ptrs = &[
+        actual as *const core::ffi::c_void,
+        forecast as *const core::ffi::c_void,
+        lengths as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
         return false;
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let min_p = if min_period > 0.0 {
-            Some(min_period)
-        } else {
-            None
-        };
-        let max_p = if max_period > 0.0 {
-            Some(max_period)
-        } else {
-            None
-        };
-        let n_freq = if n_frequencies > 0 {
-            Some(n_frequencies)
-        } else {
-            None
-        };
-        anofox_fcst_core::lomb_scargle(&values_vec, None, min_p, max_p, n_freq)
+        let lengths = std::slice::from_raw_parts(lengths, n_origins);
+        let mut actual_rows = Vec::with_capacity(n_origins);
+        let mut forecast_rows = Vec::with_capacity(n_origins);
+        for (i, &len) in lengths.iter().enumerate() {
+            let (a_ptr, f_ptr) = (*actual.add(i), *forecast.add(i));
+            if len > 0 && (a_ptr.is_null() || f_ptr.is_null()) {
+                return Err(anofox_fcst_core::ForecastError::InvalidInput(format!(
+                    "Null pointer at origin index {}",
+                    i
+                )));
+            }
+            if len == 0 {
+                actual_rows.push(Vec::new());
+                forecast_rows.push(Vec::new());
+            } else {
+                actual_rows.push(std::slice::from_raw_parts(a_ptr, len).to_vec());
+                forecast_rows.push(std::slice::from_raw_parts(f_ptr, len).to_vec());
+            }
+        }
+        anofox_fcst_core::metrics_by_horizon(&actual_rows, &forecast_rows)
     }));
 
     match result {
-        Ok(Ok(ls_result)) => {
-            (*out_result).period = ls_result.period;
-            (*out_result).frequency = ls_result.frequency;
-            (*out_result).power = ls_result.power;
-            (*out_result).false_alarm_prob = ls_result.false_alarm_prob;
-            copy_string_to_buffer(&ls_result.method, &mut (*out_result).method);
+        Ok(Ok(steps)) => {
+            let counts: Vec<usize> = steps.iter().map(|s| s.n).collect();
+            let mae: Vec<f64> = steps.iter().map(|s| s.mae).collect();
+            let rmse: Vec<f64> = steps.iter().map(|s| s.rmse).collect();
+            let bias: Vec<f64> = steps.iter().map(|s| s.bias).collect();
+            *out_result = HorizonMetricsResult {
+                n_steps: steps.len(),
+                counts: allocation::slice_to_c_array(&counts),
+                mae: vec_to_c_array(&mae),
+                rmse: vec_to_c_array(&rmse),
+                bias: vec_to_c_array(&bias),
+            };
             true
         }
         Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
-/// AIC-based period comparison.
-///
-/// Fits sinusoidal models with different candidate periods and selects
-/// the one with the lowest AIC.
+// ============================================================================
+// Seasonality Functions
+// ============================================================================
+
+/// Detect seasonal periods in a time series.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_aic_period(
+pub unsafe extern "C" fn anofox_ts_detect_seasonality(
     values: *const c_double,
     length: size_t,
-    min_period: c_double,
-    max_period: c_double,
-    n_candidates: size_t,
-    out_result: *mut types::AicPeriodResultFFI,
+    max_period: c_int,
+    out_periods: *mut *mut c_int,
+    out_n_periods: *mut size_t,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
+    init_error(out_error);
 
-    if values.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_periods as *const core::ffi::c_void,
+        out_n_periods as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
         return false;
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let min_p = if min_period > 0.0 {
-            Some(min_period)
-        } else {
-            None
-        };
-        let max_p = if max_period > 0.0 {
-            Some(max_period)
-        } else {
-            None
-        };
-        let n_cand = if n_candidates > 0 {
-            Some(n_candidates)
-        } else {
-            None
-        };
-        anofox_fcst_core::aic_comparison(&values_vec, min_p, max_p, n_cand, None)
+        anofox_fcst_core::detect_seasonality(&values_vec, to_option_usize(max_period))
     }));
 
     match result {
-        Ok(Ok(aic_result)) => {
-            (*out_result).period = aic_result.period;
-            (*out_result).aic = aic_result.aic;
-            (*out_result).bic = aic_result.bic;
-            (*out_result).rss = aic_result.rss;
-            (*out_result).r_squared = aic_result.r_squared;
-            copy_string_to_buffer(&aic_result.method, &mut (*out_result).method);
+        Ok(Ok(periods)) => {
+            let n = periods.len();
+            *out_n_periods = n;
+
+            if n > 0 {
+                let ptr = malloc(n * std::mem::size_of::<c_int>()) as *mut c_int;
+                if ptr.is_null() {
+                    set_error(
+                        out_error,
+                        ErrorCode::AllocationError,
+                        "Memory allocation failed",
+                    );
+                    return false;
+                }
+                for (i, &p) in periods.iter().enumerate() {
+                    *ptr.add(i) = p;
+                }
+                *out_periods = ptr;
+            } else {
+                *out_periods = ptr::null_mut();
+            }
             true
         }
         Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
-/// SSA (Singular Spectrum Analysis) for period detection.
+/// Analyze seasonality in a time series.
+///
+/// `strength_method` ("variance", "spectral" or "wavelet") selects the
+/// seasonal-strength estimator; null keeps the default autocorrelation-based
+/// strength.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null (except `strength_method`).
+/// Arrays must have the specified lengths.
+/// Note: timestamps parameter is for API compatibility but is ignored internally.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_ssa_period(
+pub unsafe extern "C" fn anofox_ts_analyze_seasonality(
+    _timestamps: *const i64, // Ignored, for C++ API compatibility
+    _timestamps_len: size_t, // Ignored
     values: *const c_double,
     length: size_t,
-    window_size: size_t,
-    n_components: size_t,
-    out_result: *mut types::SsaPeriodResultFFI,
+    max_period: c_int,
+    strength_method: *const c_char,
+    out_result: *mut SeasonalityResult,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
+    init_error(out_error);
 
-    if values.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
         return false;
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let win_size = if window_size > 0 {
-            Some(window_size)
-        } else {
+        let method = if strength_method.is_null() {
             None
-        };
-        let n_comp = if n_components > 0 {
-            Some(n_components)
         } else {
-            None
+            CStr::from_ptr(strength_method)
+                .to_str()
+                .ok()
+                .and_then(|s| s.parse::<anofox_fcst_core::StrengthMethod>().ok())
         };
-        anofox_fcst_core::ssa_period(&values_vec, win_size, n_comp)
+        anofox_fcst_core::analyze_seasonality_with_strength(
+            &values_vec,
+            to_option_usize(max_period),
+            method,
+        )
     }));
 
     match result {
-        Ok(Ok(ssa_result)) => {
-            (*out_result).period = ssa_result.period;
-            (*out_result).variance_explained = ssa_result.variance_explained;
-            (*out_result).n_eigenvalues = ssa_result.eigenvalues.len();
-            copy_string_to_buffer(&ssa_result.method, &mut (*out_result).method);
+        Ok(Ok(analysis)) => {
+            let n = analysis.periods.len();
+
+            // Allocate and copy detected_periods
+            if n > 0 {
+                let periods_ptr = malloc(n * std::mem::size_of::<c_int>()) as *mut c_int;
+
+                if periods_ptr.is_null() {
+                    set_error(
+                        out_error,
+                        ErrorCode::AllocationError,
+                        "Memory allocation failed",
+                    );
+                    return false;
+                }
+
+                for (i, &p) in analysis.periods.iter().enumerate() {
+                    *periods_ptr.add(i) = p;
+                }
+
+                (*out_result).detected_periods = periods_ptr;
+            } else {
+                (*out_result).detected_periods = ptr::null_mut();
+            }
+
+            (*out_result).n_periods = n;
+            (*out_result).primary_period = analysis.primary_period;
+            (*out_result).seasonal_strength = analysis.seasonal_strength;
+            (*out_result).trend_strength = analysis.trend_strength;
+
             true
         }
         Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
-/// STL-based period detection via seasonal strength optimization.
+// ============================================================================
+// Period Detection Functions (fdars-core integration)
+// ============================================================================
+
+/// Detect periods using specified method.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_stl_period(
+pub unsafe extern "C" fn anofox_ts_detect_periods(
     values: *const c_double,
     length: size_t,
-    min_period: size_t,
-    max_period: size_t,
-    n_candidates: size_t,
-    out_result: *mut types::StlPeriodResultFFI,
+    method: *const c_char,
+    out_result: *mut types::MultiPeriodResult,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -1836,30 +1843,39 @@ pub unsafe extern "C" fn anofox_ts_stl_period(
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let min_p = if min_period > 0 {
-            Some(min_period)
-        } else {
-            None
-        };
-        let max_p = if max_period > 0 {
-            Some(max_period)
-        } else {
-            None
-        };
-        let n_cand = if n_candidates > 0 {
-            Some(n_candidates)
+        let method_str = if method.is_null() {
+            "fft"
         } else {
-            None
+            CStr::from_ptr(method).to_str().unwrap_or("fft")
         };
-        anofox_fcst_core::stl_period(&values_vec, min_p, max_p, n_cand)
+        let period_method: anofox_fcst_core::PeriodMethod = method_str.parse().unwrap_or_default();
+        // Use defaults: max_period (None = 365), min_confidence (None = method-specific default)
+        anofox_fcst_core::detect_periods(&values_vec, period_method, None, None)
     }));
 
     match result {
-        Ok(Ok(stl_result)) => {
-            (*out_result).period = stl_result.period;
-            (*out_result).seasonal_strength = stl_result.seasonal_strength;
-            (*out_result).trend_strength = stl_result.trend_strength;
-            copy_string_to_buffer(&stl_result.method, &mut (*out_result).method);
+        Ok(Ok(multi_result)) => {
+            let n = multi_result.periods.len();
+            (*out_result).n_periods = n;
+            (*out_result).primary_period = multi_result.primary_period;
+            copy_string_to_buffer(&multi_result.method, &mut (*out_result).method);
+
+            if n > 0 {
+                let periods_ptr = malloc(n * std::mem::size_of::<types::DetectedPeriodFFI>())
+                    as *mut types::DetectedPeriodFFI;
+                for (i, dp) in multi_result.periods.iter().enumerate() {
+                    (*periods_ptr.add(i)).period = dp.period;
+                    (*periods_ptr.add(i)).confidence = dp.confidence;
+                    (*periods_ptr.add(i)).strength = dp.strength;
+                    (*periods_ptr.add(i)).amplitude = dp.amplitude;
+                    (*periods_ptr.add(i)).phase = dp.phase;
+                    (*periods_ptr.add(i)).iteration = dp.iteration;
+                }
+                (*out_result).periods = periods_ptr;
+            } else {
+                (*out_result).periods = ptr::null_mut();
+            }
+
             true
         }
         Ok(Err(e)) => {
@@ -1877,20 +1893,33 @@ pub unsafe extern "C" fn anofox_ts_stl_period(
     }
 }
 
-/// Matrix Profile period detection.
+/// Detect multiple periods in a time series using the specified method.
 ///
-/// Uses Matrix Profile to find motifs and estimate periodicity from
-/// the distribution of motif distances.
+/// Returns a flattened result with parallel arrays for safer FFI.
+/// This version avoids memory issues when used through R's DuckDB bindings.
+///
+/// # Arguments
+/// * `max_period` - Maximum period to search (0 = use default of 365)
+/// * `min_confidence` - Minimum confidence threshold; periods below this are filtered out.
+///   Use negative value (e.g., -1.0) to use method-specific default.
+///   Use 0.0 to disable filtering. Use positive value for custom threshold.
+/// * `expected_periods` - Optional array of expected periods to validate against (NULL = no validation)
+/// * `n_expected` - Number of expected periods (0 if expected_periods is NULL)
+/// * `tolerance` - Relative tolerance for matching (negative = use default 0.1)
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_matrix_profile_period(
+pub unsafe extern "C" fn anofox_ts_detect_periods_flat(
     values: *const c_double,
     length: size_t,
-    subsequence_length: size_t,
-    n_best: size_t,
-    out_result: *mut types::MatrixProfilePeriodResultFFI,
+    method: *const c_char,
+    max_period: size_t,
+    min_confidence: c_double,
+    expected_periods: *const c_double,
+    n_expected: size_t,
+    tolerance: c_double,
+    out_result: *mut types::FlatMultiPeriodResult,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -1906,22 +1935,103 @@ pub unsafe extern "C" fn anofox_ts_matrix_profile_period(
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let subseq_len = if subsequence_length > 0 {
-            Some(subsequence_length)
+        let method_str = if method.is_null() {
+            "fft"
+        } else {
+            CStr::from_ptr(method).to_str().unwrap_or("fft")
+        };
+        let period_method: anofox_fcst_core::PeriodMethod = method_str.parse().unwrap_or_default();
+        // Convert 0 to None (use default), otherwise Some(max_period)
+        let max_period_opt = if max_period == 0 {
+            None
+        } else {
+            Some(max_period)
+        };
+        // Convert min_confidence: negative = use default, 0 or positive = use value
+        let min_confidence_opt = if min_confidence < 0.0 || min_confidence.is_nan() {
+            None // Use method-specific default
         } else {
+            Some(min_confidence) // 0.0 disables filtering, positive value is threshold
+        };
+
+        // Convert expected_periods: NULL or n_expected=0 means no validation
+        let expected_vec: Option<Vec<f64>> = if expected_periods.is_null() || n_expected == 0 {
+            None
+        } else {
+            Some(std::slice::from_raw_parts(expected_periods, n_expected).to_vec())
+        };
+
+        // Convert tolerance: negative = use default
+        let tolerance_opt = if tolerance < 0.0 || tolerance.is_nan() {
             None
+        } else {
+            Some(tolerance)
         };
-        let n_b = if n_best > 0 { Some(n_best) } else { None };
-        anofox_fcst_core::matrix_profile_period(&values_vec, subseq_len, n_b)
+
+        anofox_fcst_core::detect_periods_with_validation(
+            &values_vec,
+            period_method,
+            max_period_opt,
+            min_confidence_opt,
+            expected_vec.as_deref(),
+            tolerance_opt,
+        )
     }));
 
     match result {
-        Ok(Ok(mp_result)) => {
-            (*out_result).period = mp_result.period;
-            (*out_result).confidence = mp_result.confidence;
-            (*out_result).n_motifs = mp_result.n_motifs;
-            (*out_result).subsequence_length = mp_result.subsequence_length;
-            copy_string_to_buffer(&mp_result.method, &mut (*out_result).method);
+        Ok(Ok(multi_result)) => {
+            let n = multi_result.periods.len();
+            (*out_result).n_periods = n;
+            (*out_result).primary_period = multi_result.primary_period;
+            copy_string_to_buffer(&multi_result.method, &mut (*out_result).method);
+
+            if n > 0 {
+                // Allocate parallel arrays instead of struct array
+                let period_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let confidence_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let strength_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let amplitude_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let phase_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let iteration_ptr = malloc(n * std::mem::size_of::<size_t>()) as *mut size_t;
+                let matches_expected_ptr = malloc(n * std::mem::size_of::<bool>()) as *mut bool;
+                let matched_expected_ptr =
+                    malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let match_deviation_ptr =
+                    malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+
+                for (i, dp) in multi_result.periods.iter().enumerate() {
+                    *period_ptr.add(i) = dp.period;
+                    *confidence_ptr.add(i) = dp.confidence;
+                    *strength_ptr.add(i) = dp.strength;
+                    *amplitude_ptr.add(i) = dp.amplitude;
+                    *phase_ptr.add(i) = dp.phase;
+                    *iteration_ptr.add(i) = dp.iteration;
+                    *matches_expected_ptr.add(i) = dp.matches_expected;
+                    *matched_expected_ptr.add(i) = dp.matched_expected_period.unwrap_or(f64::NAN);
+                    *match_deviation_ptr.add(i) = dp.match_deviation.unwrap_or(f64::NAN);
+                }
+
+                (*out_result).period_values = period_ptr;
+                (*out_result).confidence_values = confidence_ptr;
+                (*out_result).strength_values = strength_ptr;
+                (*out_result).amplitude_values = amplitude_ptr;
+                (*out_result).phase_values = phase_ptr;
+                (*out_result).iteration_values = iteration_ptr;
+                (*out_result).matches_expected_values = matches_expected_ptr;
+                (*out_result).matched_expected_values = matched_expected_ptr;
+                (*out_result).match_deviation_values = match_deviation_ptr;
+            } else {
+                (*out_result).period_values = ptr::null_mut();
+                (*out_result).confidence_values = ptr::null_mut();
+                (*out_result).strength_values = ptr::null_mut();
+                (*out_result).amplitude_values = ptr::null_mut();
+                (*out_result).phase_values = ptr::null_mut();
+                (*out_result).iteration_values = ptr::null_mut();
+                (*out_result).matches_expected_values = ptr::null_mut();
+                (*out_result).matched_expected_values = ptr::null_mut();
+                (*out_result).match_deviation_values = ptr::null_mut();
+            }
+
             true
         }
         Ok(Err(e)) => {
@@ -1939,18 +2049,15 @@ pub unsafe extern "C" fn anofox_ts_matrix_profile_period(
     }
 }
 
-/// SAZED period detection using spectral analysis with zero-padding.
+/// Estimate period using FFT.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_sazed_period(
+pub unsafe extern "C" fn anofox_ts_estimate_period_fft(
     values: *const c_double,
     length: size_t,
-    min_period: size_t,
-    max_period: size_t,
-    zero_pad_factor: size_t,
-    out_result: *mut types::SazedPeriodResultFFI,
+    out_result: *mut types::SinglePeriodResult,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -1966,31 +2073,16 @@ pub unsafe extern "C" fn anofox_ts_sazed_period(
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let zp_factor = if zero_pad_factor > 0 {
-            Some(zero_pad_factor)
-        } else {
-            None
-        };
-        let min_p = if min_period > 0 {
-            Some(min_period)
-        } else {
-            None
-        };
-        let max_p = if max_period > 0 {
-            Some(max_period)
-        } else {
-            None
-        };
-        // sazed_period signature: padding_factor, min_period, max_period
-        anofox_fcst_core::sazed_period(&values_vec, zp_factor, min_p, max_p)
+        anofox_fcst_core::estimate_period_fft_ts(&values_vec)
     }));
 
     match result {
-        Ok(Ok(sazed_result)) => {
-            (*out_result).period = sazed_result.period;
-            (*out_result).power = sazed_result.power;
-            (*out_result).snr = sazed_result.snr;
-            copy_string_to_buffer(&sazed_result.method, &mut (*out_result).method);
+        Ok(Ok(single_result)) => {
+            (*out_result).period = single_result.period;
+            (*out_result).frequency = single_result.frequency;
+            (*out_result).power = single_result.power;
+            (*out_result).confidence = single_result.confidence;
+            copy_string_to_buffer(&single_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2008,22 +2100,16 @@ pub unsafe extern "C" fn anofox_ts_sazed_period(
     }
 }
 
-// ============================================================================
-// Peak Detection Functions (fdars-core integration)
-// ============================================================================
-
-/// Detect peaks in time series.
+/// Estimate period using ACF.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_peaks(
+pub unsafe extern "C" fn anofox_ts_estimate_period_acf(
     values: *const c_double,
     length: size_t,
-    min_distance: c_double,
-    min_prominence: c_double,
-    smooth_first: bool,
-    out_result: *mut types::PeakDetectionResultFFI,
+    max_lag: c_int,
+    out_result: *mut types::SinglePeriodResult,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -2039,48 +2125,21 @@ pub unsafe extern "C" fn anofox_ts_detect_peaks(
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let min_dist = if min_distance > 0.0 {
-            Some(min_distance)
-        } else {
-            None
-        };
-        let min_prom = if min_prominence > 0.0 {
-            Some(min_prominence)
+        let max_lag_opt = if max_lag > 0 {
+            Some(max_lag as usize)
         } else {
             None
         };
-        anofox_fcst_core::detect_peaks(&values_vec, min_dist, min_prom, smooth_first, None)
+        anofox_fcst_core::estimate_period_acf_ts(&values_vec, max_lag_opt)
     }));
 
     match result {
-        Ok(Ok(peak_result)) => {
-            let n = peak_result.n_peaks;
-            (*out_result).n_peaks = n;
-            (*out_result).mean_period = peak_result.mean_period;
-
-            if n > 0 {
-                let peaks_ptr =
-                    malloc(n * std::mem::size_of::<types::PeakFFI>()) as *mut types::PeakFFI;
-                for (i, peak) in peak_result.peaks.iter().enumerate() {
-                    (*peaks_ptr.add(i)).index = peak.index;
-                    (*peaks_ptr.add(i)).time = peak.time;
-                    (*peaks_ptr.add(i)).value = peak.value;
-                    (*peaks_ptr.add(i)).prominence = peak.prominence;
-                }
-                (*out_result).peaks = peaks_ptr;
-            } else {
-                (*out_result).peaks = ptr::null_mut();
-            }
-
-            let n_dist = peak_result.inter_peak_distances.len();
-            (*out_result).n_distances = n_dist;
-            if n_dist > 0 {
-                (*out_result).inter_peak_distances =
-                    vec_to_c_array(&peak_result.inter_peak_distances);
-            } else {
-                (*out_result).inter_peak_distances = ptr::null_mut();
-            }
-
+        Ok(Ok(single_result)) => {
+            (*out_result).period = single_result.period;
+            (*out_result).frequency = single_result.frequency;
+            (*out_result).power = single_result.power;
+            (*out_result).confidence = single_result.confidence;
+            copy_string_to_buffer(&single_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2098,16 +2157,18 @@ pub unsafe extern "C" fn anofox_ts_detect_peaks(
     }
 }
 
-/// Analyze peak timing variability.
+/// Detect multiple periods in time series.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_analyze_peak_timing(
+pub unsafe extern "C" fn anofox_ts_detect_multiple_periods(
     values: *const c_double,
     length: size_t,
-    period: c_double,
-    out_result: *mut types::PeakTimingResultFFI,
+    max_periods: c_int,
+    min_confidence: c_double,
+    min_strength: c_double,
+    out_result: *mut types::MultiPeriodResult,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -2121,37 +2182,56 @@ pub unsafe extern "C" fn anofox_ts_analyze_peak_timing(
         return false;
     }
 
-    if period <= 0.0 {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
-        }
-        return false;
-    }
-
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        anofox_fcst_core::analyze_peak_timing(&values_vec, period, None)
+        let max_p = if max_periods > 0 {
+            Some(max_periods as usize)
+        } else {
+            None
+        };
+        let min_c = if min_confidence > 0.0 {
+            Some(min_confidence)
+        } else {
+            None
+        };
+        let min_s = if min_strength > 0.0 {
+            Some(min_strength)
+        } else {
+            None
+        };
+        anofox_fcst_core::detect_multiple_periods_ts(&values_vec, max_p, min_c, min_s)
     }));
 
     match result {
-        Ok(Ok(timing_result)) => {
-            let n = timing_result.peak_times.len();
-            (*out_result).n_peaks = n;
-            (*out_result).mean_timing = timing_result.mean_timing;
-            (*out_result).std_timing = timing_result.std_timing;
-            (*out_result).range_timing = timing_result.range_timing;
-            (*out_result).variability_score = timing_result.variability_score;
-            (*out_result).timing_trend = timing_result.timing_trend;
-            (*out_result).is_stable = timing_result.is_stable;
+        Ok(Ok(multi_result)) => {
+            let n = multi_result.periods.len();
+            (*out_result).n_periods = n;
+            (*out_result).primary_period = multi_result.primary_period;
+            copy_string_to_buffer(&multi_result.method, &mut (*out_result).method);
 
             if n > 0 {
-                (*out_result).peak_times = vec_to_c_array(&timing_result.peak_times);
-                (*out_result).peak_values = vec_to_c_array(&timing_result.peak_values);
-                (*out_result).normalized_timing = vec_to_c_array(&timing_result.normalized_timing);
-            } else {
-                (*out_result).peak_times = ptr::null_mut();
-                (*out_result).peak_values = ptr::null_mut();
-                (*out_result).normalized_timing = ptr::null_mut();
+                let periods_ptr = malloc(n * std::mem::size_of::<types::DetectedPeriodFFI>())
+                    as *mut types::DetectedPeriodFFI;
+                if periods_ptr.is_null() {
+                    (*out_result).n_periods = 0;
+                    (*out_result).periods = ptr::null_mut();
+                    if !out_error.is_null() {
+                        (*out_error)
+                            .set_error(ErrorCode::AllocationError, "Failed to allocate periods");
+                    }
+                    return false;
+                }
+                for (i, dp) in multi_result.periods.iter().enumerate() {
+                    (*periods_ptr.add(i)).period = dp.period;
+                    (*periods_ptr.add(i)).confidence = dp.confidence;
+                    (*periods_ptr.add(i)).strength = dp.strength;
+                    (*periods_ptr.add(i)).amplitude = dp.amplitude;
+                    (*periods_ptr.add(i)).phase = dp.phase;
+                    (*periods_ptr.add(i)).iteration = dp.iteration;
+                }
+                (*out_result).periods = periods_ptr;
+            } else {
+                (*out_result).periods = ptr::null_mut();
             }
 
             true
@@ -2171,20 +2251,21 @@ pub unsafe extern "C" fn anofox_ts_analyze_peak_timing(
     }
 }
 
-// ============================================================================
-// Detrending Functions (fdars-core integration)
-// ============================================================================
-
-/// Detrend time series using specified method.
+/// Detect multiple periods in time series.
+///
+/// Returns a flattened result with parallel arrays for safer FFI.
+/// This version avoids memory issues when used through R's DuckDB bindings.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detrend(
+pub unsafe extern "C" fn anofox_ts_detect_multiple_periods_flat(
     values: *const c_double,
     length: size_t,
-    method: *const c_char,
-    out_result: *mut types::DetrendResultFFI,
+    max_periods: c_int,
+    min_confidence: c_double,
+    min_strength: c_double,
+    out_result: *mut types::FlatMultiPeriodResult,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -2200,33 +2281,76 @@ pub unsafe extern "C" fn anofox_ts_detrend(
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let method_str = if method.is_null() {
-            "auto"
+        let max_p = if max_periods > 0 {
+            Some(max_periods as usize)
         } else {
-            CStr::from_ptr(method).to_str().unwrap_or("auto")
+            None
         };
-        let detrend_method: anofox_fcst_core::DetrendMethod =
-            method_str.parse().unwrap_or_default();
-        anofox_fcst_core::detrend(&values_vec, detrend_method)
+        let min_c = if min_confidence > 0.0 {
+            Some(min_confidence)
+        } else {
+            None
+        };
+        let min_s = if min_strength > 0.0 {
+            Some(min_strength)
+        } else {
+            None
+        };
+        anofox_fcst_core::detect_multiple_periods_ts(&values_vec, max_p, min_c, min_s)
     }));
 
     match result {
-        Ok(Ok(detrend_result)) => {
-            let n = detrend_result.trend.len();
-            (*out_result).length = n;
-            (*out_result).rss = detrend_result.rss;
-            (*out_result).n_params = detrend_result.n_params;
-            copy_string_to_buffer(&detrend_result.method, &mut (*out_result).method);
+        Ok(Ok(multi_result)) => {
+            let n = multi_result.periods.len();
+            (*out_result).n_periods = n;
+            (*out_result).primary_period = multi_result.primary_period;
+            copy_string_to_buffer(&multi_result.method, &mut (*out_result).method);
 
-            (*out_result).trend = vec_to_c_array(&detrend_result.trend);
-            (*out_result).detrended = vec_to_c_array(&detrend_result.detrended);
+            if n > 0 {
+                // Allocate parallel arrays instead of struct array
+                let period_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let confidence_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let strength_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let amplitude_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let phase_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let iteration_ptr = malloc(n * std::mem::size_of::<size_t>()) as *mut size_t;
+                let matches_expected_ptr = malloc(n * std::mem::size_of::<bool>()) as *mut bool;
+                let matched_expected_ptr =
+                    malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+                let match_deviation_ptr =
+                    malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
 
-            if let Some(ref coeffs) = detrend_result.coefficients {
-                (*out_result).n_coefficients = coeffs.len();
-                (*out_result).coefficients = vec_to_c_array(coeffs);
+                for (i, dp) in multi_result.periods.iter().enumerate() {
+                    *period_ptr.add(i) = dp.period;
+                    *confidence_ptr.add(i) = dp.confidence;
+                    *strength_ptr.add(i) = dp.strength;
+                    *amplitude_ptr.add(i) = dp.amplitude;
+                    *phase_ptr.add(i) = dp.phase;
+                    *iteration_ptr.add(i) = dp.iteration;
+                    *matches_expected_ptr.add(i) = dp.matches_expected;
+                    *matched_expected_ptr.add(i) = dp.matched_expected_period.unwrap_or(f64::NAN);
+                    *match_deviation_ptr.add(i) = dp.match_deviation.unwrap_or(f64::NAN);
+                }
+
+                (*out_result).period_values = period_ptr;
+                (*out_result).confidence_values = confidence_ptr;
+                (*out_result).strength_values = strength_ptr;
+                (*out_result).amplitude_values = amplitude_ptr;
+                (*out_result).phase_values = phase_ptr;
+                (*out_result).iteration_values = iteration_ptr;
+                (*out_result).matches_expected_values = matches_expected_ptr;
+                (*out_result).matched_expected_values = matched_expected_ptr;
+                (*out_result).match_deviation_values = match_deviation_ptr;
             } else {
-                (*out_result).n_coefficients = 0;
-                (*out_result).coefficients = ptr::null_mut();
+                (*out_result).period_values = ptr::null_mut();
+                (*out_result).confidence_values = ptr::null_mut();
+                (*out_result).strength_values = ptr::null_mut();
+                (*out_result).amplitude_values = ptr::null_mut();
+                (*out_result).phase_values = ptr::null_mut();
+                (*out_result).iteration_values = ptr::null_mut();
+                (*out_result).matches_expected_values = ptr::null_mut();
+                (*out_result).matched_expected_values = ptr::null_mut();
+                (*out_result).match_deviation_values = ptr::null_mut();
             }
 
             true
@@ -2246,17 +2370,16 @@ pub unsafe extern "C" fn anofox_ts_detrend(
     }
 }
 
-/// Decompose time series into trend, seasonal, and remainder.
+/// Autoperiod: FFT period detection with ACF validation.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_decompose(
+pub unsafe extern "C" fn anofox_ts_autoperiod(
     values: *const c_double,
     length: size_t,
-    period: c_double,
-    method: *const c_char,
-    out_result: *mut types::DecomposeResultFFI,
+    acf_threshold: c_double,
+    out_result: *mut types::AutoperiodResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -2270,36 +2393,23 @@ pub unsafe extern "C" fn anofox_ts_decompose(
         return false;
     }
 
-    if period <= 0.0 {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
-        }
-        return false;
-    }
-
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let method_str = if method.is_null() {
-            "additive"
+        let threshold = if acf_threshold > 0.0 {
+            Some(acf_threshold)
         } else {
-            CStr::from_ptr(method).to_str().unwrap_or("additive")
+            None
         };
-        let decompose_method: anofox_fcst_core::DecomposeMethod =
-            method_str.parse().unwrap_or_default();
-        anofox_fcst_core::decompose(&values_vec, period, decompose_method)
+        anofox_fcst_core::autoperiod(&values_vec, threshold)
     }));
 
     match result {
-        Ok(Ok(decompose_result)) => {
-            let n = decompose_result.trend.len();
-            (*out_result).length = n;
-            (*out_result).period = decompose_result.period;
-            copy_string_to_buffer(&decompose_result.method, &mut (*out_result).method);
-
-            (*out_result).trend = vec_to_c_array(&decompose_result.trend);
-            (*out_result).seasonal = vec_to_c_array(&decompose_result.seasonal);
-            (*out_result).remainder = vec_to_c_array(&decompose_result.remainder);
-
+        Ok(Ok(ap_result)) => {
+            (*out_result).period = ap_result.period;
+            (*out_result).fft_confidence = ap_result.fft_confidence;
+            (*out_result).acf_validation = ap_result.acf_validation;
+            (*out_result).detected = ap_result.detected;
+            copy_string_to_buffer(&ap_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2317,56 +2427,46 @@ pub unsafe extern "C" fn anofox_ts_decompose(
     }
 }
 
-// ============================================================================
-// Extended Seasonality Functions (fdars-core integration)
-// ============================================================================
-
-/// Compute seasonal strength using specified method.
+/// CFD Autoperiod: First-differenced FFT with ACF validation.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_seasonal_strength(
+pub unsafe extern "C" fn anofox_ts_cfd_autoperiod(
     values: *const c_double,
     length: size_t,
-    period: c_double,
-    method: *const c_char,
-    out_strength: *mut c_double,
+    acf_threshold: c_double,
+    out_result: *mut types::AutoperiodResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_strength.is_null() {
+    if values.is_null() || out_result.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
-    if period <= 0.0 {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
-        }
-        return false;
-    }
-
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let method_str = if method.is_null() {
-            "variance"
+        let threshold = if acf_threshold > 0.0 {
+            Some(acf_threshold)
         } else {
-            CStr::from_ptr(method).to_str().unwrap_or("variance")
+            None
         };
-        let strength_method: anofox_fcst_core::StrengthMethod =
-            method_str.parse().unwrap_or_default();
-        anofox_fcst_core::seasonal_strength(&values_vec, period, strength_method)
+        anofox_fcst_core::cfd_autoperiod(&values_vec, threshold)
     }));
 
     match result {
-        Ok(Ok(strength)) => {
-            *out_strength = strength;
+        Ok(Ok(ap_result)) => {
+            (*out_result).period = ap_result.period;
+            (*out_result).fft_confidence = ap_result.fft_confidence;
+            (*out_result).acf_validation = ap_result.acf_validation;
+            (*out_result).detected = ap_result.detected;
+            copy_string_to_buffer(&ap_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2384,66 +2484,61 @@ pub unsafe extern "C" fn anofox_ts_seasonal_strength(
     }
 }
 
-/// Compute windowed seasonal strength.
+/// Lomb-Scargle periodogram for period detection.
+///
+/// Optimal for detecting periodic signals in unevenly sampled data.
+/// More robust than FFT for noisy data and provides statistical significance.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_seasonal_strength_windowed(
+pub unsafe extern "C" fn anofox_ts_lomb_scargle(
     values: *const c_double,
     length: size_t,
-    period: c_double,
-    window_size: c_double,
-    method: *const c_char,
-    out_strengths: *mut *mut c_double,
-    out_n_windows: *mut size_t,
+    min_period: c_double,
+    max_period: c_double,
+    n_frequencies: size_t,
+    out_result: *mut types::LombScargleResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_strengths.is_null() || out_n_windows.is_null() {
+    if values.is_null() || out_result.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
-    if period <= 0.0 {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
-        }
-        return false;
-    }
-
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let window_opt = if window_size > 0.0 {
-            Some(window_size)
+        let min_p = if min_period > 0.0 {
+            Some(min_period)
         } else {
             None
         };
-        let method_str = if method.is_null() {
+        let max_p = if max_period > 0.0 {
+            Some(max_period)
+        } else {
             None
+        };
+        let n_freq = if n_frequencies > 0 {
+            Some(n_frequencies)
         } else {
-            let s = CStr::from_ptr(method).to_str().unwrap_or("variance");
-            Some(
-                s.parse::<anofox_fcst_core::StrengthMethod>()
-                    .unwrap_or_default(),
-            )
+            None
         };
-        anofox_fcst_core::seasonal_strength_windowed(&values_vec, period, window_opt, method_str)
+        anofox_fcst_core::lomb_scargle(&values_vec, None, min_p, max_p, n_freq)
     }));
 
     match result {
-        Ok(Ok(strengths)) => {
-            *out_n_windows = strengths.len();
-            if !strengths.is_empty() {
-                *out_strengths = vec_to_c_array(&strengths);
-            } else {
-                *out_strengths = ptr::null_mut();
-            }
+        Ok(Ok(ls_result)) => {
+            (*out_result).period = ls_result.period;
+            (*out_result).frequency = ls_result.frequency;
+            (*out_result).power = ls_result.power;
+            (*out_result).false_alarm_prob = ls_result.false_alarm_prob;
+            copy_string_to_buffer(&ls_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2461,18 +2556,21 @@ pub unsafe extern "C" fn anofox_ts_seasonal_strength_windowed(
     }
 }
 
-/// Classify seasonality type and pattern.
+/// AIC-based period comparison.
+///
+/// Fits sinusoidal models with different candidate periods and selects
+/// the one with the lowest AIC.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_classify_seasonality(
+pub unsafe extern "C" fn anofox_ts_aic_period(
     values: *const c_double,
     length: size_t,
-    period: c_double,
-    strength_threshold: c_double,
-    timing_threshold: c_double,
-    out_result: *mut types::SeasonalityClassificationFFI,
+    min_period: c_double,
+    max_period: c_double,
+    n_candidates: size_t,
+    out_result: *mut types::AicPeriodResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -2486,61 +2584,34 @@ pub unsafe extern "C" fn anofox_ts_classify_seasonality(
         return false;
     }
 
-    if period <= 0.0 {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
-        }
-        return false;
-    }
-
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let strength_opt = if strength_threshold > 0.0 {
-            Some(strength_threshold)
+        let min_p = if min_period > 0.0 {
+            Some(min_period)
         } else {
             None
         };
-        let timing_opt = if timing_threshold > 0.0 {
-            Some(timing_threshold)
+        let max_p = if max_period > 0.0 {
+            Some(max_period)
         } else {
             None
         };
-        anofox_fcst_core::classify_seasonality(&values_vec, period, strength_opt, timing_opt)
+        let n_cand = if n_candidates > 0 {
+            Some(n_candidates)
+        } else {
+            None
+        };
+        anofox_fcst_core::aic_comparison(&values_vec, min_p, max_p, n_cand, None)
     }));
 
     match result {
-        Ok(Ok(classification)) => {
-            (*out_result).is_seasonal = classification.is_seasonal;
-            (*out_result).has_stable_timing = classification.has_stable_timing;
-            (*out_result).timing_variability = classification.timing_variability;
-            (*out_result).seasonal_strength = classification.seasonal_strength;
-            copy_string_to_buffer(
-                classification.classification.as_str(),
-                &mut (*out_result).classification,
-            );
-
-            // Copy cycle strengths
-            let n_cycles = classification.cycle_strengths.len();
-            (*out_result).n_cycle_strengths = n_cycles;
-            if n_cycles > 0 {
-                (*out_result).cycle_strengths = vec_to_c_array(&classification.cycle_strengths);
-            } else {
-                (*out_result).cycle_strengths = ptr::null_mut();
-            }
-
-            // Copy weak seasons
-            let n_weak = classification.weak_seasons.len();
-            (*out_result).n_weak_seasons = n_weak;
-            if n_weak > 0 {
-                let weak_ptr = malloc(n_weak * std::mem::size_of::<size_t>()) as *mut size_t;
-                for (i, idx) in classification.weak_seasons.iter().enumerate() {
-                    *weak_ptr.add(i) = *idx;
-                }
-                (*out_result).weak_seasons = weak_ptr;
-            } else {
-                (*out_result).weak_seasons = ptr::null_mut();
-            }
-
+        Ok(Ok(aic_result)) => {
+            (*out_result).period = aic_result.period;
+            (*out_result).aic = aic_result.aic;
+            (*out_result).bic = aic_result.bic;
+            (*out_result).rss = aic_result.rss;
+            (*out_result).r_squared = aic_result.r_squared;
+            copy_string_to_buffer(&aic_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2558,19 +2629,17 @@ pub unsafe extern "C" fn anofox_ts_classify_seasonality(
     }
 }
 
-/// Detect seasonality changes over time.
+/// SSA (Singular Spectrum Analysis) for period detection.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_seasonality_changes(
+pub unsafe extern "C" fn anofox_ts_ssa_period(
     values: *const c_double,
     length: size_t,
-    period: c_double,
-    threshold: c_double,
-    window_size: c_double,
-    min_duration: c_double,
-    out_result: *mut types::ChangeDetectionResultFFI,
+    window_size: size_t,
+    n_components: size_t,
+    out_result: *mut types::SsaPeriodResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -2584,72 +2653,27 @@ pub unsafe extern "C" fn anofox_ts_detect_seasonality_changes(
         return false;
     }
 
-    if period <= 0.0 {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
-        }
-        return false;
-    }
-
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let threshold_opt = if threshold > 0.0 {
-            Some(threshold)
-        } else {
-            None
-        };
-        let window_opt = if window_size > 0.0 {
+        let win_size = if window_size > 0 {
             Some(window_size)
         } else {
             None
         };
-        let min_dur_opt = if min_duration > 0.0 {
-            Some(min_duration)
+        let n_comp = if n_components > 0 {
+            Some(n_components)
         } else {
             None
         };
-        anofox_fcst_core::detect_seasonality_changes(
-            &values_vec,
-            period,
-            threshold_opt,
-            window_opt,
-            min_dur_opt,
-        )
+        anofox_fcst_core::ssa_period(&values_vec, win_size, n_comp)
     }));
 
     match result {
-        Ok(Ok(change_result)) => {
-            let n = change_result.change_points.len();
-            (*out_result).n_changes = n;
-
-            if n > 0 {
-                let changes_ptr =
-                    malloc(n * std::mem::size_of::<types::SeasonalityChangePointFFI>())
-                        as *mut types::SeasonalityChangePointFFI;
-                for (i, cp) in change_result.change_points.iter().enumerate() {
-                    (*changes_ptr.add(i)).index = cp.index;
-                    (*changes_ptr.add(i)).time = cp.time;
-                    copy_string_to_buffer(
-                        cp.change_type.as_str(),
-                        &mut (*changes_ptr.add(i)).change_type,
-                    );
-                    (*changes_ptr.add(i)).strength_before = cp.strength_before;
-                    (*changes_ptr.add(i)).strength_after = cp.strength_after;
-                }
-                (*out_result).change_points = changes_ptr;
-            } else {
-                (*out_result).change_points = ptr::null_mut();
-            }
-
-            // Copy strength curve
-            let n_curve = change_result.strength_curve.len();
-            (*out_result).n_strength_curve = n_curve;
-            if n_curve > 0 {
-                (*out_result).strength_curve = vec_to_c_array(&change_result.strength_curve);
-            } else {
-                (*out_result).strength_curve = ptr::null_mut();
-            }
-
+        Ok(Ok(ssa_result)) => {
+            (*out_result).period = ssa_result.period;
+            (*out_result).variance_explained = ssa_result.variance_explained;
+            (*out_result).n_eigenvalues = ssa_result.eigenvalues.len();
+            copy_string_to_buffer(&ssa_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2667,15 +2691,18 @@ pub unsafe extern "C" fn anofox_ts_detect_seasonality_changes(
     }
 }
 
-/// Compute instantaneous period using Hilbert transform.
+/// STL-based period detection via seasonal strength optimization.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_instantaneous_period(
+pub unsafe extern "C" fn anofox_ts_stl_period(
     values: *const c_double,
     length: size_t,
-    out_result: *mut types::InstantaneousPeriodResultFFI,
+    min_period: size_t,
+    max_period: size_t,
+    n_candidates: size_t,
+    out_result: *mut types::StlPeriodResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -2691,24 +2718,30 @@ pub unsafe extern "C" fn anofox_ts_instantaneous_period(
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        anofox_fcst_core::instantaneous_period(&values_vec)
+        let min_p = if min_period > 0 {
+            Some(min_period)
+        } else {
+            None
+        };
+        let max_p = if max_period > 0 {
+            Some(max_period)
+        } else {
+            None
+        };
+        let n_cand = if n_candidates > 0 {
+            Some(n_candidates)
+        } else {
+            None
+        };
+        anofox_fcst_core::stl_period(&values_vec, min_p, max_p, n_cand)
     }));
 
     match result {
-        Ok(Ok(inst_result)) => {
-            let n = inst_result.period.len();
-            (*out_result).length = n;
-
-            if n > 0 {
-                (*out_result).periods = vec_to_c_array(&inst_result.period);
-                (*out_result).frequencies = vec_to_c_array(&inst_result.frequency);
-                (*out_result).amplitudes = vec_to_c_array(&inst_result.amplitude);
-            } else {
-                (*out_result).periods = ptr::null_mut();
-                (*out_result).frequencies = ptr::null_mut();
-                (*out_result).amplitudes = ptr::null_mut();
-            }
-
+        Ok(Ok(stl_result)) => {
+            (*out_result).period = stl_result.period;
+            (*out_result).seasonal_strength = stl_result.seasonal_strength;
+            (*out_result).trend_strength = stl_result.trend_strength;
+            copy_string_to_buffer(&stl_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2726,18 +2759,23 @@ pub unsafe extern "C" fn anofox_ts_instantaneous_period(
     }
 }
 
-/// Detect amplitude modulation in seasonal time series.
+/// Matrix Profile period detection.
+///
+/// Uses Matrix Profile to find motifs and estimate periodicity from
+/// the distribution of motif distances.
+///
+/// `subsequence_length` 0 selects `length / 10`; `exclusion_zone` (the
+/// minimum distance between motif pairs) 0 selects `subsequence_length / 4`.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_amplitude_modulation(
+pub unsafe extern "C" fn anofox_ts_matrix_profile_period(
     values: *const c_double,
     length: size_t,
-    period: c_double,
-    modulation_threshold: c_double,
-    seasonality_threshold: c_double,
-    out_result: *mut types::AmplitudeModulationResultFFI,
+    subsequence_length: size_t,
+    exclusion_zone: size_t,
+    out_result: *mut types::MatrixProfilePeriodResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -2751,51 +2789,28 @@ pub unsafe extern "C" fn anofox_ts_detect_amplitude_modulation(
         return false;
     }
 
-    if period <= 0.0 {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
-        }
-        return false;
-    }
-
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let mod_thresh = if modulation_threshold > 0.0 {
-            Some(modulation_threshold)
+        let subseq_len = if subsequence_length > 0 {
+            Some(subsequence_length)
         } else {
             None
         };
-        let seas_thresh = if seasonality_threshold > 0.0 {
-            Some(seasonality_threshold)
+        let exclusion = if exclusion_zone > 0 {
+            Some(exclusion_zone)
         } else {
             None
         };
-        anofox_fcst_core::detect_amplitude_modulation(&values_vec, period, mod_thresh, seas_thresh)
+        anofox_fcst_core::matrix_profile_period(&values_vec, subseq_len, exclusion)
     }));
 
     match result {
-        Ok(Ok(am_result)) => {
-            (*out_result).is_seasonal = am_result.is_seasonal;
-            (*out_result).seasonal_strength = am_result.seasonal_strength;
-            (*out_result).has_modulation = am_result.has_modulation;
-            copy_string_to_buffer(
-                am_result.modulation_type.as_str(),
-                &mut (*out_result).modulation_type,
-            );
-            (*out_result).modulation_score = am_result.modulation_score;
-            (*out_result).amplitude_trend = am_result.amplitude_trend;
-            (*out_result).scale = am_result.scale;
-
-            let n = am_result.wavelet_amplitude.len();
-            (*out_result).n_points = n;
-            if n > 0 {
-                (*out_result).wavelet_amplitude = vec_to_c_array(&am_result.wavelet_amplitude);
-                (*out_result).time_points = vec_to_c_array(&am_result.time_points);
-            } else {
-                (*out_result).wavelet_amplitude = ptr::null_mut();
-                (*out_result).time_points = ptr::null_mut();
-            }
-
+        Ok(Ok(mp_result)) => {
+            (*out_result).period = mp_result.period;
+            (*out_result).confidence = mp_result.confidence;
+            (*out_result).n_motifs = mp_result.n_motifs;
+            (*out_result).subsequence_length = mp_result.subsequence_length;
+            copy_string_to_buffer(&mp_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2813,28 +2828,18 @@ pub unsafe extern "C" fn anofox_ts_detect_amplitude_modulation(
     }
 }
 
-// ============================================================================
-// Decomposition Functions
-// ============================================================================
-
-/// MSTL decomposition.
-///
-/// # Arguments
-/// * `insufficient_data_mode` - How to handle insufficient data:
-///   - 0 (Fail): Error on insufficient data (default)
-///   - 1 (Trend): Apply trend-only decomposition, seasonal components are empty
-///   - 2 (None): Skip decomposition entirely, return empty result
+/// SAZED period detection using spectral analysis with zero-padding.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_mstl_decomposition(
+pub unsafe extern "C" fn anofox_ts_sazed_period(
     values: *const c_double,
     length: size_t,
-    periods: *const c_int,
-    n_periods: size_t,
-    insufficient_data_mode: c_int,
-    out_result: *mut MstlResult,
+    min_period: size_t,
+    max_period: size_t,
+    zero_pad_factor: size_t,
+    out_result: *mut types::SazedPeriodResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -2848,117 +2853,33 @@ pub unsafe extern "C" fn anofox_ts_mstl_decomposition(
         return false;
     }
 
-    let mode = anofox_fcst_core::InsufficientDataMode::from_int(insufficient_data_mode);
-
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let periods_vec: Vec<i32> = if periods.is_null() || n_periods == 0 {
-            vec![]
+        let zp_factor = if zero_pad_factor > 0 {
+            Some(zero_pad_factor)
         } else {
-            std::slice::from_raw_parts(periods, n_periods).to_vec()
+            None
+        };
+        let min_p = if min_period > 0 {
+            Some(min_period)
+        } else {
+            None
+        };
+        let max_p = if max_period > 0 {
+            Some(max_period)
+        } else {
+            None
         };
-        anofox_fcst_core::mstl_decompose(&values_vec, &periods_vec, mode)
+        // sazed_period signature: padding_factor, min_period, max_period
+        anofox_fcst_core::sazed_period(&values_vec, zp_factor, min_p, max_p)
     }));
 
     match result {
-        Ok(Ok(decomp)) => {
-            (*out_result).decomposition_applied = decomp.decomposition_applied;
-            (*out_result).n_seasonal = decomp.seasonal.len();
-
-            // Copy trend (may be None if decomposition was skipped)
-            if let Some(ref trend) = decomp.trend {
-                (*out_result).n_observations = trend.len();
-                match alloc_or_error(trend, out_error, "Failed to allocate trend") {
-                    Ok(ptr) => (*out_result).trend = ptr,
-                    Err(()) => return false,
-                }
-            } else {
-                (*out_result).n_observations = length;
-                (*out_result).trend = ptr::null_mut();
-            }
-
-            // Copy remainder (may be None if decomposition was skipped)
-            if let Some(ref remainder) = decomp.remainder {
-                match alloc_or_error(remainder, out_error, "Failed to allocate remainder") {
-                    Ok(ptr) => (*out_result).remainder = ptr,
-                    Err(()) => {
-                        free_ptr((*out_result).trend as *mut core::ffi::c_void);
-                        (*out_result).trend = ptr::null_mut();
-                        return false;
-                    }
-                }
-            } else {
-                (*out_result).remainder = ptr::null_mut();
-            }
-
-            // Copy seasonal periods
-            if !decomp.periods.is_empty() {
-                let periods_ptr =
-                    malloc(decomp.periods.len() * std::mem::size_of::<c_int>()) as *mut c_int;
-                if periods_ptr.is_null() {
-                    if !out_error.is_null() {
-                        (*out_error).set_error(
-                            ErrorCode::AllocationError,
-                            "Failed to allocate seasonal periods",
-                        );
-                    }
-                    free_ptr((*out_result).trend as *mut core::ffi::c_void);
-                    free_ptr((*out_result).remainder as *mut core::ffi::c_void);
-                    (*out_result).trend = ptr::null_mut();
-                    (*out_result).remainder = ptr::null_mut();
-                    return false;
-                }
-                for (i, &p) in decomp.periods.iter().enumerate() {
-                    *periods_ptr.add(i) = p;
-                }
-                (*out_result).seasonal_periods = periods_ptr;
-            } else {
-                (*out_result).seasonal_periods = ptr::null_mut();
-            }
-
-            // Copy seasonal components
-            if !decomp.seasonal.is_empty() {
-                let comps_ptr = malloc(decomp.seasonal.len() * std::mem::size_of::<*mut c_double>())
-                    as *mut *mut c_double;
-                if comps_ptr.is_null() {
-                    if !out_error.is_null() {
-                        (*out_error).set_error(
-                            ErrorCode::AllocationError,
-                            "Failed to allocate seasonal components array",
-                        );
-                    }
-                    free_ptr((*out_result).trend as *mut core::ffi::c_void);
-                    free_ptr((*out_result).remainder as *mut core::ffi::c_void);
-                    free_ptr((*out_result).seasonal_periods as *mut core::ffi::c_void);
-                    (*out_result).trend = ptr::null_mut();
-                    (*out_result).remainder = ptr::null_mut();
-                    (*out_result).seasonal_periods = ptr::null_mut();
-                    return false;
-                }
-                for (i, comp) in decomp.seasonal.iter().enumerate() {
-                    match alloc_or_error(comp, out_error, "Failed to allocate seasonal component") {
-                        Ok(ptr) => *comps_ptr.add(i) = ptr,
-                        Err(()) => {
-                            // Clean up already allocated seasonal components
-                            for j in 0..i {
-                                free_ptr(*comps_ptr.add(j) as *mut core::ffi::c_void);
-                            }
-                            free_ptr(comps_ptr as *mut core::ffi::c_void);
-                            free_ptr((*out_result).trend as *mut core::ffi::c_void);
-                            free_ptr((*out_result).remainder as *mut core::ffi::c_void);
-                            free_ptr((*out_result).seasonal_periods as *mut core::ffi::c_void);
-                            (*out_result).trend = ptr::null_mut();
-                            (*out_result).remainder = ptr::null_mut();
-                            (*out_result).seasonal_periods = ptr::null_mut();
-                            return false;
-                        }
-                    }
-                }
-                (*out_result).seasonal_components = comps_ptr;
-            } else {
-                (*out_result).seasonal_components = ptr::null_mut();
-            }
-
+        Ok(Ok(sazed_result)) => {
+            (*out_result).period = sazed_result.period;
+            (*out_result).power = sazed_result.power;
+            (*out_result).snr = sazed_result.snr;
+            copy_string_to_buffer(&sazed_result.method, &mut (*out_result).method);
             true
         }
         Ok(Err(e)) => {
@@ -2976,21 +2897,78 @@ pub unsafe extern "C" fn anofox_ts_mstl_decomposition(
     }
 }
 
+/// Snap a detected period to the closest expected integer period.
+///
+/// Sets `out_matched` to false (and `out_period` to 0) when no expected
+/// period lies within the relative `tolerance` (e.g. 0.1 = 10%).
+///
+/// # Safety
+/// `expected` must hold `n_expected` elements (it may be null when
+/// `n_expected` is 0); the output pointers must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_snap_period(
+    detected: c_double,
+    expected: *const size_t,
+    n_expected: size_t,
+    tolerance: c_double,
+    out_period: *mut size_t,
+    out_matched: *mut bool,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if check_null_pointers(
+        out_error,
+        &[
+            out_period as *const core::ffi::c_void,
+            out_matched as *const core::ffi::c_void,
+        ],
+    ) {
+        return false;
+    }
+    if n_expected > 0 && expected.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+    if !tolerance.is_finite() || tolerance < 0.0 {
+        set_error(
+            out_error,
+            ErrorCode::InvalidInput,
+            "Tolerance must be non-negative",
+        );
+        return false;
+    }
+
+    let expected = if n_expected > 0 {
+        std::slice::from_raw_parts(expected, n_expected)
+    } else {
+        &[]
+    };
+    let snapped = anofox_fcst_core::snap_period(detected, expected, tolerance);
+    *out_matched = snapped.is_some();
+    *out_period = snapped.unwrap_or(0);
+    true
+}
+
 // ============================================================================
-// Changepoint Functions
+// Peak Detection Functions (fdars-core integration)
 // ============================================================================
 
-/// Detect changepoints using PELT algorithm.
+/// Detect peaks in time series.
+///
+/// Peaks below `min_height` are dropped; pass NaN to keep all peaks.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_changepoints(
+pub unsafe extern "C" fn anofox_ts_detect_peaks(
     values: *const c_double,
     length: size_t,
-    min_size: c_int,
-    penalty: c_double,
-    out_result: *mut ChangepointResult,
+    min_distance: c_double,
+    min_prominence: c_double,
+    min_height: c_double,
+    smooth_first: bool,
+    out_result: *mut types::PeakDetectionResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -3006,29 +2984,54 @@ pub unsafe extern "C" fn anofox_ts_detect_changepoints(
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let pen = if penalty > 0.0 { Some(penalty) } else { None };
-        anofox_fcst_core::detect_changepoints(
-            &values_vec,
-            min_size.max(1) as usize,
-            pen,
-            anofox_fcst_core::CostFunction::L2,
+        let min_dist = if min_distance > 0.0 {
+            Some(min_distance)
+        } else {
+            None
+        };
+        let min_prom = if min_prominence > 0.0 {
+            Some(min_prominence)
+        } else {
+            None
+        };
+        anofox_fcst_core::detect_peaks(&values_vec, min_dist, min_prom, smooth_first, None).map(
+            |r| {
+                if min_height.is_nan() {
+                    r
+                } else {
+                    r.with_min_height(min_height)
+                }
+            },
         )
     }));
 
     match result {
-        Ok(Ok(cp)) => {
-            let n = cp.changepoints.len();
-            (*out_result).n_changepoints = n;
-            (*out_result).cost = cp.cost;
+        Ok(Ok(peak_result)) => {
+            let n = peak_result.n_peaks;
+            (*out_result).n_peaks = n;
+            (*out_result).mean_period = peak_result.mean_period;
 
             if n > 0 {
-                let ptr = malloc(n * std::mem::size_of::<size_t>()) as *mut size_t;
-                for (i, &c) in cp.changepoints.iter().enumerate() {
-                    *ptr.add(i) = c;
+                let peaks_ptr =
+                    malloc(n * std::mem::size_of::<types::PeakFFI>()) as *mut types::PeakFFI;
+                for (i, peak) in peak_result.peaks.iter().enumerate() {
+                    (*peaks_ptr.add(i)).index = peak.index;
+                    (*peaks_ptr.add(i)).time = peak.time;
+                    (*peaks_ptr.add(i)).value = peak.value;
+                    (*peaks_ptr.add(i)).prominence = peak.prominence;
                 }
-                (*out_result).changepoints = ptr;
+                (*out_result).peaks = peaks_ptr;
             } else {
-                (*out_result).changepoints = ptr::null_mut();
+                (*out_result).peaks = ptr::null_mut();
+            }
+
+            let n_dist = peak_result.inter_peak_distances.len();
+            (*out_result).n_distances = n_dist;
+            if n_dist > 0 {
+                (*out_result).inter_peak_distances =
+                    vec_to_c_array(&peak_result.inter_peak_distances);
+            } else {
+                (*out_result).inter_peak_distances = ptr::null_mut();
             }
 
             true
@@ -3048,17 +3051,16 @@ pub unsafe extern "C" fn anofox_ts_detect_changepoints(
     }
 }
 
-/// BOCPD changepoint detection.
+/// Analyze peak timing variability.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_changepoints_bocpd(
+pub unsafe extern "C" fn anofox_ts_analyze_peak_timing(
     values: *const c_double,
     length: size_t,
-    hazard_lambda: c_double,
-    include_probabilities: bool,
-    out_result: *mut types::BocpdResult,
+    period: c_double,
+    out_result: *mut types::PeakTimingResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -3072,46 +3074,37 @@ pub unsafe extern "C" fn anofox_ts_detect_changepoints_bocpd(
         return false;
     }
 
+    if period <= 0.0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
+        }
+        return false;
+    }
+
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        let lambda = if hazard_lambda > 0.0 {
-            hazard_lambda
-        } else {
-            250.0
-        };
-        anofox_fcst_core::detect_changepoints_bocpd(&values_vec, lambda, include_probabilities)
+        anofox_fcst_core::analyze_peak_timing(&values_vec, period, None)
     }));
 
     match result {
-        Ok(Ok(bocpd)) => {
-            let n = bocpd.is_changepoint.len();
-            (*out_result).n_points = n;
-
-            // Allocate and copy is_changepoint
-            let is_cp_ptr = malloc(n * std::mem::size_of::<bool>()) as *mut bool;
-            for (i, &cp) in bocpd.is_changepoint.iter().enumerate() {
-                *is_cp_ptr.add(i) = cp;
-            }
-            (*out_result).is_changepoint = is_cp_ptr;
-
-            // Allocate and copy changepoint_probability
-            let prob_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-            for (i, &p) in bocpd.changepoint_probability.iter().enumerate() {
-                *prob_ptr.add(i) = p;
-            }
-            (*out_result).changepoint_probability = prob_ptr;
+        Ok(Ok(timing_result)) => {
+            let n = timing_result.peak_times.len();
+            (*out_result).n_peaks = n;
+            (*out_result).mean_timing = timing_result.mean_timing;
+            (*out_result).std_timing = timing_result.std_timing;
+            (*out_result).range_timing = timing_result.range_timing;
+            (*out_result).variability_score = timing_result.variability_score;
+            (*out_result).timing_trend = timing_result.timing_trend;
+            (*out_result).is_stable = timing_result.is_stable;
 
-            // Copy changepoint indices
-            let n_cp = bocpd.changepoints.len();
-            (*out_result).n_changepoints = n_cp;
-            if n_cp > 0 {
-                let idx_ptr = malloc(n_cp * std::mem::size_of::<size_t>()) as *mut size_t;
-                for (i, &c) in bocpd.changepoints.iter().enumerate() {
-                    *idx_ptr.add(i) = c;
-                }
-                (*out_result).changepoint_indices = idx_ptr;
+            if n > 0 {
+                (*out_result).peak_times = vec_to_c_array(&timing_result.peak_times);
+                (*out_result).peak_values = vec_to_c_array(&timing_result.peak_values);
+                (*out_result).normalized_timing = vec_to_c_array(&timing_result.normalized_timing);
             } else {
-                (*out_result).changepoint_indices = ptr::null_mut();
+                (*out_result).peak_times = ptr::null_mut();
+                (*out_result).peak_values = ptr::null_mut();
+                (*out_result).normalized_timing = ptr::null_mut();
             }
 
             true
@@ -3132,18 +3125,24 @@ pub unsafe extern "C" fn anofox_ts_detect_changepoints_bocpd(
 }
 
 // ============================================================================
-// Feature Functions
+// Detrending Functions (fdars-core integration)
 // ============================================================================
 
-/// Extract time series features.
+/// Detrend time series using specified method.
+///
+/// `method` is one of "linear", "polynomial", "diff", "diff2", "loess" or
+/// "auto" (default, also used for null or unrecognised names). The result
+/// holds both the extracted trend and the detrended series; release it with
+/// `anofox_free_detrend_result`.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_features(
+pub unsafe extern "C" fn anofox_ts_detrend(
     values: *const c_double,
     length: size_t,
-    out_result: *mut FeaturesResult,
+    method: *const c_char,
+    out_result: *mut types::DetrendResultFFI,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
@@ -3159,36 +3158,33 @@ pub unsafe extern "C" fn anofox_ts_features(
 
     let result = catch_unwind(AssertUnwindSafe(|| {
         let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        anofox_fcst_core::extract_features(&values_vec)
+        let method_str = if method.is_null() {
+            "auto"
+        } else {
+            CStr::from_ptr(method).to_str().unwrap_or("auto")
+        };
+        let detrend_method: anofox_fcst_core::DetrendMethod =
+            method_str.parse().unwrap_or_default();
+        anofox_fcst_core::detrend(&values_vec, detrend_method)
     }));
 
     match result {
-        Ok(Ok(features)) => {
-            let n = features.len();
-            (*out_result).n_features = n;
-
-            if n > 0 {
-                let values_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
-                let names_ptr = malloc(n * std::mem::size_of::<*mut c_char>()) as *mut *mut c_char;
-
-                let mut sorted: Vec<_> = features.into_iter().collect();
-                sorted.sort_by(|a, b| a.0.cmp(&b.0));
-
-                for (i, (name, value)) in sorted.into_iter().enumerate() {
-                    *values_ptr.add(i) = value;
+        Ok(Ok(detrend_result)) => {
+            let n = detrend_result.trend.len();
+            (*out_result).length = n;
+            (*out_result).rss = detrend_result.rss;
+            (*out_result).n_params = detrend_result.n_params;
+            copy_string_to_buffer(&detrend_result.method, &mut (*out_result).method);
 
-                    let name_len = name.len() + 1;
-                    let name_ptr = malloc(name_len) as *mut c_char;
-                    ptr::copy_nonoverlapping(name.as_ptr() as *const c_char, name_ptr, name.len());
-                    *name_ptr.add(name.len()) = 0;
-                    *names_ptr.add(i) = name_ptr;
-                }
+            (*out_result).trend = vec_to_c_array(&detrend_result.trend);
+            (*out_result).detrended = vec_to_c_array(&detrend_result.detrended);
 
-                (*out_result).features = values_ptr;
-                (*out_result).feature_names = names_ptr;
+            if let Some(ref coeffs) = detrend_result.coefficients {
+                (*out_result).n_coefficients = coeffs.len();
+                (*out_result).coefficients = vec_to_c_array(coeffs);
             } else {
-                (*out_result).features = ptr::null_mut();
-                (*out_result).feature_names = ptr::null_mut();
+                (*out_result).n_coefficients = 0;
+                (*out_result).coefficients = ptr::null_mut();
             }
 
             true
@@ -3208,335 +3204,213 @@ pub unsafe extern "C" fn anofox_ts_features(
     }
 }
 
-/// Validate feature parameter keys and return warnings for unknown keys.
+/// Decompose time series into trend, seasonal, and remainder.
+///
+/// `method` is "additive" (default) or "multiplicative". The three
+/// components are returned as separate arrays of the input length; release
+/// them with `anofox_free_decompose_result`.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_validate_feature_params(
-    param_names: *const *const c_char,
-    n_params: size_t,
-    out_warnings: *mut *mut *mut c_char,
-    out_n_warnings: *mut size_t,
+pub unsafe extern "C" fn anofox_ts_decompose(
+    values: *const c_double,
+    length: size_t,
+    period: c_double,
+    method: *const c_char,
+    out_result: *mut types::DecomposeResultFFI,
+    out_error: *mut AnofoxError,
 ) -> bool {
-    if param_names.is_null() || out_warnings.is_null() || out_n_warnings.is_null() {
-        return false;
-    }
-
-    // Convert C strings to Rust strings
-    let mut param_vec = Vec::with_capacity(n_params);
-    for i in 0..n_params {
-        let param_ptr = *param_names.add(i);
-        if !param_ptr.is_null() {
-            if let Ok(param_str) = CStr::from_ptr(param_ptr).to_str() {
-                param_vec.push(param_str.to_string());
-            }
-        }
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
     }
 
-    // Call the validation function
-    let warnings = anofox_fcst_core::validate_feature_params(&param_vec);
-    let n = warnings.len();
-
-    *out_n_warnings = n;
-
-    if n > 0 {
-        let warnings_ptr = malloc(n * std::mem::size_of::<*mut c_char>()) as *mut *mut c_char;
-        if warnings_ptr.is_null() {
-            return false;
-        }
-
-        for (i, warning) in warnings.into_iter().enumerate() {
-            let warning_len = warning.len() + 1;
-            let warning_cstr = malloc(warning_len) as *mut c_char;
-            if !warning_cstr.is_null() {
-                ptr::copy_nonoverlapping(
-                    warning.as_ptr() as *const c_char,
-                    warning_cstr,
-                    warning.len(),
-                );
-                *warning_cstr.add(warning.len()) = 0;
-            }
-            *warnings_ptr.add(i) = warning_cstr;
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
-
-        *out_warnings = warnings_ptr;
-    } else {
-        *out_warnings = ptr::null_mut();
-    }
-
-    true
-}
-
-/// Free warnings array returned by validate_feature_params.
-///
-/// # Safety
-/// The pointer must be valid or null.
-#[no_mangle]
-pub unsafe extern "C" fn anofox_free_warnings(warnings: *mut *mut c_char, n_warnings: size_t) {
-    if warnings.is_null() {
-        return;
+        return false;
     }
 
-    for i in 0..n_warnings {
-        let warning_ptr = *warnings.add(i);
-        if !warning_ptr.is_null() {
-            free(warning_ptr as *mut core::ffi::c_void);
+    if period <= 0.0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
         }
+        return false;
     }
-    free(warnings as *mut core::ffi::c_void);
-}
 
-/// List available feature names.
-///
-/// # Safety
-/// All pointer arguments must be valid and non-null.
-#[no_mangle]
-pub unsafe extern "C" fn anofox_ts_features_list(
-    out_names: *mut *mut c_char,
-    out_count: *mut size_t,
-) -> bool {
-    let names = anofox_fcst_core::list_features();
-    let n = names.len();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let method_str = if method.is_null() {
+            "additive"
+        } else {
+            CStr::from_ptr(method).to_str().unwrap_or("additive")
+        };
+        let decompose_method: anofox_fcst_core::DecomposeMethod =
+            method_str.parse().unwrap_or_default();
+        anofox_fcst_core::decompose(&values_vec, period, decompose_method)
+    }));
 
-    *out_count = n;
+    match result {
+        Ok(Ok(decompose_result)) => {
+            let n = decompose_result.trend.len();
+            (*out_result).length = n;
+            (*out_result).period = decompose_result.period;
+            copy_string_to_buffer(&decompose_result.method, &mut (*out_result).method);
 
-    if n > 0 {
-        let names_ptr = malloc(n * std::mem::size_of::<*mut c_char>()) as *mut *mut c_char;
+            (*out_result).trend = vec_to_c_array(&decompose_result.trend);
+            (*out_result).seasonal = vec_to_c_array(&decompose_result.seasonal);
+            (*out_result).remainder = vec_to_c_array(&decompose_result.remainder);
 
-        for (i, name) in names.into_iter().enumerate() {
-            let name_len = name.len() + 1;
-            let name_ptr = malloc(name_len) as *mut c_char;
-            ptr::copy_nonoverlapping(name.as_ptr() as *const c_char, name_ptr, name.len());
-            *name_ptr.add(name.len()) = 0;
-            *names_ptr.add(i) = name_ptr;
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
         }
-
-        *out_names = names_ptr as *mut c_char;
     }
-
-    true
 }
 
 // ============================================================================
-// Forecast Functions
+// Extended Seasonality Functions (fdars-core integration)
 // ============================================================================
 
-/// Generate forecasts for a time series.
+/// Compute seasonal strength using specified method.
 ///
-/// Parse a seasonal_periods_str like "[24, 168]" into Vec<usize>.
-fn parse_seasonal_periods_str(s: &str) -> Vec<usize> {
-    if s.is_empty() {
-        return vec![];
-    }
-    s.trim()
-        .trim_start_matches('[')
-        .trim_end_matches(']')
-        .split(',')
-        .filter_map(|p| p.trim().parse::<usize>().ok())
-        .filter(|&v| v > 0)
-        .collect()
-}
-
 /// # Safety
-/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_forecast(
+pub unsafe extern "C" fn anofox_ts_seasonal_strength(
     values: *const c_double,
-    validity: *const u64,
     length: size_t,
-    options: *const ForecastOptions,
-    out_result: *mut ForecastResult,
+    period: c_double,
+    method: *const c_char,
+    out_strength: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || options.is_null() || out_result.is_null() {
+    if values.is_null() || out_strength.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
-    let result = catch_unwind(AssertUnwindSafe(|| {
-        let series = build_series(values, validity, length);
-        let opts = &*options;
-
-        // Parse model name
-        let model_str = CStr::from_ptr(opts.model.as_ptr())
-            .to_str()
-            .unwrap_or("auto");
+    if period <= 0.0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
+        }
+        return false;
+    }
 
-        let model_type: anofox_fcst_core::ModelType = match model_str.parse() {
-            Ok(m) => m,
-            Err(_) => {
-                return Err(anofox_fcst_core::ForecastError::InvalidModel(format!(
-                    "Unknown model: '{}'",
-                    model_str
-                )))
-            }
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let method_str = if method.is_null() {
+            "variance"
+        } else {
+            CStr::from_ptr(method).to_str().unwrap_or("variance")
         };
+        let strength_method: anofox_fcst_core::StrengthMethod =
+            method_str.parse().unwrap_or_default();
+        anofox_fcst_core::seasonal_strength(&values_vec, period, strength_method)
+    }));
 
-        // Parse ETS model spec (e.g., "AAA", "MNM", "AAdA")
-        let ets_spec = CStr::from_ptr(opts.ets_model.as_ptr())
-            .to_str()
-            .ok()
-            .filter(|s| !s.is_empty())
-            .map(String::from);
+    match result {
+        Ok(Ok(strength)) => {
+            *out_strength = strength;
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
 
-        // Parse seasonal_periods_str
-        let sp_str = CStr::from_ptr(opts.seasonal_periods_str.as_ptr())
-            .to_str()
-            .unwrap_or("");
-        let seasonal_periods = parse_seasonal_periods_str(sp_str);
+/// Compute windowed seasonal strength.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_seasonal_strength_windowed(
+    values: *const c_double,
+    length: size_t,
+    period: c_double,
+    window_size: c_double,
+    method: *const c_char,
+    out_strengths: *mut *mut c_double,
+    out_n_windows: *mut size_t,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
 
-        // Parse model_pool
-        let model_pool = CStr::from_ptr(opts.model_pool.as_ptr())
-            .to_str()
-            .ok()
-            .filter(|s| !s.is_empty())
-            .map(String::from);
+    if values.is_null() || out_strengths.is_null() || out_n_windows.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
 
-        // Parse laplace_variant (empty → default Auto handled downstream)
-        let laplace_variant = CStr::from_ptr(opts.laplace_variant.as_ptr())
-            .to_str()
-            .ok()
-            .filter(|s| !s.is_empty())
-            .map(anofox_fcst_core::LaplaceVariant::parse)
-            .transpose()?;
+    if period <= 0.0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
+        }
+        return false;
+    }
 
-        let core_opts = anofox_fcst_core::ForecastOptions {
-            model: model_type,
-            ets_spec,
-            horizon: opts.horizon as usize,
-            confidence_level: opts.confidence_level,
-            seasonal_period: opts.seasonal_period as usize,
-            auto_detect_seasonality: opts.auto_detect_seasonality,
-            include_fitted: opts.include_fitted,
-            include_residuals: opts.include_residuals,
-            window: opts.window.max(0) as usize,
-            seasonal_periods,
-            model_pool,
-            laplace_variant,
-            laplace_seasonal_batch_init: opts.laplace_seasonal_batch_init,
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let window_opt = if window_size > 0.0 {
+            Some(window_size)
+        } else {
+            None
         };
-
-        anofox_fcst_core::forecast(&series, &core_opts)
+        let method_str = if method.is_null() {
+            None
+        } else {
+            let s = CStr::from_ptr(method).to_str().unwrap_or("variance");
+            Some(
+                s.parse::<anofox_fcst_core::StrengthMethod>()
+                    .unwrap_or_default(),
+            )
+        };
+        anofox_fcst_core::seasonal_strength_windowed(&values_vec, period, window_opt, method_str)
     }));
 
     match result {
-        Ok(Ok(forecast)) => {
-            let n_forecasts = forecast.point.len();
-            (*out_result).n_forecasts = n_forecasts;
-
-            // Copy point forecasts with allocation error checking
-            (*out_result).point_forecasts = match alloc_or_error(
-                &forecast.point,
-                out_error,
-                "Failed to allocate point forecasts",
-            ) {
-                Ok(ptr) => ptr,
-                Err(()) => return false,
-            };
-
-            (*out_result).lower_bounds = match alloc_or_error(
-                &forecast.lower,
-                out_error,
-                "Failed to allocate lower bounds",
-            ) {
-                Ok(ptr) => ptr,
-                Err(()) => {
-                    // Clean up already allocated memory
-                    free_ptr((*out_result).point_forecasts as *mut _);
-                    (*out_result).point_forecasts = ptr::null_mut();
-                    return false;
-                }
-            };
-
-            (*out_result).upper_bounds = match alloc_or_error(
-                &forecast.upper,
-                out_error,
-                "Failed to allocate upper bounds",
-            ) {
-                Ok(ptr) => ptr,
-                Err(()) => {
-                    free_ptr((*out_result).point_forecasts as *mut _);
-                    free_ptr((*out_result).lower_bounds as *mut _);
-                    (*out_result).point_forecasts = ptr::null_mut();
-                    (*out_result).lower_bounds = ptr::null_mut();
-                    return false;
-                }
-            };
-
-            // Copy fitted values
-            if let Some(ref fitted) = forecast.fitted {
-                (*out_result).fitted_values =
-                    match alloc_or_error(fitted, out_error, "Failed to allocate fitted values") {
-                        Ok(ptr) => ptr,
-                        Err(()) => {
-                            free_ptr((*out_result).point_forecasts as *mut _);
-                            free_ptr((*out_result).lower_bounds as *mut _);
-                            free_ptr((*out_result).upper_bounds as *mut _);
-                            (*out_result).point_forecasts = ptr::null_mut();
-                            (*out_result).lower_bounds = ptr::null_mut();
-                            (*out_result).upper_bounds = ptr::null_mut();
-                            return false;
-                        }
-                    };
-                (*out_result).n_fitted = fitted.len();
-            } else {
-                (*out_result).fitted_values = ptr::null_mut();
-                (*out_result).n_fitted = 0;
-            }
-
-            // Copy residuals
-            if let Some(ref resid) = forecast.residuals {
-                (*out_result).residuals =
-                    match alloc_or_error(resid, out_error, "Failed to allocate residuals") {
-                        Ok(ptr) => ptr,
-                        Err(()) => {
-                            free_ptr((*out_result).point_forecasts as *mut _);
-                            free_ptr((*out_result).lower_bounds as *mut _);
-                            free_ptr((*out_result).upper_bounds as *mut _);
-                            free_ptr((*out_result).fitted_values as *mut _);
-                            (*out_result).point_forecasts = ptr::null_mut();
-                            (*out_result).lower_bounds = ptr::null_mut();
-                            (*out_result).upper_bounds = ptr::null_mut();
-                            (*out_result).fitted_values = ptr::null_mut();
-                            return false;
-                        }
-                    };
+        Ok(Ok(strengths)) => {
+            *out_n_windows = strengths.len();
+            if !strengths.is_empty() {
+                *out_strengths = vec_to_c_array(&strengths);
             } else {
-                (*out_result).residuals = ptr::null_mut();
+                *out_strengths = ptr::null_mut();
             }
-
-            // Copy model name
-            copy_string_to_buffer(&forecast.model_name, &mut (*out_result).model_name);
-
-            (*out_result).aic = forecast.aic.unwrap_or(f64::NAN);
-            (*out_result).bic = forecast.bic.unwrap_or(f64::NAN);
-            (*out_result).mse = forecast.mse.unwrap_or(f64::NAN);
-
             true
         }
         Ok(Err(e)) => {
             if !out_error.is_null() {
-                // Map ForecastError to appropriate ErrorCode
-                let error_code = match e.to_code() {
-                    1 => ErrorCode::NullPointer,
-                    2 => ErrorCode::InvalidInput,
-                    3 => ErrorCode::ComputationError,
-                    4 => ErrorCode::AllocationError,
-                    5 => ErrorCode::InvalidModel,
-                    6 => ErrorCode::InsufficientData,
-                    7 => ErrorCode::InvalidDateFormat,
-                    8 => ErrorCode::InvalidFrequency,
-                    9 => ErrorCode::InvalidInput, // InvalidParameter → InvalidInput at FFI boundary
-                    _ => ErrorCode::InternalError,
-                };
-                (*out_error).set_error(error_code, &e.to_string());
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
             }
             false
         }
@@ -3549,400 +3423,3398 @@ pub unsafe extern "C" fn anofox_ts_forecast(
     }
 }
 
-/// Generate time series forecasts with exogenous variables.
-///
-/// This function extends `anofox_ts_forecast` to support external regressors (xreg).
-/// Exogenous variables can improve forecast accuracy when external factors (e.g.,
-/// promotions, holidays, weather) influence the target variable.
-///
-/// # Arguments
-/// * `values` - Pointer to time series values (target variable y)
-/// * `validity` - Pointer to validity bitmask (NULL means all valid)
-/// * `length` - Number of observations
-/// * `options` - Forecast options including exogenous data
-/// * `out_result` - Output forecast result
-/// * `out_error` - Output error (optional)
-///
-/// # Supported Models
-/// The following models support exogenous variables:
-/// - AutoARIMA, ARIMA (ARIMAX)
-/// - OptimizedTheta, DynamicTheta
-/// - MFLES
+/// Compute seasonal and trend strength at a fixed, caller-supplied period.
 ///
-/// Other models will ignore the exogenous data and produce a standard forecast.
+/// Unlike `anofox_ts_analyze_seasonality`, no period detection is performed.
+/// Seasonal strength uses the variance method; trend strength uses a classical
+/// decomposition at `period`.
 ///
 /// # Safety
-/// All pointer arguments must be valid. Arrays must have the specified lengths.
-/// For exogenous data:
-/// - Each regressor's `n_values` must equal `length`
-/// - Each regressor's `n_future` must equal `options.horizon`
+/// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_forecast_exog(
+pub unsafe extern "C" fn anofox_ts_strength_at_period(
     values: *const c_double,
-    validity: *const u64,
     length: size_t,
-    options: *const ForecastOptionsExog,
-    out_result: *mut ForecastResult,
+    period: c_double,
+    out_seasonal: *mut c_double,
+    out_trend: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || options.is_null() || out_result.is_null() {
+    if values.is_null() || out_seasonal.is_null() || out_trend.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
-    let result = catch_unwind(AssertUnwindSafe(|| {
-        let series = build_series(values, validity, length);
-        let opts = &*options;
+    if period <= 0.0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
+        }
+        return false;
+    }
 
-        // Parse model name
-        let model_str = CStr::from_ptr(opts.model.as_ptr())
-            .to_str()
-            .unwrap_or("auto");
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let seasonal = anofox_fcst_core::seasonal_strength(
+            &values_vec,
+            period,
+            anofox_fcst_core::StrengthMethod::Variance,
+        )?;
+        let trend = anofox_fcst_core::trend_strength_at_period(&values_vec, period)?;
+        Ok::<_, anofox_fcst_core::ForecastError>((seasonal, trend))
+    }));
 
-        let model_type: anofox_fcst_core::ModelType = match model_str.parse() {
-            Ok(m) => m,
-            Err(_) => {
-                return Err(anofox_fcst_core::ForecastError::InvalidModel(format!(
-                    "Unknown model: '{}'",
-                    model_str
-                )))
+    match result {
+        Ok(Ok((seasonal, trend))) => {
+            *out_seasonal = seasonal;
+            *out_trend = trend;
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
             }
-        };
-
-        // Parse ETS model spec (e.g., "AAA", "MNM", "AAdA")
-        let ets_spec = CStr::from_ptr(opts.ets_model.as_ptr())
-            .to_str()
-            .ok()
-            .filter(|s| !s.is_empty())
-            .map(String::from);
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
 
-        // Build exogenous data if provided
-        let exog_data = if !opts.exog.is_null() {
-            let exog = &*opts.exog;
-            if !exog.is_empty() {
-                let mut historical: Vec<Vec<f64>> = Vec::with_capacity(exog.n_regressors);
-                let mut future: Vec<Vec<f64>> = Vec::with_capacity(exog.n_regressors);
+/// Classify seasonality type and pattern.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_classify_seasonality(
+    values: *const c_double,
+    length: size_t,
+    period: c_double,
+    strength_threshold: c_double,
+    timing_threshold: c_double,
+    out_result: *mut types::SeasonalityClassificationFFI,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
 
-                for i in 0..exog.n_regressors {
-                    let reg = &*exog.regressors.add(i);
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
 
-                    // Validate lengths
-                    if reg.n_values != length {
-                        return Err(anofox_fcst_core::error::ForecastError::InvalidInput(
-                            format!(
-                                "Exogenous regressor {} has {} values but y has {} values",
-                                i, reg.n_values, length
-                            ),
-                        ));
-                    }
-                    if reg.n_future != opts.horizon as usize {
-                        return Err(anofox_fcst_core::error::ForecastError::InvalidInput(
-                            format!(
-                                "Exogenous regressor {} has {} future values but horizon is {}",
-                                i, reg.n_future, opts.horizon
-                            ),
-                        ));
-                    }
+    if period <= 0.0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
+        }
+        return false;
+    }
 
-                    // Copy historical values
-                    let hist_slice = std::slice::from_raw_parts(reg.values, reg.n_values);
-                    historical.push(hist_slice.to_vec());
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let strength_opt = if strength_threshold > 0.0 {
+            Some(strength_threshold)
+        } else {
+            None
+        };
+        let timing_opt = if timing_threshold > 0.0 {
+            Some(timing_threshold)
+        } else {
+            None
+        };
+        anofox_fcst_core::classify_seasonality(&values_vec, period, strength_opt, timing_opt)
+    }));
 
-                    // Copy future values
-                    let future_slice = std::slice::from_raw_parts(reg.future_values, reg.n_future);
-                    future.push(future_slice.to_vec());
-                }
+    match result {
+        Ok(Ok(classification)) => {
+            (*out_result).is_seasonal = classification.is_seasonal;
+            (*out_result).has_stable_timing = classification.has_stable_timing;
+            (*out_result).timing_variability = classification.timing_variability;
+            (*out_result).seasonal_strength = classification.seasonal_strength;
+            copy_string_to_buffer(
+                classification.classification.as_str(),
+                &mut (*out_result).classification,
+            );
 
-                Some(anofox_fcst_core::ExogenousData { historical, future })
+            // Copy cycle strengths
+            let n_cycles = classification.cycle_strengths.len();
+            (*out_result).n_cycle_strengths = n_cycles;
+            if n_cycles > 0 {
+                (*out_result).cycle_strengths = vec_to_c_array(&classification.cycle_strengths);
             } else {
-                None
+                (*out_result).cycle_strengths = ptr::null_mut();
             }
-        } else {
-            None
-        };
 
-        // Parse seasonal_periods_str
-        let sp_str = CStr::from_ptr(opts.seasonal_periods_str.as_ptr())
-            .to_str()
-            .unwrap_or("");
-        let seasonal_periods = parse_seasonal_periods_str(sp_str);
+            // Copy weak seasons
+            let n_weak = classification.weak_seasons.len();
+            (*out_result).n_weak_seasons = n_weak;
+            if n_weak > 0 {
+                let weak_ptr = malloc(n_weak * std::mem::size_of::<size_t>()) as *mut size_t;
+                for (i, idx) in classification.weak_seasons.iter().enumerate() {
+                    *weak_ptr.add(i) = *idx;
+                }
+                (*out_result).weak_seasons = weak_ptr;
+            } else {
+                (*out_result).weak_seasons = ptr::null_mut();
+            }
 
-        // Parse model_pool
-        let model_pool = CStr::from_ptr(opts.model_pool.as_ptr())
-            .to_str()
-            .ok()
-            .filter(|s| !s.is_empty())
-            .map(String::from);
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
 
-        // Parse laplace_variant (empty → default Auto handled downstream)
-        let laplace_variant = CStr::from_ptr(opts.laplace_variant.as_ptr())
-            .to_str()
-            .ok()
-            .filter(|s| !s.is_empty())
-            .map(anofox_fcst_core::LaplaceVariant::parse)
-            .transpose()?;
+/// Detect seasonality changes over time.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_detect_seasonality_changes(
+    values: *const c_double,
+    length: size_t,
+    period: c_double,
+    threshold: c_double,
+    window_size: c_double,
+    min_duration: c_double,
+    out_result: *mut types::ChangeDetectionResultFFI,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
 
-        let core_opts = anofox_fcst_core::ForecastOptionsExog {
-            model: model_type,
-            ets_spec,
-            horizon: opts.horizon as usize,
-            confidence_level: opts.confidence_level,
-            seasonal_period: opts.seasonal_period as usize,
-            auto_detect_seasonality: opts.auto_detect_seasonality,
-            include_fitted: opts.include_fitted,
-            include_residuals: opts.include_residuals,
-            exog: exog_data,
-            window: opts.window.max(0) as usize,
-            seasonal_periods,
-            model_pool,
-            laplace_variant,
-            laplace_seasonal_batch_init: opts.laplace_seasonal_batch_init,
-        };
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
 
-        anofox_fcst_core::forecast_with_exog(&series, &core_opts)
+    if period <= 0.0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let threshold_opt = if threshold > 0.0 {
+            Some(threshold)
+        } else {
+            None
+        };
+        let window_opt = if window_size > 0.0 {
+            Some(window_size)
+        } else {
+            None
+        };
+        let min_dur_opt = if min_duration > 0.0 {
+            Some(min_duration)
+        } else {
+            None
+        };
+        anofox_fcst_core::detect_seasonality_changes(
+            &values_vec,
+            period,
+            threshold_opt,
+            window_opt,
+            min_dur_opt,
+        )
     }));
 
     match result {
-        Ok(Ok(forecast)) => {
-            let n_forecasts = forecast.point.len();
-            (*out_result).n_forecasts = n_forecasts;
-
-            // Copy point forecasts with allocation error checking
-            (*out_result).point_forecasts = match alloc_or_error(
-                &forecast.point,
-                out_error,
-                "Failed to allocate point forecasts",
-            ) {
-                Ok(ptr) => ptr,
-                Err(()) => return false,
-            };
+        Ok(Ok(change_result)) => {
+            let n = change_result.change_points.len();
+            (*out_result).n_changes = n;
 
-            (*out_result).lower_bounds = match alloc_or_error(
-                &forecast.lower,
-                out_error,
-                "Failed to allocate lower bounds",
-            ) {
-                Ok(ptr) => ptr,
-                Err(()) => {
-                    free_ptr((*out_result).point_forecasts as *mut _);
-                    (*out_result).point_forecasts = ptr::null_mut();
-                    return false;
+            if n > 0 {
+                let changes_ptr =
+                    malloc(n * std::mem::size_of::<types::SeasonalityChangePointFFI>())
+                        as *mut types::SeasonalityChangePointFFI;
+                for (i, cp) in change_result.change_points.iter().enumerate() {
+                    (*changes_ptr.add(i)).index = cp.index;
+                    (*changes_ptr.add(i)).time = cp.time;
+                    copy_string_to_buffer(
+                        cp.change_type.as_str(),
+                        &mut (*changes_ptr.add(i)).change_type,
+                    );
+                    (*changes_ptr.add(i)).strength_before = cp.strength_before;
+                    (*changes_ptr.add(i)).strength_after = cp.strength_after;
                 }
-            };
+                (*out_result).change_points = changes_ptr;
+            } else {
+                (*out_result).change_points = ptr::null_mut();
+            }
 
-            (*out_result).upper_bounds = match alloc_or_error(
-                &forecast.upper,
-                out_error,
-                "Failed to allocate upper bounds",
-            ) {
-                Ok(ptr) => ptr,
-                Err(()) => {
-                    free_ptr((*out_result).point_forecasts as *mut _);
-                    free_ptr((*out_result).lower_bounds as *mut _);
-                    (*out_result).point_forecasts = ptr::null_mut();
-                    (*out_result).lower_bounds = ptr::null_mut();
-                    return false;
-                }
-            };
+            // Copy strength curve
+            let n_curve = change_result.strength_curve.len();
+            (*out_result).n_strength_curve = n_curve;
+            if n_curve > 0 {
+                (*out_result).strength_curve = vec_to_c_array(&change_result.strength_curve);
+            } else {
+                (*out_result).strength_curve = ptr::null_mut();
+            }
 
-            // Copy fitted values
-            if let Some(ref fitted) = forecast.fitted {
-                (*out_result).fitted_values =
-                    match alloc_or_error(fitted, out_error, "Failed to allocate fitted values") {
-                        Ok(ptr) => ptr,
-                        Err(()) => {
-                            free_ptr((*out_result).point_forecasts as *mut _);
-                            free_ptr((*out_result).lower_bounds as *mut _);
-                            free_ptr((*out_result).upper_bounds as *mut _);
-                            (*out_result).point_forecasts = ptr::null_mut();
-                            (*out_result).lower_bounds = ptr::null_mut();
-                            (*out_result).upper_bounds = ptr::null_mut();
-                            return false;
-                        }
-                    };
-                (*out_result).n_fitted = fitted.len();
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Compute instantaneous period using Hilbert transform.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_instantaneous_period(
+    values: *const c_double,
+    length: size_t,
+    out_result: *mut types::InstantaneousPeriodResultFFI,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        anofox_fcst_core::instantaneous_period(&values_vec)
+    }));
+
+    match result {
+        Ok(Ok(inst_result)) => {
+            let n = inst_result.period.len();
+            (*out_result).length = n;
+
+            if n > 0 {
+                (*out_result).periods = vec_to_c_array(&inst_result.period);
+                (*out_result).frequencies = vec_to_c_array(&inst_result.frequency);
+                (*out_result).amplitudes = vec_to_c_array(&inst_result.amplitude);
+            } else {
+                (*out_result).periods = ptr::null_mut();
+                (*out_result).frequencies = ptr::null_mut();
+                (*out_result).amplitudes = ptr::null_mut();
+            }
+
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Detect amplitude modulation in seasonal time series.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_detect_amplitude_modulation(
+    values: *const c_double,
+    length: size_t,
+    period: c_double,
+    modulation_threshold: c_double,
+    seasonality_threshold: c_double,
+    out_result: *mut types::AmplitudeModulationResultFFI,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    if period <= 0.0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::InvalidInput, "Period must be positive");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let mod_thresh = if modulation_threshold > 0.0 {
+            Some(modulation_threshold)
+        } else {
+            None
+        };
+        let seas_thresh = if seasonality_threshold > 0.0 {
+            Some(seasonality_threshold)
+        } else {
+            None
+        };
+        anofox_fcst_core::detect_amplitude_modulation(&values_vec, period, mod_thresh, seas_thresh)
+    }));
+
+    match result {
+        Ok(Ok(am_result)) => {
+            (*out_result).is_seasonal = am_result.is_seasonal;
+            (*out_result).seasonal_strength = am_result.seasonal_strength;
+            (*out_result).has_modulation = am_result.has_modulation;
+            copy_string_to_buffer(
+                am_result.modulation_type.as_str(),
+                &mut (*out_result).modulation_type,
+            );
+            (*out_result).modulation_score = am_result.modulation_score;
+            (*out_result).amplitude_trend = am_result.amplitude_trend;
+            (*out_result).scale = am_result.scale;
+
+            let n = am_result.wavelet_amplitude.len();
+            (*out_result).n_points = n;
+            if n > 0 {
+                (*out_result).wavelet_amplitude = vec_to_c_array(&am_result.wavelet_amplitude);
+                (*out_result).time_points = vec_to_c_array(&am_result.time_points);
+            } else {
+                (*out_result).wavelet_amplitude = ptr::null_mut();
+                (*out_result).time_points = ptr::null_mut();
+            }
+
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+// ============================================================================
+// Decomposition Functions
+// ============================================================================
+
+/// MSTL decomposition.
+///
+/// # Arguments
+/// * `insufficient_data_mode` - How to handle insufficient data:
+///   - 0 (Fail): Error on insufficient data (default)
+///   - 1 (Trend): Apply trend-only decomposition, seasonal components are empty
+///   - 2 (None): Skip decomposition entirely, return empty result
+///
+///   Any other value is rejected with `InvalidInput`.
+/// * `multiplicative` - Decompose `log(values)` and exponentiate the components,
+///   so `values = trend * seasonal * remainder`. Requires strictly positive values.
+/// * `robust` - Downweight outliers with the STL robustness iterations so
+///   spikes stay in the remainder instead of bending the trend.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_mstl_decomposition(
+    values: *const c_double,
+    length: size_t,
+    periods: *const c_int,
+    n_periods: size_t,
+    insufficient_data_mode: c_int,
+    multiplicative: bool,
+    robust: bool,
+    out_result: *mut MstlResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    if !(0..=2).contains(&insufficient_data_mode) {
+        if !out_error.is_null() {
+            (*out_error).set_error(
+                ErrorCode::InvalidInput,
+                "insufficient_data_mode must be 0 (fail), 1 (trend) or 2 (none)",
+            );
+        }
+        return false;
+    }
+    let mode = anofox_fcst_core::InsufficientDataMode::from_int(insufficient_data_mode);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let periods_vec: Vec<i32> = if periods.is_null() || n_periods == 0 {
+            vec![]
+        } else {
+            std::slice::from_raw_parts(periods, n_periods).to_vec()
+        };
+        if multiplicative {
+            anofox_fcst_core::mstl_decompose_multiplicative(&values_vec, &periods_vec, mode, robust)
+        } else {
+            anofox_fcst_core::mstl_decompose(&values_vec, &periods_vec, mode, robust)
+        }
+    }));
+
+    match result {
+        Ok(Ok(decomp)) => {
+            (*out_result).decomposition_applied = decomp.decomposition_applied;
+            (*out_result).n_seasonal = decomp.seasonal.len();
+            (*out_result).seasonal_strength = decomp.seasonal_strength.unwrap_or(f64::NAN);
+            (*out_result).trend_strength = decomp.trend_strength.unwrap_or(f64::NAN);
+
+            // Copy trend (may be None if decomposition was skipped)
+            if let Some(ref trend) = decomp.trend {
+                (*out_result).n_observations = trend.len();
+                match alloc_or_error(trend, out_error, "Failed to allocate trend") {
+                    Ok(ptr) => (*out_result).trend = ptr,
+                    Err(()) => return false,
+                }
             } else {
-                (*out_result).fitted_values = ptr::null_mut();
-                (*out_result).n_fitted = 0;
+                (*out_result).n_observations = length;
+                (*out_result).trend = ptr::null_mut();
             }
 
-            // Copy residuals
-            if let Some(ref resid) = forecast.residuals {
-                (*out_result).residuals =
-                    match alloc_or_error(resid, out_error, "Failed to allocate residuals") {
-                        Ok(ptr) => ptr,
-                        Err(()) => {
-                            free_ptr((*out_result).point_forecasts as *mut _);
-                            free_ptr((*out_result).lower_bounds as *mut _);
-                            free_ptr((*out_result).upper_bounds as *mut _);
-                            free_ptr((*out_result).fitted_values as *mut _);
-                            (*out_result).point_forecasts = ptr::null_mut();
-                            (*out_result).lower_bounds = ptr::null_mut();
-                            (*out_result).upper_bounds = ptr::null_mut();
-                            (*out_result).fitted_values = ptr::null_mut();
-                            return false;
-                        }
-                    };
-            } else {
-                (*out_result).residuals = ptr::null_mut();
-            }
+            // Copy remainder (may be None if decomposition was skipped)
+            if let Some(ref remainder) = decomp.remainder {
+                match alloc_or_error(remainder, out_error, "Failed to allocate remainder") {
+                    Ok(ptr) => (*out_result).remainder = ptr,
+                    Err(()) => {
+                        free_ptr((*out_result).trend as *mut core::ffi::c_void);
+                        (*out_result).trend = ptr::null_mut();
+                        return false;
+                    }
+                }
+            } else {
+                (*out_result).remainder = ptr::null_mut();
+            }
+
+            // Copy seasonal periods
+            if !decomp.periods.is_empty() {
+                let periods_ptr =
+                    malloc(decomp.periods.len() * std::mem::size_of::<c_int>()) as *mut c_int;
+                if periods_ptr.is_null() {
+                    if !out_error.is_null() {
+                        (*out_error).set_error(
+                            ErrorCode::AllocationError,
+                            "Failed to allocate seasonal periods",
+                        );
+                    }
+                    free_ptr((*out_result).trend as *mut core::ffi::c_void);
+                    free_ptr((*out_result).remainder as *mut core::ffi::c_void);
+                    (*out_result).trend = ptr::null_mut();
+                    (*out_result).remainder = ptr::null_mut();
+                    return false;
+                }
+                for (i, &p) in decomp.periods.iter().enumerate() {
+                    *periods_ptr.add(i) = p;
+                }
+                (*out_result).seasonal_periods = periods_ptr;
+            } else {
+                (*out_result).seasonal_periods = ptr::null_mut();
+            }
+
+            // Copy seasonal components
+            if !decomp.seasonal.is_empty() {
+                let comps_ptr = malloc(decomp.seasonal.len() * std::mem::size_of::<*mut c_double>())
+                    as *mut *mut c_double;
+                if comps_ptr.is_null() {
+                    if !out_error.is_null() {
+                        (*out_error).set_error(
+                            ErrorCode::AllocationError,
+                            "Failed to allocate seasonal components array",
+                        );
+                    }
+                    free_ptr((*out_result).trend as *mut core::ffi::c_void);
+                    free_ptr((*out_result).remainder as *mut core::ffi::c_void);
+                    free_ptr((*out_result).seasonal_periods as *mut core::ffi::c_void);
+                    (*out_result).trend = ptr::null_mut();
+                    (*out_result).remainder = ptr::null_mut();
+                    (*out_result).seasonal_periods = ptr::null_mut();
+                    return false;
+                }
+                for (i, comp) in decomp.seasonal.iter().enumerate() {
+                    match alloc_or_error(comp, out_error, "Failed to allocate seasonal component") {
+                        Ok(ptr) => *comps_ptr.add(i) = ptr,
+                        Err(()) => {
+                            // Clean up already allocated seasonal components
+                            for j in 0..i {
+                                free_ptr(*comps_ptr.add(j) as *mut core::ffi::c_void);
+                            }
+                            free_ptr(comps_ptr as *mut core::ffi::c_void);
+                            free_ptr((*out_result).trend as *mut core::ffi::c_void);
+                            free_ptr((*out_result).remainder as *mut core::ffi::c_void);
+                            free_ptr((*out_result).seasonal_periods as *mut core::ffi::c_void);
+                            (*out_result).trend = ptr::null_mut();
+                            (*out_result).remainder = ptr::null_mut();
+                            (*out_result).seasonal_periods = ptr::null_mut();
+                            return false;
+                        }
+                    }
+                }
+                (*out_result).seasonal_components = comps_ptr;
+            } else {
+                (*out_result).seasonal_components = ptr::null_mut();
+            }
+
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Remove the seasonal component of a series using MSTL.
+///
+/// Returns `values - seasonal`, or `values / seasonal` when `multiplicative`
+/// (strictly positive values only). Free the output with
+/// `anofox_free_double_array`.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `values` must have `length` elements.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_seasonally_adjust(
+    values: *const c_double,
+    length: size_t,
+    period: size_t,
+    multiplicative: bool,
+    out_values: *mut *mut c_double,
+    out_length: *mut size_t,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_values as *const core::ffi::c_void,
+        out_length as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
+
+    let mode = if multiplicative {
+        anofox_fcst_core::SeasonalMode::Multiplicative
+    } else {
+        anofox_fcst_core::SeasonalMode::Additive
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_slice = std::slice::from_raw_parts(values, length);
+        anofox_fcst_core::seasonally_adjust(values_slice, period, mode)
+    }));
+
+    match result {
+        Ok(Ok(adjusted)) => {
+            *out_length = adjusted.len();
+            match alloc_or_error(&adjusted, out_error, "Failed to allocate adjusted series") {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
+                }
+                Err(()) => false,
+            }
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+// ============================================================================
+// Changepoint Functions
+// ============================================================================
+
+/// Detect changepoints using PELT algorithm.
+///
+/// `cost_function` selects the segment cost: 0 = L2, 1 = L1 (robust to
+/// outliers), 2 = Normal/RBF (change in mean and variance).
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_detect_changepoints(
+    values: *const c_double,
+    length: size_t,
+    min_size: c_int,
+    penalty: c_double,
+    cost_function: c_int,
+    out_result: *mut ChangepointResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let cost_fn = match anofox_fcst_core::CostFunction::from_int(cost_function) {
+        Ok(c) => c,
+        Err(e) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::InvalidInput, &e.to_string());
+            }
+            return false;
+        }
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let pen = if penalty > 0.0 { Some(penalty) } else { None };
+        anofox_fcst_core::detect_changepoints(&values_vec, min_size.max(1) as usize, pen, cost_fn)
+    }));
+
+    match result {
+        Ok(Ok(cp)) => {
+            let n = cp.changepoints.len();
+            (*out_result).n_changepoints = n;
+            (*out_result).cost = cp.cost;
+
+            if n > 0 {
+                let ptr = malloc(n * std::mem::size_of::<size_t>()) as *mut size_t;
+                for (i, &c) in cp.changepoints.iter().enumerate() {
+                    *ptr.add(i) = c;
+                }
+                (*out_result).changepoints = ptr;
+            } else {
+                (*out_result).changepoints = ptr::null_mut();
+            }
+
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// BOCPD changepoint detection.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_detect_changepoints_bocpd(
+    values: *const c_double,
+    length: size_t,
+    hazard_lambda: c_double,
+    include_probabilities: bool,
+    out_result: *mut types::BocpdResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let lambda = if hazard_lambda > 0.0 {
+            hazard_lambda
+        } else {
+            250.0
+        };
+        anofox_fcst_core::detect_changepoints_bocpd(&values_vec, lambda, include_probabilities)
+    }));
+
+    match result {
+        Ok(Ok(bocpd)) => {
+            let n = bocpd.is_changepoint.len();
+            (*out_result).n_points = n;
+
+            // Allocate and copy is_changepoint
+            let is_cp_ptr = malloc(n * std::mem::size_of::<bool>()) as *mut bool;
+            for (i, &cp) in bocpd.is_changepoint.iter().enumerate() {
+                *is_cp_ptr.add(i) = cp;
+            }
+            (*out_result).is_changepoint = is_cp_ptr;
+
+            // Allocate and copy changepoint_probability
+            let prob_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+            for (i, &p) in bocpd.changepoint_probability.iter().enumerate() {
+                *prob_ptr.add(i) = p;
+            }
+            (*out_result).changepoint_probability = prob_ptr;
+
+            // Copy changepoint indices
+            let n_cp = bocpd.changepoints.len();
+            (*out_result).n_changepoints = n_cp;
+            if n_cp > 0 {
+                let idx_ptr = malloc(n_cp * std::mem::size_of::<size_t>()) as *mut size_t;
+                for (i, &c) in bocpd.changepoints.iter().enumerate() {
+                    *idx_ptr.add(i) = c;
+                }
+                (*out_result).changepoint_indices = idx_ptr;
+            } else {
+                (*out_result).changepoint_indices = ptr::null_mut();
+            }
+
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Create a streaming PELT changepoint detector.
+///
+/// `cost_function` uses the same codes as `anofox_ts_detect_changepoints`.
+/// `max_history` limits how far back a segment may start (0 = unbounded).
+/// Returns null on error; the handle must be released with `anofox_pelt_free`.
+///
+/// # Safety
+/// `out_error` may be null; otherwise it must point to a valid `AnofoxError`.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_pelt_new(
+    min_size: c_int,
+    penalty: c_double,
+    cost_function: c_int,
+    max_history: size_t,
+    out_error: *mut AnofoxError,
+) -> *mut types::PeltHandle {
+    init_error(out_error);
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        anofox_fcst_core::CostFunction::from_int(cost_function)
+            .and_then(|cost_fn| {
+                anofox_fcst_core::PeltState::new(min_size.max(1) as usize, penalty, cost_fn)
+            })
+            .map(|state| {
+                if max_history > 0 {
+                    state.with_max_history(max_history)
+                } else {
+                    state
+                }
+            })
+    }));
+
+    match result {
+        Ok(Ok(state)) => Box::into_raw(Box::new(types::PeltHandle { state })),
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::InvalidInput, &e.to_string());
+            ptr::null_mut()
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Push one observation into a streaming PELT detector.
+///
+/// On success `out_detected` tells whether a new changepoint was confirmed
+/// and `out_changepoint` holds its index (0-based position in the stream).
+/// Non-finite values are rejected with `InvalidInput` and leave the detector
+/// unchanged, so stream positions stay aligned with the caller's rows.
+///
+/// # Safety
+/// `handle` must come from `anofox_pelt_new` and not have been freed.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_pelt_push(
+    handle: *mut types::PeltHandle,
+    value: c_double,
+    out_detected: *mut bool,
+    out_changepoint: *mut size_t,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    let ptrs = &[
+        handle as *const core::ffi::c_void,
+        out_detected as *const core::ffi::c_void,
+        out_changepoint as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
+
+    if !value.is_finite() {
+        set_error(
+            out_error,
+            ErrorCode::InvalidInput,
+            "PELT observations must be finite",
+        );
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| (*handle).state.push(value)));
+
+    match result {
+        Ok(detected) => {
+            *out_detected = detected.is_some();
+            *out_changepoint = detected.unwrap_or(0);
+            true
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Free a streaming PELT detector.
+///
+/// # Safety
+/// `handle` must come from `anofox_pelt_new` (or be null) and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_pelt_free(handle: *mut types::PeltHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Create an online simple exponential smoothing updater.
+///
+/// Returns null on error; the handle must be released with
+/// `anofox_online_ses_free`.
+///
+/// # Safety
+/// `out_error` may be null; otherwise it must point to a valid `AnofoxError`.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_online_ses_new(
+    alpha: c_double,
+    out_error: *mut AnofoxError,
+) -> *mut types::OnlineSesHandle {
+    init_error(out_error);
+
+    match anofox_fcst_core::OnlineSes::new(alpha) {
+        Ok(state) => Box::into_raw(Box::new(types::OnlineSesHandle { state })),
+        Err(e) => {
+            set_error(out_error, ErrorCode::InvalidInput, &e.to_string());
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Push one observation into an online SES updater. Non-finite values are skipped.
+///
+/// # Safety
+/// `handle` must come from `anofox_online_ses_new` and not have been freed.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_online_ses_update(
+    handle: *mut types::OnlineSesHandle,
+    value: c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if handle.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    (*handle).state.update(value);
+    true
+}
+
+/// Forecast `horizon` steps from the current level of an online SES updater.
+///
+/// Fails with `InsufficientData` before the first observation. Free
+/// `out_values` with `anofox_free_double_array`.
+///
+/// # Safety
+/// `handle` must come from `anofox_online_ses_new` and not have been freed;
+/// `out_values` and `out_length` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_online_ses_forecast(
+    handle: *const types::OnlineSesHandle,
+    horizon: size_t,
+    out_values: *mut *mut c_double,
+    out_length: *mut size_t,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if handle.is_null() || out_values.is_null() || out_length.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let state = &(*handle).state;
+    if state.level().is_none() {
+        set_error(
+            out_error,
+            ErrorCode::InsufficientData,
+            "No observations have been pushed",
+        );
+        return false;
+    }
+
+    let point = state.forecast(horizon);
+    *out_length = point.len();
+    match alloc_or_error(&point, out_error, "Failed to allocate forecasts") {
+        Ok(ptr) => {
+            *out_values = ptr;
+            true
+        }
+        Err(()) => false,
+    }
+}
+
+/// Free an online SES updater.
+///
+/// # Safety
+/// `handle` must come from `anofox_online_ses_new` (or be null) and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_online_ses_free(handle: *mut types::OnlineSesHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+// ============================================================================
+// Feature Functions
+// ============================================================================
+
+/// Extract time series features.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_features(
+    values: *const c_double,
+    length: size_t,
+    out_result: *mut FeaturesResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        anofox_fcst_core::extract_features(&values_vec)
+    }));
+
+    match result {
+        Ok(Ok(features)) => {
+            write_features_result(features, out_result);
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Extract only the named time series features.
+///
+/// Unknown feature names are reported as an error instead of being ignored.
+/// Features that are undefined for the series are omitted from the result.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `feature_names` must hold
+/// `n_names` valid null-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_features_subset(
+    values: *const c_double,
+    length: size_t,
+    feature_names: *const *const c_char,
+    n_names: size_t,
+    out_result: *mut FeaturesResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || feature_names.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+
+        let mut names = Vec::with_capacity(n_names);
+        for i in 0..n_names {
+            let name_ptr = *feature_names.add(i);
+            if name_ptr.is_null() {
+                return Err(anofox_fcst_core::ForecastError::InvalidInput(format!(
+                    "Feature name {} is null",
+                    i
+                )));
+            }
+            names.push(CStr::from_ptr(name_ptr).to_string_lossy().into_owned());
+        }
+
+        anofox_fcst_core::extract_features_subset(&values_vec, &names)
+    }));
+
+    match result {
+        Ok(Ok(features)) => {
+            write_features_result(features, out_result);
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Extract time series features with tunable parameters.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_features_with_params(
+    values: *const c_double,
+    length: size_t,
+    params: *const FeatureParamsFFI,
+    out_result: *mut FeaturesResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || params.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let ffi_params = *params;
+        let defaults = anofox_fcst_core::FeatureParams::default();
+        let core_params = anofox_fcst_core::FeatureParams {
+            max_lag: if ffi_params.max_lag > 0 {
+                ffi_params.max_lag
+            } else {
+                defaults.max_lag
+            },
+            n_bins: if ffi_params.n_bins > 0 {
+                ffi_params.n_bins
+            } else {
+                defaults.n_bins
+            },
+            rolling_window: if ffi_params.rolling_window > 0 {
+                Some(ffi_params.rolling_window)
+            } else {
+                None
+            },
+            seasonal_period: if ffi_params.seasonal_period > 0 {
+                Some(ffi_params.seasonal_period)
+            } else {
+                None
+            },
+        };
+        anofox_fcst_core::extract_features_with_params(&values_vec, &core_params)
+    }));
+
+    match result {
+        Ok(Ok(features)) => {
+            write_features_result(features, out_result);
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Copy extracted features into a `FeaturesResult`, sorted by name.
+unsafe fn write_features_result(
+    features: std::collections::HashMap<String, f64>,
+    out_result: *mut FeaturesResult,
+) {
+    let n = features.len();
+    (*out_result).n_features = n;
+
+    if n > 0 {
+        let values_ptr = malloc(n * std::mem::size_of::<c_double>()) as *mut c_double;
+        let names_ptr = malloc(n * std::mem::size_of::<*mut c_char>()) as *mut *mut c_char;
+
+        let mut sorted: Vec<_> = features.into_iter().collect();
+        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (i, (name, value)) in sorted.into_iter().enumerate() {
+            *values_ptr.add(i) = value;
+
+            let name_len = name.len() + 1;
+            let name_ptr = malloc(name_len) as *mut c_char;
+            ptr::copy_nonoverlapping(name.as_ptr() as *const c_char, name_ptr, name.len());
+            *name_ptr.add(name.len()) = 0;
+            *names_ptr.add(i) = name_ptr;
+        }
+
+        (*out_result).features = values_ptr;
+        (*out_result).feature_names = names_ptr;
+    } else {
+        (*out_result).features = ptr::null_mut();
+        (*out_result).feature_names = ptr::null_mut();
+    }
+}
+
+/// Validate feature parameter keys and return warnings for unknown keys.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_validate_feature_params(
+    param_names: *const *const c_char,
+    n_params: size_t,
+    out_warnings: *mut *mut *mut c_char,
+    out_n_warnings: *mut size_t,
+) -> bool {
+    if param_names.is_null() || out_warnings.is_null() || out_n_warnings.is_null() {
+        return false;
+    }
+
+    // Convert C strings to Rust strings
+    let mut param_vec = Vec::with_capacity(n_params);
+    for i in 0..n_params {
+        let param_ptr = *param_names.add(i);
+        if !param_ptr.is_null() {
+            if let Ok(param_str) = CStr::from_ptr(param_ptr).to_str() {
+                param_vec.push(param_str.to_string());
+            }
+        }
+    }
+
+    // Call the validation function
+    let warnings = anofox_fcst_core::validate_feature_params(&param_vec);
+    let n = warnings.len();
+
+    *out_n_warnings = n;
+
+    if n > 0 {
+        let warnings_ptr = malloc(n * std::mem::size_of::<*mut c_char>()) as *mut *mut c_char;
+        if warnings_ptr.is_null() {
+            return false;
+        }
+
+        for (i, warning) in warnings.into_iter().enumerate() {
+            let warning_len = warning.len() + 1;
+            let warning_cstr = malloc(warning_len) as *mut c_char;
+            if !warning_cstr.is_null() {
+                ptr::copy_nonoverlapping(
+                    warning.as_ptr() as *const c_char,
+                    warning_cstr,
+                    warning.len(),
+                );
+                *warning_cstr.add(warning.len()) = 0;
+            }
+            *warnings_ptr.add(i) = warning_cstr;
+        }
+
+        *out_warnings = warnings_ptr;
+    } else {
+        *out_warnings = ptr::null_mut();
+    }
+
+    true
+}
+
+/// Free warnings array returned by validate_feature_params.
+///
+/// # Safety
+/// The pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_warnings(warnings: *mut *mut c_char, n_warnings: size_t) {
+    if warnings.is_null() {
+        return;
+    }
+
+    for i in 0..n_warnings {
+        let warning_ptr = *warnings.add(i);
+        if !warning_ptr.is_null() {
+            free(warning_ptr as *mut core::ffi::c_void);
+        }
+    }
+    free(warnings as *mut core::ffi::c_void);
+}
+
+/// List available feature names.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_features_list(
+    out_names: *mut *mut c_char,
+    out_count: *mut size_t,
+) -> bool {
+    let names = anofox_fcst_core::list_features();
+    let n = names.len();
+
+    *out_count = n;
+
+    if n > 0 {
+        let names_ptr = malloc(n * std::mem::size_of::<*mut c_char>()) as *mut *mut c_char;
+
+        for (i, name) in names.into_iter().enumerate() {
+            let name_len = name.len() + 1;
+            let name_ptr = malloc(name_len) as *mut c_char;
+            ptr::copy_nonoverlapping(name.as_ptr() as *const c_char, name_ptr, name.len());
+            *name_ptr.add(name.len()) = 0;
+            *names_ptr.add(i) = name_ptr;
+        }
+
+        *out_names = names_ptr as *mut c_char;
+    }
+
+    true
+}
+
+// ============================================================================
+// Forecast Functions
+// ============================================================================
+
+/// Generate forecasts for a time series.
+///
+/// Parse a seasonal_periods_str like "[24, 168]" into Vec<usize>.
+fn parse_seasonal_periods_str(s: &str) -> Vec<usize> {
+    if s.is_empty() {
+        return vec![];
+    }
+    s.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .filter_map(|p| p.trim().parse::<usize>().ok())
+        .filter(|&v| v > 0)
+        .collect()
+}
+
+/// Split a `model` buffer of the form `"ETS"` or `"ETS:AAA"` into the model
+/// and its ETS spec.
+///
+/// `ets_model` is the separate spec buffer; when both carry a spec they must
+/// agree. Only ETS accepts a spec in the `model` buffer.
+fn parse_model_spec(
+    model_str: &str,
+    ets_model: Option<String>,
+) -> anofox_fcst_core::Result<(anofox_fcst_core::ModelType, Option<String>)> {
+    let (name, inline_spec) = match model_str.split_once(':') {
+        Some((name, spec)) => (name.trim(), Some(spec.trim()).filter(|s| !s.is_empty())),
+        None => (model_str, None),
+    };
+
+    let model: anofox_fcst_core::ModelType = name.parse()?;
+
+    let Some(spec) = inline_spec else {
+        return Ok((model, ets_model));
+    };
+    if model != anofox_fcst_core::ModelType::ETS {
+        return Err(anofox_fcst_core::ForecastError::InvalidModel(format!(
+            "Model '{}' does not take a specification (got '{}')",
+            name, spec
+        )));
+    }
+    if let Some(other) = ets_model.filter(|other| other != spec) {
+        return Err(anofox_fcst_core::ForecastError::InvalidInput(format!(
+            "Conflicting ETS specs: '{}' in model and '{}' in ets_model",
+            spec, other
+        )));
+    }
+    Ok((model, Some(spec.to_string())))
+}
+
+/// Copy a core forecast into a `ForecastResult`.
+///
+/// Returns false (with `out_error` set) if an allocation fails; nothing is
+/// left allocated in that case.
+///
+/// # Safety
+/// `out_result` must be valid; `out_error` may be null.
+unsafe fn write_forecast_result(
+    forecast: &anofox_fcst_core::ForecastOutput,
+    out_result: *mut ForecastResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    let n_forecasts = forecast.point.len();
+    (*out_result).n_forecasts = n_forecasts;
+
+    // Copy point forecasts with allocation error checking
+    (*out_result).point_forecasts = match alloc_or_error(
+        &forecast.point,
+        out_error,
+        "Failed to allocate point forecasts",
+    ) {
+        Ok(ptr) => ptr,
+        Err(()) => return false,
+    };
+
+    (*out_result).lower_bounds = match alloc_or_error(
+        &forecast.lower,
+        out_error,
+        "Failed to allocate lower bounds",
+    ) {
+        Ok(ptr) => ptr,
+        Err(()) => {
+            // Clean up already allocated memory
+            free_ptr((*out_result).point_forecasts as *mut _);
+            (*out_result).point_forecasts = ptr::null_mut();
+            return false;
+        }
+    };
+
+    (*out_result).upper_bounds = match alloc_or_error(
+        &forecast.upper,
+        out_error,
+        "Failed to allocate upper bounds",
+    ) {
+        Ok(ptr) => ptr,
+        Err(()) => {
+            free_ptr((*out_result).point_forecasts as *mut _);
+            free_ptr((*out_result).lower_bounds as *mut _);
+            (*out_result).point_forecasts = ptr::null_mut();
+            (*out_result).lower_bounds = ptr::null_mut();
+            return false;
+        }
+    };
+
+    // Copy fitted values
+    if let Some(ref fitted) = forecast.fitted {
+        (*out_result).fitted_values =
+            match alloc_or_error(fitted, out_error, "Failed to allocate fitted values") {
+                Ok(ptr) => ptr,
+                Err(()) => {
+                    free_ptr((*out_result).point_forecasts as *mut _);
+                    free_ptr((*out_result).lower_bounds as *mut _);
+                    free_ptr((*out_result).upper_bounds as *mut _);
+                    (*out_result).point_forecasts = ptr::null_mut();
+                    (*out_result).lower_bounds = ptr::null_mut();
+                    (*out_result).upper_bounds = ptr::null_mut();
+                    return false;
+                }
+            };
+        (*out_result).n_fitted = fitted.len();
+    } else {
+        (*out_result).fitted_values = ptr::null_mut();
+        (*out_result).n_fitted = 0;
+    }
+
+    // Copy residuals
+    if let Some(ref resid) = forecast.residuals {
+        (*out_result).residuals =
+            match alloc_or_error(resid, out_error, "Failed to allocate residuals") {
+                Ok(ptr) => ptr,
+                Err(()) => {
+                    free_ptr((*out_result).point_forecasts as *mut _);
+                    free_ptr((*out_result).lower_bounds as *mut _);
+                    free_ptr((*out_result).upper_bounds as *mut _);
+                    free_ptr((*out_result).fitted_values as *mut _);
+                    (*out_result).point_forecasts = ptr::null_mut();
+                    (*out_result).lower_bounds = ptr::null_mut();
+                    (*out_result).upper_bounds = ptr::null_mut();
+                    (*out_result).fitted_values = ptr::null_mut();
+                    return false;
+                }
+            };
+    } else {
+        (*out_result).residuals = ptr::null_mut();
+    }
+
+    // Copy model name
+    copy_string_to_buffer(&forecast.model_name, &mut (*out_result).model_name);
+
+    (*out_result).aic = forecast.aic.unwrap_or(f64::NAN);
+    (*out_result).bic = forecast.bic.unwrap_or(f64::NAN);
+    (*out_result).mse = forecast.mse.unwrap_or(f64::NAN);
+    (*out_result).holdout_score = forecast.holdout_score.unwrap_or(f64::NAN);
+    (*out_result).seasonal_period_used = forecast.seasonal_period.unwrap_or(1);
+
+    let warnings: Vec<&str> = forecast.warnings.iter().map(|w| w.as_str()).collect();
+    if !alloc_string_array(&warnings, &mut (*out_result).warnings) {
+        set_error(
+            out_error,
+            ErrorCode::AllocationError,
+            "Failed to allocate warnings",
+        );
+        free_ptr((*out_result).point_forecasts as *mut _);
+        free_ptr((*out_result).lower_bounds as *mut _);
+        free_ptr((*out_result).upper_bounds as *mut _);
+        free_ptr((*out_result).fitted_values as *mut _);
+        free_ptr((*out_result).residuals as *mut _);
+        (*out_result).point_forecasts = ptr::null_mut();
+        (*out_result).lower_bounds = ptr::null_mut();
+        (*out_result).upper_bounds = ptr::null_mut();
+        (*out_result).fitted_values = ptr::null_mut();
+        (*out_result).residuals = ptr::null_mut();
+        return false;
+    }
+    (*out_result).n_warnings = warnings.len();
+
+    let lower_rows: Vec<&[f64]> = forecast
+        .intervals
+        .iter()
+        .map(|(l, _)| l.as_slice())
+        .collect();
+    let upper_rows: Vec<&[f64]> = forecast
+        .intervals
+        .iter()
+        .map(|(_, u)| u.as_slice())
+        .collect();
+    (*out_result).level_lower = ptr::null_mut();
+    (*out_result).level_upper = ptr::null_mut();
+    (*out_result).n_levels = 0;
+    let level_bounds = alloc_double_rows(&lower_rows, out_error, "Failed to allocate level bounds")
+        .and_then(|lower| {
+            (*out_result).level_lower = lower;
+            (*out_result).n_levels = lower_rows.len();
+            alloc_double_rows(&upper_rows, out_error, "Failed to allocate level bounds")
+        });
+    match level_bounds {
+        Ok(upper) => (*out_result).level_upper = upper,
+        Err(()) => {
+            anofox_free_forecast_result(out_result);
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Allocate one C array per row, returning null when there are no rows.
+///
+/// Nothing is left allocated on failure.
+///
+/// # Safety
+/// `out_error` may be null.
+unsafe fn alloc_double_rows(
+    rows: &[&[f64]],
+    out_error: *mut AnofoxError,
+    error_msg: &str,
+) -> std::result::Result<*mut *mut c_double, ()> {
+    if rows.is_empty() {
+        return Ok(ptr::null_mut());
+    }
+    let rows_ptr = malloc(rows.len() * std::mem::size_of::<*mut c_double>()) as *mut *mut c_double;
+    if rows_ptr.is_null() {
+        set_error(out_error, ErrorCode::AllocationError, error_msg);
+        return Err(());
+    }
+    for (i, row) in rows.iter().enumerate() {
+        match alloc_or_error(row, out_error, error_msg) {
+            Ok(row_ptr) => *rows_ptr.add(i) = row_ptr,
+            Err(()) => {
+                for j in 0..i {
+                    free_ptr(*rows_ptr.add(j) as *mut core::ffi::c_void);
+                }
+                free_ptr(rows_ptr as *mut core::ffi::c_void);
+                return Err(());
+            }
+        }
+    }
+    Ok(rows_ptr)
+}
+
+/// Map a core forecast error to the FFI error code.
+fn forecast_error_code(e: &anofox_fcst_core::ForecastError) -> ErrorCode {
+    match e.to_code() {
+        1 => ErrorCode::NullPointer,
+        2 => ErrorCode::InvalidInput,
+        3 => ErrorCode::ComputationError,
+        4 => ErrorCode::AllocationError,
+        5 => ErrorCode::InvalidModel,
+        6 => ErrorCode::InsufficientData,
+        7 => ErrorCode::InvalidDateFormat,
+        8 => ErrorCode::InvalidFrequency,
+        9 => ErrorCode::InvalidInput, // InvalidParameter → InvalidInput at FFI boundary
+        _ => ErrorCode::InternalError,
+    }
+}
+
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_forecast(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    options: *const ForecastOptions,
+    out_result: *mut ForecastResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || options.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        let core_opts = build_core_options(&*options)?;
+
+        anofox_fcst_core::forecast(&series, &core_opts)
+    }));
+
+    match result {
+        Ok(Ok(forecast)) => write_forecast_result(&forecast, out_result, out_error),
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(forecast_error_code(&e), &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Forecast with bounds at several confidence levels from a single fit.
+///
+/// Behaves like `anofox_ts_forecast`; additionally `out_result.level_lower[i]`
+/// and `out_result.level_upper[i]` hold `n_forecasts` bounds at `levels[i]`
+/// (each in (0, 1)). Free the result with `anofox_free_forecast_result`.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `levels` must hold
+/// `n_levels` elements (it may be null when `n_levels` is 0).
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_forecast_levels(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    options: *const ForecastOptions,
+    levels: *const c_double,
+    n_levels: size_t,
+    out_result: *mut ForecastResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if check_null_pointers(
+        out_error,
+        &[
+            values as *const core::ffi::c_void,
+            options as *const core::ffi::c_void,
+            out_result as *const core::ffi::c_void,
+        ],
+    ) {
+        return false;
+    }
+    if n_levels > 0 && levels.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        let mut core_opts = build_core_options(&*options)?;
+        if n_levels > 0 {
+            core_opts.confidence_levels = std::slice::from_raw_parts(levels, n_levels).to_vec();
+        }
+
+        anofox_fcst_core::forecast(&series, &core_opts)
+    }));
+
+    match result {
+        Ok(Ok(forecast)) => write_forecast_result(&forecast, out_result, out_error),
+        Ok(Err(e)) => {
+            set_error(out_error, forecast_error_code(&e), &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Fit a forecast model once so it can be predicted at several horizons.
+///
+/// Uses the same options as `anofox_ts_forecast`; `options.horizon` is
+/// ignored. Returns null on error; the handle must be released with
+/// `anofox_model_free`. Handles are not synchronised: use each handle from
+/// one thread at a time.
+///
+/// # Safety
+/// `values` (and `validity`, if non-null) must hold `length` elements and
+/// `options` must be valid. `out_error` may be null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_model_fit(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    options: *const ForecastOptions,
+    out_error: *mut AnofoxError,
+) -> *mut types::FittedModelHandle {
+    init_error(out_error);
+
+    if check_null_pointers(
+        out_error,
+        &[
+            values as *const core::ffi::c_void,
+            options as *const core::ffi::c_void,
+        ],
+    ) {
+        return ptr::null_mut();
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        let core_opts = build_core_options(&*options)?;
+
+        anofox_fcst_core::fit(&series, &core_opts)
+    }));
+
+    match result {
+        Ok(Ok(model)) => Box::into_raw(Box::new(types::FittedModelHandle { model })),
+        Ok(Err(e)) => {
+            set_error(out_error, forecast_error_code(&e), &e.to_string());
+            ptr::null_mut()
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Forecast `horizon` steps ahead from a fitted model handle.
+///
+/// Forecasts continue from the state estimated by `anofox_model_fit`; the
+/// model is not re-estimated, so repeated calls are cheap. Free the result
+/// with `anofox_free_forecast_result`.
+///
+/// # Safety
+/// `handle` must come from `anofox_model_fit` and not have been freed.
+/// `out_result` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_model_predict(
+    handle: *const types::FittedModelHandle,
+    horizon: size_t,
+    out_result: *mut ForecastResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if check_null_pointers(
+        out_error,
+        &[
+            handle as *const core::ffi::c_void,
+            out_result as *const core::ffi::c_void,
+        ],
+    ) {
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| (*handle).model.predict(horizon)));
+
+    match result {
+        Ok(Ok(forecast)) => write_forecast_result(&forecast, out_result, out_error),
+        Ok(Err(e)) => {
+            set_error(out_error, forecast_error_code(&e), &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Free a fitted model handle.
+///
+/// # Safety
+/// `handle` must come from `anofox_model_fit` (or be null) and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_model_free(handle: *mut types::FittedModelHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Forecast many series with shared options in one call.
+///
+/// `values` holds all series back to back; series `i` spans
+/// `[offsets[i], offsets[i + 1])`, so `offsets` has `n_series + 1`
+/// non-decreasing entries. `validity` (may be null) is a bitmask over the
+/// whole flattened array. A series that cannot be forecast is flagged in
+/// `success` rather than failing the call. `n_threads` is a thread-count hint
+/// for builds with the `parallel` feature (0 = rayon default). Free the
+/// result with `anofox_free_forecast_batch_result`.
+///
+/// # Safety
+/// `values` must hold `offsets[n_series]` elements, `offsets` must hold
+/// `n_series + 1` elements, and `options` and `out_result` must be valid.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_forecast_batch(
+    values: *const c_double,
+    validity: *const u64,
+    offsets: *const size_t,
+    n_series: size_t,
+    options: *const ForecastOptions,
+    n_threads: size_t,
+    out_result: *mut ForecastBatchResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if check_null_pointers(
+        out_error,
+        &[
+            values as *const core::ffi::c_void,
+            offsets as *const core::ffi::c_void,
+            options as *const core::ffi::c_void,
+            out_result as *const core::ffi::c_void,
+        ],
+    ) {
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let offsets = std::slice::from_raw_parts(offsets, n_series + 1);
+        if offsets.windows(2).any(|w| w[1] < w[0]) {
+            return Err(anofox_fcst_core::ForecastError::InvalidInput(
+                "Series offsets must be non-decreasing".to_string(),
+            ));
+        }
+        let core_opts = build_core_options(&*options)?;
+
+        let flat = build_series(values, validity, offsets[n_series]);
+        let series: Vec<&[Option<f64>]> = offsets.windows(2).map(|w| &flat[w[0]..w[1]]).collect();
+
+        Ok(anofox_fcst_core::forecast_batch(
+            &series, &core_opts, n_threads,
+        ))
+    }));
+
+    match result {
+        Ok(Ok(forecasts)) => {
+            let mut out_offsets = Vec::with_capacity(n_series + 1);
+            let mut success = Vec::with_capacity(n_series);
+            let (mut point, mut lower, mut upper) = (Vec::new(), Vec::new(), Vec::new());
+            out_offsets.push(0);
+            for forecast in &forecasts {
+                if let Ok(f) = forecast {
+                    point.extend_from_slice(&f.point);
+                    lower.extend_from_slice(&f.lower);
+                    upper.extend_from_slice(&f.upper);
+                }
+                success.push(forecast.is_ok());
+                out_offsets.push(point.len());
+            }
+
+            *out_result = ForecastBatchResult {
+                n_series,
+                n_forecasts: point.len(),
+                ..Default::default()
+            };
+            let r = &mut *out_result;
+            let allocated = (|| -> Result<(), ()> {
+                let msg = "Failed to allocate batch forecasts";
+                r.offsets = alloc_or_error(&out_offsets, out_error, msg)?;
+                r.success = alloc_or_error(&success, out_error, msg)?;
+                r.point_forecasts = alloc_or_error(&point, out_error, msg)?;
+                r.lower_bounds = alloc_or_error(&lower, out_error, msg)?;
+                r.upper_bounds = alloc_or_error(&upper, out_error, msg)?;
+                Ok(())
+            })();
+            if allocated.is_err() {
+                anofox_free_forecast_batch_result(out_result);
+                return false;
+            }
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, forecast_error_code(&e), &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// List all models with their capability bitflags (`MODEL_CAP_*`).
+///
+/// # Safety
+/// `out_result` must be valid and non-null. Free the result with
+/// `anofox_free_models_info_result`.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_models_info(
+    out_result: *mut ModelsInfoResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if out_result.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        anofox_fcst_core::list_models()
+            .into_iter()
+            .map(|name| {
+                let model: anofox_fcst_core::ModelType = name.parse()?;
+                let caps = model.capabilities();
+                let mut flags = 0u32;
+                if caps.supports_exog {
+                    flags |= MODEL_CAP_EXOG;
+                }
+                if caps.supports_seasonal {
+                    flags |= MODEL_CAP_SEASONAL;
+                }
+                if caps.is_intermittent {
+                    flags |= MODEL_CAP_INTERMITTENT;
+                }
+                if caps.produces_intervals {
+                    flags |= MODEL_CAP_INTERVALS;
+                }
+                Ok((name, flags))
+            })
+            .collect::<anofox_fcst_core::Result<Vec<(String, u32)>>>()
+    }));
+
+    match result {
+        Ok(Ok(models)) => {
+            let out = &mut *out_result;
+            let names: Vec<&str> = models.iter().map(|(name, _)| name.as_str()).collect();
+            if !alloc_string_array(&names, &mut out.names) {
+                set_error(
+                    out_error,
+                    ErrorCode::AllocationError,
+                    "Failed to allocate model names",
+                );
+                return false;
+            }
+            let flags_ptr = malloc(models.len() * std::mem::size_of::<u32>()) as *mut u32;
+            if flags_ptr.is_null() {
+                out.n_models = models.len();
+                anofox_free_models_info_result(out);
+                set_error(
+                    out_error,
+                    ErrorCode::AllocationError,
+                    "Failed to allocate model capabilities",
+                );
+                return false;
+            }
+            for (i, (_, flags)) in models.iter().enumerate() {
+                *flags_ptr.add(i) = *flags;
+            }
+            out.capabilities = flags_ptr;
+            out.n_models = models.len();
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Free a ModelsInfoResult.
+///
+/// # Safety
+/// The result pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_models_info_result(result: *mut ModelsInfoResult) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    if !r.names.is_null() {
+        for i in 0..r.n_models {
+            let name_ptr = *r.names.add(i);
+            if !name_ptr.is_null() {
+                free(name_ptr as *mut core::ffi::c_void);
+            }
+        }
+        free(r.names as *mut core::ffi::c_void);
+        r.names = ptr::null_mut();
+    }
+
+    if !r.capabilities.is_null() {
+        free(r.capabilities as *mut core::ffi::c_void);
+        r.capabilities = ptr::null_mut();
+    }
+    r.n_models = 0;
+}
+
+/// Forecast with several models and average the results.
+///
+/// # Arguments
+/// * `model_names` - Array of `n_models` model name strings (e.g. "Naive", "AutoETS")
+/// * `weights` - Optional array of `n_models` non-negative weights (NULL = equal weights)
+/// * `horizon` - Forecast horizon
+///
+/// Point forecasts are the weighted mean of the members; intervals take the
+/// widest member bounds. Fitted values and residuals are not populated.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null (except `validity` and
+/// `weights`). Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_forecast_ensemble(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    model_names: *const *const c_char,
+    n_models: size_t,
+    weights: *const c_double,
+    horizon: c_int,
+    out_result: *mut ForecastResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if values.is_null() || model_names.is_null() || out_result.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+
+        let mut models = Vec::with_capacity(n_models);
+        for i in 0..n_models {
+            let name_ptr = *model_names.add(i);
+            if name_ptr.is_null() {
+                return Err(anofox_fcst_core::ForecastError::InvalidInput(format!(
+                    "Model name {} is null",
+                    i
+                )));
+            }
+            let name = CStr::from_ptr(name_ptr).to_str().unwrap_or("");
+            let model: anofox_fcst_core::ModelType = name.parse()?;
+            models.push(model);
+        }
+
+        let weights = if weights.is_null() {
+            None
+        } else {
+            Some(std::slice::from_raw_parts(weights, n_models))
+        };
+
+        anofox_fcst_core::forecast_ensemble(&series, &models, horizon.max(0) as usize, weights)
+    }));
+
+    match result {
+        Ok(Ok(forecast)) => write_forecast_result(&forecast, out_result, out_error),
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Generate time series forecasts with exogenous variables.
+///
+/// This function extends `anofox_ts_forecast` to support external regressors (xreg).
+/// Exogenous variables can improve forecast accuracy when external factors (e.g.,
+/// promotions, holidays, weather) influence the target variable.
+///
+/// # Arguments
+/// * `values` - Pointer to time series values (target variable y)
+/// * `validity` - Pointer to validity bitmask (NULL means all valid)
+/// * `length` - Number of observations
+/// * `options` - Forecast options including exogenous data
+/// * `out_result` - Output forecast result
+/// * `out_error` - Output error (optional)
+///
+/// # Supported Models
+/// The following models support exogenous variables:
+/// - AutoARIMA, ARIMA (ARIMAX)
+/// - OptimizedTheta, DynamicTheta
+/// - MFLES
+/// - ETS, AutoETS (ETSX), HoltWinters (HoltWintersX)
+///
+/// Other models will ignore the exogenous data and produce a standard forecast.
+///
+/// # Safety
+/// All pointer arguments must be valid. Arrays must have the specified lengths.
+/// For exogenous data:
+/// - Each regressor's `n_values` must equal `length`
+/// - Each regressor's `n_future` must equal `options.horizon`
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_forecast_exog(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    options: *const ForecastOptionsExog,
+    out_result: *mut ForecastResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || options.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        let opts = &*options;
+
+        // Parse model name and ETS spec ("ETS:AAA" or the separate `ets_model`)
+        let model_str = CStr::from_ptr(opts.model.as_ptr()).to_str().map_err(|_| {
+            anofox_fcst_core::ForecastError::InvalidModel(
+                "Model name is not valid UTF-8".to_string(),
+            )
+        })?;
+        let ets_model = CStr::from_ptr(opts.ets_model.as_ptr())
+            .to_str()
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(String::from);
+        let (model_type, ets_spec) = parse_model_spec(model_str, ets_model)?;
+
+        // Build exogenous data if provided
+        let exog_data = if !opts.exog.is_null() {
+            let exog = &*opts.exog;
+            if !exog.is_empty() {
+                let mut historical: Vec<Vec<f64>> = Vec::with_capacity(exog.n_regressors);
+                let mut future: Vec<Vec<f64>> = Vec::with_capacity(exog.n_regressors);
+
+                for i in 0..exog.n_regressors {
+                    let reg = &*exog.regressors.add(i);
+
+                    // Validate lengths
+                    if reg.n_values != length {
+                        return Err(anofox_fcst_core::error::ForecastError::InvalidInput(
+                            format!(
+                                "Exogenous regressor {} has {} values but y has {} values",
+                                i, reg.n_values, length
+                            ),
+                        ));
+                    }
+                    if reg.n_future != opts.horizon as usize {
+                        return Err(anofox_fcst_core::error::ForecastError::InvalidInput(
+                            format!(
+                                "Exogenous regressor {} has {} future values but horizon is {}",
+                                i, reg.n_future, opts.horizon
+                            ),
+                        ));
+                    }
+
+                    // Copy historical values
+                    let hist_slice = std::slice::from_raw_parts(reg.values, reg.n_values);
+                    historical.push(hist_slice.to_vec());
+
+                    // Copy future values
+                    let future_slice = std::slice::from_raw_parts(reg.future_values, reg.n_future);
+                    future.push(future_slice.to_vec());
+                }
+
+                Some(anofox_fcst_core::ExogenousData { historical, future })
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        // Parse seasonal_periods_str
+        let sp_str = CStr::from_ptr(opts.seasonal_periods_str.as_ptr())
+            .to_str()
+            .unwrap_or("");
+        let seasonal_periods = parse_seasonal_periods_str(sp_str);
+
+        // Parse model_pool
+        let model_pool = CStr::from_ptr(opts.model_pool.as_ptr())
+            .to_str()
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(String::from);
+
+        // Parse laplace_variant (empty → default Auto handled downstream)
+        let laplace_variant = CStr::from_ptr(opts.laplace_variant.as_ptr())
+            .to_str()
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(anofox_fcst_core::LaplaceVariant::parse)
+            .transpose()?;
+
+        let interval_method = CStr::from_ptr(opts.interval_method.as_ptr())
+            .to_str()
+            .ok()
+            .map(anofox_fcst_core::PredictionIntervalMethod::parse)
+            .transpose()?
+            .unwrap_or_default();
+
+        let seasonal_mode = CStr::from_ptr(opts.seasonal_mode.as_ptr())
+            .to_str()
+            .ok()
+            .map(anofox_fcst_core::SeasonalMode::parse)
+            .transpose()?
+            .unwrap_or_default();
+
+        let core_opts = anofox_fcst_core::ForecastOptionsExog {
+            model: model_type,
+            ets_spec,
+            horizon: opts.horizon as usize,
+            confidence_level: opts.confidence_level,
+            seasonal_period: opts.seasonal_period as usize,
+            auto_detect_seasonality: opts.auto_detect_seasonality,
+            include_fitted: opts.include_fitted,
+            include_residuals: opts.include_residuals,
+            exog: exog_data,
+            window: opts.window.max(0) as usize,
+            seasonal_periods,
+            model_pool,
+            laplace_variant,
+            laplace_seasonal_batch_init: opts.laplace_seasonal_batch_init,
+            robust_intervals: opts.robust_intervals,
+            interval_method,
+            damped: opts.damped,
+            phi: (opts.phi > 0.0).then_some(opts.phi),
+            seasonal_mode,
+        };
+
+        anofox_fcst_core::forecast_with_exog(&series, &core_opts)
+    }));
+
+    match result {
+        Ok(Ok(forecast)) => write_forecast_result(&forecast, out_result, out_error),
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                let error_code = match e.to_code() {
+                    1 => ErrorCode::NullPointer,
+                    2 => ErrorCode::InvalidInput,
+                    3 => ErrorCode::ComputationError,
+                    4 => ErrorCode::AllocationError,
+                    5 => ErrorCode::InvalidModel,
+                    6 => ErrorCode::InsufficientData,
+                    7 => ErrorCode::InvalidDateFormat,
+                    8 => ErrorCode::InvalidFrequency,
+                    9 => ErrorCode::InvalidInput, // InvalidParameter → InvalidInput at FFI boundary
+                    _ => ErrorCode::InternalError,
+                };
+                (*out_error).set_error(error_code, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Rolling-origin cross-validation of a forecast model.
+///
+/// The last window forecasts the final `horizon` observations; each earlier
+/// window moves the forecast origin back by `step`.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null (except `validity`).
+/// Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_cross_validate(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    options: *const ForecastOptions,
+    n_windows: size_t,
+    step: size_t,
+    out_result: *mut CrossValResultFFI,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if values.is_null() || options.is_null() || out_result.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        let core_opts = build_core_options(&*options)?;
+        anofox_fcst_core::cross_validate(&series, &core_opts, n_windows, step)
+    }));
+
+    match result {
+        Ok(Ok(cv)) => {
+            *out_result = CrossValResultFFI {
+                n_windows: cv.n_windows,
+                horizon: cv.horizon,
+                mae: cv.mae,
+                rmse: cv.rmse,
+                mase: cv.mase,
+                ..Default::default()
+            };
+            let r = &mut *out_result;
+            let allocated = (|| -> Result<(), ()> {
+                let msg = "Failed to allocate cross-validation results";
+                r.train_lengths = alloc_or_error(&cv.train_lengths, out_error, msg)?;
+                r.window_mae = alloc_or_error(&cv.window_mae, out_error, msg)?;
+                r.window_rmse = alloc_or_error(&cv.window_rmse, out_error, msg)?;
+                r.window_mase = alloc_or_error(&cv.window_mase, out_error, msg)?;
+                r.horizon_mae = alloc_or_error(&cv.horizon_mae, out_error, msg)?;
+                r.horizon_rmse = alloc_or_error(&cv.horizon_rmse, out_error, msg)?;
+                r.horizon_mase = alloc_or_error(&cv.horizon_mase, out_error, msg)?;
+                Ok(())
+            })();
+            if allocated.is_err() {
+                anofox_free_cross_val_result(out_result);
+                return false;
+            }
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+// ============================================================================
+// Forecast Explainability (Inspectable + Explainable)
+// ============================================================================
+
+/// Allocate a NUL-terminated C string on the FFI heap and hand ownership to the
+/// caller. Returns null if allocation fails.
+///
+/// # Safety
+/// The caller must eventually free the returned pointer via `anofox_free_string`.
+unsafe fn alloc_c_string(s: &str) -> *mut c_char {
+    let bytes = s.as_bytes();
+    let len = bytes.len();
+    let buf = malloc(len + 1) as *mut c_char;
+    if buf.is_null() {
+        return ptr::null_mut();
+    }
+    ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, buf, len);
+    *buf.add(len) = 0;
+    buf
+}
+
+/// Free a C string previously allocated by an inspect / explain call.
+///
+/// # Safety
+/// `ptr` must be either null or a pointer previously returned by one of
+/// `anofox_ts_forecast_inspect` / `anofox_ts_forecast_explain` via their
+/// `out_json` out-argument.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_string(ptr: *mut c_char) {
+    if !ptr.is_null() {
+        free(ptr as *mut _);
+    }
+}
+
+/// Fit the requested model and serialise its `Inspectable::explanation()`
+/// snapshot as JSON.
+///
+/// The JSON structure is the serde-serialised
+/// `anofox_forecast::models::inspect::Explanation` enum: an object with a
+/// single key equal to the model family (e.g. `AutoETS`, `Arima`,
+/// `Laplace`) whose value is the per-family payload struct.
+///
+/// # Arguments
+/// * `values`, `validity`, `length` — series (validity may be null for
+///   fully-valid data)
+/// * `options` — the same `ForecastOptions` used by `anofox_ts_forecast`;
+///   the `horizon` field is ignored (inspect is a fit-state snapshot)
+/// * `out_json` — receives ownership of a newly-allocated NUL-terminated
+///   JSON string; must be freed with `anofox_free_string`
+/// * `out_error` — optional error slot
+///
+/// # Supported models
+/// AutoETS, AutoARIMA, AutoTheta, AutoTBATS, MFLES, AutoMFLES, MSTL,
+/// AutoMSTL, Laplace. Any other model returns an `InvalidModel` error.
+///
+/// # Safety
+/// Pointer arguments must be valid; `out_json` and `options` must be non-null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_forecast_inspect(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    options: *const ForecastOptions,
+    out_json: *mut *mut c_char,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || options.is_null() || out_json.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+    *out_json = ptr::null_mut();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        let core_opts = build_core_options(&*options)?;
+        anofox_fcst_core::forecast_inspect(&series, &core_opts)
+    }));
+
+    match result {
+        Ok(Ok(json)) => {
+            let ptr = alloc_c_string(&json);
+            if ptr.is_null() {
+                if !out_error.is_null() {
+                    (*out_error).set_error(
+                        ErrorCode::AllocationError,
+                        "Failed to allocate JSON output buffer",
+                    );
+                }
+                return false;
+            }
+            *out_json = ptr;
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                let code = match e.to_code() {
+                    1 => ErrorCode::NullPointer,
+                    2 => ErrorCode::InvalidInput,
+                    3 => ErrorCode::ComputationError,
+                    4 => ErrorCode::AllocationError,
+                    5 => ErrorCode::InvalidModel,
+                    6 => ErrorCode::InsufficientData,
+                    7 => ErrorCode::InvalidDateFormat,
+                    8 => ErrorCode::InvalidFrequency,
+                    9 => ErrorCode::InvalidInput,
+                    _ => ErrorCode::InternalError,
+                };
+                (*out_error).set_error(code, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Fit the requested model and serialise its per-horizon
+/// `Explainable::explain(horizon)` decomposition as JSON.
+///
+/// The JSON has keys `level`, `trend`, `seasonal`, `residual` (each a
+/// Vec\<f64\> of length `horizon`, absent when the family has no such
+/// component) and `named_components` (a map of arbitrary named additive
+/// components).
+///
+/// # Arguments
+/// * `values`, `validity`, `length` — historical series
+/// * `horizon` — number of periods ahead to explain (must equal the
+///   horizon you'll subsequently forecast with; internally we call
+///   `.explain(horizon)`)
+/// * `options` — `ForecastOptions`; `horizon` field is ignored (uses the
+///   explicit `horizon` argument instead)
+/// * `out_json`, `out_error` — see `anofox_ts_forecast_inspect`
+///
+/// # Supported models
+/// ETS (fixed spec), MSTL / AutoMSTL, Theta. Any other model returns an
+/// `InvalidModel` error.
+///
+/// # Safety
+/// Pointer arguments must be valid; `out_json` and `options` must be non-null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_forecast_explain(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    horizon: size_t,
+    options: *const ForecastOptions,
+    out_json: *mut *mut c_char,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || options.is_null() || out_json.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+    *out_json = ptr::null_mut();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        let core_opts = build_core_options(&*options)?;
+        anofox_fcst_core::forecast_explain(&series, horizon, &core_opts)
+    }));
+
+    match result {
+        Ok(Ok(json)) => {
+            let ptr = alloc_c_string(&json);
+            if ptr.is_null() {
+                if !out_error.is_null() {
+                    (*out_error).set_error(
+                        ErrorCode::AllocationError,
+                        "Failed to allocate JSON output buffer",
+                    );
+                }
+                return false;
+            }
+            *out_json = ptr;
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                let code = match e.to_code() {
+                    1 => ErrorCode::NullPointer,
+                    2 => ErrorCode::InvalidInput,
+                    3 => ErrorCode::ComputationError,
+                    4 => ErrorCode::AllocationError,
+                    5 => ErrorCode::InvalidModel,
+                    6 => ErrorCode::InsufficientData,
+                    7 => ErrorCode::InvalidDateFormat,
+                    8 => ErrorCode::InvalidFrequency,
+                    9 => ErrorCode::InvalidInput,
+                    _ => ErrorCode::InternalError,
+                };
+                (*out_error).set_error(code, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Shared FFI → core `ForecastOptions` conversion.
+///
+/// Used by every entry point taking `ForecastOptions` so the buffered string
+/// fields (`model`, `ets_model`, `seasonal_periods_str`, `model_pool`,
+/// `laplace_variant`, `interval_method`, `seasonal_mode`) parse identically.
+///
+/// # Safety
+/// The string fields of `opts` must be null-terminated.
+unsafe fn build_core_options(
+    opts: &ForecastOptions,
+) -> Result<anofox_fcst_core::ForecastOptions, anofox_fcst_core::ForecastError> {
+    let model_str = CStr::from_ptr(opts.model.as_ptr()).to_str().map_err(|_| {
+        anofox_fcst_core::ForecastError::InvalidModel("Model name is not valid UTF-8".to_string())
+    })?;
+    let ets_model = CStr::from_ptr(opts.ets_model.as_ptr())
+        .to_str()
+        .ok()
+        .filter(|s| !s.is_empty())
+        .map(String::from);
+    let (model_type, ets_spec) = parse_model_spec(model_str, ets_model)?;
+
+    let sp_str = CStr::from_ptr(opts.seasonal_periods_str.as_ptr())
+        .to_str()
+        .unwrap_or("");
+    let seasonal_periods = parse_seasonal_periods_str(sp_str);
+
+    let model_pool = CStr::from_ptr(opts.model_pool.as_ptr())
+        .to_str()
+        .ok()
+        .filter(|s| !s.is_empty())
+        .map(String::from);
+
+    let laplace_variant = CStr::from_ptr(opts.laplace_variant.as_ptr())
+        .to_str()
+        .ok()
+        .filter(|s| !s.is_empty())
+        .map(anofox_fcst_core::LaplaceVariant::parse)
+        .transpose()?;
+
+    let interval_method = CStr::from_ptr(opts.interval_method.as_ptr())
+        .to_str()
+        .ok()
+        .map(anofox_fcst_core::PredictionIntervalMethod::parse)
+        .transpose()?
+        .unwrap_or_default();
+
+    let seasonal_mode = CStr::from_ptr(opts.seasonal_mode.as_ptr())
+        .to_str()
+        .ok()
+        .map(anofox_fcst_core::SeasonalMode::parse)
+        .transpose()?
+        .unwrap_or_default();
+
+    Ok(anofox_fcst_core::ForecastOptions {
+        model: model_type,
+        ets_spec,
+        horizon: opts.horizon as usize,
+        confidence_level: opts.confidence_level,
+        confidence_levels: vec![],
+        seasonal_period: opts.seasonal_period as usize,
+        auto_detect_seasonality: opts.auto_detect_seasonality,
+        include_fitted: opts.include_fitted,
+        include_residuals: opts.include_residuals,
+        window: opts.window.max(0) as usize,
+        seasonal_periods,
+        model_pool,
+        laplace_variant,
+        laplace_seasonal_batch_init: opts.laplace_seasonal_batch_init,
+        robust_intervals: opts.robust_intervals,
+        auto_select_by_holdout: opts.auto_select_by_holdout,
+        damped: opts.damped,
+        phi: (opts.phi > 0.0).then_some(opts.phi),
+        interval_method,
+        seasonal_mode,
+        log_transform: opts.log_transform,
+    })
+}
+
+// ============================================================================
+// Data Quality Functions
+// ============================================================================
+
+/// Compute data quality metrics.
+///
+/// `thresholds` may be null to use the default thresholds; they only affect
+/// the `passed` flag, not the dimension scores.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null (except `validity` and
+/// `thresholds`). Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_data_quality(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    thresholds: *const QualityThresholdsC,
+    out_result: *mut DataQualityResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if values.is_null() || out_result.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        let core_thresholds = if thresholds.is_null() {
+            anofox_fcst_core::QualityThresholds::default()
+        } else {
+            (*thresholds).into()
+        };
+        anofox_fcst_core::compute_data_quality_with_thresholds(&series, None, &core_thresholds)
+    }));
+
+    match result {
+        Ok(Ok(quality)) => {
+            (*out_result).structural_score = quality.structural_score;
+            (*out_result).temporal_score = quality.temporal_score;
+            (*out_result).magnitude_score = quality.magnitude_score;
+            (*out_result).behavioral_score = quality.behavioral_score;
+            (*out_result).overall_score = quality.overall_score;
+            (*out_result).n_gaps = quality.n_gaps;
+            (*out_result).n_missing = quality.n_missing;
+            (*out_result).is_constant = quality.is_constant;
+            (*out_result).passed = quality.passed;
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Generate a quality report with recommendations for multiple series.
+///
+/// `values` holds all series concatenated; `series_lengths[i]` is the length
+/// of series `i`. A null `thresholds` pointer uses the default thresholds.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null (except `validity` and
+/// `thresholds`). Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_quality_report(
+    values: *const c_double,
+    validity: *const u64,
+    series_lengths: *const size_t,
+    n_series: size_t,
+    thresholds: *const QualityThresholdsC,
+    out_result: *mut QualityReportResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if values.is_null() || series_lengths.is_null() || out_result.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let lengths = std::slice::from_raw_parts(series_lengths, n_series);
+        let total: usize = lengths.iter().sum();
+        let all = build_series(values, validity, total);
+
+        let mut series_list = Vec::with_capacity(n_series);
+        let mut offset = 0;
+        for &len in lengths {
+            series_list.push(all[offset..offset + len].to_vec());
+            offset += len;
+        }
+
+        let core_thresholds = if thresholds.is_null() {
+            anofox_fcst_core::QualityThresholds::default()
+        } else {
+            (*thresholds).into()
+        };
+        anofox_fcst_core::generate_quality_report(&series_list, &core_thresholds)
+    }));
+
+    match result {
+        Ok(report) => {
+            *out_result = QualityReportResult {
+                n_passed: report.n_passed,
+                n_gap_issues: report.n_gap_issues,
+                n_missing_issues: report.n_missing_issues,
+                n_constant: report.n_constant,
+                n_short: report.n_short,
+                n_sparse: report.n_sparse,
+                n_total: report.n_total,
+                ..Default::default()
+            };
+
+            let recommendations: Vec<&str> =
+                report.recommendations.iter().map(|r| r.as_str()).collect();
+            if !alloc_string_array(&recommendations, &mut (*out_result).recommendations) {
+                set_error(
+                    out_error,
+                    ErrorCode::AllocationError,
+                    "Failed to allocate recommendations",
+                );
+                return false;
+            }
+            (*out_result).n_recommendations = recommendations.len();
+            true
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+// ============================================================================
+// Imputation Functions
+// ============================================================================
+
+/// Fill NULL values with a constant.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_fill_nulls_const(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    fill_value: c_double,
+    out_values: *mut *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_values.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let series = build_series(values, validity, length);
+    let filled = anofox_fcst_core::fill_nulls_const(&series, fill_value);
+
+    match alloc_or_error(&filled, out_error, "Failed to allocate filled values") {
+        Ok(ptr) => {
+            *out_values = ptr;
+            true
+        }
+        Err(()) => false,
+    }
+}
+
+/// Fill NULL values with a constant and report which positions were imputed.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_fill_nulls_const_masked(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    fill_value: c_double,
+    out_result: *mut FilledMaskResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series = build_series(values, validity, length);
+        let filled = anofox_fcst_core::fill_nulls_const(&series, fill_value);
+        (series, filled)
+    }));
+
+    let (series, filled) = match result {
+        Ok(r) => r,
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            return false;
+        }
+    };
+
+    *out_result = FilledMaskResult::default();
+    (*out_result).length = filled.len();
+
+    if !filled.is_empty() {
+        let values_ptr = alloc_double_array(filled.len());
+        let mask_ptr = malloc(filled.len() * std::mem::size_of::<bool>()) as *mut bool;
+        if values_ptr.is_null() || mask_ptr.is_null() {
+            free_ptr(values_ptr as *mut core::ffi::c_void);
+            free_ptr(mask_ptr as *mut core::ffi::c_void);
+            (*out_result).length = 0;
+            set_error(
+                out_error,
+                ErrorCode::AllocationError,
+                "Failed to allocate filled values",
+            );
+            return false;
+        }
+
+        for (i, (&v, original)) in filled.iter().zip(series.iter()).enumerate() {
+            *values_ptr.add(i) = v;
+            *mask_ptr.add(i) = original.is_none();
+        }
+        (*out_result).values = values_ptr;
+        (*out_result).filled = mask_ptr;
+    }
+
+    true
+}
+
+/// Fill NULL values with the series mean.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_fill_nulls_mean(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    out_values: *mut *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_values.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let series = build_series(values, validity, length);
+    let filled = anofox_fcst_core::fill_nulls_mean(&series);
+
+    match alloc_or_error(&filled, out_error, "Failed to allocate filled values") {
+        Ok(ptr) => {
+            *out_values = ptr;
+            true
+        }
+        Err(()) => false,
+    }
+}
+
+// ============================================================================
+// Correlation Functions
+// ============================================================================
+
+/// Shared body of `anofox_ts_acf` and `anofox_ts_pacf`.
+unsafe fn correlation_to_c_array(
+    values: *const c_double,
+    length: size_t,
+    max_lag: size_t,
+    out_values: *mut *mut c_double,
+    out_length: *mut size_t,
+    out_error: *mut AnofoxError,
+    compute: fn(&[f64], usize) -> anofox_fcst_core::Result<Vec<f64>>,
+) -> bool {
+    init_error(out_error);
+
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_values as *const core::ffi::c_void,
+        out_length as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_slice = std::slice::from_raw_parts(values, length);
+        compute(values_slice, max_lag)
+    }));
+
+    match result {
+        Ok(Ok(coefficients)) => {
+            *out_length = coefficients.len();
+            match alloc_or_error(
+                &coefficients,
+                out_error,
+                "Failed to allocate correlation result",
+            ) {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
+                }
+                Err(()) => false,
+            }
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Compute the sample autocorrelation function for lags `0..=max_lag`.
+///
+/// Writes `max_lag + 1` values; free them with `anofox_free_double_array`.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `values` must have `length` elements.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_acf(
+    values: *const c_double,
+    length: size_t,
+    max_lag: size_t,
+    out_values: *mut *mut c_double,
+    out_length: *mut size_t,
+    out_error: *mut AnofoxError,
+) -> bool {
+    correlation_to_c_array(
+        values,
+        length,
+        max_lag,
+        out_values,
+        out_length,
+        out_error,
+        anofox_fcst_core::acf,
+    )
+}
+
+/// Compute the sample partial autocorrelation function for lags `0..=max_lag`.
+///
+/// Writes `max_lag + 1` values; free them with `anofox_free_double_array`.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `values` must have `length` elements.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_pacf(
+    values: *const c_double,
+    length: size_t,
+    max_lag: size_t,
+    out_values: *mut *mut c_double,
+    out_length: *mut size_t,
+    out_error: *mut AnofoxError,
+) -> bool {
+    correlation_to_c_array(
+        values,
+        length,
+        max_lag,
+        out_values,
+        out_length,
+        out_error,
+        anofox_fcst_core::pacf,
+    )
+}
+
+// ============================================================================
+// Stationarity Tests
+// ============================================================================
+
+/// Shared body of `anofox_ts_adf_test` and `anofox_ts_kpss_test`.
+unsafe fn stationarity_test(
+    values: *const c_double,
+    length: size_t,
+    n_lags: c_int,
+    out_result: *mut StationarityTestResult,
+    out_error: *mut AnofoxError,
+    test: fn(
+        &[f64],
+        Option<usize>,
+    ) -> anofox_fcst_core::Result<anofox_fcst_core::StationarityResult>,
+) -> bool {
+    init_error(out_error);
+
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
+
+    // Zero lags is a valid request, so only negative values mean automatic
+    let lags = if n_lags < 0 {
+        None
+    } else {
+        Some(n_lags as usize)
+    };
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_slice = std::slice::from_raw_parts(values, length);
+        test(values_slice, lags)
+    }));
+
+    match result {
+        Ok(Ok(test_result)) => {
+            *out_result = test_result.into();
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Augmented Dickey-Fuller unit-root test (constant, no trend).
+///
+/// `max_lag` is the number of augmentation lags; pass a negative value to
+/// select it by AIC. The series is stationary when the unit root is rejected.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `values` must have `length` elements.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_adf_test(
+    values: *const c_double,
+    length: size_t,
+    max_lag: c_int,
+    out_result: *mut StationarityTestResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    stationarity_test(
+        values,
+        length,
+        max_lag,
+        out_result,
+        out_error,
+        anofox_fcst_core::adf_test,
+    )
+}
+
+/// KPSS level-stationarity test.
+///
+/// `n_lags` is the Bartlett bandwidth; pass a negative value for the default.
+/// The series is stationary when level stationarity is not rejected.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `values` must have `length` elements.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_kpss_test(
+    values: *const c_double,
+    length: size_t,
+    n_lags: c_int,
+    out_result: *mut StationarityTestResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    stationarity_test(
+        values,
+        length,
+        n_lags,
+        out_result,
+        out_error,
+        anofox_fcst_core::kpss_test,
+    )
+}
+
+// ============================================================================
+// Anomaly Detection
+// ============================================================================
+
+/// Flag anomalies from the remainder of a robust MSTL decomposition.
+///
+/// Points whose robust z-score (median/MAD of the remainder) exceeds
+/// `threshold` in absolute value are flagged. `period` below 2 removes the
+/// trend only.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `values` must have `length` elements.
+/// Free the result with `anofox_free_anomaly_result`.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_detect_anomalies(
+    values: *const c_double,
+    length: size_t,
+    period: size_t,
+    threshold: c_double,
+    out_result: *mut AnomalyDetectionResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_slice = std::slice::from_raw_parts(values, length);
+        anofox_fcst_core::detect_anomalies(values_slice, period, threshold)
+    }));
+
+    match result {
+        Ok(Ok(anomalies)) => {
+            *out_result = AnomalyDetectionResult {
+                length: anomalies.scores.len(),
+                scores: vec_to_c_array(&anomalies.scores),
+                is_anomaly: allocation::slice_to_c_array(&anomalies.is_anomaly),
+                n_anomalies: anomalies.indices.len(),
+                indices: allocation::slice_to_c_array(&anomalies.indices),
+            };
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+// ============================================================================
+// Intermittent Demand Functions
+// ============================================================================
+
+/// Compute the Croston demand-size and interval components.
+///
+/// Pass NaN as `alpha` for the default smoothing parameter (0.1).
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `values` must have `length` elements.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_croston_components(
+    values: *const c_double,
+    length: size_t,
+    alpha: c_double,
+    out_result: *mut CrostonComponentsResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
+
+    let alpha = if alpha.is_nan() { None } else { Some(alpha) };
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_slice = std::slice::from_raw_parts(values, length);
+        anofox_fcst_core::croston_components(values_slice, alpha)
+    }));
+
+    match result {
+        Ok(Ok(components)) => {
+            *out_result = components.into();
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Classify a demand history into the Syntetos-Boylan quadrants.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. `values` must have `length` elements.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_classify_demand(
+    values: *const c_double,
+    length: size_t,
+    out_result: *mut DemandClassificationFFI,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
 
-            // Copy model name
-            copy_string_to_buffer(&forecast.model_name, &mut (*out_result).model_name);
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
 
-            (*out_result).aic = forecast.aic.unwrap_or(f64::NAN);
-            (*out_result).bic = forecast.bic.unwrap_or(f64::NAN);
-            (*out_result).mse = forecast.mse.unwrap_or(f64::NAN);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_slice = std::slice::from_raw_parts(values, length);
+        anofox_fcst_core::classify_demand(values_slice)
+    }));
 
+    match result {
+        Ok(Ok(classification)) => {
+            *out_result = DemandClassificationFFI {
+                adi: classification.adi,
+                cv2: classification.cv2,
+                ..Default::default()
+            };
+            copy_string_to_buffer(
+                classification.class.as_str(),
+                &mut (*out_result).classification,
+            );
             true
         }
         Ok(Err(e)) => {
-            if !out_error.is_null() {
-                let error_code = match e.to_code() {
-                    1 => ErrorCode::NullPointer,
-                    2 => ErrorCode::InvalidInput,
-                    3 => ErrorCode::ComputationError,
-                    4 => ErrorCode::AllocationError,
-                    5 => ErrorCode::InvalidModel,
-                    6 => ErrorCode::InsufficientData,
-                    7 => ErrorCode::InvalidDateFormat,
-                    8 => ErrorCode::InvalidFrequency,
-                    9 => ErrorCode::InvalidInput, // InvalidParameter → InvalidInput at FFI boundary
-                    _ => ErrorCode::InternalError,
-                };
-                (*out_error).set_error(error_code, &e.to_string());
-            }
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
             false
         }
         Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
             false
         }
     }
 }
 
 // ============================================================================
-// Forecast Explainability (Inspectable + Explainable)
+// Filter Functions
 // ============================================================================
 
-/// Allocate a NUL-terminated C string on the FFI heap and hand ownership to the
-/// caller. Returns null if allocation fails.
-///
-/// # Safety
-/// The caller must eventually free the returned pointer via `anofox_free_string`.
-unsafe fn alloc_c_string(s: &str) -> *mut c_char {
-    let bytes = s.as_bytes();
-    let len = bytes.len();
-    let buf = malloc(len + 1) as *mut c_char;
-    if buf.is_null() {
-        return ptr::null_mut();
-    }
-    ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, buf, len);
-    *buf.add(len) = 0;
-    buf
-}
-
-/// Free a C string previously allocated by an inspect / explain call.
-///
-/// # Safety
-/// `ptr` must be either null or a pointer previously returned by one of
-/// `anofox_ts_forecast_inspect` / `anofox_ts_forecast_explain` via their
-/// `out_json` out-argument.
-#[no_mangle]
-pub unsafe extern "C" fn anofox_free_string(ptr: *mut c_char) {
-    if !ptr.is_null() {
-        free(ptr as *mut _);
-    }
-}
-
-/// Fit the requested model and serialise its `Inspectable::explanation()`
-/// snapshot as JSON.
-///
-/// The JSON structure is the serde-serialised
-/// `anofox_forecast::models::inspect::Explanation` enum: an object with a
-/// single key equal to the model family (e.g. `AutoETS`, `Arima`,
-/// `Laplace`) whose value is the per-family payload struct.
-///
-/// # Arguments
-/// * `values`, `validity`, `length` — series (validity may be null for
-///   fully-valid data)
-/// * `options` — the same `ForecastOptions` used by `anofox_ts_forecast`;
-///   the `horizon` field is ignored (inspect is a fit-state snapshot)
-/// * `out_json` — receives ownership of a newly-allocated NUL-terminated
-///   JSON string; must be freed with `anofox_free_string`
-/// * `out_error` — optional error slot
-///
-/// # Supported models
-/// AutoETS, AutoARIMA, AutoTheta, AutoTBATS, MFLES, AutoMFLES, MSTL,
-/// AutoMSTL, Laplace. Any other model returns an `InvalidModel` error.
+/// Compute differences of a time series.
 ///
 /// # Safety
-/// Pointer arguments must be valid; `out_json` and `options` must be non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_forecast_inspect(
+pub unsafe extern "C" fn anofox_ts_diff(
     values: *const c_double,
-    validity: *const u64,
     length: size_t,
-    options: *const ForecastOptions,
-    out_json: *mut *mut c_char,
+    order: c_int,
+    out_values: *mut *mut c_double,
+    out_length: *mut size_t,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || options.is_null() || out_json.is_null() {
+    if values.is_null() || out_values.is_null() || out_length.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
-    *out_json = ptr::null_mut();
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let series = build_series(values, validity, length);
-        let core_opts = build_core_options(&*options)?;
-        anofox_fcst_core::forecast_inspect(&series, &core_opts)
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        anofox_fcst_core::diff(&values_vec, order.max(0) as usize)
     }));
 
     match result {
-        Ok(Ok(json)) => {
-            let ptr = alloc_c_string(&json);
-            if ptr.is_null() {
-                if !out_error.is_null() {
-                    (*out_error).set_error(
-                        ErrorCode::AllocationError,
-                        "Failed to allocate JSON output buffer",
-                    );
+        Ok(Ok(diffed)) => {
+            *out_length = diffed.len();
+            match alloc_or_error(&diffed, out_error, "Failed to allocate diff result") {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
                 }
-                return false;
+                Err(()) => false,
             }
-            *out_json = ptr;
-            true
         }
         Ok(Err(e)) => {
             if !out_error.is_null() {
-                let code = match e.to_code() {
-                    1 => ErrorCode::NullPointer,
-                    2 => ErrorCode::InvalidInput,
-                    3 => ErrorCode::ComputationError,
-                    4 => ErrorCode::AllocationError,
-                    5 => ErrorCode::InvalidModel,
-                    6 => ErrorCode::InsufficientData,
-                    7 => ErrorCode::InvalidDateFormat,
-                    8 => ErrorCode::InvalidFrequency,
-                    9 => ErrorCode::InvalidInput,
-                    _ => ErrorCode::InternalError,
-                };
-                (*out_error).set_error(code, &e.to_string());
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
             }
             false
         }
@@ -3955,87 +6827,63 @@ pub unsafe extern "C" fn anofox_ts_forecast_inspect(
     }
 }
 
-/// Fit the requested model and serialise its per-horizon
-/// `Explainable::explain(horizon)` decomposition as JSON.
-///
-/// The JSON has keys `level`, `trend`, `seasonal`, `residual` (each a
-/// Vec\<f64\> of length `horizon`, absent when the family has no such
-/// component) and `named_components` (a map of arbitrary named additive
-/// components).
-///
-/// # Arguments
-/// * `values`, `validity`, `length` — historical series
-/// * `horizon` — number of periods ahead to explain (must equal the
-///   horizon you'll subsequently forecast with; internally we call
-///   `.explain(horizon)`)
-/// * `options` — `ForecastOptions`; `horizon` field is ignored (uses the
-///   explicit `horizon` argument instead)
-/// * `out_json`, `out_error` — see `anofox_ts_forecast_inspect`
+/// Invert differencing by cumulatively summing back up `order` times.
 ///
-/// # Supported models
-/// ETS (fixed spec), MSTL / AutoMSTL, Theta. Any other model returns an
-/// `InvalidModel` error.
+/// `initial` holds `order` seed values: the first value of the series
+/// differenced 0, 1, ..., `order - 1` times.
 ///
 /// # Safety
-/// Pointer arguments must be valid; `out_json` and `options` must be non-null.
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_forecast_explain(
-    values: *const c_double,
-    validity: *const u64,
-    length: size_t,
-    horizon: size_t,
-    options: *const ForecastOptions,
-    out_json: *mut *mut c_char,
+pub unsafe extern "C" fn anofox_ts_undiff(
+    diffed: *const c_double,
+    diffed_length: size_t,
+    initial: *const c_double,
+    initial_length: size_t,
+    order: c_int,
+    out_values: *mut *mut c_double,
+    out_length: *mut size_t,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || options.is_null() || out_json.is_null() {
+    if diffed.is_null()
+        || (initial.is_null() && initial_length > 0)
+        || out_values.is_null()
+        || out_length.is_null()
+    {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
-    *out_json = ptr::null_mut();
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let series = build_series(values, validity, length);
-        let core_opts = build_core_options(&*options)?;
-        anofox_fcst_core::forecast_explain(&series, horizon, &core_opts)
+        let diffed_vec = std::slice::from_raw_parts(diffed, diffed_length).to_vec();
+        let initial_vec = if initial_length > 0 {
+            std::slice::from_raw_parts(initial, initial_length).to_vec()
+        } else {
+            Vec::new()
+        };
+        anofox_fcst_core::undiff(&diffed_vec, &initial_vec, order.max(0) as usize)
     }));
 
     match result {
-        Ok(Ok(json)) => {
-            let ptr = alloc_c_string(&json);
-            if ptr.is_null() {
-                if !out_error.is_null() {
-                    (*out_error).set_error(
-                        ErrorCode::AllocationError,
-                        "Failed to allocate JSON output buffer",
-                    );
+        Ok(Ok(restored)) => {
+            *out_length = restored.len();
+            match alloc_or_error(&restored, out_error, "Failed to allocate undiff result") {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
                 }
-                return false;
+                Err(()) => false,
             }
-            *out_json = ptr;
-            true
         }
         Ok(Err(e)) => {
             if !out_error.is_null() {
-                let code = match e.to_code() {
-                    1 => ErrorCode::NullPointer,
-                    2 => ErrorCode::InvalidInput,
-                    3 => ErrorCode::ComputationError,
-                    4 => ErrorCode::AllocationError,
-                    5 => ErrorCode::InvalidModel,
-                    6 => ErrorCode::InsufficientData,
-                    7 => ErrorCode::InvalidDateFormat,
-                    8 => ErrorCode::InvalidFrequency,
-                    9 => ErrorCode::InvalidInput,
-                    _ => ErrorCode::InternalError,
-                };
-                (*out_error).set_error(code, &e.to_string());
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
             }
             false
         }
@@ -4048,83 +6896,119 @@ pub unsafe extern "C" fn anofox_ts_forecast_explain(
     }
 }
 
-/// Shared FFI → core `ForecastOptions` conversion used by inspect + explain.
+/// Compute cheap pre-flight flags for a series.
 ///
-/// Extracted so both entry points parse the buffered string fields
-/// (`model`, `ets_model`, `seasonal_periods_str`, `model_pool`,
-/// `laplace_variant`) identically to `anofox_ts_forecast`.
-unsafe fn build_core_options(
-    opts: &ForecastOptions,
-) -> Result<anofox_fcst_core::ForecastOptions, anofox_fcst_core::ForecastError> {
-    let model_str = CStr::from_ptr(opts.model.as_ptr())
-        .to_str()
-        .unwrap_or("auto");
-    let model_type: anofox_fcst_core::ModelType = model_str.parse().map_err(|_| {
-        anofox_fcst_core::ForecastError::InvalidModel(format!("Unknown model: '{}'", model_str))
-    })?;
+/// Lets callers skip degenerate series (all NULL, constant, or shorter than
+/// `min_length` non-NULL values) before running a model fit.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null (`validity` may be null).
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_series_flags(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    min_length: size_t,
+    out_result: *mut SeriesFlags,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if (values.is_null() && length > 0) || out_result.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let series = if length > 0 {
+        build_series(values, validity, length)
+    } else {
+        vec![]
+    };
+    let n_valid = series.iter().filter(|v| v.is_some()).count();
+
+    *out_result = SeriesFlags {
+        is_constant: anofox_fcst_core::is_constant(&series),
+        is_short: anofox_fcst_core::is_short(&series, min_length),
+        all_null: n_valid == 0,
+        n_valid,
+    };
+    true
+}
+
+/// Drop leading and trailing zeros from a series.
+///
+/// NULLs are kept, so a series that starts with NULLs is not trimmed. The
+/// number of removed positions on each side is reported so callers can
+/// realign timestamps.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null (`validity` may be null).
+/// Free the result with `anofox_free_trimmed_series_result`.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_drop_edge_zeros(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    out_result: *mut TrimmedSeriesResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_result as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
 
-    let ets_spec = CStr::from_ptr(opts.ets_model.as_ptr())
-        .to_str()
-        .ok()
-        .filter(|s| !s.is_empty())
-        .map(String::from);
+    let series = build_series(values, validity, length);
+    let without_leading = anofox_fcst_core::drop_leading_zeros(&series);
+    let trimmed = anofox_fcst_core::drop_trailing_zeros(&without_leading);
 
-    let sp_str = CStr::from_ptr(opts.seasonal_periods_str.as_ptr())
-        .to_str()
-        .unwrap_or("");
-    let seasonal_periods = parse_seasonal_periods_str(sp_str);
+    *out_result = TrimmedSeriesResult {
+        length: trimmed.len(),
+        n_leading_removed: series.len() - without_leading.len(),
+        n_trailing_removed: without_leading.len() - trimmed.len(),
+        ..Default::default()
+    };
 
-    let model_pool = CStr::from_ptr(opts.model_pool.as_ptr())
-        .to_str()
-        .ok()
-        .filter(|s| !s.is_empty())
-        .map(String::from);
+    if !trimmed.is_empty() {
+        (*out_result).values = alloc_double_array(trimmed.len());
+        (*out_result).validity = alloc_validity(trimmed.len());
 
-    let laplace_variant = CStr::from_ptr(opts.laplace_variant.as_ptr())
-        .to_str()
-        .ok()
-        .filter(|s| !s.is_empty())
-        .map(anofox_fcst_core::LaplaceVariant::parse)
-        .transpose()?;
+        for (i, v) in trimmed.iter().enumerate() {
+            *(*out_result).values.add(i) = v.unwrap_or(f64::NAN);
+            set_validity_bit((*out_result).validity, i, v.is_some());
+        }
+    }
 
-    Ok(anofox_fcst_core::ForecastOptions {
-        model: model_type,
-        ets_spec,
-        horizon: opts.horizon as usize,
-        confidence_level: opts.confidence_level,
-        seasonal_period: opts.seasonal_period as usize,
-        auto_detect_seasonality: opts.auto_detect_seasonality,
-        include_fitted: opts.include_fitted,
-        include_residuals: opts.include_residuals,
-        window: opts.window.max(0) as usize,
-        seasonal_periods,
-        model_pool,
-        laplace_variant,
-        laplace_seasonal_batch_init: opts.laplace_seasonal_batch_init,
-    })
+    true
 }
 
-// ============================================================================
-// Data Quality Functions
-// ============================================================================
-
-/// Compute data quality metrics.
+/// Box-Cox transform a series.
+///
+/// Pass NaN as `lambda` to estimate it; the lambda used is written to
+/// `out_lambda` so forecasts can be back-transformed with
+/// `anofox_ts_inv_box_cox`.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_data_quality(
+pub unsafe extern "C" fn anofox_ts_box_cox(
     values: *const c_double,
-    validity: *const u64,
     length: size_t,
-    out_result: *mut DataQualityResult,
+    lambda: c_double,
+    out_values: *mut *mut c_double,
+    out_length: *mut size_t,
+    out_lambda: *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_result.is_null() {
+    if values.is_null() || out_values.is_null() || out_length.is_null() || out_lambda.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
@@ -4132,21 +7016,22 @@ pub unsafe extern "C" fn anofox_ts_data_quality(
     }
 
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let series = build_series(values, validity, length);
-        anofox_fcst_core::compute_data_quality(&series, None)
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        let lambda = if lambda.is_nan() { None } else { Some(lambda) };
+        anofox_fcst_core::box_cox(&values_vec, lambda)
     }));
 
     match result {
-        Ok(Ok(quality)) => {
-            (*out_result).structural_score = quality.structural_score;
-            (*out_result).temporal_score = quality.temporal_score;
-            (*out_result).magnitude_score = quality.magnitude_score;
-            (*out_result).behavioral_score = quality.behavioral_score;
-            (*out_result).overall_score = quality.overall_score;
-            (*out_result).n_gaps = quality.n_gaps;
-            (*out_result).n_missing = quality.n_missing;
-            (*out_result).is_constant = quality.is_constant;
-            true
+        Ok(Ok((transformed, used_lambda))) => {
+            *out_length = transformed.len();
+            *out_lambda = used_lambda;
+            match alloc_or_error(&transformed, out_error, "Failed to allocate Box-Cox result") {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
+                }
+                Err(()) => false,
+            }
         }
         Ok(Err(e)) => {
             if !out_error.is_null() {
@@ -4163,124 +7048,196 @@ pub unsafe extern "C" fn anofox_ts_data_quality(
     }
 }
 
-// ============================================================================
-// Imputation Functions
-// ============================================================================
-
-/// Fill NULL values with a constant.
+/// Invert a Box-Cox transform (e.g. on point forecasts or interval bounds).
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_fill_nulls_const(
+pub unsafe extern "C" fn anofox_ts_inv_box_cox(
     values: *const c_double,
-    validity: *const u64,
     length: size_t,
-    fill_value: c_double,
+    lambda: c_double,
     out_values: *mut *mut c_double,
+    out_length: *mut size_t,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_values.is_null() {
+    if values.is_null() || out_values.is_null() || out_length.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
-    let series = build_series(values, validity, length);
-    let filled = anofox_fcst_core::fill_nulls_const(&series, fill_value);
-
-    match alloc_or_error(&filled, out_error, "Failed to allocate filled values") {
-        Ok(ptr) => {
-            *out_values = ptr;
-            true
-        }
-        Err(()) => false,
-    }
-}
-
-/// Fill NULL values with the series mean.
-///
-/// # Safety
-/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
-#[no_mangle]
-pub unsafe extern "C" fn anofox_ts_fill_nulls_mean(
-    values: *const c_double,
-    validity: *const u64,
-    length: size_t,
-    out_values: *mut *mut c_double,
-    out_error: *mut AnofoxError,
-) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
-
-    if values.is_null() || out_values.is_null() {
+    if !lambda.is_finite() {
         if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+            (*out_error).set_error(ErrorCode::InvalidInput, "Lambda must be finite");
         }
         return false;
     }
 
-    let series = build_series(values, validity, length);
-    let filled = anofox_fcst_core::fill_nulls_mean(&series);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
+        anofox_fcst_core::inv_box_cox(&values_vec, lambda)
+    }));
 
-    match alloc_or_error(&filled, out_error, "Failed to allocate filled values") {
-        Ok(ptr) => {
-            *out_values = ptr;
-            true
+    match result {
+        Ok(restored) => {
+            *out_length = restored.len();
+            match alloc_or_error(
+                &restored,
+                out_error,
+                "Failed to allocate inverse Box-Cox result",
+            ) {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
+                }
+                Err(()) => false,
+            }
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
         }
-        Err(()) => false,
     }
 }
 
 // ============================================================================
-// Filter Functions
+// Gap Filling Functions
 // ============================================================================
 
-/// Compute differences of a time series.
+/// Allocate a validity bitmask for n elements.
+unsafe fn alloc_validity(n: size_t) -> *mut u64 {
+    let n_words = n.div_ceil(64);
+    if n_words == 0 {
+        return ptr::null_mut();
+    }
+    let ptr = malloc(n_words * std::mem::size_of::<u64>()) as *mut u64;
+    if !ptr.is_null() {
+        // Initialize all bits to 1 (all valid)
+        for i in 0..n_words {
+            *ptr.add(i) = !0u64;
+        }
+    }
+    ptr
+}
+
+/// Set validity bit for index.
+unsafe fn set_validity_bit(validity: *mut u64, index: usize, is_valid: bool) {
+    let word_idx = index / 64;
+    let bit_idx = index % 64;
+    if is_valid {
+        *validity.add(word_idx) |= 1u64 << bit_idx;
+    } else {
+        *validity.add(word_idx) &= !(1u64 << bit_idx);
+    }
+}
+
+/// Allocate an i64 array using libc malloc.
+unsafe fn alloc_i64_array(n: size_t) -> *mut i64 {
+    if n == 0 {
+        return ptr::null_mut();
+    }
+    malloc(n * std::mem::size_of::<i64>()) as *mut i64
+}
+
+/// Fill gaps in a time series with new timestamps.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+///
+/// # Arguments
+/// * `dates` - Array of timestamps in microseconds
+/// * `values` - Array of values
+/// * `validity` - Validity bitmask (NULL means all valid)
+/// * `length` - Number of elements
+/// * `frequency_micros` - Frequency in microseconds (used for Fixed type)
+/// * `frequency_type` - Type of frequency (0=Fixed, 1=Monthly, 2=Quarterly, 3=Yearly)
+/// * `out_result` - Output result structure
+/// * `out_error` - Output error structure
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_diff(
+pub unsafe extern "C" fn anofox_ts_fill_gaps(
+    dates: *const i64,
     values: *const c_double,
+    validity: *const u64,
     length: size_t,
-    order: c_int,
-    out_values: *mut *mut c_double,
-    out_length: *mut size_t,
+    frequency_micros: i64,
+    frequency_type: FrequencyType,
+    out_result: *mut GapFillResult,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_values.is_null() || out_length.is_null() {
+    if dates.is_null() || values.is_null() || out_result.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
+    // For Fixed frequency, require positive frequency_micros
+    // For calendar frequencies, the value is ignored
+    if frequency_type == FrequencyType::Fixed && frequency_micros <= 0 {
+        if !out_error.is_null() {
+            (*out_error).set_error(
+                ErrorCode::InvalidFrequency,
+                "Frequency must be positive for fixed intervals",
+            );
+        }
+        return false;
+    }
+
+    let freq_type_core: anofox_fcst_core::FrequencyType = frequency_type.into();
+
     let result = catch_unwind(AssertUnwindSafe(|| {
-        let values_vec = std::slice::from_raw_parts(values, length).to_vec();
-        anofox_fcst_core::diff(&values_vec, order.max(0) as usize)
+        let dates_vec: Vec<i64> = std::slice::from_raw_parts(dates, length).to_vec();
+        let series = build_series(values, validity, length);
+        anofox_fcst_core::fill_gaps(&dates_vec, &series, frequency_micros, freq_type_core)
     }));
 
     match result {
-        Ok(Ok(diffed)) => {
-            *out_length = diffed.len();
-            match alloc_or_error(&diffed, out_error, "Failed to allocate diff result") {
-                Ok(ptr) => {
-                    *out_values = ptr;
-                    true
+        Ok(Ok((filled_dates, filled_values))) => {
+            let n = filled_dates.len();
+            (*out_result).length = n;
+
+            if n > 0 {
+                // Allocate and copy dates
+                (*out_result).dates = alloc_i64_array(n);
+                for (i, &d) in filled_dates.iter().enumerate() {
+                    *(*out_result).dates.add(i) = d;
                 }
-                Err(()) => false,
+
+                // Allocate values and validity
+                (*out_result).values = alloc_double_array(n);
+                (*out_result).validity = alloc_validity(n);
+
+                for (i, v) in filled_values.iter().enumerate() {
+                    match v {
+                        Some(val) => {
+                            *(*out_result).values.add(i) = *val;
+                            set_validity_bit((*out_result).validity, i, true);
+                        }
+                        None => {
+                            *(*out_result).values.add(i) = f64::NAN;
+                            set_validity_bit((*out_result).validity, i, false);
+                        }
+                    }
+                }
+            } else {
+                (*out_result).dates = ptr::null_mut();
+                (*out_result).values = ptr::null_mut();
+                (*out_result).validity = ptr::null_mut();
             }
+
+            true
         }
         Ok(Err(e)) => {
             if !out_error.is_null() {
@@ -4297,46 +7254,7 @@ pub unsafe extern "C" fn anofox_ts_diff(
     }
 }
 
-// ============================================================================
-// Gap Filling Functions
-// ============================================================================
-
-/// Allocate a validity bitmask for n elements.
-unsafe fn alloc_validity(n: size_t) -> *mut u64 {
-    let n_words = n.div_ceil(64);
-    if n_words == 0 {
-        return ptr::null_mut();
-    }
-    let ptr = malloc(n_words * std::mem::size_of::<u64>()) as *mut u64;
-    if !ptr.is_null() {
-        // Initialize all bits to 1 (all valid)
-        for i in 0..n_words {
-            *ptr.add(i) = !0u64;
-        }
-    }
-    ptr
-}
-
-/// Set validity bit for index.
-unsafe fn set_validity_bit(validity: *mut u64, index: usize, is_valid: bool) {
-    let word_idx = index / 64;
-    let bit_idx = index % 64;
-    if is_valid {
-        *validity.add(word_idx) |= 1u64 << bit_idx;
-    } else {
-        *validity.add(word_idx) &= !(1u64 << bit_idx);
-    }
-}
-
-/// Allocate an i64 array using libc malloc.
-unsafe fn alloc_i64_array(n: size_t) -> *mut i64 {
-    if n == 0 {
-        return ptr::null_mut();
-    }
-    malloc(n * std::mem::size_of::<i64>()) as *mut i64
-}
-
-/// Fill gaps in a time series with new timestamps.
+/// Fill forward to a target date.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
@@ -4346,16 +7264,18 @@ unsafe fn alloc_i64_array(n: size_t) -> *mut i64 {
 /// * `values` - Array of values
 /// * `validity` - Validity bitmask (NULL means all valid)
 /// * `length` - Number of elements
+/// * `target_date` - Target date to extend to (microseconds since epoch)
 /// * `frequency_micros` - Frequency in microseconds (used for Fixed type)
 /// * `frequency_type` - Type of frequency (0=Fixed, 1=Monthly, 2=Quarterly, 3=Yearly)
 /// * `out_result` - Output result structure
 /// * `out_error` - Output error structure
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_fill_gaps(
+pub unsafe extern "C" fn anofox_ts_fill_forward_dates(
     dates: *const i64,
     values: *const c_double,
     validity: *const u64,
     length: size_t,
+    target_date: i64,
     frequency_micros: i64,
     frequency_type: FrequencyType,
     out_result: *mut GapFillResult,
@@ -4389,7 +7309,13 @@ pub unsafe extern "C" fn anofox_ts_fill_gaps(
     let result = catch_unwind(AssertUnwindSafe(|| {
         let dates_vec: Vec<i64> = std::slice::from_raw_parts(dates, length).to_vec();
         let series = build_series(values, validity, length);
-        anofox_fcst_core::fill_gaps(&dates_vec, &series, frequency_micros, freq_type_core)
+        anofox_fcst_core::fill_forward(
+            &dates_vec,
+            &series,
+            target_date,
+            frequency_micros,
+            freq_type_core,
+        )
     }));
 
     match result {
@@ -4438,190 +7364,347 @@ pub unsafe extern "C" fn anofox_ts_fill_gaps(
             if !out_error.is_null() {
                 (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
             }
-            false
+            false
+        }
+    }
+}
+
+/// Detect the frequency of a time series.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_detect_frequency(
+    dates: *const i64,
+    length: size_t,
+    out_frequency: *mut i64,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if dates.is_null() || out_frequency.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let dates_vec: Vec<i64> = std::slice::from_raw_parts(dates, length).to_vec();
+        anofox_fcst_core::detect_frequency(&dates_vec)
+    }));
+
+    match result {
+        Ok(Ok(freq)) => {
+            *out_frequency = freq;
+            true
+        }
+        Ok(Err(e)) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
+            }
+            false
+        }
+        Err(_) => {
+            if !out_error.is_null() {
+                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
+            }
+            false
+        }
+    }
+}
+
+/// Detect the frequency of a time series together with a label
+/// ("hourly", "daily", "weekly", "monthly", ...).
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_detect_frequency_label(
+    dates: *const i64,
+    length: size_t,
+    out_result: *mut FrequencyLabelResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if dates.is_null() || out_result.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let dates_vec: Vec<i64> = std::slice::from_raw_parts(dates, length).to_vec();
+        anofox_fcst_core::detect_frequency_label(&dates_vec)
+    }));
+
+    match result {
+        Ok(Ok((freq, label))) => {
+            let out = &mut *out_result;
+            out.frequency = freq;
+            copy_string_to_buffer(&label, &mut out.label);
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Detect the frequency of a time series and report how regular its spacing is.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_detect_frequency_report(
+    dates: *const i64,
+    length: size_t,
+    out_result: *mut FrequencyReportResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if dates.is_null() || out_result.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let dates_vec: Vec<i64> = std::slice::from_raw_parts(dates, length).to_vec();
+        anofox_fcst_core::detect_frequency_report(&dates_vec)
+    }));
+
+    match result {
+        Ok(Ok(report)) => {
+            *out_result = FrequencyReportResult {
+                modal_interval: report.modal_interval,
+                match_fraction: report.match_fraction,
+                min_interval: report.min_interval,
+                max_interval: report.max_interval,
+                is_regular: report.is_regular,
+            };
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+/// Fill NULL values with forward fill.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_fill_nulls_forward(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    out_result: *mut FilledValuesResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let series = build_series(values, validity, length);
+    let filled = anofox_fcst_core::fill_nulls_forward(&series);
+
+    (*out_result).length = filled.len();
+
+    if !filled.is_empty() {
+        (*out_result).values = alloc_double_array(filled.len());
+        (*out_result).validity = alloc_validity(filled.len());
+
+        for (i, v) in filled.iter().enumerate() {
+            match v {
+                Some(val) => {
+                    *(*out_result).values.add(i) = *val;
+                    set_validity_bit((*out_result).validity, i, true);
+                }
+                None => {
+                    *(*out_result).values.add(i) = f64::NAN;
+                    set_validity_bit((*out_result).validity, i, false);
+                }
+            }
+        }
+    } else {
+        (*out_result).values = ptr::null_mut();
+        (*out_result).validity = ptr::null_mut();
+    }
+
+    true
+}
+
+/// Fill NULL values with backward fill.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_fill_nulls_backward(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    out_result: *mut FilledValuesResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    if !out_error.is_null() {
+        *out_error = AnofoxError::success();
+    }
+
+    if values.is_null() || out_result.is_null() {
+        if !out_error.is_null() {
+            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
+        }
+        return false;
+    }
+
+    let series = build_series(values, validity, length);
+    let filled = anofox_fcst_core::fill_nulls_backward(&series);
+
+    (*out_result).length = filled.len();
+
+    if !filled.is_empty() {
+        (*out_result).values = alloc_double_array(filled.len());
+        (*out_result).validity = alloc_validity(filled.len());
+
+        for (i, v) in filled.iter().enumerate() {
+            match v {
+                Some(val) => {
+                    *(*out_result).values.add(i) = *val;
+                    set_validity_bit((*out_result).validity, i, true);
+                }
+                None => {
+                    *(*out_result).values.add(i) = f64::NAN;
+                    set_validity_bit((*out_result).validity, i, false);
+                }
+            }
         }
+    } else {
+        (*out_result).values = ptr::null_mut();
+        (*out_result).validity = ptr::null_mut();
     }
+
+    true
 }
 
-/// Fill forward to a target date.
+/// Fill NULL values with linear interpolation.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
-///
-/// # Arguments
-/// * `dates` - Array of timestamps in microseconds
-/// * `values` - Array of values
-/// * `validity` - Validity bitmask (NULL means all valid)
-/// * `length` - Number of elements
-/// * `target_date` - Target date to extend to (microseconds since epoch)
-/// * `frequency_micros` - Frequency in microseconds (used for Fixed type)
-/// * `frequency_type` - Type of frequency (0=Fixed, 1=Monthly, 2=Quarterly, 3=Yearly)
-/// * `out_result` - Output result structure
-/// * `out_error` - Output error structure
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_fill_forward_dates(
-    dates: *const i64,
+pub unsafe extern "C" fn anofox_ts_fill_nulls_interpolate(
     values: *const c_double,
     validity: *const u64,
     length: size_t,
-    target_date: i64,
-    frequency_micros: i64,
-    frequency_type: FrequencyType,
-    out_result: *mut GapFillResult,
+    out_values: *mut *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if dates.is_null() || values.is_null() || out_result.is_null() {
+    if values.is_null() || out_values.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
-    // For Fixed frequency, require positive frequency_micros
-    // For calendar frequencies, the value is ignored
-    if frequency_type == FrequencyType::Fixed && frequency_micros <= 0 {
-        if !out_error.is_null() {
-            (*out_error).set_error(
-                ErrorCode::InvalidFrequency,
-                "Frequency must be positive for fixed intervals",
-            );
-        }
-        return false;
-    }
-
-    let freq_type_core: anofox_fcst_core::FrequencyType = frequency_type.into();
-
-    let result = catch_unwind(AssertUnwindSafe(|| {
-        let dates_vec: Vec<i64> = std::slice::from_raw_parts(dates, length).to_vec();
-        let series = build_series(values, validity, length);
-        anofox_fcst_core::fill_forward(
-            &dates_vec,
-            &series,
-            target_date,
-            frequency_micros,
-            freq_type_core,
-        )
-    }));
-
-    match result {
-        Ok(Ok((filled_dates, filled_values))) => {
-            let n = filled_dates.len();
-            (*out_result).length = n;
-
-            if n > 0 {
-                // Allocate and copy dates
-                (*out_result).dates = alloc_i64_array(n);
-                for (i, &d) in filled_dates.iter().enumerate() {
-                    *(*out_result).dates.add(i) = d;
-                }
-
-                // Allocate values and validity
-                (*out_result).values = alloc_double_array(n);
-                (*out_result).validity = alloc_validity(n);
-
-                for (i, v) in filled_values.iter().enumerate() {
-                    match v {
-                        Some(val) => {
-                            *(*out_result).values.add(i) = *val;
-                            set_validity_bit((*out_result).validity, i, true);
-                        }
-                        None => {
-                            *(*out_result).values.add(i) = f64::NAN;
-                            set_validity_bit((*out_result).validity, i, false);
-                        }
-                    }
-                }
-            } else {
-                (*out_result).dates = ptr::null_mut();
-                (*out_result).values = ptr::null_mut();
-                (*out_result).validity = ptr::null_mut();
-            }
+    let series = build_series(values, validity, length);
+    let filled = anofox_fcst_core::fill_nulls_interpolate(&series);
 
+    match alloc_or_error(&filled, out_error, "Failed to allocate interpolated values") {
+        Ok(ptr) => {
+            *out_values = ptr;
             true
         }
-        Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
-            false
-        }
-        Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
-            false
-        }
+        Err(()) => false,
     }
 }
 
-/// Detect the frequency of a time series.
+/// Fill NULL values by interpolating the seasonally adjusted series.
+///
+/// Falls back to linear interpolation when `period < 2` or fewer than two
+/// periods of observations are available.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_detect_frequency(
-    dates: *const i64,
+pub unsafe extern "C" fn anofox_ts_fill_nulls_seasonal(
+    values: *const c_double,
+    validity: *const u64,
     length: size_t,
-    out_frequency: *mut i64,
+    period: c_int,
+    out_values: *mut *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if dates.is_null() || out_frequency.is_null() {
+    if values.is_null() || out_values.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
         return false;
     }
 
-    let result = catch_unwind(AssertUnwindSafe(|| {
-        let dates_vec: Vec<i64> = std::slice::from_raw_parts(dates, length).to_vec();
-        anofox_fcst_core::detect_frequency(&dates_vec)
-    }));
+    let series = build_series(values, validity, length);
+    let filled = anofox_fcst_core::fill_nulls_seasonal(&series, period.max(0) as usize);
 
-    match result {
-        Ok(Ok(freq)) => {
-            *out_frequency = freq;
+    match alloc_or_error(
+        &filled,
+        out_error,
+        "Failed to allocate seasonally filled values",
+    ) {
+        Ok(ptr) => {
+            *out_values = ptr;
             true
         }
-        Ok(Err(e)) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::ComputationError, &e.to_string());
-            }
-            false
-        }
-        Err(_) => {
-            if !out_error.is_null() {
-                (*out_error).set_error(ErrorCode::PanicCaught, "Panic in Rust code");
-            }
-            false
-        }
+        Err(()) => false,
     }
 }
 
-/// Fill NULL values with forward fill.
+/// Fill NULL values with natural cubic spline interpolation.
+///
+/// Leading and trailing NULLs are held flat; fewer than four observed values
+/// fall back to linear interpolation.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_fill_nulls_forward(
+pub unsafe extern "C" fn anofox_ts_fill_nulls_spline(
     values: *const c_double,
     validity: *const u64,
     length: size_t,
-    out_result: *mut FilledValuesResult,
+    out_values: *mut *mut c_double,
     out_error: *mut AnofoxError,
 ) -> bool {
     if !out_error.is_null() {
         *out_error = AnofoxError::success();
     }
 
-    if values.is_null() || out_result.is_null() {
+    if values.is_null() || out_values.is_null() {
         if !out_error.is_null() {
             (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
         }
@@ -4629,118 +7712,264 @@ pub unsafe extern "C" fn anofox_ts_fill_nulls_forward(
     }
 
     let series = build_series(values, validity, length);
-    let filled = anofox_fcst_core::fill_nulls_forward(&series);
+    let filled = anofox_fcst_core::fill_nulls_spline(&series);
 
-    (*out_result).length = filled.len();
+    match alloc_or_error(&filled, out_error, "Failed to allocate spline values") {
+        Ok(ptr) => {
+            *out_values = ptr;
+            true
+        }
+        Err(()) => false,
+    }
+}
 
-    if !filled.is_empty() {
-        (*out_result).values = alloc_double_array(filled.len());
-        (*out_result).validity = alloc_validity(filled.len());
+/// Rolling-window statistic over a trailing window.
+///
+/// NULLs are skipped; positions whose window holds fewer than `min_periods`
+/// observations are NaN.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_rolling(
+    values: *const c_double,
+    validity: *const u64,
+    length: size_t,
+    window: size_t,
+    min_periods: size_t,
+    agg: types::RollingAggFFI,
+    out_values: *mut *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
 
-        for (i, v) in filled.iter().enumerate() {
-            match v {
-                Some(val) => {
-                    *(*out_result).values.add(i) = *val;
-                    set_validity_bit((*out_result).validity, i, true);
-                }
-                None => {
-                    *(*out_result).values.add(i) = f64::NAN;
-                    set_validity_bit((*out_result).validity, i, false);
+    let ptrs = &[
+        values as *const core::ffi::c_void,
+        out_values as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
+        return false;
+    }
+
+    if window == 0 {
+        set_error(
+            out_error,
+            ErrorCode::InvalidInput,
+            "Window must be positive",
+        );
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let series: Vec<f64> = build_series(values, validity, length)
+            .into_iter()
+            .map(|v| v.unwrap_or(f64::NAN))
+            .collect();
+        anofox_fcst_core::rolling(&series, window, min_periods, agg.into())
+    }));
+
+    match result {
+        Ok(rolled) => {
+            match alloc_or_error(&rolled, out_error, "Failed to allocate rolling values") {
+                Ok(ptr) => {
+                    *out_values = ptr;
+                    true
                 }
+                Err(()) => false,
             }
         }
-    } else {
-        (*out_result).values = ptr::null_mut();
-        (*out_result).validity = ptr::null_mut();
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in Rust code");
+            false
+        }
+    }
+}
+
+// ============================================================================
+// Exogenous Regressor Functions
+// ============================================================================
+
+/// Fill a RegressorMatrixResult from regressor columns.
+unsafe fn fill_regressor_matrix(
+    out: *mut RegressorMatrixResult,
+    columns: &[Vec<f64>],
+    n_rows: usize,
+    out_error: *mut AnofoxError,
+) -> bool {
+    *out = RegressorMatrixResult::default();
+    let flat: Vec<f64> = columns.iter().flatten().copied().collect();
+    if !flat.is_empty() {
+        match alloc_or_error(&flat, out_error, "Failed to allocate regressor matrix") {
+            Ok(ptr) => (*out).values = ptr,
+            Err(()) => return false,
+        }
+    }
+    (*out).n_rows = n_rows;
+    (*out).n_columns = columns.len();
+    true
+}
+
+/// Build holiday/event indicator columns aligned to the series dates.
+///
+/// Produces `window_before + window_after + 1` columns, one per observation
+/// offset relative to each event. Events not present in `dates` are ignored.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_event_indicators(
+    dates: *const i64,
+    dates_length: size_t,
+    event_dates: *const i64,
+    event_dates_length: size_t,
+    window_before: i64,
+    window_after: i64,
+    out_result: *mut RegressorMatrixResult,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if (dates.is_null() && dates_length > 0)
+        || (event_dates.is_null() && event_dates_length > 0)
+        || out_result.is_null()
+    {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let dates_slice: &[i64] = if dates_length > 0 {
+            std::slice::from_raw_parts(dates, dates_length)
+        } else {
+            &[]
+        };
+        let events_slice: &[i64] = if event_dates_length > 0 {
+            std::slice::from_raw_parts(event_dates, event_dates_length)
+        } else {
+            &[]
+        };
+        anofox_fcst_core::event_indicators(dates_slice, events_slice, window_before, window_after)
+    }));
+
+    match result {
+        Ok(Ok(columns)) => fill_regressor_matrix(out_result, &columns, dates_length, out_error),
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::InvalidInput, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(
+                out_error,
+                ErrorCode::PanicCaught,
+                "Panic in event_indicators",
+            );
+            false
+        }
     }
-
-    true
 }
 
-/// Fill NULL values with backward fill.
+/// Build a single event dummy column aligned to the series dates.
+///
+/// Flags every date within `window_days` days of any event, whether or not the
+/// event itself is one of `dates`. Produces one column.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_fill_nulls_backward(
-    values: *const c_double,
-    validity: *const u64,
-    length: size_t,
-    out_result: *mut FilledValuesResult,
+pub unsafe extern "C" fn anofox_ts_event_dummies(
+    dates: *const i64,
+    dates_length: size_t,
+    event_dates: *const i64,
+    event_dates_length: size_t,
+    window_days: i64,
+    out_result: *mut RegressorMatrixResult,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
+    init_error(out_error);
 
-    if values.is_null() || out_result.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    if (dates.is_null() && dates_length > 0)
+        || (event_dates.is_null() && event_dates_length > 0)
+        || out_result.is_null()
+    {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
         return false;
     }
 
-    let series = build_series(values, validity, length);
-    let filled = anofox_fcst_core::fill_nulls_backward(&series);
-
-    (*out_result).length = filled.len();
-
-    if !filled.is_empty() {
-        (*out_result).values = alloc_double_array(filled.len());
-        (*out_result).validity = alloc_validity(filled.len());
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let dates_slice: &[i64] = if dates_length > 0 {
+            std::slice::from_raw_parts(dates, dates_length)
+        } else {
+            &[]
+        };
+        let events_slice: &[i64] = if event_dates_length > 0 {
+            std::slice::from_raw_parts(event_dates, event_dates_length)
+        } else {
+            &[]
+        };
+        anofox_fcst_core::event_dummies(dates_slice, events_slice, window_days)
+    }));
 
-        for (i, v) in filled.iter().enumerate() {
-            match v {
-                Some(val) => {
-                    *(*out_result).values.add(i) = *val;
-                    set_validity_bit((*out_result).validity, i, true);
-                }
-                None => {
-                    *(*out_result).values.add(i) = f64::NAN;
-                    set_validity_bit((*out_result).validity, i, false);
-                }
-            }
+    match result {
+        Ok(columns) => fill_regressor_matrix(out_result, &columns, dates_length, out_error),
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in event_dummies");
+            false
         }
-    } else {
-        (*out_result).values = ptr::null_mut();
-        (*out_result).validity = ptr::null_mut();
     }
-
-    true
 }
 
-/// Fill NULL values with linear interpolation.
+/// Build Fourier seasonality regressors for the history and the forecast horizon.
+///
+/// Both matrices have `2 * k` sine/cosine columns; `out_historical` covers
+/// `t = 0..n` and `out_future` continues with `t = n..n + horizon`.
 ///
 /// # Safety
-/// All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+/// All pointer arguments must be valid and non-null.
 #[no_mangle]
-pub unsafe extern "C" fn anofox_ts_fill_nulls_interpolate(
-    values: *const c_double,
-    validity: *const u64,
-    length: size_t,
-    out_values: *mut *mut c_double,
+pub unsafe extern "C" fn anofox_ts_fourier_terms(
+    n: size_t,
+    horizon: size_t,
+    period: c_double,
+    k: size_t,
+    out_historical: *mut RegressorMatrixResult,
+    out_future: *mut RegressorMatrixResult,
     out_error: *mut AnofoxError,
 ) -> bool {
-    if !out_error.is_null() {
-        *out_error = AnofoxError::success();
-    }
+    init_error(out_error);
 
-    if values.is_null() || out_values.is_null() {
-        if !out_error.is_null() {
-            (*out_error).set_error(ErrorCode::NullPointer, "Null pointer argument");
-        }
+    let ptrs = &[
+        out_historical as *const core::ffi::c_void,
+        out_future as *const core::ffi::c_void,
+    ];
+    if check_null_pointers(out_error, ptrs) {
         return false;
     }
 
-    let series = build_series(values, validity, length);
-    let filled = anofox_fcst_core::fill_nulls_interpolate(&series);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let historical = anofox_fcst_core::fourier_terms(n, period, k)?;
+        let future = anofox_fcst_core::fourier_terms_future(n, horizon, period, k)?;
+        Ok::<_, anofox_fcst_core::ForecastError>((historical, future))
+    }));
 
-    match alloc_or_error(&filled, out_error, "Failed to allocate interpolated values") {
-        Ok(ptr) => {
-            *out_values = ptr;
+    match result {
+        Ok(Ok((historical, future))) => {
+            if !fill_regressor_matrix(out_historical, &historical, n, out_error) {
+                return false;
+            }
+            if !fill_regressor_matrix(out_future, &future, horizon, out_error) {
+                anofox_free_regressor_matrix_result(out_historical);
+                return false;
+            }
             true
         }
-        Err(()) => false,
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::InvalidInput, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in fourier_terms");
+            false
+        }
     }
 }
 
@@ -5025,7 +8254,9 @@ pub unsafe extern "C" fn anofox_ts_conformal_predict_adaptive(
 
 /// Perform asymmetric conformal prediction.
 ///
-/// Uses separate quantiles for positive and negative residuals.
+/// Uses separate quantiles for positive and negative residuals. The per-side
+/// margins are `point - lower` and `upper - point`; `conformity_score` holds
+/// their average.
 ///
 /// # Safety
 /// All pointer arguments must be valid and non-null.
@@ -5240,11 +8471,25 @@ pub unsafe extern "C" fn anofox_ts_conformal_apply(
         return false;
     }
 
+    // A profile must come from anofox_ts_conformal_learn; reject hand-built
+    // profiles with missing per-level arrays instead of reading through null.
+    let profile_ref = &*profile;
+    if profile_ref.alphas.is_null()
+        || profile_ref.scores_lower.is_null()
+        || profile_ref.scores_upper.is_null()
+    {
+        set_error(
+            out_error,
+            ErrorCode::NullPointer,
+            "Calibration profile has null alphas or scores",
+        );
+        return false;
+    }
+
     let result = catch_unwind(AssertUnwindSafe(|| {
         let forecast_slice = std::slice::from_raw_parts(forecasts, n_forecasts);
 
         // Reconstruct CalibrationProfile from FFI
-        let profile_ref = &*profile;
         let core_profile = anofox_fcst_core::CalibrationProfile {
             method: profile_ref.method.into(),
             strategy: profile_ref.strategy.into(),
@@ -5344,6 +8589,53 @@ pub unsafe extern "C" fn anofox_ts_conformal_coverage(
     }
 }
 
+/// Compute the mean Winkler (interval) score of prediction intervals.
+///
+/// Lower is better: the interval width plus a `2 / alpha` penalty per unit
+/// an actual falls outside its interval.
+///
+/// # Safety
+/// All pointer arguments must be valid and non-null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_ts_winkler_score(
+    actuals: *const c_double,
+    lower: *const c_double,
+    upper: *const c_double,
+    length: size_t,
+    alpha: c_double,
+    out_score: *mut c_double,
+    out_error: *mut AnofoxError,
+) -> bool {
+    init_error(out_error);
+
+    if actuals.is_null() || lower.is_null() || upper.is_null() || out_score.is_null() {
+        set_error(out_error, ErrorCode::NullPointer, "Null pointer argument");
+        return false;
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let actuals_slice = std::slice::from_raw_parts(actuals, length);
+        let lower_slice = std::slice::from_raw_parts(lower, length);
+        let upper_slice = std::slice::from_raw_parts(upper, length);
+        anofox_fcst_core::winkler_score(actuals_slice, lower_slice, upper_slice, alpha)
+    }));
+
+    match result {
+        Ok(Ok(score)) => {
+            *out_score = score;
+            true
+        }
+        Ok(Err(e)) => {
+            set_error(out_error, ErrorCode::ComputationError, &e.to_string());
+            false
+        }
+        Err(_) => {
+            set_error(out_error, ErrorCode::PanicCaught, "Panic in winkler_score");
+            false
+        }
+    }
+}
+
 /// Compute comprehensive conformal evaluation metrics.
 ///
 /// # Safety
@@ -5846,6 +9138,37 @@ pub unsafe extern "C" fn anofox_free_ts_stats_result(_result: *mut TsStatsResult
     // No heap allocations
 }
 
+/// Free an AnomalyDetectionResult.
+///
+/// # Safety
+/// The result pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_anomaly_result(result: *mut AnomalyDetectionResult) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    free_fields!(r, scores, is_anomaly, indices);
+    r.length = 0;
+    r.n_anomalies = 0;
+}
+
+/// Free a TsStatsRollingResult.
+///
+/// # Safety
+/// The result pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_ts_stats_rolling_result(result: *mut TsStatsRollingResult) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    free_fields!(r, mean, std_dev, min, max, n_nulls);
+    r.length = 0;
+}
+
 /// Free a GapFillResult.
 ///
 /// # Safety
@@ -5871,6 +9194,21 @@ pub unsafe extern "C" fn anofox_free_gap_fill_result(result: *mut GapFillResult)
     }
 }
 
+/// Free a TrimmedSeriesResult.
+///
+/// # Safety
+/// The result pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_trimmed_series_result(result: *mut TrimmedSeriesResult) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    free_fields!(r, values, validity);
+    r.length = 0;
+}
+
 /// Free a FilledValuesResult.
 ///
 /// # Safety
@@ -5892,6 +9230,129 @@ pub unsafe extern "C" fn anofox_free_filled_values_result(result: *mut FilledVal
     }
 }
 
+/// Free a FilledMaskResult.
+///
+/// # Safety
+/// The result pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_filled_mask_result(result: *mut FilledMaskResult) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    if !r.values.is_null() {
+        free(r.values as *mut core::ffi::c_void);
+        r.values = ptr::null_mut();
+    }
+    if !r.filled.is_null() {
+        free(r.filled as *mut core::ffi::c_void);
+        r.filled = ptr::null_mut();
+    }
+}
+
+/// Free a RegressorMatrixResult.
+///
+/// # Safety
+/// The result pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_regressor_matrix_result(result: *mut RegressorMatrixResult) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    if !r.values.is_null() {
+        free(r.values as *mut core::ffi::c_void);
+        r.values = ptr::null_mut();
+    }
+}
+
+/// Free a QualityReportResult.
+///
+/// # Safety
+/// The result pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_quality_report_result(result: *mut QualityReportResult) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    if !r.recommendations.is_null() {
+        for i in 0..r.n_recommendations {
+            let rec_ptr = *r.recommendations.add(i);
+            if !rec_ptr.is_null() {
+                free(rec_ptr as *mut core::ffi::c_void);
+            }
+        }
+        free(r.recommendations as *mut core::ffi::c_void);
+        r.recommendations = ptr::null_mut();
+    }
+    r.n_recommendations = 0;
+}
+
+/// Free a HorizonMetricsResult.
+///
+/// # Safety
+/// The result pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_horizon_metrics_result(result: *mut HorizonMetricsResult) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    free_fields!(r, counts, mae, rmse, bias);
+    r.n_steps = 0;
+}
+
+/// Free a CrossValResultFFI.
+///
+/// # Safety
+/// The result pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_cross_val_result(result: *mut CrossValResultFFI) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    free_fields!(
+        r,
+        train_lengths,
+        window_mae,
+        window_rmse,
+        window_mase,
+        horizon_mae,
+        horizon_rmse,
+        horizon_mase,
+    );
+}
+
+/// Free a ForecastBatchResult.
+///
+/// # Safety
+/// The pointer must be valid or null.
+#[no_mangle]
+pub unsafe extern "C" fn anofox_free_forecast_batch_result(result: *mut ForecastBatchResult) {
+    if result.is_null() {
+        return;
+    }
+    let r = &mut *result;
+
+    free_fields!(
+        r,
+        offsets,
+        success,
+        point_forecasts,
+        lower_bounds,
+        upper_bounds
+    );
+    r.n_series = 0;
+    r.n_forecasts = 0;
+}
+
 /// Free a ForecastResult.
 ///
 /// # Safety
@@ -5923,6 +9384,30 @@ pub unsafe extern "C" fn anofox_free_forecast_result(result: *mut ForecastResult
         free(r.residuals as *mut core::ffi::c_void);
         r.residuals = ptr::null_mut();
     }
+    if !r.warnings.is_null() {
+        for i in 0..r.n_warnings {
+            let warning_ptr = *r.warnings.add(i);
+            if !warning_ptr.is_null() {
+                free(warning_ptr as *mut core::ffi::c_void);
+            }
+        }
+        free(r.warnings as *mut core::ffi::c_void);
+        r.warnings = ptr::null_mut();
+    }
+    r.n_warnings = 0;
+    for rows in [&mut r.level_lower, &mut r.level_upper] {
+        if !rows.is_null() {
+            for i in 0..r.n_levels {
+                let row_ptr = *rows.add(i);
+                if !row_ptr.is_null() {
+                    free(row_ptr as *mut core::ffi::c_void);
+                }
+            }
+            free(*rows as *mut core::ffi::c_void);
+            *rows = ptr::null_mut();
+        }
+    }
+    r.n_levels = 0;
 }
 
 /// Free a ChangepointResult.
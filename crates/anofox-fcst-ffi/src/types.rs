@@ -323,6 +323,285 @@ impl From<anofox_fcst_core::TsStats> for TsStatsResult {
     }
 }
 
+/// Rolling-window statistics, one array per statistic (see `anofox_ts_stats_rolling`).
+///
+/// Every array has `length` entries; entry `i` summarises the trailing window
+/// ending at position `i`.
+#[repr(C)]
+pub struct TsStatsRollingResult {
+    /// Number of positions (the series length)
+    pub length: size_t,
+    /// Window mean
+    pub mean: *mut c_double,
+    /// Window standard deviation
+    pub std_dev: *mut c_double,
+    /// Window minimum
+    pub min: *mut c_double,
+    /// Window maximum
+    pub max: *mut c_double,
+    /// Number of NULL values in the window
+    pub n_nulls: *mut size_t,
+}
+
+impl Default for TsStatsRollingResult {
+    fn default() -> Self {
+        Self {
+            length: 0,
+            mean: std::ptr::null_mut(),
+            std_dev: std::ptr::null_mut(),
+            min: std::ptr::null_mut(),
+            max: std::ptr::null_mut(),
+            n_nulls: std::ptr::null_mut(),
+        }
+    }
+}
+
+/// Stationarity test result (see `anofox_ts_adf_test` and `anofox_ts_kpss_test`).
+#[repr(C)]
+pub struct StationarityTestResult {
+    /// Test statistic
+    pub statistic: c_double,
+    /// Approximate p-value
+    pub p_value: c_double,
+    /// Number of lags used
+    pub n_lags: size_t,
+    /// Whether the series is judged stationary at the 5% level
+    pub is_stationary: bool,
+}
+
+impl Default for StationarityTestResult {
+    fn default() -> Self {
+        Self {
+            statistic: f64::NAN,
+            p_value: f64::NAN,
+            n_lags: 0,
+            is_stationary: false,
+        }
+    }
+}
+
+impl From<anofox_fcst_core::StationarityResult> for StationarityTestResult {
+    fn from(result: anofox_fcst_core::StationarityResult) -> Self {
+        Self {
+            statistic: result.statistic,
+            p_value: result.p_value,
+            n_lags: result.n_lags,
+            is_stationary: result.is_stationary,
+        }
+    }
+}
+
+/// Residual-based anomaly detection result (see `anofox_ts_detect_anomalies`).
+#[repr(C)]
+pub struct AnomalyDetectionResult {
+    /// Number of points (length of `scores` and `is_anomaly`)
+    pub length: size_t,
+    /// Robust z-score of the remainder at every point
+    pub scores: *mut c_double,
+    /// Whether each point is flagged
+    pub is_anomaly: *mut bool,
+    /// Number of flagged points (length of `indices`)
+    pub n_anomalies: size_t,
+    /// Indices of flagged points, in increasing order
+    pub indices: *mut size_t,
+}
+
+impl Default for AnomalyDetectionResult {
+    fn default() -> Self {
+        Self {
+            length: 0,
+            scores: std::ptr::null_mut(),
+            is_anomaly: std::ptr::null_mut(),
+            n_anomalies: 0,
+            indices: std::ptr::null_mut(),
+        }
+    }
+}
+
+/// Croston demand-size and interval components (see `anofox_ts_croston_components`).
+#[repr(C)]
+pub struct CrostonComponentsResult {
+    /// Smoothed non-zero demand size
+    pub demand: c_double,
+    /// Smoothed interval between non-zero demands
+    pub interval: c_double,
+    /// Per-period demand forecast `demand / interval`
+    pub forecast: c_double,
+}
+
+impl Default for CrostonComponentsResult {
+    fn default() -> Self {
+        Self {
+            demand: f64::NAN,
+            interval: f64::NAN,
+            forecast: f64::NAN,
+        }
+    }
+}
+
+impl From<anofox_fcst_core::CrostonComponents> for CrostonComponentsResult {
+    fn from(c: anofox_fcst_core::CrostonComponents) -> Self {
+        Self {
+            demand: c.demand,
+            interval: c.interval,
+            forecast: c.forecast,
+        }
+    }
+}
+
+/// Demand classification result (see `anofox_ts_classify_demand`).
+#[repr(C)]
+pub struct DemandClassificationFFI {
+    /// Average demand interval (periods per non-zero demand)
+    pub adi: c_double,
+    /// Squared coefficient of variation of non-zero demand sizes
+    pub cv2: c_double,
+    /// Classification (smooth, intermittent, erratic, lumpy)
+    pub classification: [c_char; 32],
+}
+
+impl Default for DemandClassificationFFI {
+    fn default() -> Self {
+        Self {
+            adi: f64::NAN,
+            cv2: f64::NAN,
+            classification: [0; 32],
+        }
+    }
+}
+
+/// Point-accuracy metrics bundle (see `anofox_ts_metrics_all`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct MetricsBundle {
+    /// Number of aligned, finite pairs the metrics were computed over
+    pub n: size_t,
+    /// Mean Absolute Error
+    pub mae: c_double,
+    /// Mean Squared Error
+    pub mse: c_double,
+    /// Root Mean Squared Error
+    pub rmse: c_double,
+    /// Mean Absolute Percentage Error (NaN if all actuals are zero)
+    pub mape: c_double,
+    /// Symmetric Mean Absolute Percentage Error
+    pub smape: c_double,
+    /// Mean bias (forecast - actual)
+    pub bias: c_double,
+    /// Coefficient of determination
+    pub r2: c_double,
+}
+
+impl Default for MetricsBundle {
+    fn default() -> Self {
+        Self {
+            n: 0,
+            mae: f64::NAN,
+            mse: f64::NAN,
+            rmse: f64::NAN,
+            mape: f64::NAN,
+            smape: f64::NAN,
+            bias: f64::NAN,
+            r2: f64::NAN,
+        }
+    }
+}
+
+impl From<anofox_fcst_core::PointMetrics> for MetricsBundle {
+    fn from(m: anofox_fcst_core::PointMetrics) -> Self {
+        Self {
+            n: m.n,
+            mae: m.mae,
+            mse: m.mse,
+            rmse: m.rmse,
+            mape: m.mape,
+            smape: m.smape,
+            bias: m.bias,
+            r2: m.r2,
+        }
+    }
+}
+
+/// Per-step accuracy (see `anofox_ts_metrics_by_horizon`).
+///
+/// All arrays have `n_steps` entries; index `i` is step `i + 1`.
+#[repr(C)]
+pub struct HorizonMetricsResult {
+    /// Number of forecast steps
+    pub n_steps: size_t,
+    /// Number of origins that reach each step
+    pub counts: *mut size_t,
+    /// MAE at each step
+    pub mae: *mut c_double,
+    /// RMSE at each step
+    pub rmse: *mut c_double,
+    /// Mean bias (forecast - actual) at each step
+    pub bias: *mut c_double,
+}
+
+impl Default for HorizonMetricsResult {
+    fn default() -> Self {
+        Self {
+            n_steps: 0,
+            counts: std::ptr::null_mut(),
+            mae: std::ptr::null_mut(),
+            rmse: std::ptr::null_mut(),
+            bias: std::ptr::null_mut(),
+        }
+    }
+}
+
+/// Rolling-origin cross-validation result (FFI version).
+///
+/// Per-window arrays have `n_windows` entries; per-horizon arrays have
+/// `horizon` entries.
+#[repr(C)]
+pub struct CrossValResultFFI {
+    /// Number of forecast origins evaluated
+    pub n_windows: size_t,
+    /// Forecast horizon of each window
+    pub horizon: size_t,
+    /// Training length (forecast origin) of each window
+    pub train_lengths: *mut size_t,
+    /// MAE of each window
+    pub window_mae: *mut c_double,
+    /// RMSE of each window
+    pub window_rmse: *mut c_double,
+    /// MASE of each window
+    pub window_mase: *mut c_double,
+    /// MAE at each horizon step
+    pub horizon_mae: *mut c_double,
+    /// RMSE at each horizon step
+    pub horizon_rmse: *mut c_double,
+    /// MASE at each horizon step
+    pub horizon_mase: *mut c_double,
+    /// Overall MAE
+    pub mae: c_double,
+    /// Overall RMSE
+    pub rmse: c_double,
+    /// Overall MASE
+    pub mase: c_double,
+}
+
+impl Default for CrossValResultFFI {
+    fn default() -> Self {
+        Self {
+            n_windows: 0,
+            horizon: 0,
+            train_lengths: std::ptr::null_mut(),
+            window_mae: std::ptr::null_mut(),
+            window_rmse: std::ptr::null_mut(),
+            window_mase: std::ptr::null_mut(),
+            horizon_mae: std::ptr::null_mut(),
+            horizon_rmse: std::ptr::null_mut(),
+            horizon_mase: std::ptr::null_mut(),
+            mae: f64::NAN,
+            rmse: f64::NAN,
+            mase: f64::NAN,
+        }
+    }
+}
+
 /// Forecast result structure.
 #[repr(C)]
 pub struct ForecastResult {
@@ -348,6 +627,21 @@ pub struct ForecastResult {
     pub bic: c_double,
     /// Mean Squared Error
     pub mse: c_double,
+    /// Holdout score of the selected model (NaN unless `auto_select_by_holdout`)
+    pub holdout_score: c_double,
+    /// Seasonal period used by the forecast (1 when non-seasonal)
+    pub seasonal_period_used: size_t,
+    /// Array of warning strings describing automatic fallbacks
+    pub warnings: *mut *mut c_char,
+    /// Number of warnings
+    pub n_warnings: size_t,
+    /// Lower bounds per requested confidence level (`n_levels` arrays of
+    /// `n_forecasts`; NULL unless `anofox_ts_forecast_levels` was used)
+    pub level_lower: *mut *mut c_double,
+    /// Upper bounds per requested confidence level
+    pub level_upper: *mut *mut c_double,
+    /// Number of requested confidence levels
+    pub n_levels: size_t,
 }
 
 impl Default for ForecastResult {
@@ -364,6 +658,80 @@ impl Default for ForecastResult {
             aic: f64::NAN,
             bic: f64::NAN,
             mse: f64::NAN,
+            holdout_score: f64::NAN,
+            seasonal_period_used: 0,
+            warnings: std::ptr::null_mut(),
+            n_warnings: 0,
+            level_lower: std::ptr::null_mut(),
+            level_upper: std::ptr::null_mut(),
+            n_levels: 0,
+        }
+    }
+}
+
+/// Batch forecast result for many series.
+///
+/// Forecasts for series `i` occupy `[offsets[i], offsets[i + 1])` of the
+/// point and bound arrays. A series that failed has `success[i] == false`
+/// and an empty range.
+#[repr(C)]
+pub struct ForecastBatchResult {
+    /// Number of series
+    pub n_series: size_t,
+    /// Series start offsets into the forecast arrays (`n_series + 1` entries)
+    pub offsets: *mut size_t,
+    /// Whether each series was forecast successfully
+    pub success: *mut bool,
+    /// Concatenated point forecasts
+    pub point_forecasts: *mut c_double,
+    /// Concatenated lower confidence bounds
+    pub lower_bounds: *mut c_double,
+    /// Concatenated upper confidence bounds
+    pub upper_bounds: *mut c_double,
+    /// Total number of forecast points
+    pub n_forecasts: size_t,
+}
+
+impl Default for ForecastBatchResult {
+    fn default() -> Self {
+        Self {
+            n_series: 0,
+            offsets: std::ptr::null_mut(),
+            success: std::ptr::null_mut(),
+            point_forecasts: std::ptr::null_mut(),
+            lower_bounds: std::ptr::null_mut(),
+            upper_bounds: std::ptr::null_mut(),
+            n_forecasts: 0,
+        }
+    }
+}
+
+/// Model capability bit: uses exogenous regressors.
+pub const MODEL_CAP_EXOG: u32 = 1;
+/// Model capability bit: uses a seasonal period.
+pub const MODEL_CAP_SEASONAL: u32 = 1 << 1;
+/// Model capability bit: intermittent-demand model.
+pub const MODEL_CAP_INTERMITTENT: u32 = 1 << 2;
+/// Model capability bit: returns prediction intervals.
+pub const MODEL_CAP_INTERVALS: u32 = 1 << 3;
+
+/// Available models with their capability bitflags (`MODEL_CAP_*`).
+#[repr(C)]
+pub struct ModelsInfoResult {
+    /// Model names
+    pub names: *mut *mut c_char,
+    /// Capability bitflags, parallel to `names`
+    pub capabilities: *mut u32,
+    /// Number of models
+    pub n_models: size_t,
+}
+
+impl Default for ModelsInfoResult {
+    fn default() -> Self {
+        Self {
+            names: std::ptr::null_mut(),
+            capabilities: std::ptr::null_mut(),
+            n_models: 0,
         }
     }
 }
@@ -403,6 +771,21 @@ pub struct ForecastOptions {
     /// growing amplitude / phase-shifted seasonality (softmax abandons the
     /// seasonal-EMA leaf and forecast collapses to flat).
     pub laplace_seasonal_batch_init: bool,
+    /// Use a MAD-based (outlier-robust) scale for prediction intervals
+    pub robust_intervals: bool,
+    /// For Auto* models, pick the best candidate by holdout backtest
+    pub auto_select_by_holdout: bool,
+    /// Damp the trend of Holt and HoltWinters
+    pub damped: bool,
+    /// Damping factor in (0, 1] when `damped` is set; <= 0 or NaN = estimate
+    pub phi: c_double,
+    /// Prediction interval method ("normal", "bootstrap"), empty = "normal"
+    pub interval_method: [c_char; 16],
+    /// HoltWinters seasonal mode ("additive", "multiplicative"), empty = "additive"
+    pub seasonal_mode: [c_char; 16],
+    /// Forecast on the log scale with a bias-corrected back-transform.
+    /// Requires strictly positive values.
+    pub log_transform: bool,
 }
 
 impl Default for ForecastOptions {
@@ -426,6 +809,13 @@ impl Default for ForecastOptions {
             model_pool: [0; 32],
             laplace_variant: [0; 16],
             laplace_seasonal_batch_init: false,
+            robust_intervals: false,
+            auto_select_by_holdout: false,
+            damped: false,
+            phi: 0.0,
+            interval_method: [0; 16],
+            seasonal_mode: [0; 16],
+            log_transform: false,
         }
     }
 }
@@ -496,6 +886,16 @@ pub struct ForecastOptionsExog {
     pub laplace_variant: [c_char; 16],
     /// Enable `LaplaceForecaster::with_seasonal_batch_init()` (opt-in).
     pub laplace_seasonal_batch_init: bool,
+    /// Use a MAD-based (outlier-robust) scale for prediction intervals
+    pub robust_intervals: bool,
+    /// Prediction interval method ("normal", "bootstrap"), empty = "normal"
+    pub interval_method: [c_char; 16],
+    /// Damp the trend of Holt and HoltWinters
+    pub damped: bool,
+    /// Damping factor in (0, 1] when `damped` is set; <= 0 or NaN = estimate
+    pub phi: c_double,
+    /// HoltWinters seasonal mode ("additive", "multiplicative"), empty = "additive"
+    pub seasonal_mode: [c_char; 16],
 }
 
 impl Default for ForecastOptionsExog {
@@ -520,6 +920,11 @@ impl Default for ForecastOptionsExog {
             model_pool: [0; 32],
             laplace_variant: [0; 16],
             laplace_seasonal_batch_init: false,
+            robust_intervals: false,
+            interval_method: [0; 16],
+            damped: false,
+            phi: 0.0,
+            seasonal_mode: [0; 16],
         }
     }
 }
@@ -545,6 +950,28 @@ impl Default for ChangepointResult {
     }
 }
 
+/// Opaque handle for streaming PELT changepoint detection.
+///
+/// Created by `anofox_pelt_new` and released with `anofox_pelt_free`.
+pub struct PeltHandle {
+    pub(crate) state: anofox_fcst_core::PeltState,
+}
+
+/// Opaque handle for a fitted forecast model.
+///
+/// Created by `anofox_model_fit` and released with `anofox_model_free`.
+/// A handle may be used from any thread, but not from two threads at once.
+pub struct FittedModelHandle {
+    pub(crate) model: anofox_fcst_core::FittedModel,
+}
+
+/// Opaque handle for online simple exponential smoothing.
+///
+/// Created by `anofox_online_ses_new` and released with `anofox_online_ses_free`.
+pub struct OnlineSesHandle {
+    pub(crate) state: anofox_fcst_core::OnlineSes,
+}
+
 /// BOCPD changepoint detection result.
 /// C++ API compatible: per-point is_changepoint and changepoint_probability.
 #[repr(C)]
@@ -594,6 +1021,20 @@ impl Default for FeaturesResult {
     }
 }
 
+/// Feature extraction parameters. Zero fields select the defaults.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FeatureParamsFFI {
+    /// Highest autocorrelation lag (0 = 10)
+    pub max_lag: size_t,
+    /// Histogram bins for binned entropy (0 = 10)
+    pub n_bins: size_t,
+    /// Window length for aggregated linear trend features (0 = length / 10)
+    pub rolling_window: size_t,
+    /// Seasonal period for tsfeatures-style STL features (0 = non-seasonal)
+    pub seasonal_period: size_t,
+}
+
 /// Seasonality analysis result.
 /// C++ API compatible field names.
 #[repr(C)]
@@ -639,6 +1080,10 @@ pub struct MstlResult {
     pub seasonal_periods: *mut c_int,
     /// Whether decomposition was actually applied
     pub decomposition_applied: bool,
+    /// Seasonal strength in [0, 1] (NaN without seasonal components)
+    pub seasonal_strength: c_double,
+    /// Trend strength in [0, 1] (NaN if decomposition was skipped)
+    pub trend_strength: c_double,
 }
 
 impl Default for MstlResult {
@@ -651,6 +1096,8 @@ impl Default for MstlResult {
             n_seasonal: 0,
             seasonal_periods: std::ptr::null_mut(),
             decomposition_applied: false,
+            seasonal_strength: f64::NAN,
+            trend_strength: f64::NAN,
         }
     }
 }
@@ -674,6 +1121,8 @@ pub struct DataQualityResult {
     pub n_missing: size_t,
     /// Is constant series
     pub is_constant: bool,
+    /// Passes all threshold checks
+    pub passed: bool,
 }
 
 impl Default for DataQualityResult {
@@ -687,13 +1136,55 @@ impl Default for DataQualityResult {
             n_gaps: 0,
             n_missing: 0,
             is_constant: false,
+            passed: false,
+        }
+    }
+}
+
+/// Quality report thresholds (mirrors the core `QualityThresholds`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct QualityThresholdsC {
+    /// Maximum allowed gap ratio
+    pub max_gap_ratio: c_double,
+    /// Maximum allowed missing ratio
+    pub max_missing_ratio: c_double,
+    /// Minimum series length
+    pub min_length: size_t,
+    /// Minimum non-zero ratio
+    pub min_nonzero_ratio: c_double,
+}
+
+impl Default for QualityThresholdsC {
+    fn default() -> Self {
+        anofox_fcst_core::QualityThresholds::default().into()
+    }
+}
+
+impl From<anofox_fcst_core::QualityThresholds> for QualityThresholdsC {
+    fn from(t: anofox_fcst_core::QualityThresholds) -> Self {
+        Self {
+            max_gap_ratio: t.max_gap_ratio,
+            max_missing_ratio: t.max_missing_ratio,
+            min_length: t.min_length,
+            min_nonzero_ratio: t.min_nonzero_ratio,
+        }
+    }
+}
+
+impl From<QualityThresholdsC> for anofox_fcst_core::QualityThresholds {
+    fn from(t: QualityThresholdsC) -> Self {
+        Self {
+            max_gap_ratio: t.max_gap_ratio,
+            max_missing_ratio: t.max_missing_ratio,
+            min_length: t.min_length,
+            min_nonzero_ratio: t.min_nonzero_ratio,
         }
     }
 }
 
 /// Quality report result.
 #[repr(C)]
-#[derive(Default)]
 pub struct QualityReportResult {
     /// Number of series passing all checks
     pub n_passed: size_t,
@@ -705,6 +1196,30 @@ pub struct QualityReportResult {
     pub n_constant: size_t,
     /// Total series analyzed
     pub n_total: size_t,
+    /// Array of recommendation strings
+    pub recommendations: *mut *mut c_char,
+    /// Number of recommendations
+    pub n_recommendations: size_t,
+    /// Number of series shorter than the minimum length
+    pub n_short: size_t,
+    /// Number of series below the minimum non-zero ratio
+    pub n_sparse: size_t,
+}
+
+impl Default for QualityReportResult {
+    fn default() -> Self {
+        Self {
+            n_passed: 0,
+            n_gap_issues: 0,
+            n_missing_issues: 0,
+            n_constant: 0,
+            n_total: 0,
+            recommendations: std::ptr::null_mut(),
+            n_recommendations: 0,
+            n_short: 0,
+            n_sparse: 0,
+        }
+    }
 }
 
 /// Gap fill result containing dates and values with filled gaps.
@@ -731,6 +1246,43 @@ impl Default for GapFillResult {
     }
 }
 
+/// Detected frequency with a human-readable label.
+#[repr(C)]
+#[derive(Default)]
+pub struct FrequencyLabelResult {
+    /// Modal interval between observations in seconds
+    pub frequency: i64,
+    /// Label such as "hourly", "daily", "weekly", "monthly" or "other"
+    pub label: [c_char; 16],
+}
+
+/// Regularity of the spacing between timestamps.
+#[repr(C)]
+pub struct FrequencyReportResult {
+    /// Most common interval between observations in seconds
+    pub modal_interval: i64,
+    /// Fraction of intervals matching the modal one
+    pub match_fraction: c_double,
+    /// Smallest interval in seconds
+    pub min_interval: i64,
+    /// Largest interval in seconds
+    pub max_interval: i64,
+    /// Whether at least 90% of intervals match the modal one
+    pub is_regular: bool,
+}
+
+impl Default for FrequencyReportResult {
+    fn default() -> Self {
+        Self {
+            modal_interval: 0,
+            match_fraction: f64::NAN,
+            min_interval: 0,
+            max_interval: 0,
+            is_regular: false,
+        }
+    }
+}
+
 /// Filled values result (for imputation functions that return values with validity).
 #[repr(C)]
 pub struct FilledValuesResult {
@@ -752,6 +1304,91 @@ impl Default for FilledValuesResult {
     }
 }
 
+/// Cheap pre-flight checks for a series (see `anofox_ts_series_flags`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SeriesFlags {
+    /// All non-NULL values are equal (or fewer than two are present)
+    pub is_constant: bool,
+    /// Fewer non-NULL values than the requested minimum length
+    pub is_short: bool,
+    /// Every value is NULL (also true for an empty series)
+    pub all_null: bool,
+    /// Number of non-NULL values
+    pub n_valid: size_t,
+}
+
+/// Series with leading and trailing zeros removed (see `anofox_ts_drop_edge_zeros`).
+#[repr(C)]
+pub struct TrimmedSeriesResult {
+    /// Array of remaining values
+    pub values: *mut c_double,
+    /// Validity bitmask (bit `i` indicates if `values[i]` is valid)
+    pub validity: *mut u64,
+    /// Number of remaining values
+    pub length: size_t,
+    /// Number of positions removed from the start
+    pub n_leading_removed: size_t,
+    /// Number of positions removed from the end
+    pub n_trailing_removed: size_t,
+}
+
+impl Default for TrimmedSeriesResult {
+    fn default() -> Self {
+        Self {
+            values: std::ptr::null_mut(),
+            validity: std::ptr::null_mut(),
+            length: 0,
+            n_leading_removed: 0,
+            n_trailing_removed: 0,
+        }
+    }
+}
+
+/// Filled values with a per-index mask marking which positions were imputed.
+#[repr(C)]
+pub struct FilledMaskResult {
+    /// Array of filled values
+    pub values: *mut c_double,
+    /// `filled[i]` is true if `values[i]` was NULL and has been imputed
+    pub filled: *mut bool,
+    /// Number of values
+    pub length: size_t,
+}
+
+impl Default for FilledMaskResult {
+    fn default() -> Self {
+        Self {
+            values: std::ptr::null_mut(),
+            filled: std::ptr::null_mut(),
+            length: 0,
+        }
+    }
+}
+
+/// Regressor columns for the exogenous forecast path.
+///
+/// Layout: `values` is flattened column-major as [col0_rows..., col1_rows..., ...]
+#[repr(C)]
+pub struct RegressorMatrixResult {
+    /// Flattened regressor values (n_columns * n_rows, column-major order)
+    pub values: *mut c_double,
+    /// Number of rows (observations)
+    pub n_rows: size_t,
+    /// Number of regressor columns
+    pub n_columns: size_t,
+}
+
+impl Default for RegressorMatrixResult {
+    fn default() -> Self {
+        Self {
+            values: std::ptr::null_mut(),
+            n_rows: 0,
+            n_columns: 0,
+        }
+    }
+}
+
 // ============================================================================
 // Period Detection Types (fdars-core integration)
 // ============================================================================
@@ -1521,6 +2158,8 @@ pub enum ConformalMethodFFI {
     Asymmetric = 1,
     /// Adaptive intervals scaled by difficulty scores
     Adaptive = 2,
+    /// Conformalized Quantile Regression (residuals are CQR scores)
+    Cqr = 3,
 }
 
 impl From<anofox_fcst_core::ConformalMethod> for ConformalMethodFFI {
@@ -1529,6 +2168,7 @@ impl From<anofox_fcst_core::ConformalMethod> for ConformalMethodFFI {
             anofox_fcst_core::ConformalMethod::Symmetric => Self::Symmetric,
             anofox_fcst_core::ConformalMethod::Asymmetric => Self::Asymmetric,
             anofox_fcst_core::ConformalMethod::Adaptive => Self::Adaptive,
+            anofox_fcst_core::ConformalMethod::Cqr => Self::Cqr,
         }
     }
 }
@@ -1539,6 +2179,7 @@ impl From<ConformalMethodFFI> for anofox_fcst_core::ConformalMethod {
             ConformalMethodFFI::Symmetric => Self::Symmetric,
             ConformalMethodFFI::Asymmetric => Self::Asymmetric,
             ConformalMethodFFI::Adaptive => Self::Adaptive,
+            ConformalMethodFFI::Cqr => Self::Cqr,
         }
     }
 }
@@ -1670,3 +2311,33 @@ pub struct ConformalEvaluationFFI {
     /// Number of observations evaluated
     pub n_observations: size_t,
 }
+
+// ============================================================================
+// Rolling Window Types
+// ============================================================================
+
+/// Rolling-window aggregation for FFI.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RollingAggFFI {
+    /// Arithmetic mean
+    #[default]
+    RollingMean = 0,
+    /// Sample standard deviation
+    RollingStd = 1,
+    /// Minimum
+    RollingMin = 2,
+    /// Maximum
+    RollingMax = 3,
+}
+
+impl From<RollingAggFFI> for anofox_fcst_core::RollingAgg {
+    fn from(agg: RollingAggFFI) -> Self {
+        match agg {
+            RollingAggFFI::RollingMean => Self::Mean,
+            RollingAggFFI::RollingStd => Self::Std,
+            RollingAggFFI::RollingMin => Self::Min,
+            RollingAggFFI::RollingMax => Self::Max,
+        }
+    }
+}
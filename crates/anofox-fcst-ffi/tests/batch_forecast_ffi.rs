@@ -0,0 +1,152 @@
+//! Batch forecasting through the FFI boundary.
+//!
+//! `anofox_ts_forecast_batch()` must return, for every series, exactly what a
+//! separate `anofox_ts_forecast()` call returns.
+
+mod common;
+
+use anofox_fcst_ffi::types::{AnofoxError, ForecastBatchResult, ForecastOptions, ForecastResult};
+
+use common::{
+    anofox_free_forecast_batch_result, anofox_free_forecast_result, anofox_ts_forecast,
+    anofox_ts_forecast_batch, options,
+};
+
+fn series(n: usize, level: f64) -> Vec<f64> {
+    (0..n)
+        .map(|i| {
+            let season = 4.0 * (2.0 * std::f64::consts::PI * i as f64 / 12.0).sin();
+            let noise = ((i * 7 + 3) % 11) as f64 * 0.1 - 0.5;
+            level + 0.1 * i as f64 + season + noise
+        })
+        .collect()
+}
+
+/// Point, lower and upper forecasts from a single-series call.
+fn single(values: &[f64], opts: &ForecastOptions) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let mut result = ForecastResult::default();
+    let mut error = AnofoxError::default();
+    unsafe {
+        let ok = anofox_ts_forecast(
+            values.as_ptr(),
+            std::ptr::null(),
+            values.len(),
+            opts,
+            &mut result,
+            &mut error,
+        );
+        assert!(ok);
+        let n = result.n_forecasts;
+        let out = (
+            std::slice::from_raw_parts(result.point_forecasts, n).to_vec(),
+            std::slice::from_raw_parts(result.lower_bounds, n).to_vec(),
+            std::slice::from_raw_parts(result.upper_bounds, n).to_vec(),
+        );
+        anofox_free_forecast_result(&mut result);
+        out
+    }
+}
+
+#[test]
+fn forecast_batch_matches_single_calls() {
+    let all = [series(24, 10.0), series(37, 50.0), series(60, 100.0)];
+    let mut flat = Vec::new();
+    let mut offsets = vec![0usize];
+    for s in &all {
+        flat.extend_from_slice(s);
+        offsets.push(flat.len());
+    }
+
+    for model_name in ["SES", "HoltWinters"] {
+        let opts = options(model_name);
+        let mut result = ForecastBatchResult::default();
+        let mut error = AnofoxError::default();
+
+        unsafe {
+            let ok = anofox_ts_forecast_batch(
+                flat.as_ptr(),
+                std::ptr::null(),
+                offsets.as_ptr(),
+                all.len(),
+                &opts,
+                0,
+                &mut result,
+                &mut error,
+            );
+            assert!(ok, "anofox_ts_forecast_batch failed for {}", model_name);
+            assert_eq!(result.n_series, all.len());
+
+            let out_offsets = std::slice::from_raw_parts(result.offsets, all.len() + 1);
+            let success = std::slice::from_raw_parts(result.success, all.len());
+            let n = result.n_forecasts;
+            let point = std::slice::from_raw_parts(result.point_forecasts, n);
+            let lower = std::slice::from_raw_parts(result.lower_bounds, n);
+            let upper = std::slice::from_raw_parts(result.upper_bounds, n);
+
+            for (i, s) in all.iter().enumerate() {
+                assert!(success[i]);
+                let range = out_offsets[i]..out_offsets[i + 1];
+                let (p, l, u) = single(s, &opts);
+                assert_eq!(
+                    &point[range.clone()],
+                    p.as_slice(),
+                    "{} series {}",
+                    model_name,
+                    i
+                );
+                assert_eq!(&lower[range.clone()], l.as_slice());
+                assert_eq!(&upper[range], u.as_slice());
+            }
+
+            anofox_free_forecast_batch_result(&mut result);
+        }
+    }
+}
+
+#[test]
+fn forecast_batch_flags_failed_series() {
+    let flat = [series(30, 10.0), vec![1.0, 2.0], series(30, 20.0)].concat();
+    let offsets = [0usize, 30, 32, 62];
+    let opts = options("SES");
+    let mut result = ForecastBatchResult::default();
+    let mut error = AnofoxError::default();
+
+    unsafe {
+        let ok = anofox_ts_forecast_batch(
+            flat.as_ptr(),
+            std::ptr::null(),
+            offsets.as_ptr(),
+            3,
+            &opts,
+            2,
+            &mut result,
+            &mut error,
+        );
+        assert!(ok);
+
+        let success = std::slice::from_raw_parts(result.success, 3);
+        assert_eq!(success, &[true, false, true]);
+        let out_offsets = std::slice::from_raw_parts(result.offsets, 4);
+        assert_eq!(out_offsets[1], out_offsets[2]);
+        assert_eq!(result.n_forecasts, 2 * opts.horizon as usize);
+
+        anofox_free_forecast_batch_result(&mut result);
+    }
+
+    // Decreasing offsets are rejected
+    let bad_offsets = [0usize, 30, 20];
+    let mut result = ForecastBatchResult::default();
+    let ok = unsafe {
+        anofox_ts_forecast_batch(
+            flat.as_ptr(),
+            std::ptr::null(),
+            bad_offsets.as_ptr(),
+            2,
+            &opts,
+            0,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(!ok);
+}
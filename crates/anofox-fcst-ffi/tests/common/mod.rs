@@ -0,0 +1,251 @@
+//! Declarations and fixtures shared by the FFI integration tests.
+//!
+//! Each test binary compiles this module separately and uses only part of it.
+#![allow(dead_code)]
+
+use std::ffi::{c_char, c_double, c_int};
+
+use anofox_fcst_ffi::types::{
+    AnofoxError, ConformalEvaluationFFI, DataQualityResult, FilledMaskResult,
+    FlatMultiPeriodResult, ForecastBatchResult, ForecastOptions, ForecastResult,
+    MatrixProfilePeriodResultFFI, MstlResult, QualityReportResult, QualityThresholdsC, SeriesFlags,
+    TrimmedSeriesResult,
+};
+
+/// Opaque stand-in for `FittedModelHandle`, as C callers see it.
+#[repr(C)]
+pub struct FittedModelHandle {
+    _private: [u8; 0],
+}
+
+/// Opaque stand-in for `PeltHandle`, as C callers see it.
+#[repr(C)]
+pub struct PeltHandle {
+    _private: [u8; 0],
+}
+
+// Defined in anofox_fcst_ffi/src/lib.rs
+extern "C" {
+    pub fn anofox_ts_forecast(
+        values: *const c_double,
+        validity: *const u64,
+        length: usize,
+        options: *const ForecastOptions,
+        out_result: *mut ForecastResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_ts_forecast_levels(
+        values: *const c_double,
+        validity: *const u64,
+        length: usize,
+        options: *const ForecastOptions,
+        levels: *const c_double,
+        n_levels: usize,
+        out_result: *mut ForecastResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_ts_forecast_ensemble(
+        values: *const c_double,
+        validity: *const u64,
+        length: usize,
+        model_names: *const *const c_char,
+        n_models: usize,
+        weights: *const c_double,
+        horizon: c_int,
+        out_result: *mut ForecastResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_ts_forecast_batch(
+        values: *const c_double,
+        validity: *const u64,
+        offsets: *const usize,
+        n_series: usize,
+        options: *const ForecastOptions,
+        n_threads: usize,
+        out_result: *mut ForecastBatchResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_free_forecast_result(result: *mut ForecastResult);
+
+    pub fn anofox_free_forecast_batch_result(result: *mut ForecastBatchResult);
+
+    pub fn anofox_model_fit(
+        values: *const c_double,
+        validity: *const u64,
+        length: usize,
+        options: *const ForecastOptions,
+        out_error: *mut AnofoxError,
+    ) -> *mut FittedModelHandle;
+
+    pub fn anofox_model_predict(
+        handle: *const FittedModelHandle,
+        horizon: usize,
+        out_result: *mut ForecastResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_model_free(handle: *mut FittedModelHandle);
+
+    pub fn anofox_ts_drop_edge_zeros(
+        values: *const c_double,
+        validity: *const u64,
+        length: usize,
+        out_result: *mut TrimmedSeriesResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_free_trimmed_series_result(result: *mut TrimmedSeriesResult);
+
+    pub fn anofox_ts_series_flags(
+        values: *const c_double,
+        validity: *const u64,
+        length: usize,
+        min_length: usize,
+        out_result: *mut SeriesFlags,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_ts_fill_nulls_const_masked(
+        values: *const c_double,
+        validity: *const u64,
+        length: usize,
+        fill_value: c_double,
+        out_result: *mut FilledMaskResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_free_filled_mask_result(result: *mut FilledMaskResult);
+
+    pub fn anofox_ts_winkler_score(
+        actuals: *const c_double,
+        lower: *const c_double,
+        upper: *const c_double,
+        length: usize,
+        alpha: c_double,
+        out_score: *mut c_double,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_ts_conformal_evaluate(
+        actuals: *const c_double,
+        lower: *const c_double,
+        upper: *const c_double,
+        length: usize,
+        alpha: c_double,
+        out_eval: *mut ConformalEvaluationFFI,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_ts_mstl_decomposition(
+        values: *const c_double,
+        length: usize,
+        periods: *const c_int,
+        n_periods: usize,
+        insufficient_data_mode: c_int,
+        multiplicative: bool,
+        robust: bool,
+        out_result: *mut MstlResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_free_mstl_result(result: *mut MstlResult);
+
+    pub fn anofox_pelt_new(
+        min_size: c_int,
+        penalty: c_double,
+        cost_function: c_int,
+        max_history: usize,
+        out_error: *mut AnofoxError,
+    ) -> *mut PeltHandle;
+
+    pub fn anofox_pelt_push(
+        handle: *mut PeltHandle,
+        value: c_double,
+        out_detected: *mut bool,
+        out_changepoint: *mut usize,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_pelt_free(handle: *mut PeltHandle);
+
+    pub fn anofox_ts_snap_period(
+        detected: c_double,
+        expected: *const usize,
+        n_expected: usize,
+        tolerance: c_double,
+        out_period: *mut usize,
+        out_matched: *mut bool,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_ts_detect_periods_flat(
+        values: *const c_double,
+        length: usize,
+        method: *const c_char,
+        max_period: usize,
+        min_confidence: c_double,
+        expected_periods: *const c_double,
+        n_expected: usize,
+        tolerance: c_double,
+        out_result: *mut FlatMultiPeriodResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_free_flat_multi_period_result(result: *mut FlatMultiPeriodResult);
+
+    pub fn anofox_ts_matrix_profile_period(
+        values: *const c_double,
+        length: usize,
+        subsequence_length: usize,
+        exclusion_zone: usize,
+        out_result: *mut MatrixProfilePeriodResultFFI,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_ts_data_quality(
+        values: *const c_double,
+        validity: *const u64,
+        length: usize,
+        thresholds: *const QualityThresholdsC,
+        out_result: *mut DataQualityResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_ts_quality_report(
+        values: *const c_double,
+        validity: *const u64,
+        series_lengths: *const usize,
+        n_series: usize,
+        thresholds: *const QualityThresholdsC,
+        out_result: *mut QualityReportResult,
+        out_error: *mut AnofoxError,
+    ) -> bool;
+
+    pub fn anofox_free_quality_report_result(result: *mut QualityReportResult);
+}
+
+/// Copy `value` into a fixed-size C string buffer, truncating to fit.
+pub fn write_buffer(buffer: &mut [c_char], value: &str) {
+    let bytes = value.as_bytes();
+    let n = bytes.len().min(buffer.len() - 1);
+    for (dst, &b) in buffer.iter_mut().zip(&bytes[..n]) {
+        *dst = b as c_char;
+    }
+    buffer[n] = 0;
+}
+
+/// Options for `model_name` with a 6-step horizon, period 12 and no
+/// seasonality detection.
+pub fn options(model_name: &str) -> ForecastOptions {
+    let mut opts = ForecastOptions::default();
+    write_buffer(&mut opts.model, model_name);
+    opts.horizon = 6;
+    opts.seasonal_period = 12;
+    opts.confidence_level = 0.95;
+    opts.auto_detect_seasonality = false;
+    opts
+}
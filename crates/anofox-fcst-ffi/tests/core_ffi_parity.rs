@@ -9,7 +9,9 @@
 //! `anofox-fcst-core::forecast()` independently of the library, so only point
 //! forecasts are compared here.
 
-use std::ffi::{c_char, c_double, CStr};
+mod common;
+
+use std::ffi::{c_char, CStr};
 
 use anofox_forecast::core::TimeSeries;
 use anofox_forecast::models::arima::{AutoARIMA, AutoARIMAConfig};
@@ -30,19 +32,7 @@ use chrono::{Duration, TimeZone, Utc};
 
 use anofox_fcst_ffi::types::{AnofoxError, ForecastOptions as FfiForecastOptions, ForecastResult};
 
-// Defined in anofox_fcst_ffi/src/lib.rs
-extern "C" {
-    fn anofox_ts_forecast(
-        values: *const c_double,
-        validity: *const u64,
-        length: usize,
-        options: *const FfiForecastOptions,
-        out_result: *mut ForecastResult,
-        out_error: *mut AnofoxError,
-    ) -> bool;
-
-    fn anofox_free_forecast_result(result: *mut ForecastResult);
-}
+use common::{anofox_free_forecast_result, anofox_ts_forecast};
 
 // ── Constants ──────────────────────────────────────────────────────────
 
@@ -0,0 +1,129 @@
+//! Series filtering helpers through the FFI boundary.
+
+mod common;
+
+use anofox_fcst_ffi::types::{AnofoxError, ErrorCode, SeriesFlags, TrimmedSeriesResult};
+
+use common::{
+    anofox_free_trimmed_series_result, anofox_ts_drop_edge_zeros, anofox_ts_series_flags,
+};
+
+/// Trim `values` (all valid) and return the kept values with the removed counts.
+fn trim(values: &[f64]) -> (Vec<f64>, usize, usize) {
+    let mut result = TrimmedSeriesResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_drop_edge_zeros(
+            values.as_ptr(),
+            std::ptr::null(),
+            values.len(),
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(ok, "drop_edge_zeros failed: {:?}", error.code);
+
+    let kept = if result.length > 0 {
+        unsafe { std::slice::from_raw_parts(result.values, result.length) }.to_vec()
+    } else {
+        vec![]
+    };
+    let counts = (result.n_leading_removed, result.n_trailing_removed);
+    unsafe { anofox_free_trimmed_series_result(&mut result) };
+    (kept, counts.0, counts.1)
+}
+
+#[test]
+fn drop_edge_zeros_reports_both_sides() {
+    let (kept, leading, trailing) = trim(&[0.0, 0.0, 5.0, 3.0, 0.0, 0.0]);
+    assert_eq!(kept, vec![5.0, 3.0]);
+    assert_eq!(leading, 2);
+    assert_eq!(trailing, 2);
+}
+
+#[test]
+fn drop_edge_zeros_keeps_interior_zeros() {
+    let (kept, leading, trailing) = trim(&[0.0, 4.0, 0.0, 2.0]);
+    assert_eq!(kept, vec![4.0, 0.0, 2.0]);
+    assert_eq!((leading, trailing), (1, 0));
+}
+
+#[test]
+fn drop_edge_zeros_all_zero_series_is_emptied() {
+    let (kept, leading, trailing) = trim(&[0.0, 0.0, 0.0]);
+    assert!(kept.is_empty());
+    assert_eq!(leading + trailing, 3);
+}
+
+#[test]
+fn drop_edge_zeros_rejects_null_values() {
+    let mut result = TrimmedSeriesResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_drop_edge_zeros(
+            std::ptr::null(),
+            std::ptr::null(),
+            3,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(!ok);
+    assert_eq!(error.code, ErrorCode::NullPointer);
+}
+
+/// Flags for `values`, with positions whose validity bit is clear treated as NULL.
+fn flags(values: &[f64], validity: Option<u64>, min_length: usize) -> SeriesFlags {
+    let mut result = SeriesFlags::default();
+    let mut error = AnofoxError::default();
+    let mask = validity.map(|m| [m]);
+    let ok = unsafe {
+        anofox_ts_series_flags(
+            values.as_ptr(),
+            mask.as_ref().map_or(std::ptr::null(), |m| m.as_ptr()),
+            values.len(),
+            min_length,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(ok, "series_flags failed: {:?}", error.code);
+    result
+}
+
+#[test]
+fn series_flags_constant_series() {
+    let result = flags(&[3.0, 3.0, 3.0, 3.0, 3.0], None, 3);
+    assert!(result.is_constant);
+    assert!(!result.is_short);
+    assert!(!result.all_null);
+    assert_eq!(result.n_valid, 5);
+}
+
+#[test]
+fn series_flags_short_series() {
+    // Only positions 0 and 2 are valid
+    let result = flags(&[1.0, 0.0, 2.0, 0.0], Some(0b0101), 3);
+    assert!(result.is_short);
+    assert!(!result.is_constant);
+    assert_eq!(result.n_valid, 2);
+}
+
+#[test]
+fn series_flags_normal_series() {
+    let values: Vec<f64> = (0..20).map(|i| i as f64 * 1.5).collect();
+    let result = flags(&values, None, 10);
+    assert!(!result.is_constant);
+    assert!(!result.is_short);
+    assert!(!result.all_null);
+    assert_eq!(result.n_valid, 20);
+}
+
+#[test]
+fn series_flags_all_null_series() {
+    let result = flags(&[1.0, 2.0, 3.0], Some(0), 1);
+    assert!(result.all_null);
+    assert!(result.is_short);
+    assert!(result.is_constant);
+    assert_eq!(result.n_valid, 0);
+}
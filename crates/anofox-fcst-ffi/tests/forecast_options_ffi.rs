@@ -0,0 +1,83 @@
+//! Parsing of the `ForecastOptions` string buffers through the FFI boundary.
+
+mod common;
+
+use std::ffi::CStr;
+
+use anofox_fcst_ffi::types::{AnofoxError, ErrorCode, ForecastOptions, ForecastResult};
+
+use common::{anofox_free_forecast_result, anofox_ts_forecast, options, write_buffer};
+
+fn ets_options(model: &str, ets_model: &str) -> ForecastOptions {
+    let mut opts = options(model);
+    write_buffer(&mut opts.ets_model, ets_model);
+    opts
+}
+
+/// Run a forecast on a seasonal series; returns the model name or the error.
+fn run(opts: &ForecastOptions) -> Result<String, (ErrorCode, String)> {
+    let values: Vec<f64> = (0..48)
+        .map(|i| 100.0 + i as f64 + 5.0 * ((i % 12) as f64 - 5.5).abs())
+        .collect();
+    let mut result = ForecastResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_forecast(
+            values.as_ptr(),
+            std::ptr::null(),
+            values.len(),
+            opts,
+            &mut result,
+            &mut error,
+        )
+    };
+    if !ok {
+        let message = unsafe { CStr::from_ptr(error.message.as_ptr()) }
+            .to_string_lossy()
+            .into_owned();
+        return Err((error.code, message));
+    }
+
+    let model_name = unsafe { CStr::from_ptr(result.model_name.as_ptr()) }
+        .to_string_lossy()
+        .into_owned();
+    unsafe { anofox_free_forecast_result(&mut result) };
+    Ok(model_name)
+}
+
+#[test]
+fn model_buffer_carries_ets_spec() {
+    // Without a spec ETS falls back to its default choice of model
+    assert_eq!(run(&ets_options("ETS", "")).unwrap(), "ETS");
+
+    // The spec after the colon is fitted explicitly, as with `ets_model`
+    let inline = run(&ets_options("ETS:AAA", "")).unwrap();
+    assert!(inline.starts_with("ETS("), "{}", inline);
+    assert_eq!(inline, run(&ets_options("ETS", "AAA")).unwrap());
+}
+
+#[test]
+fn model_buffer_spec_must_agree_with_ets_model() {
+    assert!(run(&ets_options("ETS:AAA", "AAA")).is_ok());
+
+    let (code, _) = run(&ets_options("ETS:AAA", "ANN")).unwrap_err();
+    assert_eq!(code, ErrorCode::InvalidInput);
+}
+
+#[test]
+fn model_buffer_spec_only_for_ets() {
+    let (code, message) = run(&ets_options("Holt:AAA", "")).unwrap_err();
+    assert_eq!(code, ErrorCode::InvalidModel);
+    assert!(message.contains("Holt"), "{}", message);
+}
+
+#[test]
+fn unknown_model_is_rejected() {
+    for model in ["ETZ", "ETZ:AAA"] {
+        let (code, message) = run(&ets_options(model, "")).unwrap_err();
+        assert_eq!(code, ErrorCode::InvalidModel);
+        assert!(message.contains("Unknown model: 'ETZ'"), "{}", message);
+        // The message lists the valid names instead of running AutoETS
+        assert!(message.contains("Valid models:"), "{}", message);
+    }
+}
@@ -0,0 +1,166 @@
+//! `ForecastResult` fields beyond the point forecasts, through the FFI boundary.
+
+mod common;
+
+use std::ffi::{c_char, CStr};
+
+use anofox_fcst_ffi::types::{AnofoxError, ErrorCode, ForecastOptions, ForecastResult};
+
+use common::{
+    anofox_free_forecast_result, anofox_ts_forecast, anofox_ts_forecast_ensemble,
+    anofox_ts_forecast_levels, options,
+};
+
+fn seasonal_series(n: usize) -> Vec<f64> {
+    (0..n)
+        .map(|i| 100.0 + i as f64 + 5.0 * ((i % 12) as f64 - 5.5).abs())
+        .collect()
+}
+
+/// Run a forecast (all values valid) and return the warnings it reported.
+fn forecast_warnings(values: &[f64], opts: &ForecastOptions) -> Vec<String> {
+    let mut result = ForecastResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_forecast(
+            values.as_ptr(),
+            std::ptr::null(),
+            values.len(),
+            opts,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(ok, "anofox_ts_forecast failed: {:?}", error.code);
+
+    let warnings = (0..result.n_warnings)
+        .map(|i| {
+            unsafe { CStr::from_ptr(*result.warnings.add(i)) }
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+    unsafe { anofox_free_forecast_result(&mut result) };
+    assert!(result.warnings.is_null());
+    warnings
+}
+
+#[test]
+fn ets_fallback_is_reported_as_warning() {
+    let warnings = forecast_warnings(&seasonal_series(48), &options("ETS"));
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert!(warnings[0].contains("HoltWinters"), "{:?}", warnings);
+}
+
+#[test]
+fn plain_forecast_has_no_warnings() {
+    let warnings = forecast_warnings(&seasonal_series(48), &options("HoltWinters"));
+    assert!(warnings.is_empty(), "{:?}", warnings);
+}
+
+/// Bounds `(lower, upper)` at each of `levels`.
+fn level_bounds(
+    values: &[f64],
+    opts: &ForecastOptions,
+    levels: &[f64],
+) -> Vec<(Vec<f64>, Vec<f64>)> {
+    let mut result = ForecastResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_forecast_levels(
+            values.as_ptr(),
+            std::ptr::null(),
+            values.len(),
+            opts,
+            levels.as_ptr(),
+            levels.len(),
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(ok, "anofox_ts_forecast_levels failed: {:?}", error.code);
+    assert_eq!(result.n_levels, levels.len());
+
+    let n = result.n_forecasts;
+    let bounds = (0..result.n_levels)
+        .map(|i| unsafe {
+            (
+                std::slice::from_raw_parts(*result.level_lower.add(i), n).to_vec(),
+                std::slice::from_raw_parts(*result.level_upper.add(i), n).to_vec(),
+            )
+        })
+        .collect();
+    unsafe { anofox_free_forecast_result(&mut result) };
+    assert!(result.level_lower.is_null() && result.level_upper.is_null());
+    bounds
+}
+
+#[test]
+fn forecast_levels_returns_nested_bands() {
+    let bounds = level_bounds(&seasonal_series(48), &options("HoltWinters"), &[0.8, 0.95]);
+    let (lower_80, upper_80) = &bounds[0];
+    let (lower_95, upper_95) = &bounds[1];
+    assert_eq!(lower_80.len(), 6);
+    let bands_95 = lower_95.iter().zip(upper_95);
+    for ((l80, u80), (l95, u95)) in lower_80.iter().zip(upper_80).zip(bands_95) {
+        assert!(u95 - l95 > u80 - l80);
+    }
+}
+
+#[test]
+fn forecast_levels_rejects_invalid_level() {
+    let values = seasonal_series(48);
+    let mut result = ForecastResult::default();
+    let mut error = AnofoxError::default();
+    let levels = [0.8, 1.2];
+    let ok = unsafe {
+        anofox_ts_forecast_levels(
+            values.as_ptr(),
+            std::ptr::null(),
+            values.len(),
+            &options("HoltWinters"),
+            levels.as_ptr(),
+            levels.len(),
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(!ok);
+    assert_eq!(error.code, ErrorCode::InvalidInput);
+}
+
+#[test]
+fn ensemble_result_is_fully_initialized() {
+    let values = seasonal_series(48);
+    let names = [c"Naive", c"SES"];
+    let name_ptrs: Vec<*const c_char> = names.iter().map(|n| n.as_ptr()).collect();
+
+    // Start from garbage so any field the ensemble forgets to set shows up.
+    let mut result = ForecastResult {
+        n_warnings: 7,
+        n_levels: 3,
+        ..Default::default()
+    };
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_forecast_ensemble(
+            values.as_ptr(),
+            std::ptr::null(),
+            values.len(),
+            name_ptrs.as_ptr(),
+            name_ptrs.len(),
+            std::ptr::null(),
+            6,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(ok, "anofox_ts_forecast_ensemble failed: {:?}", error.code);
+    assert_eq!(result.n_forecasts, 6);
+    assert_eq!(result.n_warnings, 0);
+    assert_eq!(result.n_levels, 0);
+    assert!(result.level_lower.is_null() && result.level_upper.is_null());
+
+    unsafe { anofox_free_forecast_result(&mut result) };
+    assert!(result.point_forecasts.is_null());
+}
@@ -0,0 +1,55 @@
+//! NULL imputation through the FFI boundary.
+
+mod common;
+
+use anofox_fcst_ffi::types::{AnofoxError, ErrorCode, FilledMaskResult};
+
+use common::{anofox_free_filled_mask_result, anofox_ts_fill_nulls_const_masked};
+
+#[test]
+fn fill_nulls_const_masked_marks_imputed_positions() {
+    let values = [1.0, 99.0, 3.0, 99.0];
+    // Positions 1 and 3 are NULL
+    let validity = [0b0101u64];
+    let mut result = FilledMaskResult::default();
+    let mut error = AnofoxError::default();
+
+    let ok = unsafe {
+        anofox_ts_fill_nulls_const_masked(
+            values.as_ptr(),
+            validity.as_ptr(),
+            values.len(),
+            0.0,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(ok, "fill_nulls_const_masked failed: {:?}", error.code);
+    assert_eq!(result.length, 4);
+
+    let filled = unsafe { std::slice::from_raw_parts(result.values, result.length) }.to_vec();
+    let mask = unsafe { std::slice::from_raw_parts(result.filled, result.length) }.to_vec();
+    unsafe { anofox_free_filled_mask_result(&mut result) };
+
+    assert_eq!(filled, vec![1.0, 0.0, 3.0, 0.0]);
+    assert_eq!(mask, vec![false, true, false, true]);
+}
+
+#[test]
+fn fill_nulls_const_masked_rejects_null_values() {
+    let mut result = FilledMaskResult::default();
+    let mut error = AnofoxError::default();
+
+    let ok = unsafe {
+        anofox_ts_fill_nulls_const_masked(
+            std::ptr::null(),
+            std::ptr::null(),
+            3,
+            0.0,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(!ok);
+    assert_eq!(error.code, ErrorCode::NullPointer);
+}
@@ -0,0 +1,96 @@
+//! Interval-evaluation functions called through the FFI boundary.
+//!
+//! Mirrors the core `conformal` tests to check that the C entry points pass
+//! arrays and results through unchanged.
+
+mod common;
+
+use anofox_fcst_ffi::types::{AnofoxError, ConformalEvaluationFFI};
+
+use common::{anofox_ts_conformal_evaluate, anofox_ts_winkler_score};
+
+fn winkler(actuals: &[f64], lower: &[f64], upper: &[f64], alpha: f64) -> f64 {
+    let mut score = f64::NAN;
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_winkler_score(
+            actuals.as_ptr(),
+            lower.as_ptr(),
+            upper.as_ptr(),
+            actuals.len(),
+            alpha,
+            &mut score,
+            &mut error,
+        )
+    };
+    assert!(ok, "anofox_ts_winkler_score failed");
+    score
+}
+
+#[test]
+fn winkler_score_ffi() {
+    // All within bounds - score is the mean width
+    let score = winkler(
+        &[100.0, 105.0, 110.0],
+        &[95.0, 100.0, 105.0],
+        &[105.0, 110.0, 115.0],
+        0.1,
+    );
+    assert!((score - 10.0).abs() < 1e-9);
+
+    // 5 below the lower bound: width 10 + (2 / 0.1) * 5
+    let score = winkler(&[90.0], &[95.0], &[105.0], 0.1);
+    assert!((score - 110.0).abs() < 1e-9);
+}
+
+#[test]
+fn winkler_score_ffi_rejects_null() {
+    let mut error = AnofoxError::default();
+    let mut score = 0.0;
+    let ok = unsafe {
+        anofox_ts_winkler_score(
+            std::ptr::null(),
+            std::ptr::null(),
+            std::ptr::null(),
+            0,
+            0.1,
+            &mut score,
+            &mut error,
+        )
+    };
+    assert!(!ok);
+}
+
+#[test]
+fn interval_evaluation_ffi() {
+    let actuals = [100.0, 105.0, 110.0];
+    let lower = [95.0, 100.0, 105.0];
+    let upper = [105.0, 110.0, 115.0];
+
+    let mut eval = ConformalEvaluationFFI {
+        coverage: f64::NAN,
+        violation_rate: f64::NAN,
+        mean_width: f64::NAN,
+        winkler_score: f64::NAN,
+        n_observations: 0,
+    };
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_conformal_evaluate(
+            actuals.as_ptr(),
+            lower.as_ptr(),
+            upper.as_ptr(),
+            actuals.len(),
+            0.1,
+            &mut eval,
+            &mut error,
+        )
+    };
+
+    assert!(ok);
+    assert!((eval.coverage - 1.0).abs() < 1e-9);
+    assert!(eval.violation_rate.abs() < 1e-9);
+    assert!((eval.mean_width - 10.0).abs() < 1e-9);
+    assert!((eval.winkler_score - 10.0).abs() < 1e-9);
+    assert_eq!(eval.n_observations, 3);
+}
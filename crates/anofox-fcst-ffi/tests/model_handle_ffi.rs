@@ -0,0 +1,118 @@
+//! Fitted-model handle round trips through the FFI boundary.
+//!
+//! A handle fitted once must predict the same points as a one-shot
+//! `anofox_ts_forecast()` call at every horizon.
+
+mod common;
+
+use anofox_fcst_ffi::types::{AnofoxError, ErrorCode, ForecastOptions, ForecastResult};
+
+use common::{
+    anofox_free_forecast_result, anofox_model_fit, anofox_model_free, anofox_model_predict,
+    anofox_ts_forecast, options,
+};
+
+fn seasonal_data() -> Vec<f64> {
+    (0..60)
+        .map(|i| {
+            let trend = 10.0 + 0.15 * i as f64;
+            let season = 5.0 * (2.0 * std::f64::consts::PI * i as f64 / 12.0).sin();
+            let noise = ((i * 7 + 3) % 11) as f64 * 0.1 - 0.5;
+            trend + season + noise
+        })
+        .collect()
+}
+
+/// Take the point forecasts out of a result and free it.
+unsafe fn take_points(result: &mut ForecastResult) -> Vec<f64> {
+    let points = std::slice::from_raw_parts(result.point_forecasts, result.n_forecasts).to_vec();
+    anofox_free_forecast_result(result);
+    points
+}
+
+fn one_shot(data: &[f64], model_name: &str, horizon: usize) -> Vec<f64> {
+    let opts = ForecastOptions {
+        horizon: horizon as i32,
+        ..options(model_name)
+    };
+    let mut result = ForecastResult::default();
+    let mut error = AnofoxError::default();
+    unsafe {
+        let ok = anofox_ts_forecast(
+            data.as_ptr(),
+            std::ptr::null(),
+            data.len(),
+            &opts,
+            &mut result,
+            &mut error,
+        );
+        assert!(ok, "anofox_ts_forecast failed for {}", model_name);
+        take_points(&mut result)
+    }
+}
+
+#[test]
+fn model_handle_predicts_multiple_horizons() {
+    let data = seasonal_data();
+
+    for model_name in ["Naive", "SES", "HoltWinters"] {
+        // The horizon in the options is ignored by the handle
+        let opts = ForecastOptions {
+            horizon: 1,
+            ..options(model_name)
+        };
+        let mut error = AnofoxError::default();
+
+        unsafe {
+            let handle = anofox_model_fit(
+                data.as_ptr(),
+                std::ptr::null(),
+                data.len(),
+                &opts,
+                &mut error,
+            );
+            assert!(
+                !handle.is_null(),
+                "anofox_model_fit failed for {}",
+                model_name
+            );
+
+            for horizon in [3, 8] {
+                let mut result = ForecastResult::default();
+                let ok = anofox_model_predict(handle, horizon, &mut result, &mut error);
+                assert!(ok, "anofox_model_predict failed for {}", model_name);
+
+                let points = take_points(&mut result);
+                assert_eq!(points.len(), horizon);
+                let expected = one_shot(&data, model_name, horizon);
+                for (p, e) in points.iter().zip(&expected) {
+                    assert!((p - e).abs() < 1e-9, "{}: {} vs {}", model_name, p, e);
+                }
+            }
+
+            anofox_model_free(handle);
+        }
+    }
+}
+
+#[test]
+fn model_fit_reports_errors() {
+    let data = [1.0, 2.0];
+    let opts = options("Naive");
+    let mut error = AnofoxError::default();
+
+    let handle = unsafe {
+        anofox_model_fit(
+            data.as_ptr(),
+            std::ptr::null(),
+            data.len(),
+            &opts,
+            &mut error,
+        )
+    };
+    assert!(handle.is_null());
+    assert_eq!(error.code, ErrorCode::InsufficientData);
+
+    // Freeing a null handle is a no-op
+    unsafe { anofox_model_free(handle) };
+}
@@ -0,0 +1,87 @@
+//! MSTL decomposition through the FFI boundary.
+//!
+//! Covers the `insufficient_data_mode` choices for series shorter than two
+//! seasonal cycles, and the strengths reported alongside the components.
+
+mod common;
+
+use std::ffi::c_int;
+
+use anofox_fcst_ffi::types::{AnofoxError, ErrorCode, MstlResult};
+
+use common::{anofox_free_mstl_result, anofox_ts_mstl_decomposition};
+
+/// Decompose `values` with a single period and the given mode.
+fn decompose(values: &[f64], period: c_int, mode: c_int) -> (bool, MstlResult, AnofoxError) {
+    let periods = [period];
+    let mut result = MstlResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_mstl_decomposition(
+            values.as_ptr(),
+            values.len(),
+            periods.as_ptr(),
+            periods.len(),
+            mode,
+            false,
+            false,
+            &mut result,
+            &mut error,
+        )
+    };
+    (ok, result, error)
+}
+
+#[test]
+fn mstl_short_series_fail_mode() {
+    let values: Vec<f64> = (0..10).map(|i| i as f64).collect();
+    let (ok, _, error) = decompose(&values, 12, 0);
+    assert!(!ok);
+    assert_eq!(error.code, ErrorCode::ComputationError);
+}
+
+#[test]
+fn mstl_short_series_trend_mode() {
+    let values: Vec<f64> = (0..10).map(|i| i as f64).collect();
+    let (ok, mut result, _) = decompose(&values, 12, 1);
+    assert!(ok);
+    assert!(result.decomposition_applied);
+    assert!(!result.trend.is_null());
+    assert!(!result.remainder.is_null());
+    assert_eq!(result.n_observations, values.len());
+    assert_eq!(result.n_seasonal, 0);
+    assert!(result.seasonal_strength.is_nan());
+    unsafe { anofox_free_mstl_result(&mut result) };
+}
+
+#[test]
+fn mstl_short_series_none_mode() {
+    let values: Vec<f64> = (0..10).map(|i| i as f64).collect();
+    let (ok, mut result, _) = decompose(&values, 12, 2);
+    assert!(ok);
+    assert!(!result.decomposition_applied);
+    assert!(result.trend.is_null());
+    assert!(result.remainder.is_null());
+    assert_eq!(result.n_seasonal, 0);
+    unsafe { anofox_free_mstl_result(&mut result) };
+}
+
+#[test]
+fn mstl_rejects_unknown_mode() {
+    let values: Vec<f64> = (0..10).map(|i| i as f64).collect();
+    let (ok, _, error) = decompose(&values, 12, 7);
+    assert!(!ok);
+    assert_eq!(error.code, ErrorCode::InvalidInput);
+}
+
+#[test]
+fn mstl_reports_seasonal_strength() {
+    let values: Vec<f64> = (0..120)
+        .map(|i| 50.0 + 10.0 * (2.0 * std::f64::consts::PI * i as f64 / 12.0).sin())
+        .collect();
+    let (ok, mut result, _) = decompose(&values, 12, 0);
+    assert!(ok);
+    assert_eq!(result.n_seasonal, 1);
+    assert!(result.seasonal_strength > 0.95);
+    unsafe { anofox_free_mstl_result(&mut result) };
+}
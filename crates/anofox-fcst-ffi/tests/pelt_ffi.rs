@@ -0,0 +1,65 @@
+//! Streaming PELT changepoint detection through the FFI boundary.
+
+mod common;
+
+use anofox_fcst_ffi::types::{AnofoxError, ErrorCode};
+
+use common::{anofox_pelt_free, anofox_pelt_new, anofox_pelt_push, PeltHandle};
+
+/// Push `value`, returning the confirmed changepoint if any.
+fn push(handle: *mut PeltHandle, value: f64) -> Result<Option<usize>, ErrorCode> {
+    let mut detected = false;
+    let mut changepoint = 0usize;
+    let mut error = AnofoxError::default();
+    let ok =
+        unsafe { anofox_pelt_push(handle, value, &mut detected, &mut changepoint, &mut error) };
+    if ok {
+        Ok(detected.then_some(changepoint))
+    } else {
+        Err(error.code)
+    }
+}
+
+#[test]
+fn pelt_stream_detects_level_shift() {
+    let mut error = AnofoxError::default();
+    let handle = unsafe { anofox_pelt_new(5, 10.0, 0, 0, &mut error) };
+    assert!(!handle.is_null(), "pelt_new failed: {:?}", error.code);
+
+    let detected: Vec<usize> = (0..100)
+        .filter_map(|i| push(handle, if i < 50 { 0.0 } else { 10.0 }).unwrap())
+        .collect();
+    unsafe { anofox_pelt_free(handle) };
+
+    assert_eq!(detected, vec![50]);
+}
+
+#[test]
+fn pelt_push_rejects_non_finite_values() {
+    let mut error = AnofoxError::default();
+    let handle = unsafe { anofox_pelt_new(5, 10.0, 0, 0, &mut error) };
+    assert!(!handle.is_null());
+
+    let mut detected = Vec::new();
+    for i in 0..100 {
+        if i == 20 {
+            assert_eq!(push(handle, f64::NAN), Err(ErrorCode::InvalidInput));
+            assert_eq!(push(handle, f64::INFINITY), Err(ErrorCode::InvalidInput));
+        }
+        if let Some(cp) = push(handle, if i < 50 { 0.0 } else { 10.0 }).unwrap() {
+            detected.push(cp);
+        }
+    }
+    unsafe { anofox_pelt_free(handle) };
+
+    // The rejected values left the stream positions untouched
+    assert_eq!(detected, vec![50]);
+}
+
+#[test]
+fn pelt_new_rejects_invalid_cost_function() {
+    let mut error = AnofoxError::default();
+    let handle = unsafe { anofox_pelt_new(5, 10.0, 99, 0, &mut error) };
+    assert!(handle.is_null());
+    assert_eq!(error.code, ErrorCode::InvalidInput);
+}
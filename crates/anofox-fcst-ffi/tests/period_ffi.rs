@@ -0,0 +1,146 @@
+//! Period detection helpers through the FFI boundary.
+
+mod common;
+
+use anofox_fcst_ffi::types::{
+    AnofoxError, ErrorCode, FlatMultiPeriodResult, MatrixProfilePeriodResultFFI,
+};
+
+use common::{
+    anofox_free_flat_multi_period_result, anofox_ts_detect_periods_flat,
+    anofox_ts_matrix_profile_period, anofox_ts_snap_period,
+};
+
+/// Snap `detected` to `expected`; `None` when nothing matched.
+fn snap(detected: f64, expected: &[usize], tolerance: f64) -> Option<usize> {
+    let mut period = usize::MAX;
+    let mut matched = false;
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_snap_period(
+            detected,
+            expected.as_ptr(),
+            expected.len(),
+            tolerance,
+            &mut period,
+            &mut matched,
+            &mut error,
+        )
+    };
+    assert!(ok, "snap_period failed: {:?}", error.code);
+    matched.then_some(period)
+}
+
+#[test]
+fn snap_period_within_tolerance() {
+    assert_eq!(snap(12.674, &[7, 12, 365], 0.1), Some(12));
+    assert_eq!(snap(10.0, &[12], 0.1), None);
+    assert_eq!(snap(12.0, &[], 0.1), None);
+}
+
+#[test]
+fn snap_period_rejects_negative_tolerance() {
+    let mut period = 0;
+    let mut matched = false;
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_snap_period(
+            12.0,
+            [12usize].as_ptr(),
+            1,
+            -0.1,
+            &mut period,
+            &mut matched,
+            &mut error,
+        )
+    };
+    assert!(!ok);
+    assert_eq!(error.code, ErrorCode::InvalidInput);
+}
+
+fn sine_series(period: f64, n: usize) -> Vec<f64> {
+    (0..n)
+        .map(|i| 10.0 + 3.0 * (2.0 * std::f64::consts::PI * i as f64 / period).sin())
+        .collect()
+}
+
+/// Primary detected period with its `(matches_expected, matched_period)` validation.
+fn detect_validated(values: &[f64], expected: &[f64], tolerance: f64) -> (f64, bool, f64) {
+    let mut result = FlatMultiPeriodResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_detect_periods_flat(
+            values.as_ptr(),
+            values.len(),
+            c"fft".as_ptr(),
+            0,
+            -1.0,
+            expected.as_ptr(),
+            expected.len(),
+            tolerance,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(ok, "detect_periods_flat failed: {:?}", error.code);
+    assert!(result.n_periods > 0);
+
+    let first = unsafe {
+        (
+            *result.period_values,
+            *result.matches_expected_values,
+            *result.matched_expected_values,
+        )
+    };
+    unsafe { anofox_free_flat_multi_period_result(&mut result) };
+    first
+}
+
+#[test]
+fn detect_periods_flat_matches_expected_period() {
+    let (period, matches, matched) = detect_validated(&sine_series(12.674, 240), &[7.0, 12.0], 0.1);
+    assert!((period - 12.674).abs() / 12.674 < 0.1, "{}", period);
+    assert!(matches);
+    assert_eq!(matched, 12.0);
+}
+
+#[test]
+fn detect_periods_flat_rejects_distant_period() {
+    let (period, matches, matched) = detect_validated(&sine_series(10.0, 240), &[12.0], 0.1);
+    assert!((period - 10.0).abs() < 1.0, "{}", period);
+    assert!(!matches);
+    assert!(matched.is_nan());
+}
+
+#[test]
+fn matrix_profile_period_on_noisy_series() {
+    // Period-12 cycle whose second harmonic drifts slowly, so the closest
+    // repeat of each window is one cycle away, plus uniform noise
+    let mut state: u64 = 5;
+    let values: Vec<f64> = (0..240)
+        .map(|i| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            let e = (state >> 33) as f64 / (1u64 << 31) as f64 - 0.5;
+            let t = 2.0 * std::f64::consts::PI * i as f64 / 12.0;
+            10.0 + 3.0 * t.sin() + 1.5 * (2.0 * t + 0.02 * i as f64).sin() + 0.3 * e
+        })
+        .collect();
+
+    let mut result = MatrixProfilePeriodResultFFI::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_matrix_profile_period(
+            values.as_ptr(),
+            values.len(),
+            0,
+            0,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(ok, "matrix_profile_period failed: {:?}", error.code);
+    assert!((result.period - 12.0).abs() <= 1.0, "{}", result.period);
+    assert!(result.n_motifs > 0);
+    // Default subsequence length is a tenth of the series
+    assert_eq!(result.subsequence_length, 24);
+}
@@ -0,0 +1,104 @@
+//! Data quality thresholds through the FFI boundary.
+
+mod common;
+
+use anofox_fcst_ffi::types::{
+    AnofoxError, DataQualityResult, ErrorCode, QualityReportResult, QualityThresholdsC,
+};
+
+use common::{anofox_free_quality_report_result, anofox_ts_data_quality, anofox_ts_quality_report};
+
+/// 20 values, 30% of them non-zero.
+fn sparse_series() -> Vec<f64> {
+    (0..20)
+        .map(|i| if i % 10 < 3 { i as f64 + 1.0 } else { 0.0 })
+        .collect()
+}
+
+fn thresholds(min_nonzero_ratio: f64) -> QualityThresholdsC {
+    QualityThresholdsC {
+        max_gap_ratio: 0.1,
+        max_missing_ratio: 0.2,
+        min_length: 10,
+        min_nonzero_ratio,
+    }
+}
+
+fn data_quality_passed(values: &[f64], thresholds: &QualityThresholdsC) -> bool {
+    let mut result = DataQualityResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_data_quality(
+            values.as_ptr(),
+            std::ptr::null(),
+            values.len(),
+            thresholds,
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(ok, "data_quality failed: {:?}", error.code);
+    result.passed
+}
+
+#[test]
+fn data_quality_honours_min_nonzero_ratio() {
+    let values = sparse_series();
+
+    assert!(!data_quality_passed(&values, &thresholds(0.5)));
+    assert!(data_quality_passed(&values, &thresholds(0.2)));
+}
+
+#[test]
+fn quality_report_counts_sparse_series() {
+    let dense: Vec<f64> = (1..=20).map(|v| v as f64).collect();
+    let values: Vec<f64> = dense.iter().copied().chain(sparse_series()).collect();
+    let lengths = [20usize, 20];
+    let strict = thresholds(0.5);
+
+    let mut report = QualityReportResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_quality_report(
+            values.as_ptr(),
+            std::ptr::null(),
+            lengths.as_ptr(),
+            lengths.len(),
+            &strict,
+            &mut report,
+            &mut error,
+        )
+    };
+    assert!(ok, "quality_report failed: {:?}", error.code);
+
+    assert_eq!(report.n_total, 2);
+    assert_eq!(report.n_sparse, 1);
+    assert_eq!(report.n_passed, 1);
+    // The report agrees with the per-series verdicts.
+    let n_passed = [&dense, &sparse_series()]
+        .iter()
+        .filter(|s| data_quality_passed(s, &strict))
+        .count();
+    assert_eq!(report.n_passed, n_passed);
+    assert_eq!(report.n_recommendations, 1);
+
+    unsafe { anofox_free_quality_report_result(&mut report) };
+}
+
+#[test]
+fn data_quality_rejects_null_values() {
+    let mut result = DataQualityResult::default();
+    let mut error = AnofoxError::default();
+    let ok = unsafe {
+        anofox_ts_data_quality(
+            std::ptr::null(),
+            std::ptr::null(),
+            0,
+            std::ptr::null(),
+            &mut result,
+            &mut error,
+        )
+    };
+    assert!(!ok);
+    assert_eq!(error.code, ErrorCode::NullPointer);
+}
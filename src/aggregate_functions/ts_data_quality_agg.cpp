@@ -159,6 +159,7 @@ static void TsDataQualityAggFinalize(Vector &state_vector, AggregateInputData &a
             sorted_values.data(),
             validity.data(),
             sorted_values.size(),
+            nullptr,
             &dq_result,
             &error
         );
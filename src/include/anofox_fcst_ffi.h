@@ -10,6 +10,26 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * Model capability bit: uses exogenous regressors.
+ */
+#define MODEL_CAP_EXOG 1
+
+/**
+ * Model capability bit: uses a seasonal period.
+ */
+#define MODEL_CAP_SEASONAL (1 << 1)
+
+/**
+ * Model capability bit: intermittent-demand model.
+ */
+#define MODEL_CAP_INTERMITTENT (1 << 2)
+
+/**
+ * Model capability bit: returns prediction intervals.
+ */
+#define MODEL_CAP_INTERVALS (1 << 3)
+
 /**
  * Conformal method enumeration for FFI.
  */
@@ -26,6 +46,10 @@ typedef enum ConformalMethodFFI {
      * Adaptive intervals scaled by difficulty scores
      */
     ADAPTIVE = 2,
+    /**
+     * Conformalized Quantile Regression (residuals are CQR scores)
+     */
+    CQR = 3,
 } ConformalMethodFFI;
 
 /**
@@ -109,6 +133,50 @@ typedef enum FrequencyType {
     YEARLY = 3,
 } FrequencyType;
 
+/**
+ * Rolling-window aggregation for FFI.
+ */
+typedef enum RollingAggFFI {
+    /**
+     * Arithmetic mean
+     */
+    ROLLING_MEAN = 0,
+    /**
+     * Sample standard deviation
+     */
+    ROLLING_STD = 1,
+    /**
+     * Minimum
+     */
+    ROLLING_MIN = 2,
+    /**
+     * Maximum
+     */
+    ROLLING_MAX = 3,
+} RollingAggFFI;
+
+/**
+ * Opaque handle for a fitted forecast model.
+ *
+ * Created by `anofox_model_fit` and released with `anofox_model_free`.
+ * A handle may be used from any thread, but not from two threads at once.
+ */
+typedef struct FittedModelHandle FittedModelHandle;
+
+/**
+ * Opaque handle for online simple exponential smoothing.
+ *
+ * Created by `anofox_online_ses_new` and released with `anofox_online_ses_free`.
+ */
+typedef struct OnlineSesHandle OnlineSesHandle;
+
+/**
+ * Opaque handle for streaming PELT changepoint detection.
+ *
+ * Created by `anofox_pelt_new` and released with `anofox_pelt_free`.
+ */
+typedef struct PeltHandle PeltHandle;
+
 /**
  * Time series statistics result (34 metrics).
  */
@@ -271,6 +339,105 @@ typedef struct AnofoxError {
     char message[256];
 } AnofoxError;
 
+/**
+ * Rolling-window statistics, one array per statistic (see `anofox_ts_stats_rolling`).
+ *
+ * Every array has `length` entries; entry `i` summarises the trailing window
+ * ending at position `i`.
+ */
+typedef struct TsStatsRollingResult {
+    /**
+     * Number of positions (the series length)
+     */
+    size_t length;
+    /**
+     * Window mean
+     */
+    double *mean;
+    /**
+     * Window standard deviation
+     */
+    double *std_dev;
+    /**
+     * Window minimum
+     */
+    double *min;
+    /**
+     * Window maximum
+     */
+    double *max;
+    /**
+     * Number of NULL values in the window
+     */
+    size_t *n_nulls;
+} TsStatsRollingResult;
+
+/**
+ * Point-accuracy metrics bundle (see `anofox_ts_metrics_all`).
+ */
+typedef struct MetricsBundle {
+    /**
+     * Number of aligned, finite pairs the metrics were computed over
+     */
+    size_t n;
+    /**
+     * Mean Absolute Error
+     */
+    double mae;
+    /**
+     * Mean Squared Error
+     */
+    double mse;
+    /**
+     * Root Mean Squared Error
+     */
+    double rmse;
+    /**
+     * Mean Absolute Percentage Error (NaN if all actuals are zero)
+     */
+    double mape;
+    /**
+     * Symmetric Mean Absolute Percentage Error
+     */
+    double smape;
+    /**
+     * Mean bias (forecast - actual)
+     */
+    double bias;
+    /**
+     * Coefficient of determination
+     */
+    double r2;
+} MetricsBundle;
+
+/**
+ * Per-step accuracy (see `anofox_ts_metrics_by_horizon`).
+ *
+ * All arrays have `n_steps` entries; index `i` is step `i + 1`.
+ */
+typedef struct HorizonMetricsResult {
+    /**
+     * Number of forecast steps
+     */
+    size_t n_steps;
+    /**
+     * Number of origins that reach each step
+     */
+    size_t *counts;
+    /**
+     * MAE at each step
+     */
+    double *mae;
+    /**
+     * RMSE at each step
+     */
+    double *rmse;
+    /**
+     * Mean bias (forecast - actual) at each step
+     */
+    double *bias;
+} HorizonMetricsResult;
+
 /**
  * Seasonality analysis result.
  * C++ API compatible field names.
@@ -965,6 +1132,14 @@ typedef struct MstlResult {
      * Whether decomposition was actually applied
      */
     bool decomposition_applied;
+    /**
+     * Seasonal strength in [0, 1] (NaN without seasonal components)
+     */
+    double seasonal_strength;
+    /**
+     * Trend strength in [0, 1] (NaN if decomposition was skipped)
+     */
+    double trend_strength;
 } MstlResult;
 
 /**
@@ -1030,6 +1205,28 @@ typedef struct FeaturesResult {
     size_t n_features;
 } FeaturesResult;
 
+/**
+ * Feature extraction parameters. Zero fields select the defaults.
+ */
+typedef struct FeatureParamsFFI {
+    /**
+     * Highest autocorrelation lag (0 = 10)
+     */
+    size_t max_lag;
+    /**
+     * Histogram bins for binned entropy (0 = 10)
+     */
+    size_t n_bins;
+    /**
+     * Window length for aggregated linear trend features (0 = length / 10)
+     */
+    size_t rolling_window;
+    /**
+     * Seasonal period for tsfeatures-style STL features (0 = non-seasonal)
+     */
+    size_t seasonal_period;
+} FeatureParamsFFI;
+
 /**
  * Forecast options.
  */
@@ -1092,6 +1289,35 @@ typedef struct ForecastOptions {
      * seasonal-EMA leaf and forecast collapses to flat).
      */
     bool laplace_seasonal_batch_init;
+    /**
+     * Use a MAD-based (outlier-robust) scale for prediction intervals
+     */
+    bool robust_intervals;
+    /**
+     * For Auto* models, pick the best candidate by holdout backtest
+     */
+    bool auto_select_by_holdout;
+    /**
+     * Damp the trend of Holt and HoltWinters
+     */
+    bool damped;
+    /**
+     * Damping factor in (0, 1] when `damped` is set; <= 0 or NaN = estimate
+     */
+    double phi;
+    /**
+     * Prediction interval method ("normal", "bootstrap"), empty = "normal"
+     */
+    char interval_method[16];
+    /**
+     * HoltWinters seasonal mode ("additive", "multiplicative"), empty = "additive"
+     */
+    char seasonal_mode[16];
+    /**
+     * Forecast on the log scale with a bias-corrected back-transform.
+     * Requires strictly positive values.
+     */
+    bool log_transform;
 } ForecastOptions;
 
 /**
@@ -1142,8 +1368,93 @@ typedef struct ForecastResult {
      * Mean Squared Error
      */
     double mse;
+    /**
+     * Holdout score of the selected model (NaN unless `auto_select_by_holdout`)
+     */
+    double holdout_score;
+    /**
+     * Seasonal period used by the forecast (1 when non-seasonal)
+     */
+    size_t seasonal_period_used;
+    /**
+     * Array of warning strings describing automatic fallbacks
+     */
+    char **warnings;
+    /**
+     * Number of warnings
+     */
+    size_t n_warnings;
+    /**
+     * Lower bounds per requested confidence level (`n_levels` arrays of
+     * `n_forecasts`; NULL unless `anofox_ts_forecast_levels` was used)
+     */
+    double **level_lower;
+    /**
+     * Upper bounds per requested confidence level
+     */
+    double **level_upper;
+    /**
+     * Number of requested confidence levels
+     */
+    size_t n_levels;
 } ForecastResult;
 
+/**
+ * Batch forecast result for many series.
+ *
+ * Forecasts for series `i` occupy `[offsets[i], offsets[i + 1])` of the
+ * point and bound arrays. A series that failed has `success[i] == false`
+ * and an empty range.
+ */
+typedef struct ForecastBatchResult {
+    /**
+     * Number of series
+     */
+    size_t n_series;
+    /**
+     * Series start offsets into the forecast arrays (`n_series + 1` entries)
+     */
+    size_t *offsets;
+    /**
+     * Whether each series was forecast successfully
+     */
+    bool *success;
+    /**
+     * Concatenated point forecasts
+     */
+    double *point_forecasts;
+    /**
+     * Concatenated lower confidence bounds
+     */
+    double *lower_bounds;
+    /**
+     * Concatenated upper confidence bounds
+     */
+    double *upper_bounds;
+    /**
+     * Total number of forecast points
+     */
+    size_t n_forecasts;
+} ForecastBatchResult;
+
+/**
+ * Available models with their capability bitflags (`MODEL_CAP_*`).
+ */
+typedef struct ModelsInfoResult {
+    /**
+     * Model names
+     */
+    char **names;
+    /**
+     * Capability bitflags, parallel to `names`
+     */
+    uint32_t *capabilities;
+    /**
+     * Number of models
+     */
+    size_t n_models;
+} ModelsInfoResult;
+
 /**
  * Exogenous regressor data for a single regressor.
  *
@@ -1246,8 +1557,107 @@ typedef struct ForecastOptionsExog {
      * Enable `LaplaceForecaster::with_seasonal_batch_init()` (opt-in).
      */
     bool laplace_seasonal_batch_init;
+    /**
+     * Use a MAD-based (outlier-robust) scale for prediction intervals
+     */
+    bool robust_intervals;
+    /**
+     * Prediction interval method ("normal", "bootstrap"), empty = "normal"
+     */
+    char interval_method[16];
+    /**
+     * Damp the trend of Holt and HoltWinters
+     */
+    bool damped;
+    /**
+     * Damping factor in (0, 1] when `damped` is set; <= 0 or NaN = estimate
+     */
+    double phi;
+    /**
+     * HoltWinters seasonal mode ("additive", "multiplicative"), empty = "additive"
+     */
+    char seasonal_mode[16];
 } ForecastOptionsExog;
 
+/**
+ * Rolling-origin cross-validation result (FFI version).
+ *
+ * Per-window arrays have `n_windows` entries; per-horizon arrays have
+ * `horizon` entries.
+ */
+typedef struct CrossValResultFFI {
+    /**
+     * Number of forecast origins evaluated
+     */
+    size_t n_windows;
+    /**
+     * Forecast horizon of each window
+     */
+    size_t horizon;
+    /**
+     * Training length (forecast origin) of each window
+     */
+    size_t *train_lengths;
+    /**
+     * MAE of each window
+     */
+    double *window_mae;
+    /**
+     * RMSE of each window
+     */
+    double *window_rmse;
+    /**
+     * MASE of each window
+     */
+    double *window_mase;
+    /**
+     * MAE at each horizon step
+     */
+    double *horizon_mae;
+    /**
+     * RMSE at each horizon step
+     */
+    double *horizon_rmse;
+    /**
+     * MASE at each horizon step
+     */
+    double *horizon_mase;
+    /**
+     * Overall MAE
+     */
+    double mae;
+    /**
+     * Overall RMSE
+     */
+    double rmse;
+    /**
+     * Overall MASE
+     */
+    double mase;
+} CrossValResultFFI;
+
+/**
+ * Quality report thresholds (mirrors the core `QualityThresholds`).
+ */
+typedef struct QualityThresholdsC {
+    /**
+     * Maximum allowed gap ratio
+     */
+    double max_gap_ratio;
+    /**
+     * Maximum allowed missing ratio
+     */
+    double max_missing_ratio;
+    /**
+     * Minimum series length
+     */
+    size_t min_length;
+    /**
+     * Minimum non-zero ratio
+     */
+    double min_nonzero_ratio;
+} QualityThresholdsC;
+
 /**
  * Data quality result (per-series).
  */
@@ -1284,48 +1694,304 @@ typedef struct DataQualityResult {
      * Is constant series
      */
     bool is_constant;
+    /**
+     * Passes all threshold checks
+     */
+    bool passed;
 } DataQualityResult;
 
 /**
- * Gap fill result containing dates and values with filled gaps.
+ * Quality report result.
  */
-typedef struct GapFillResult {
+typedef struct QualityReportResult {
     /**
-     * Array of filled date values
+     * Number of series passing all checks
      */
-    int64_t *dates;
+    size_t n_passed;
     /**
-     * Array of filled values
+     * Number of series with gap issues
      */
-    double *values;
+    size_t n_gap_issues;
     /**
-     * Validity bitmask for filled values (bit `i` indicates if `values[i]` is valid)
+     * Number of series with missing value issues
      */
-    uint64_t *validity;
+    size_t n_missing_issues;
     /**
-     * Number of observations after gap filling
+     * Number of constant series
      */
-    size_t length;
-} GapFillResult;
+    size_t n_constant;
+    /**
+     * Total series analyzed
+     */
+    size_t n_total;
+    /**
+     * Array of recommendation strings
+     */
+    char **recommendations;
+    /**
+     * Number of recommendations
+     */
+    size_t n_recommendations;
+    /**
+     * Number of series shorter than the minimum length
+     */
+    size_t n_short;
+    /**
+     * Number of series below the minimum non-zero ratio
+     */
+    size_t n_sparse;
+} QualityReportResult;
 
 /**
- * Filled values result (for imputation functions that return values with validity).
+ * Filled values with a per-index mask marking which positions were imputed.
  */
-typedef struct FilledValuesResult {
+typedef struct FilledMaskResult {
     /**
      * Array of filled values
      */
     double *values;
     /**
-     * Validity bitmask (bit `i` indicates if `values[i]` is valid)
+     * `filled[i]` is true if `values[i]` was NULL and has been imputed
      */
-    uint64_t *validity;
+    bool *filled;
+    /**
+     * Number of values
+     */
+    size_t length;
+} FilledMaskResult;
+
+/**
+ * Stationarity test result (see `anofox_ts_adf_test` and `anofox_ts_kpss_test`).
+ */
+typedef struct StationarityTestResult {
+    /**
+     * Test statistic
+     */
+    double statistic;
+    /**
+     * Approximate p-value
+     */
+    double p_value;
+    /**
+     * Number of lags used
+     */
+    size_t n_lags;
+    /**
+     * Whether the series is judged stationary at the 5% level
+     */
+    bool is_stationary;
+} StationarityTestResult;
+
+/**
+ * Residual-based anomaly detection result (see `anofox_ts_detect_anomalies`).
+ */
+typedef struct AnomalyDetectionResult {
+    /**
+     * Number of points (length of `scores` and `is_anomaly`)
+     */
+    size_t length;
+    /**
+     * Robust z-score of the remainder at every point
+     */
+    double *scores;
+    /**
+     * Whether each point is flagged
+     */
+    bool *is_anomaly;
+    /**
+     * Number of flagged points (length of `indices`)
+     */
+    size_t n_anomalies;
+    /**
+     * Indices of flagged points, in increasing order
+     */
+    size_t *indices;
+} AnomalyDetectionResult;
+
+/**
+ * Croston demand-size and interval components (see `anofox_ts_croston_components`).
+ */
+typedef struct CrostonComponentsResult {
+    /**
+     * Smoothed non-zero demand size
+     */
+    double demand;
+    /**
+     * Smoothed interval between non-zero demands
+     */
+    double interval;
+    /**
+     * Per-period demand forecast `demand / interval`
+     */
+    double forecast;
+} CrostonComponentsResult;
+
+/**
+ * Demand classification result (see `anofox_ts_classify_demand`).
+ */
+typedef struct DemandClassificationFFI {
+    /**
+     * Average demand interval (periods per non-zero demand)
+     */
+    double adi;
+    /**
+     * Squared coefficient of variation of non-zero demand sizes
+     */
+    double cv2;
+    /**
+     * Classification (smooth, intermittent, erratic, lumpy)
+     */
+    char classification[32];
+} DemandClassificationFFI;
+
+/**
+ * Cheap pre-flight checks for a series (see `anofox_ts_series_flags`).
+ */
+typedef struct SeriesFlags {
+    /**
+     * All non-NULL values are equal (or fewer than two are present)
+     */
+    bool is_constant;
+    /**
+     * Fewer non-NULL values than the requested minimum length
+     */
+    bool is_short;
+    /**
+     * Every value is NULL (also true for an empty series)
+     */
+    bool all_null;
+    /**
+     * Number of non-NULL values
+     */
+    size_t n_valid;
+} SeriesFlags;
+
+/**
+ * Series with leading and trailing zeros removed (see `anofox_ts_drop_edge_zeros`).
+ */
+typedef struct TrimmedSeriesResult {
+    /**
+     * Array of remaining values
+     */
+    double *values;
+    /**
+     * Validity bitmask (bit `i` indicates if `values[i]` is valid)
+     */
+    uint64_t *validity;
+    /**
+     * Number of remaining values
+     */
+    size_t length;
+    /**
+     * Number of positions removed from the start
+     */
+    size_t n_leading_removed;
+    /**
+     * Number of positions removed from the end
+     */
+    size_t n_trailing_removed;
+} TrimmedSeriesResult;
+
+/**
+ * Gap fill result containing dates and values with filled gaps.
+ */
+typedef struct GapFillResult {
+    /**
+     * Array of filled date values
+     */
+    int64_t *dates;
+    /**
+     * Array of filled values
+     */
+    double *values;
+    /**
+     * Validity bitmask for filled values (bit `i` indicates if `values[i]` is valid)
+     */
+    uint64_t *validity;
+    /**
+     * Number of observations after gap filling
+     */
+    size_t length;
+} GapFillResult;
+
+/**
+ * Detected frequency with a human-readable label.
+ */
+typedef struct FrequencyLabelResult {
+    /**
+     * Modal interval between observations in seconds
+     */
+    int64_t frequency;
+    /**
+     * Label such as "hourly", "daily", "weekly", "monthly" or "other"
+     */
+    char label[16];
+} FrequencyLabelResult;
+
+/**
+ * Regularity of the spacing between timestamps.
+ */
+typedef struct FrequencyReportResult {
+    /**
+     * Most common interval between observations in seconds
+     */
+    int64_t modal_interval;
+    /**
+     * Fraction of intervals matching the modal one
+     */
+    double match_fraction;
+    /**
+     * Smallest interval in seconds
+     */
+    int64_t min_interval;
+    /**
+     * Largest interval in seconds
+     */
+    int64_t max_interval;
+    /**
+     * Whether at least 90% of intervals match the modal one
+     */
+    bool is_regular;
+} FrequencyReportResult;
+
+/**
+ * Filled values result (for imputation functions that return values with validity).
+ */
+typedef struct FilledValuesResult {
+    /**
+     * Array of filled values
+     */
+    double *values;
+    /**
+     * Validity bitmask (bit `i` indicates if `values[i]` is valid)
+     */
+    uint64_t *validity;
     /**
      * Number of values
      */
     size_t length;
 } FilledValuesResult;
 
+/**
+ * Regressor columns for the exogenous forecast path.
+ *
+ * Layout: `values` is flattened column-major as [col0_rows..., col1_rows..., ...]
+ */
+typedef struct RegressorMatrixResult {
+    /**
+     * Flattened regressor values (n_columns * n_rows, column-major order)
+     */
+    double *values;
+    /**
+     * Number of rows (observations)
+     */
+    size_t n_rows;
+    /**
+     * Number of regressor columns
+     */
+    size_t n_columns;
+} RegressorMatrixResult;
+
 /**
  * Result of conformal prediction with prediction intervals.
  */
@@ -1631,32 +2297,6 @@ typedef struct DateArray {
     enum DateType date_type;
 } DateArray;
 
-/**
- * Quality report result.
- */
-typedef struct QualityReportResult {
-    /**
-     * Number of series passing all checks
-     */
-    size_t n_passed;
-    /**
-     * Number of series with gap issues
-     */
-    size_t n_gap_issues;
-    /**
-     * Number of series with missing value issues
-     */
-    size_t n_missing_issues;
-    /**
-     * Number of constant series
-     */
-    size_t n_constant;
-    /**
-     * Total series analyzed
-     */
-    size_t n_total;
-} QualityReportResult;
-
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
@@ -1673,6 +2313,20 @@ bool anofox_ts_stats(const double *values,
                      struct TsStatsResult *out_result,
                      struct AnofoxError *out_error);
 
+/**
+ * Compute time series statistics over a trailing window at every position.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null (`validity` may be null).
+ * Free the result with `anofox_free_ts_stats_rolling_result`.
+ */
+bool anofox_ts_stats_rolling(const double *values,
+                             const uint64_t *validity,
+                             size_t length,
+                             size_t window,
+                             struct TsStatsRollingResult *out_result,
+                             struct AnofoxError *out_error);
+
 /**
  * Compute time series statistics with date information for gap detection.
  *
@@ -1772,6 +2426,45 @@ bool anofox_ts_smape(const double *actual,
                      double *out_result,
                      struct AnofoxError *out_error);
 
+/**
+ * Median Absolute Percentage Error
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_mdape(const double *actual,
+                     size_t actual_len,
+                     const double *forecast,
+                     size_t forecast_len,
+                     double *out_result,
+                     struct AnofoxError *out_error);
+
+/**
+ * Symmetric Median Absolute Percentage Error
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_smdape(const double *actual,
+                      size_t actual_len,
+                      const double *forecast,
+                      size_t forecast_len,
+                      double *out_result,
+                      struct AnofoxError *out_error);
+
+/**
+ * Mean Arctangent Absolute Percentage Error (radians, bounded in [0, pi/2])
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_maape(const double *actual,
+                     size_t actual_len,
+                     const double *forecast,
+                     size_t forecast_len,
+                     double *out_result,
+                     struct AnofoxError *out_error);
+
 /**
  * R-squared (Coefficient of Determination)
  *
@@ -1785,6 +2478,19 @@ bool anofox_ts_r2(const double *actual,
                   double *out_result,
                   struct AnofoxError *out_error);
 
+/**
+ * Lin's concordance correlation coefficient
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_ccc(const double *actual,
+                   size_t actual_len,
+                   const double *forecast,
+                   size_t forecast_len,
+                   double *out_result,
+                   struct AnofoxError *out_error);
+
 /**
  * Bias (Mean Error)
  *
@@ -1798,6 +2504,19 @@ bool anofox_ts_bias(const double *actual,
                     double *out_result,
                     struct AnofoxError *out_error);
 
+/**
+ * Tracking signal (cumulative error over MAD; |value| > 4 suggests bias)
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_tracking_signal(const double *actual,
+                               size_t actual_len,
+                               const double *forecast,
+                               size_t forecast_len,
+                               double *out_result,
+                               struct AnofoxError *out_error);
+
 /**
  * Relative MAE comparing two models.
  *
@@ -1813,6 +2532,21 @@ bool anofox_ts_rmae(const double *actual,
                     double *out_result,
                     struct AnofoxError *out_error);
 
+/**
+ * Geometric Mean Relative Absolute Error against a benchmark forecast.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_gmrae(const double *actual,
+                     size_t actual_len,
+                     const double *forecast,
+                     size_t forecast_len,
+                     const double *baseline,
+                     size_t baseline_len,
+                     double *out_result,
+                     struct AnofoxError *out_error);
+
 /**
  * Mean Absolute Scaled Error.
  *
@@ -1828,6 +2562,106 @@ bool anofox_ts_mase(const double *actual,
                     double *out_result,
                     struct AnofoxError *out_error);
 
+/**
+ * Mean Absolute Scaled Error against the in-sample seasonal naive error of `train`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_mase_scaled(const double *actual,
+                           size_t actual_len,
+                           const double *forecast,
+                           size_t forecast_len,
+                           const double *train,
+                           size_t train_len,
+                           size_t period,
+                           double *out_result,
+                           struct AnofoxError *out_error);
+
+/**
+ * In-sample seasonal naive errors (`train[i] - train[i - period]`), the
+ * MASE scaling errors. `period = 1` gives the ordinary naive errors.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_seasonal_naive_errors(const double *train,
+                                     size_t length,
+                                     size_t period,
+                                     double **out_values,
+                                     size_t *out_length,
+                                     struct AnofoxError *out_error);
+
+/**
+ * Mean Scaled Interval Score of `(1 - alpha)` prediction intervals.
+ *
+ * `train` is the training series; the interval score is scaled by its
+ * seasonal naive MAE with lag `period`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `actual`, `lower` and
+ * `upper` must have `length` elements, `train` must have `train_length`.
+ */
+bool anofox_ts_msis(const double *actual,
+                    const double *lower,
+                    const double *upper,
+                    size_t length,
+                    const double *train,
+                    size_t train_length,
+                    size_t period,
+                    double alpha,
+                    double *out_result,
+                    struct AnofoxError *out_error);
+
+/**
+ * Overall Weighted Average of sMAPE and MASE relative to the Naive2 benchmark.
+ *
+ * Naive2 is computed from `train` (seasonally adjusted with lag `period`
+ * when the series is significantly seasonal).
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_owa(const double *actual,
+                   size_t actual_len,
+                   const double *forecast,
+                   size_t forecast_len,
+                   const double *train,
+                   size_t train_len,
+                   size_t period,
+                   double *out_result,
+                   struct AnofoxError *out_error);
+
+/**
+ * Root Mean Squared Scaled Error.
+ *
+ * `baseline` is the training series used for the naive-error scale.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_rmsse(const double *actual,
+                     size_t actual_len,
+                     const double *forecast,
+                     size_t forecast_len,
+                     const double *baseline,
+                     size_t baseline_len,
+                     double *out_result,
+                     struct AnofoxError *out_error);
+
+/**
+ * Theil's U2 statistic (forecast vs one-step naive; < 1 beats naive)
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_theil_u2(const double *actual,
+                        size_t actual_len,
+                        const double *forecast,
+                        size_t forecast_len,
+                        double *out_result,
+                        struct AnofoxError *out_error);
+
 /**
  * Quantile loss function.
  *
@@ -1857,6 +2691,59 @@ bool anofox_ts_mqloss(const double *actual,
                       double *out_result,
                       struct AnofoxError *out_error);
 
+/**
+ * Quantile loss at each quantile level.
+ *
+ * On success `out_values` holds `n_levels` losses aligned with `levels`;
+ * free it with `anofox_free_double_array`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ * quantiles is a 2D array: n_levels arrays, each of length actual_len.
+ */
+bool anofox_ts_mqloss_by_level(const double *actual,
+                               size_t actual_len,
+                               const double *const *quantiles,
+                               size_t n_levels,
+                               const double *levels,
+                               double **out_values,
+                               struct AnofoxError *out_error);
+
+/**
+ * Empirical coverage (fraction of actuals at or below the forecast) at each
+ * quantile level.
+ *
+ * On success `out_values` holds `n_levels` proportions aligned with `levels`;
+ * free it with `anofox_free_double_array`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ * quantiles is a 2D array: n_levels arrays, each of length actual_len.
+ */
+bool anofox_ts_quantile_coverage(const double *actual,
+                                 size_t actual_len,
+                                 const double *const *quantiles,
+                                 size_t n_levels,
+                                 const double *levels,
+                                 double **out_values,
+                                 struct AnofoxError *out_error);
+
+/**
+ * Continuous Ranked Probability Score from quantile forecasts.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ * quantiles is a 2D array: n_levels arrays, each of length actual_len.
+ * levels must be strictly increasing in (0, 1).
+ */
+bool anofox_ts_crps(const double *actual,
+                    size_t actual_len,
+                    const double *const *quantiles,
+                    size_t n_levels,
+                    const double *levels,
+                    double *out_result,
+                    struct AnofoxError *out_error);
+
 /**
  * Coverage of prediction intervals.
  *
@@ -1870,6 +2757,40 @@ bool anofox_ts_coverage(const double *actual,
                         double *out_result,
                         struct AnofoxError *out_error);
 
+/**
+ * Compute the standard point-accuracy metrics (MAE, MSE, RMSE, MAPE, sMAPE,
+ * bias, R²) in a single call.
+ *
+ * Non-finite (actual, forecast) pairs are dropped once, so all metrics in the
+ * bundle are computed over the same observations.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_metrics_all(const double *actual,
+                           size_t actual_len,
+                           const double *forecast,
+                           size_t forecast_len,
+                           struct MetricsBundle *out_result,
+                           struct AnofoxError *out_error);
+
+/**
+ * Compute MAE, RMSE and bias separately for each forecast step.
+ *
+ * `actual` and `forecast` are 2D arrays of `n_origins` rows; row `i` holds
+ * `lengths[i]` values, the 1..h-step-ahead values of one forecast origin.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Each row must have
+ * `lengths[i]` elements.
+ */
+bool anofox_ts_metrics_by_horizon(const double *const *actual,
+                                  const double *const *forecast,
+                                  const size_t *lengths,
+                                  size_t n_origins,
+                                  struct HorizonMetricsResult *out_result,
+                                  struct AnofoxError *out_error);
+
 /**
  * Detect seasonal periods in a time series.
  *
@@ -1886,8 +2807,13 @@ bool anofox_ts_detect_seasonality(const double *values,
 /**
  * Analyze seasonality in a time series.
  *
+ * `strength_method` ("variance", "spectral" or "wavelet") selects the
+ * seasonal-strength estimator; null keeps the default autocorrelation-based
+ * strength.
+ *
  * # Safety
- * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ * All pointer arguments must be valid and non-null (except `strength_method`).
+ * Arrays must have the specified lengths.
  * Note: timestamps parameter is for API compatibility but is ignored internally.
  */
 bool anofox_ts_analyze_seasonality(const int64_t *_timestamps,
@@ -1895,6 +2821,7 @@ bool anofox_ts_analyze_seasonality(const int64_t *_timestamps,
                                    const double *values,
                                    size_t length,
                                    int max_period,
+                                   const char *strength_method,
                                    struct SeasonalityResult *out_result,
                                    struct AnofoxError *out_error);
 
@@ -2084,13 +3011,16 @@ bool anofox_ts_stl_period(const double *values,
  * Uses Matrix Profile to find motifs and estimate periodicity from
  * the distribution of motif distances.
  *
+ * `subsequence_length` 0 selects `length / 10`; `exclusion_zone` (the
+ * minimum distance between motif pairs) 0 selects `subsequence_length / 4`.
+ *
  * # Safety
  * All pointer arguments must be valid and non-null.
  */
 bool anofox_ts_matrix_profile_period(const double *values,
                                      size_t length,
                                      size_t subsequence_length,
-                                     size_t n_best,
+                                     size_t exclusion_zone,
                                      struct MatrixProfilePeriodResultFFI *out_result,
                                      struct AnofoxError *out_error);
 
@@ -2108,9 +3038,29 @@ bool anofox_ts_sazed_period(const double *values,
                             struct SazedPeriodResultFFI *out_result,
                             struct AnofoxError *out_error);
 
+/**
+ * Snap a detected period to the closest expected integer period.
+ *
+ * Sets `out_matched` to false (and `out_period` to 0) when no expected
+ * period lies within the relative `tolerance` (e.g. 0.1 = 10%).
+ *
+ * # Safety
+ * `expected` must hold `n_expected` elements (it may be null when
+ * `n_expected` is 0); the output pointers must be valid.
+ */
+bool anofox_ts_snap_period(double detected,
+                           const size_t *expected,
+                           size_t n_expected,
+                           double tolerance,
+                           size_t *out_period,
+                           bool *out_matched,
+                           struct AnofoxError *out_error);
+
 /**
  * Detect peaks in time series.
  *
+ * Peaks below `min_height` are dropped; pass NaN to keep all peaks.
+ *
  * # Safety
  * All pointer arguments must be valid and non-null.
  */
@@ -2118,6 +3068,7 @@ bool anofox_ts_detect_peaks(const double *values,
                             size_t length,
                             double min_distance,
                             double min_prominence,
+                            double min_height,
                             bool smooth_first,
                             struct PeakDetectionResultFFI *out_result,
                             struct AnofoxError *out_error);
@@ -2137,6 +3088,11 @@ bool anofox_ts_analyze_peak_timing(const double *values,
 /**
  * Detrend time series using specified method.
  *
+ * `method` is one of "linear", "polynomial", "diff", "diff2", "loess" or
+ * "auto" (default, also used for null or unrecognised names). The result
+ * holds both the extracted trend and the detrended series; release it with
+ * `anofox_free_detrend_result`.
+ *
  * # Safety
  * All pointer arguments must be valid and non-null.
  */
@@ -2149,6 +3105,10 @@ bool anofox_ts_detrend(const double *values,
 /**
  * Decompose time series into trend, seasonal, and remainder.
  *
+ * `method` is "additive" (default) or "multiplicative". The three
+ * components are returned as separate arrays of the input length; release
+ * them with `anofox_free_decompose_result`.
+ *
  * # Safety
  * All pointer arguments must be valid and non-null.
  */
@@ -2187,6 +3147,23 @@ bool anofox_ts_seasonal_strength_windowed(const double *values,
                                           size_t *out_n_windows,
                                           struct AnofoxError *out_error);
 
+/**
+ * Compute seasonal and trend strength at a fixed, caller-supplied period.
+ *
+ * Unlike `anofox_ts_analyze_seasonality`, no period detection is performed.
+ * Seasonal strength uses the variance method; trend strength uses a classical
+ * decomposition at `period`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null.
+ */
+bool anofox_ts_strength_at_period(const double *values,
+                                  size_t length,
+                                  double period,
+                                  double *out_seasonal,
+                                  double *out_trend,
+                                  struct AnofoxError *out_error);
+
 /**
  * Classify seasonality type and pattern.
  *
@@ -2250,6 +3227,12 @@ bool anofox_ts_detect_amplitude_modulation(const double *values,
  *   - 1 (Trend): Apply trend-only decomposition, seasonal components are empty
  *   - 2 (None): Skip decomposition entirely, return empty result
  *
+ *   Any other value is rejected with `InvalidInput`.
+ * * `multiplicative` - Decompose `log(values)` and exponentiate the components,
+ *   so `values = trend * seasonal * remainder`. Requires strictly positive values.
+ * * `robust` - Downweight outliers with the STL robustness iterations so
+ *   spikes stay in the remainder instead of bending the trend.
+ *
  * # Safety
  * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
  */
@@ -2258,12 +3241,35 @@ bool anofox_ts_mstl_decomposition(const double *values,
                                   const int *periods,
                                   size_t n_periods,
                                   int insufficient_data_mode,
+                                  bool multiplicative,
+                                  bool robust,
                                   struct MstlResult *out_result,
                                   struct AnofoxError *out_error);
 
+/**
+ * Remove the seasonal component of a series using MSTL.
+ *
+ * Returns `values - seasonal`, or `values / seasonal` when `multiplicative`
+ * (strictly positive values only). Free the output with
+ * `anofox_free_double_array`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `values` must have `length` elements.
+ */
+bool anofox_ts_seasonally_adjust(const double *values,
+                                 size_t length,
+                                 size_t period,
+                                 bool multiplicative,
+                                 double **out_values,
+                                 size_t *out_length,
+                                 struct AnofoxError *out_error);
+
 /**
  * Detect changepoints using PELT algorithm.
  *
+ * `cost_function` selects the segment cost: 0 = L2, 1 = L1 (robust to
+ * outliers), 2 = Normal/RBF (change in mean and variance).
+ *
  * # Safety
  * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
  */
@@ -2271,6 +3277,7 @@ bool anofox_ts_detect_changepoints(const double *values,
                                    size_t length,
                                    int min_size,
                                    double penalty,
+                                   int cost_function,
                                    struct ChangepointResult *out_result,
                                    struct AnofoxError *out_error);
 
@@ -2287,6 +3294,92 @@ bool anofox_ts_detect_changepoints_bocpd(const double *values,
                                          struct BocpdResult *out_result,
                                          struct AnofoxError *out_error);
 
+/**
+ * Create a streaming PELT changepoint detector.
+ *
+ * `cost_function` uses the same codes as `anofox_ts_detect_changepoints`.
+ * `max_history` limits how far back a segment may start (0 = unbounded).
+ * Returns null on error; the handle must be released with `anofox_pelt_free`.
+ *
+ * # Safety
+ * `out_error` may be null; otherwise it must point to a valid `AnofoxError`.
+ */
+struct PeltHandle *anofox_pelt_new(int min_size,
+                                   double penalty,
+                                   int cost_function,
+                                   size_t max_history,
+                                   struct AnofoxError *out_error);
+
+/**
+ * Push one observation into a streaming PELT detector.
+ *
+ * On success `out_detected` tells whether a new changepoint was confirmed
+ * and `out_changepoint` holds its index (0-based position in the stream).
+ * Non-finite values are rejected with `InvalidInput` and leave the detector
+ * unchanged, so stream positions stay aligned with the caller's rows.
+ *
+ * # Safety
+ * `handle` must come from `anofox_pelt_new` and not have been freed.
+ */
+bool anofox_pelt_push(struct PeltHandle *handle,
+                      double value,
+                      bool *out_detected,
+                      size_t *out_changepoint,
+                      struct AnofoxError *out_error);
+
+/**
+ * Free a streaming PELT detector.
+ *
+ * # Safety
+ * `handle` must come from `anofox_pelt_new` (or be null) and not be used afterwards.
+ */
+void anofox_pelt_free(struct PeltHandle *handle);
+
+/**
+ * Create an online simple exponential smoothing updater.
+ *
+ * Returns null on error; the handle must be released with
+ * `anofox_online_ses_free`.
+ *
+ * # Safety
+ * `out_error` may be null; otherwise it must point to a valid `AnofoxError`.
+ */
+struct OnlineSesHandle *anofox_online_ses_new(double alpha, struct AnofoxError *out_error);
+
+/**
+ * Push one observation into an online SES updater. Non-finite values are skipped.
+ *
+ * # Safety
+ * `handle` must come from `anofox_online_ses_new` and not have been freed.
+ */
+bool anofox_online_ses_update(struct OnlineSesHandle *handle,
+                              double value,
+                              struct AnofoxError *out_error);
+
+/**
+ * Forecast `horizon` steps from the current level of an online SES updater.
+ *
+ * Fails with `InsufficientData` before the first observation. Free
+ * `out_values` with `anofox_free_double_array`.
+ *
+ * # Safety
+ * `handle` must come from `anofox_online_ses_new` and not have been freed;
+ * `out_values` and `out_length` must be valid.
+ */
+bool anofox_online_ses_forecast(const struct OnlineSesHandle *handle,
+                                size_t horizon,
+                                double **out_values,
+                                size_t *out_length,
+                                struct AnofoxError *out_error);
+
+/**
+ * Free an online SES updater.
+ *
+ * # Safety
+ * `handle` must come from `anofox_online_ses_new` (or be null) and not be used afterwards.
+ */
+void anofox_online_ses_free(struct OnlineSesHandle *handle);
+
 /**
  * Extract time series features.
  *
@@ -2298,6 +3391,35 @@ bool anofox_ts_features(const double *values,
                         struct FeaturesResult *out_result,
                         struct AnofoxError *out_error);
 
+/**
+ * Extract only the named time series features.
+ *
+ * Unknown feature names are reported as an error instead of being ignored.
+ * Features that are undefined for the series are omitted from the result.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `feature_names` must hold
+ * `n_names` valid null-terminated strings.
+ */
+bool anofox_ts_features_subset(const double *values,
+                               size_t length,
+                               const char *const *feature_names,
+                               size_t n_names,
+                               struct FeaturesResult *out_result,
+                               struct AnofoxError *out_error);
+
+/**
+ * Extract time series features with tunable parameters.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_features_with_params(const double *values,
+                                    size_t length,
+                                    const struct FeatureParamsFFI *params,
+                                    struct FeaturesResult *out_result,
+                                    struct AnofoxError *out_error);
+
 /**
  * Validate feature parameter keys and return warnings for unknown keys.
  *
@@ -2336,6 +3458,134 @@ bool anofox_ts_forecast(const double *values,
                         struct ForecastResult *out_result,
                         struct AnofoxError *out_error);
 
+/**
+ * Forecast with bounds at several confidence levels from a single fit.
+ *
+ * Behaves like `anofox_ts_forecast`; additionally `out_result.level_lower[i]`
+ * and `out_result.level_upper[i]` hold `n_forecasts` bounds at `levels[i]`
+ * (each in (0, 1)). Free the result with `anofox_free_forecast_result`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `levels` must hold
+ * `n_levels` elements (it may be null when `n_levels` is 0).
+ */
+bool anofox_ts_forecast_levels(const double *values,
+                               const uint64_t *validity,
+                               size_t length,
+                               const struct ForecastOptions *options,
+                               const double *levels,
+                               size_t n_levels,
+                               struct ForecastResult *out_result,
+                               struct AnofoxError *out_error);
+
+/**
+ * Fit a forecast model once so it can be predicted at several horizons.
+ *
+ * Uses the same options as `anofox_ts_forecast`; `options.horizon` is
+ * ignored. Returns null on error; the handle must be released with
+ * `anofox_model_free`. Handles are not synchronised: use each handle from
+ * one thread at a time.
+ *
+ * # Safety
+ * `values` (and `validity`, if non-null) must hold `length` elements and
+ * `options` must be valid. `out_error` may be null.
+ */
+struct FittedModelHandle *anofox_model_fit(const double *values,
+                                           const uint64_t *validity,
+                                           size_t length,
+                                           const struct ForecastOptions *options,
+                                           struct AnofoxError *out_error);
+
+/**
+ * Forecast `horizon` steps ahead from a fitted model handle.
+ *
+ * Forecasts continue from the state estimated by `anofox_model_fit`; the
+ * model is not re-estimated, so repeated calls are cheap. Free the result
+ * with `anofox_free_forecast_result`.
+ *
+ * # Safety
+ * `handle` must come from `anofox_model_fit` and not have been freed.
+ * `out_result` must be valid.
+ */
+bool anofox_model_predict(const struct FittedModelHandle *handle,
+                          size_t horizon,
+                          struct ForecastResult *out_result,
+                          struct AnofoxError *out_error);
+
+/**
+ * Free a fitted model handle.
+ *
+ * # Safety
+ * `handle` must come from `anofox_model_fit` (or be null) and not be used afterwards.
+ */
+void anofox_model_free(struct FittedModelHandle *handle);
+
+/**
+ * Forecast many series with shared options in one call.
+ *
+ * `values` holds all series back to back; series `i` spans
+ * `[offsets[i], offsets[i + 1])`, so `offsets` has `n_series + 1`
+ * non-decreasing entries. `validity` (may be null) is a bitmask over the
+ * whole flattened array. A series that cannot be forecast is flagged in
+ * `success` rather than failing the call. `n_threads` is a thread-count hint
+ * for builds with the `parallel` feature (0 = rayon default). Free the
+ * result with `anofox_free_forecast_batch_result`.
+ *
+ * # Safety
+ * `values` must hold `offsets[n_series]` elements, `offsets` must hold
+ * `n_series + 1` elements, and `options` and `out_result` must be valid.
+ */
+bool anofox_ts_forecast_batch(const double *values,
+                              const uint64_t *validity,
+                              const size_t *offsets,
+                              size_t n_series,
+                              const struct ForecastOptions *options,
+                              size_t n_threads,
+                              struct ForecastBatchResult *out_result,
+                              struct AnofoxError *out_error);
+
+/**
+ * List all models with their capability bitflags (`MODEL_CAP_*`).
+ *
+ * # Safety
+ * `out_result` must be valid and non-null. Free the result with
+ * `anofox_free_models_info_result`.
+ */
+bool anofox_ts_models_info(struct ModelsInfoResult *out_result, struct AnofoxError *out_error);
+
+/**
+ * Free a ModelsInfoResult.
+ *
+ * # Safety
+ * The result pointer must be valid or null.
+ */
+void anofox_free_models_info_result(struct ModelsInfoResult *result);
+
+/**
+ * Forecast with several models and average the results.
+ *
+ * # Arguments
+ * * `model_names` - Array of `n_models` model name strings (e.g. "Naive", "AutoETS")
+ * * `weights` - Optional array of `n_models` non-negative weights (NULL = equal weights)
+ * * `horizon` - Forecast horizon
+ *
+ * Point forecasts are the weighted mean of the members; intervals take the
+ * widest member bounds. Fitted values and residuals are not populated.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null (except `validity` and
+ * `weights`). Arrays must have the specified lengths.
+ */
+bool anofox_ts_forecast_ensemble(const double *values,
+                                 const uint64_t *validity,
+                                 size_t length,
+                                 const char *const *model_names,
+                                 size_t n_models,
+                                 const double *weights,
+                                 int horizon,
+                                 struct ForecastResult *out_result,
+                                 struct AnofoxError *out_error);
+
 /**
  * Generate time series forecasts with exogenous variables.
  *
@@ -2356,6 +3606,7 @@ bool anofox_ts_forecast(const double *values,
  * - AutoARIMA, ARIMA (ARIMAX)
  * - OptimizedTheta, DynamicTheta
  * - MFLES
+ * - ETS, AutoETS (ETSX), HoltWinters (HoltWintersX)
  *
  * Other models will ignore the exogenous data and produce a standard forecast.
  *
@@ -2372,6 +3623,25 @@ bool anofox_ts_forecast_exog(const double *values,
                              struct ForecastResult *out_result,
                              struct AnofoxError *out_error);
 
+/**
+ * Rolling-origin cross-validation of a forecast model.
+ *
+ * The last window forecasts the final `horizon` observations; each earlier
+ * window moves the forecast origin back by `step`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null (except `validity`).
+ * Arrays must have the specified lengths.
+ */
+bool anofox_ts_cross_validate(const double *values,
+                              const uint64_t *validity,
+                              size_t length,
+                              const struct ForecastOptions *options,
+                              size_t n_windows,
+                              size_t step,
+                              struct CrossValResultFFI *out_result,
+                              struct AnofoxError *out_error);
+
 /**
  * Free a C string previously allocated by an inspect / explain call.
  *
@@ -2450,15 +3720,38 @@ bool anofox_ts_forecast_explain(const double *values,
 /**
  * Compute data quality metrics.
  *
+ * `thresholds` may be null to use the default thresholds; they only affect
+ * the `passed` flag, not the dimension scores.
+ *
  * # Safety
- * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ * All pointer arguments must be valid and non-null (except `validity` and
+ * `thresholds`). Arrays must have the specified lengths.
  */
 bool anofox_ts_data_quality(const double *values,
                             const uint64_t *validity,
                             size_t length,
+                            const struct QualityThresholdsC *thresholds,
                             struct DataQualityResult *out_result,
                             struct AnofoxError *out_error);
 
+/**
+ * Generate a quality report with recommendations for multiple series.
+ *
+ * `values` holds all series concatenated; `series_lengths[i]` is the length
+ * of series `i`. A null `thresholds` pointer uses the default thresholds.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null (except `validity` and
+ * `thresholds`). Arrays must have the specified lengths.
+ */
+bool anofox_ts_quality_report(const double *values,
+                              const uint64_t *validity,
+                              const size_t *series_lengths,
+                              size_t n_series,
+                              const struct QualityThresholdsC *thresholds,
+                              struct QualityReportResult *out_result,
+                              struct AnofoxError *out_error);
+
 /**
  * Fill NULL values with a constant.
  *
@@ -2472,6 +3765,19 @@ bool anofox_ts_fill_nulls_const(const double *values,
                                 double **out_values,
                                 struct AnofoxError *out_error);
 
+/**
+ * Fill NULL values with a constant and report which positions were imputed.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_fill_nulls_const_masked(const double *values,
+                                       const uint64_t *validity,
+                                       size_t length,
+                                       double fill_value,
+                                       struct FilledMaskResult *out_result,
+                                       struct AnofoxError *out_error);
+
 /**
  * Fill NULL values with the series mean.
  *
@@ -2484,6 +3790,109 @@ bool anofox_ts_fill_nulls_mean(const double *values,
                                double **out_values,
                                struct AnofoxError *out_error);
 
+/**
+ * Compute the sample autocorrelation function for lags `0..=max_lag`.
+ *
+ * Writes `max_lag + 1` values; free them with `anofox_free_double_array`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `values` must have `length` elements.
+ */
+bool anofox_ts_acf(const double *values,
+                   size_t length,
+                   size_t max_lag,
+                   double **out_values,
+                   size_t *out_length,
+                   struct AnofoxError *out_error);
+
+/**
+ * Compute the sample partial autocorrelation function for lags `0..=max_lag`.
+ *
+ * Writes `max_lag + 1` values; free them with `anofox_free_double_array`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `values` must have `length` elements.
+ */
+bool anofox_ts_pacf(const double *values,
+                    size_t length,
+                    size_t max_lag,
+                    double **out_values,
+                    size_t *out_length,
+                    struct AnofoxError *out_error);
+
+/**
+ * Augmented Dickey-Fuller unit-root test (constant, no trend).
+ *
+ * `max_lag` is the number of augmentation lags; pass a negative value to
+ * select it by AIC. The series is stationary when the unit root is rejected.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `values` must have `length` elements.
+ */
+bool anofox_ts_adf_test(const double *values,
+                        size_t length,
+                        int max_lag,
+                        struct StationarityTestResult *out_result,
+                        struct AnofoxError *out_error);
+
+/**
+ * KPSS level-stationarity test.
+ *
+ * `n_lags` is the Bartlett bandwidth; pass a negative value for the default.
+ * The series is stationary when level stationarity is not rejected.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `values` must have `length` elements.
+ */
+bool anofox_ts_kpss_test(const double *values,
+                         size_t length,
+                         int n_lags,
+                         struct StationarityTestResult *out_result,
+                         struct AnofoxError *out_error);
+
+/**
+ * Flag anomalies from the remainder of a robust MSTL decomposition.
+ *
+ * Points whose robust z-score (median/MAD of the remainder) exceeds
+ * `threshold` in absolute value are flagged. `period` below 2 removes the
+ * trend only.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `values` must have `length` elements.
+ * Free the result with `anofox_free_anomaly_result`.
+ */
+bool anofox_ts_detect_anomalies(const double *values,
+                                size_t length,
+                                size_t period,
+                                double threshold,
+                                struct AnomalyDetectionResult *out_result,
+                                struct AnofoxError *out_error);
+
+/**
+ * Compute the Croston demand-size and interval components.
+ *
+ * Pass NaN as `alpha` for the default smoothing parameter (0.1).
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `values` must have `length` elements.
+ */
+bool anofox_ts_croston_components(const double *values,
+                                  size_t length,
+                                  double alpha,
+                                  struct CrostonComponentsResult *out_result,
+                                  struct AnofoxError *out_error);
+
+/**
+ * Classify a demand history into the Syntetos-Boylan quadrants.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. `values` must have `length` elements.
+ */
+bool anofox_ts_classify_demand(const double *values,
+                               size_t length,
+                               struct DemandClassificationFFI *out_result,
+                               struct AnofoxError *out_error);
+
 /**
  * Compute differences of a time series.
  *
@@ -2497,6 +3906,88 @@ bool anofox_ts_diff(const double *values,
                     size_t *out_length,
                     struct AnofoxError *out_error);
 
+/**
+ * Invert differencing by cumulatively summing back up `order` times.
+ *
+ * `initial` holds `order` seed values: the first value of the series
+ * differenced 0, 1, ..., `order - 1` times.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_undiff(const double *diffed,
+                      size_t diffed_length,
+                      const double *initial,
+                      size_t initial_length,
+                      int order,
+                      double **out_values,
+                      size_t *out_length,
+                      struct AnofoxError *out_error);
+
+/**
+ * Compute cheap pre-flight flags for a series.
+ *
+ * Lets callers skip degenerate series (all NULL, constant, or shorter than
+ * `min_length` non-NULL values) before running a model fit.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null (`validity` may be null).
+ */
+bool anofox_ts_series_flags(const double *values,
+                            const uint64_t *validity,
+                            size_t length,
+                            size_t min_length,
+                            struct SeriesFlags *out_result,
+                            struct AnofoxError *out_error);
+
+/**
+ * Drop leading and trailing zeros from a series.
+ *
+ * NULLs are kept, so a series that starts with NULLs is not trimmed. The
+ * number of removed positions on each side is reported so callers can
+ * realign timestamps.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null (`validity` may be null).
+ * Free the result with `anofox_free_trimmed_series_result`.
+ */
+bool anofox_ts_drop_edge_zeros(const double *values,
+                               const uint64_t *validity,
+                               size_t length,
+                               struct TrimmedSeriesResult *out_result,
+                               struct AnofoxError *out_error);
+
+/**
+ * Box-Cox transform a series.
+ *
+ * Pass NaN as `lambda` to estimate it; the lambda used is written to
+ * `out_lambda` so forecasts can be back-transformed with
+ * `anofox_ts_inv_box_cox`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_box_cox(const double *values,
+                       size_t length,
+                       double lambda,
+                       double **out_values,
+                       size_t *out_length,
+                       double *out_lambda,
+                       struct AnofoxError *out_error);
+
+/**
+ * Invert a Box-Cox transform (e.g. on point forecasts or interval bounds).
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_inv_box_cox(const double *values,
+                           size_t length,
+                           double lambda,
+                           double **out_values,
+                           size_t *out_length,
+                           struct AnofoxError *out_error);
+
 /**
  * Fill gaps in a time series with new timestamps.
  *
@@ -2560,6 +4051,29 @@ bool anofox_ts_detect_frequency(const int64_t *dates,
                                 int64_t *out_frequency,
                                 struct AnofoxError *out_error);
 
+/**
+ * Detect the frequency of a time series together with a label
+ * ("hourly", "daily", "weekly", "monthly", ...).
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_detect_frequency_label(const int64_t *dates,
+                                      size_t length,
+                                      struct FrequencyLabelResult *out_result,
+                                      struct AnofoxError *out_error);
+
+/**
+ * Detect the frequency of a time series and report how regular its spacing is.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_detect_frequency_report(const int64_t *dates,
+                                       size_t length,
+                                       struct FrequencyReportResult *out_result,
+                                       struct AnofoxError *out_error);
+
 /**
  * Fill NULL values with forward fill.
  *
@@ -2596,6 +4110,107 @@ bool anofox_ts_fill_nulls_interpolate(const double *values,
                                       double **out_values,
                                       struct AnofoxError *out_error);
 
+/**
+ * Fill NULL values by interpolating the seasonally adjusted series.
+ *
+ * Falls back to linear interpolation when `period < 2` or fewer than two
+ * periods of observations are available.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_fill_nulls_seasonal(const double *values,
+                                   const uint64_t *validity,
+                                   size_t length,
+                                   int period,
+                                   double **out_values,
+                                   struct AnofoxError *out_error);
+
+/**
+ * Fill NULL values with natural cubic spline interpolation.
+ *
+ * Leading and trailing NULLs are held flat; fewer than four observed values
+ * fall back to linear interpolation.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_fill_nulls_spline(const double *values,
+                                 const uint64_t *validity,
+                                 size_t length,
+                                 double **out_values,
+                                 struct AnofoxError *out_error);
+
+/**
+ * Rolling-window statistic over a trailing window.
+ *
+ * NULLs are skipped; positions whose window holds fewer than `min_periods`
+ * observations are NaN.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_rolling(const double *values,
+                       const uint64_t *validity,
+                       size_t length,
+                       size_t window,
+                       size_t min_periods,
+                       enum RollingAggFFI agg,
+                       double **out_values,
+                       struct AnofoxError *out_error);
+
+/**
+ * Build holiday/event indicator columns aligned to the series dates.
+ *
+ * Produces `window_before + window_after + 1` columns, one per observation
+ * offset relative to each event. Events not present in `dates` are ignored.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_event_indicators(const int64_t *dates,
+                                size_t dates_length,
+                                const int64_t *event_dates,
+                                size_t event_dates_length,
+                                int64_t window_before,
+                                int64_t window_after,
+                                struct RegressorMatrixResult *out_result,
+                                struct AnofoxError *out_error);
+
+/**
+ * Build a single event dummy column aligned to the series dates.
+ *
+ * Flags every date within `window_days` days of any event, whether or not the
+ * event itself is one of `dates`. Produces one column.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null. Arrays must have the specified lengths.
+ */
+bool anofox_ts_event_dummies(const int64_t *dates,
+                             size_t dates_length,
+                             const int64_t *event_dates,
+                             size_t event_dates_length,
+                             int64_t window_days,
+                             struct RegressorMatrixResult *out_result,
+                             struct AnofoxError *out_error);
+
+/**
+ * Build Fourier seasonality regressors for the history and the forecast horizon.
+ *
+ * Both matrices have `2 * k` sine/cosine columns; `out_historical` covers
+ * `t = 0..n` and `out_future` continues with `t = n..n + horizon`.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null.
+ */
+bool anofox_ts_fourier_terms(size_t n,
+                             size_t horizon,
+                             double period,
+                             size_t k,
+                             struct RegressorMatrixResult *out_historical,
+                             struct RegressorMatrixResult *out_future,
+                             struct AnofoxError *out_error);
+
 /**
  * Compute the conformity score (quantile) from calibration residuals.
  *
@@ -2682,7 +4297,9 @@ bool anofox_ts_conformal_predict_adaptive(const double *residuals,
 /**
  * Perform asymmetric conformal prediction.
  *
- * Uses separate quantiles for positive and negative residuals.
+ * Uses separate quantiles for positive and negative residuals. The per-side
+ * margins are `point - lower` and `upper - point`; `conformity_score` holds
+ * their average.
  *
  * # Safety
  * All pointer arguments must be valid and non-null.
@@ -2763,6 +4380,23 @@ bool anofox_ts_conformal_coverage(const double *actuals,
                                   double *out_coverage,
                                   struct AnofoxError *out_error);
 
+/**
+ * Compute the mean Winkler (interval) score of prediction intervals.
+ *
+ * Lower is better: the interval width plus a `2 / alpha` penalty per unit
+ * an actual falls outside its interval.
+ *
+ * # Safety
+ * All pointer arguments must be valid and non-null.
+ */
+bool anofox_ts_winkler_score(const double *actuals,
+                             const double *lower,
+                             const double *upper,
+                             size_t length,
+                             double alpha,
+                             double *out_score,
+                             struct AnofoxError *out_error);
+
 /**
  * Compute comprehensive conformal evaluation metrics.
  *
@@ -2861,6 +4495,22 @@ void anofox_free_conformal_per_step_result(struct ConformalPerStepResultFFI *res
  */
 void anofox_free_ts_stats_result(struct TsStatsResult *_result);
 
+/**
+ * Free an AnomalyDetectionResult.
+ *
+ * # Safety
+ * The result pointer must be valid or null.
+ */
+void anofox_free_anomaly_result(struct AnomalyDetectionResult *result);
+
+/**
+ * Free a TsStatsRollingResult.
+ *
+ * # Safety
+ * The result pointer must be valid or null.
+ */
+void anofox_free_ts_stats_rolling_result(struct TsStatsRollingResult *result);
+
 /**
  * Free a GapFillResult.
  *
@@ -2869,6 +4519,14 @@ void anofox_free_ts_stats_result(struct TsStatsResult *_result);
  */
 void anofox_free_gap_fill_result(struct GapFillResult *result);
 
+/**
+ * Free a TrimmedSeriesResult.
+ *
+ * # Safety
+ * The result pointer must be valid or null.
+ */
+void anofox_free_trimmed_series_result(struct TrimmedSeriesResult *result);
+
 /**
  * Free a FilledValuesResult.
  *
@@ -2877,6 +4535,54 @@ void anofox_free_gap_fill_result(struct GapFillResult *result);
  */
 void anofox_free_filled_values_result(struct FilledValuesResult *result);
 
+/**
+ * Free a FilledMaskResult.
+ *
+ * # Safety
+ * The result pointer must be valid or null.
+ */
+void anofox_free_filled_mask_result(struct FilledMaskResult *result);
+
+/**
+ * Free a RegressorMatrixResult.
+ *
+ * # Safety
+ * The result pointer must be valid or null.
+ */
+void anofox_free_regressor_matrix_result(struct RegressorMatrixResult *result);
+
+/**
+ * Free a QualityReportResult.
+ *
+ * # Safety
+ * The result pointer must be valid or null.
+ */
+void anofox_free_quality_report_result(struct QualityReportResult *result);
+
+/**
+ * Free a HorizonMetricsResult.
+ *
+ * # Safety
+ * The result pointer must be valid or null.
+ */
+void anofox_free_horizon_metrics_result(struct HorizonMetricsResult *result);
+
+/**
+ * Free a CrossValResultFFI.
+ *
+ * # Safety
+ * The result pointer must be valid or null.
+ */
+void anofox_free_cross_val_result(struct CrossValResultFFI *result);
+
+/**
+ * Free a ForecastBatchResult.
+ *
+ * # Safety
+ * The pointer must be valid or null.
+ */
+void anofox_free_forecast_batch_result(struct ForecastBatchResult *result);
+
 /**
  * Free a ForecastResult.
  *
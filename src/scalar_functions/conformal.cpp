@@ -564,6 +564,8 @@ static ConformalMethodFFI ParseConformalMethod(const string &method_str) {
         return ASYMMETRIC;
     } else if (method_str == "adaptive" || method_str == "Adaptive") {
         return ADAPTIVE;
+    } else if (method_str == "cqr" || method_str == "CQR" || method_str == "Cqr") {
+        return CQR;
     }
     return SYMMETRIC;  // default
 }
@@ -584,6 +586,7 @@ static const char* MethodToString(ConformalMethodFFI method) {
         case SYMMETRIC: return "symmetric";
         case ASYMMETRIC: return "asymmetric";
         case ADAPTIVE: return "adaptive";
+        case CQR: return "cqr";
     }
     return "symmetric";
 }
@@ -80,6 +80,7 @@ static void TsDetectChangepointsFunction(DataChunk &args, ExpressionState &state
             values.size(),
             2,    // min_size
             0.0,  // penalty = auto
+            0,    // cost_function = L2
             &cp_result,
             &error
         );
@@ -173,6 +174,7 @@ static void TsDetectChangepointsWithParamsFunction(DataChunk &args, ExpressionSt
             values.size(),
             min_size,
             penalty,
+            0,  // cost_function = L2
             &cp_result,
             &error
         );
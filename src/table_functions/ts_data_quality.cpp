@@ -82,6 +82,7 @@ static void TsDataQualityFunction(DataChunk &args, ExpressionState &state, Vecto
             values.data(),
             validity.empty() ? nullptr : validity.data(),
             values.size(),
+            nullptr,
             &dq_result,
             &error
         );
@@ -82,6 +82,8 @@ static void TsMstlDecompositionFunction(DataChunk &args, ExpressionState &state,
             nullptr,  // periods - auto detect
             0,
             insufficient_data_mode,
+            false,  // additive
+            false,  // non-robust
             &mstl_result,
             &error
         );
@@ -296,6 +296,8 @@ static OperatorFinalizeResultType TsMstlDecompositionNativeFinalize(
                 nullptr,  // periods - auto detect
                 0,
                 bind_data.insufficient_data_mode,
+                false,  // additive
+                false,  // non-robust
                 &mstl_result,
                 &error
             );
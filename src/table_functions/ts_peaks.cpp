@@ -5,6 +5,7 @@
 #include "duckdb/function/scalar_function.hpp"
 #include "duckdb/parser/parsed_data/create_scalar_function_info.hpp"
 #include "duckdb/common/types/vector.hpp"
+#include <limits>
 
 namespace duckdb {
 
@@ -90,6 +91,7 @@ static void TsDetectPeaksFunction(DataChunk &args, ExpressionState &state, Vecto
             values.size(),
             min_distance,
             min_prominence,
+            std::numeric_limits<double>::quiet_NaN(),  // min_height = keep all
             smooth_first,
             &peak_result,
             &error
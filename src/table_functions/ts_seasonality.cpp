@@ -168,6 +168,7 @@ static void TsAnalyzeSeasonalityWithTimestampsFunction(DataChunk &args, Expressi
             values.data(),
             values.size(),
             0,              // max_period = auto
+            nullptr,        // strength_method = default
             &seas_result,
             &error
         );
@@ -233,6 +234,7 @@ static void TsAnalyzeSeasonalityFunction(DataChunk &args, ExpressionState &state
             values.data(),
             values.size(),
             0,              // max_period = auto
+            nullptr,        // strength_method = default
             &seas_result,
             &error
         );